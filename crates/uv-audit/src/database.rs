@@ -0,0 +1,275 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use rustc_hash::FxHashMap;
+use tracing::debug;
+
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+use crate::osv::Advisory;
+
+#[derive(Debug, thiserror::Error)]
+pub enum DatabaseError {
+    #[error("Advisory database not found at: `{}`", _0.user_display())]
+    NotFound(PathBuf),
+    #[error(
+        "Unsupported advisory database format: `{}` (expected a directory or a `.zip` archive)",
+        _0.user_display()
+    )]
+    UnsupportedFormat(PathBuf),
+    #[error("Failed to read advisory database at: `{}`", _0.user_display())]
+    Io(PathBuf, #[source] std::io::Error),
+    #[error("Failed to read advisory database archive: `{}`", _0.user_display())]
+    Zip(PathBuf, #[source] zip::result::ZipError),
+    #[error("Failed to walk advisory database directory: `{}`", _0.user_display())]
+    WalkDir(PathBuf, #[source] walkdir::Error),
+    #[error("Failed to parse advisory: `{0}`")]
+    Json(String, #[source] serde_json::Error),
+}
+
+/// An offline snapshot of an OSV advisory database, indexed by package name.
+///
+/// The database can be read from a directory of OSV JSON records (e.g., an unpacked export of
+/// <https://osv-vulnerabilities.storage.googleapis.com/PyPI/all.zip>) or directly from the
+/// `.zip` archive.
+#[derive(Debug, Default)]
+pub struct AdvisoryDatabase {
+    advisories: Vec<Advisory>,
+    by_package: FxHashMap<PackageName, Vec<usize>>,
+}
+
+impl AdvisoryDatabase {
+    /// Read an [`AdvisoryDatabase`] from a directory or `.zip` archive.
+    pub fn from_path(path: &Path) -> Result<Self, DatabaseError> {
+        let metadata = match fs_err::metadata(path) {
+            Ok(metadata) => metadata,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(DatabaseError::NotFound(path.to_path_buf()));
+            }
+            Err(err) => return Err(DatabaseError::Io(path.to_path_buf(), err)),
+        };
+
+        let mut database = Self::default();
+        if metadata.is_dir() {
+            database.read_directory(path)?;
+        } else if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("zip"))
+        {
+            database.read_zip(path)?;
+        } else {
+            return Err(DatabaseError::UnsupportedFormat(path.to_path_buf()));
+        }
+
+        debug!(
+            "Loaded {} advisories for {} packages from: `{}`",
+            database.advisories.len(),
+            database.by_package.len(),
+            path.user_display()
+        );
+
+        Ok(database)
+    }
+
+    /// Read all OSV records from a directory, recursively.
+    fn read_directory(&mut self, root: &Path) -> Result<(), DatabaseError> {
+        let mut paths = Vec::new();
+        for entry in walkdir::WalkDir::new(root) {
+            let entry = entry.map_err(|err| DatabaseError::WalkDir(root.to_path_buf(), err))?;
+            if entry.file_type().is_file()
+                && entry
+                    .path()
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+            {
+                paths.push(entry.into_path());
+            }
+        }
+
+        // Sort the paths to ensure a deterministic ordering.
+        paths.sort();
+
+        for path in paths {
+            let contents =
+                fs_err::read(&path).map_err(|err| DatabaseError::Io(path.clone(), err))?;
+            self.insert(&contents, &path.user_display().to_string())?;
+        }
+
+        Ok(())
+    }
+
+    /// Read all OSV records from a `.zip` archive.
+    fn read_zip(&mut self, path: &Path) -> Result<(), DatabaseError> {
+        let file =
+            fs_err::File::open(path).map_err(|err| DatabaseError::Io(path.to_path_buf(), err))?;
+        let mut archive = zip::ZipArchive::new(file)
+            .map_err(|err| DatabaseError::Zip(path.to_path_buf(), err))?;
+
+        let mut names = archive
+            .file_names()
+            .filter(|name| {
+                Path::new(name)
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+            })
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        names.sort();
+
+        let mut contents = Vec::new();
+        for name in names {
+            let mut entry = archive
+                .by_name(&name)
+                .map_err(|err| DatabaseError::Zip(path.to_path_buf(), err))?;
+            contents.clear();
+            entry
+                .read_to_end(&mut contents)
+                .map_err(|err| DatabaseError::Io(path.to_path_buf(), err))?;
+            self.insert(&contents, &name)?;
+        }
+
+        Ok(())
+    }
+
+    /// Parse and index a single OSV record.
+    fn insert(&mut self, contents: &[u8], source: &str) -> Result<(), DatabaseError> {
+        let advisory = serde_json::from_slice::<Advisory>(contents)
+            .map_err(|err| DatabaseError::Json(source.to_string(), err))?;
+
+        if advisory.is_withdrawn() {
+            debug!("Skipping withdrawn advisory: {}", advisory.id);
+            return Ok(());
+        }
+
+        let index = self.advisories.len();
+        let mut packages = advisory.packages().collect::<Vec<_>>();
+        if packages.is_empty() {
+            return Ok(());
+        }
+        packages.sort();
+        packages.dedup();
+        for package in packages {
+            self.by_package.entry(package).or_default().push(index);
+        }
+        self.advisories.push(advisory);
+
+        Ok(())
+    }
+
+    /// Returns the number of advisories in the database.
+    pub fn len(&self) -> usize {
+        self.advisories.len()
+    }
+
+    /// Returns `true` if the database contains no advisories.
+    pub fn is_empty(&self) -> bool {
+        self.advisories.is_empty()
+    }
+
+    /// Returns the advisories that affect the given package version.
+    pub fn query(&self, name: &PackageName, version: &Version) -> Vec<&Advisory> {
+        self.by_package
+            .get(name)
+            .into_iter()
+            .flatten()
+            .map(|index| &self.advisories[*index])
+            .filter(|advisory| advisory.affects(name, version))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::str::FromStr;
+
+    use uv_normalize::PackageName;
+    use uv_pep440::Version;
+
+    use super::AdvisoryDatabase;
+
+    const JINJA2: &str = r#"{
+        "id": "PYSEC-2021-66",
+        "affected": [{
+            "package": {"ecosystem": "PyPI", "name": "jinja2"},
+            "ranges": [{"type": "ECOSYSTEM", "events": [{"introduced": "0"}, {"fixed": "2.11.3"}]}]
+        }]
+    }"#;
+
+    const WITHDRAWN: &str = r#"{
+        "id": "PYSEC-2021-67",
+        "withdrawn": "2021-06-01T00:00:00Z",
+        "affected": [{
+            "package": {"ecosystem": "PyPI", "name": "jinja2"},
+            "ranges": [{"type": "ECOSYSTEM", "events": [{"introduced": "0"}]}]
+        }]
+    }"#;
+
+    const NPM: &str = r#"{
+        "id": "GHSA-0000-0000-0000",
+        "affected": [{
+            "package": {"ecosystem": "npm", "name": "jinja2"},
+            "ranges": [{"type": "SEMVER", "events": [{"introduced": "0"}]}]
+        }]
+    }"#;
+
+    #[test]
+    fn directory() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::create_dir(temp_dir.path().join("PyPI")).unwrap();
+        fs_err::write(temp_dir.path().join("PyPI/PYSEC-2021-66.json"), JINJA2).unwrap();
+        fs_err::write(temp_dir.path().join("PyPI/PYSEC-2021-67.json"), WITHDRAWN).unwrap();
+        fs_err::write(temp_dir.path().join("GHSA-0000-0000-0000.json"), NPM).unwrap();
+        fs_err::write(temp_dir.path().join("README.md"), "# Advisories").unwrap();
+
+        let database = AdvisoryDatabase::from_path(temp_dir.path()).unwrap();
+        assert_eq!(database.len(), 1);
+
+        let name = PackageName::from_str("jinja2").unwrap();
+        let ids = database
+            .query(&name, &Version::from_str("2.11.2").unwrap())
+            .into_iter()
+            .map(|advisory| advisory.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["PYSEC-2021-66"]);
+
+        let ids = database.query(&name, &Version::from_str("2.11.3").unwrap());
+        assert!(ids.is_empty());
+    }
+
+    #[test]
+    fn zip() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("all.zip");
+        let mut writer = zip::ZipWriter::new(fs_err::File::create(&path).unwrap());
+        writer
+            .start_file(
+                "PYSEC-2021-66.json",
+                zip::write::SimpleFileOptions::default(),
+            )
+            .unwrap();
+        writer.write_all(JINJA2.as_bytes()).unwrap();
+        writer
+            .start_file(
+                "GHSA-0000-0000-0000.json",
+                zip::write::SimpleFileOptions::default(),
+            )
+            .unwrap();
+        writer.write_all(NPM.as_bytes()).unwrap();
+        writer.finish().unwrap();
+
+        let database = AdvisoryDatabase::from_path(&path).unwrap();
+        assert_eq!(database.len(), 1);
+    }
+
+    #[test]
+    fn unsupported() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("advisories.tar.gz");
+        fs_err::write(&path, "").unwrap();
+        let err = AdvisoryDatabase::from_path(&path).unwrap_err();
+        assert!(matches!(err, super::DatabaseError::UnsupportedFormat(_)));
+    }
+}
@@ -0,0 +1,63 @@
+//! Offline vulnerability auditing of locked packages against an OSV advisory database.
+
+use uv_normalize::PackageName;
+
+pub use crate::database::{AdvisoryDatabase, DatabaseError};
+pub use crate::osv::Advisory;
+pub use crate::report::{AuditReport, AuditedPackage, Finding, FindingAdvisory};
+
+mod database;
+mod osv;
+mod report;
+
+/// The advisories and packages to exclude from an audit.
+#[derive(Debug, Clone, Default)]
+pub struct AuditIgnore {
+    /// Advisory identifiers (or aliases, like CVE identifiers) to ignore.
+    ids: Vec<String>,
+    /// Packages for which all advisories should be ignored.
+    packages: Vec<PackageName>,
+}
+
+impl AuditIgnore {
+    /// Create a new [`AuditIgnore`] from the given advisory identifiers and package names.
+    pub fn new(ids: Vec<String>, packages: Vec<PackageName>) -> Self {
+        Self { ids, packages }
+    }
+
+    /// Returns `true` if the advisory should be ignored for the given package.
+    fn contains(&self, name: &PackageName, advisory: &Advisory) -> bool {
+        self.packages.contains(name) || self.ids.iter().any(|id| advisory.is_identified_by(id))
+    }
+}
+
+/// Audit the given packages against the [`AdvisoryDatabase`].
+pub fn audit(
+    database: &AdvisoryDatabase,
+    packages: impl IntoIterator<Item = AuditedPackage>,
+    ignore: &AuditIgnore,
+) -> AuditReport {
+    let mut report = AuditReport::default();
+
+    for package in packages {
+        report.packages += 1;
+
+        for advisory in database.query(&package.name, &package.version) {
+            let finding = Finding {
+                name: package.name.clone(),
+                version: package.version.clone(),
+                marker: package.marker.clone(),
+                reachable: package.reachable,
+                advisory: FindingAdvisory::new(advisory, &package.name),
+                line: package.line,
+            };
+            if ignore.contains(&package.name, advisory) {
+                report.ignored.push(finding);
+            } else {
+                report.findings.push(finding);
+            }
+        }
+    }
+
+    report
+}
@@ -0,0 +1,355 @@
+//! A subset of the [Open Source Vulnerability (OSV)](https://ossf.github.io/osv-schema/) schema,
+//! limited to the fields required to match advisories against Python packages.
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+/// The OSV ecosystem identifier for packages hosted on PyPI.
+const PYPI_ECOSYSTEM: &str = "PyPI";
+
+/// A single OSV vulnerability record.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Advisory {
+    /// The unique identifier of the advisory (e.g., `PYSEC-2021-66` or `GHSA-g3rq-g295-4j3m`).
+    pub id: String,
+    /// Other identifiers for the same vulnerability (e.g., CVE identifiers).
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// A one-line summary of the vulnerability.
+    #[serde(default)]
+    pub summary: Option<String>,
+    /// A longer description of the vulnerability.
+    #[serde(default)]
+    pub details: Option<String>,
+    /// The time at which the advisory was withdrawn, if any.
+    #[serde(default)]
+    pub withdrawn: Option<String>,
+    /// The severity scores attached to the advisory.
+    #[serde(default)]
+    pub severity: Vec<Severity>,
+    /// The packages and versions affected by the vulnerability.
+    #[serde(default)]
+    pub affected: Vec<Affected>,
+    /// Links to further information about the vulnerability.
+    #[serde(default)]
+    pub references: Vec<Reference>,
+    /// Database-specific metadata, e.g., the GitHub Advisory Database severity label.
+    #[serde(default)]
+    pub database_specific: Option<DatabaseSpecific>,
+}
+
+impl Advisory {
+    /// Returns `true` if the advisory has been withdrawn by its publisher.
+    pub fn is_withdrawn(&self) -> bool {
+        self.withdrawn.is_some()
+    }
+
+    /// Returns `true` if the advisory is known by the given identifier, either as its primary
+    /// identifier or as an alias.
+    pub fn is_identified_by(&self, id: &str) -> bool {
+        self.id.eq_ignore_ascii_case(id)
+            || self
+                .aliases
+                .iter()
+                .any(|alias| alias.eq_ignore_ascii_case(id))
+    }
+
+    /// Returns the names of all PyPI packages referenced by the advisory.
+    pub fn packages(&self) -> impl Iterator<Item = PackageName> + '_ {
+        self.affected.iter().filter_map(Affected::pypi_name)
+    }
+
+    /// Returns `true` if the given package version is affected by the advisory.
+    pub fn affects(&self, name: &PackageName, version: &Version) -> bool {
+        self.affected.iter().any(|affected| {
+            affected
+                .pypi_name()
+                .is_some_and(|affected_name| affected_name == *name)
+                && affected.contains(version)
+        })
+    }
+
+    /// Returns the versions in which the advisory is fixed for the given package, in ascending
+    /// order.
+    pub fn fixed_versions(&self, name: &PackageName) -> Vec<Version> {
+        let mut fixed = self
+            .affected
+            .iter()
+            .filter(|affected| {
+                affected
+                    .pypi_name()
+                    .is_some_and(|affected_name| affected_name == *name)
+            })
+            .flat_map(|affected| &affected.ranges)
+            .filter(|range| range.kind == RangeKind::Ecosystem)
+            .flat_map(|range| &range.events)
+            .filter_map(|event| match event {
+                Event::Fixed(version) => Version::from_str(version).ok(),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        fixed.sort();
+        fixed.dedup();
+        fixed
+    }
+
+    /// Returns the human-readable severity label of the advisory, if known.
+    pub fn severity_label(&self) -> Option<&str> {
+        self.database_specific
+            .as_ref()
+            .and_then(|database_specific| database_specific.severity.as_deref())
+    }
+
+    /// Returns the primary URL with more information about the advisory, if any.
+    pub fn url(&self) -> Option<&str> {
+        self.references
+            .iter()
+            .find(|reference| reference.kind == "ADVISORY")
+            .or_else(|| self.references.first())
+            .map(|reference| reference.url.as_str())
+    }
+}
+
+/// A severity score attached to an advisory.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Severity {
+    /// The scoring system (e.g., `CVSS_V3`).
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// The score, typically a CVSS vector string.
+    pub score: String,
+}
+
+/// A reference to further information about an advisory.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Reference {
+    /// The type of reference (e.g., `ADVISORY`, `WEB`, or `FIX`).
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// The URL of the reference.
+    pub url: String,
+}
+
+/// Database-specific metadata attached to an advisory.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DatabaseSpecific {
+    /// The severity label, as used by the GitHub Advisory Database (e.g., `HIGH`).
+    #[serde(default)]
+    pub severity: Option<String>,
+}
+
+/// A package affected by an advisory, along with the affected versions.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Affected {
+    /// The affected package.
+    pub package: Option<AffectedPackage>,
+    /// The ranges of affected versions.
+    #[serde(default)]
+    pub ranges: Vec<Range>,
+    /// An explicit enumeration of affected versions.
+    #[serde(default)]
+    pub versions: Vec<String>,
+}
+
+impl Affected {
+    /// Returns the normalized package name if the entry refers to a PyPI package.
+    fn pypi_name(&self) -> Option<PackageName> {
+        let package = self.package.as_ref()?;
+        if package.ecosystem != PYPI_ECOSYSTEM {
+            return None;
+        }
+        PackageName::from_str(&package.name).ok()
+    }
+
+    /// Returns `true` if the given version is affected.
+    fn contains(&self, version: &Version) -> bool {
+        if self
+            .versions
+            .iter()
+            .filter_map(|affected| Version::from_str(affected).ok())
+            .any(|affected| affected == *version)
+        {
+            return true;
+        }
+        self.ranges
+            .iter()
+            .filter(|range| range.kind == RangeKind::Ecosystem)
+            .any(|range| range.contains(version))
+    }
+}
+
+/// The package referenced by an [`Affected`] entry.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AffectedPackage {
+    /// The ecosystem of the package (e.g., `PyPI`).
+    pub ecosystem: String,
+    /// The name of the package, as published in the ecosystem.
+    pub name: String,
+}
+
+/// A range of affected versions.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Range {
+    /// The versioning scheme used by the range.
+    #[serde(rename = "type")]
+    pub kind: RangeKind,
+    /// The events that introduce or resolve the vulnerability.
+    #[serde(default)]
+    pub events: Vec<Event>,
+}
+
+impl Range {
+    /// Returns `true` if the given version falls within the range.
+    ///
+    /// Follows the evaluation algorithm described in the OSV schema: events are sorted by
+    /// version, and the version is affected if the last applicable event introduced the
+    /// vulnerability.
+    fn contains(&self, version: &Version) -> bool {
+        let mut events = self
+            .events
+            .iter()
+            .filter_map(|event| Some((event.version()?, event)))
+            .collect::<Vec<_>>();
+        events.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut affected = false;
+        for (bound, event) in events {
+            match event {
+                Event::Introduced(_) => {
+                    if *version >= bound {
+                        affected = true;
+                    }
+                }
+                Event::Fixed(_) | Event::Limit(_) => {
+                    if *version >= bound {
+                        affected = false;
+                    }
+                }
+                Event::LastAffected(_) => {
+                    if *version > bound {
+                        affected = false;
+                    }
+                }
+            }
+        }
+        affected
+    }
+}
+
+/// The versioning scheme used by a [`Range`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RangeKind {
+    /// Versions as defined by the package's ecosystem (i.e., PEP 440 for PyPI).
+    Ecosystem,
+    /// Semantic versions.
+    Semver,
+    /// Git commit hashes.
+    Git,
+    /// Any other (unsupported) scheme.
+    #[serde(other)]
+    Unknown,
+}
+
+/// An event within a [`Range`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Event {
+    Introduced(String),
+    Fixed(String),
+    LastAffected(String),
+    Limit(String),
+}
+
+impl Event {
+    /// Parse the version associated with the event.
+    ///
+    /// The special version `0` in an `introduced` event denotes the lowest possible version.
+    fn version(&self) -> Option<Version> {
+        match self {
+            Self::Introduced(version) if version == "0" => Some(Version::new([0])),
+            Self::Introduced(version)
+            | Self::Fixed(version)
+            | Self::LastAffected(version)
+            | Self::Limit(version) => Version::from_str(version).ok(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_normalize::PackageName;
+    use uv_pep440::Version;
+
+    use super::Advisory;
+
+    fn advisory() -> Advisory {
+        serde_json::from_str(
+            r#"{
+                "id": "PYSEC-2021-66",
+                "aliases": ["CVE-2020-28493", "GHSA-g3rq-g295-4j3m"],
+                "summary": "ReDoS in Jinja2",
+                "affected": [{
+                    "package": {"ecosystem": "PyPI", "name": "Jinja2"},
+                    "ranges": [
+                        {"type": "ECOSYSTEM", "events": [{"introduced": "0"}, {"fixed": "2.11.3"}]},
+                        {"type": "ECOSYSTEM", "events": [{"introduced": "3.0.0a1"}, {"last_affected": "3.0.0rc2"}]},
+                        {"type": "GIT", "repo": "https://github.com/pallets/jinja", "events": [{"introduced": "0"}]}
+                    ],
+                    "versions": ["3.1.0"]
+                }]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn affects() {
+        let advisory = advisory();
+        let name = PackageName::from_str("jinja2").unwrap();
+
+        for (version, expected) in [
+            ("2.10", true),
+            ("2.11.2", true),
+            ("2.11.3", false),
+            ("2.12", false),
+            ("3.0.0a1", true),
+            ("3.0.0rc2", true),
+            ("3.0.0", false),
+            ("3.1.0", true),
+        ] {
+            assert_eq!(
+                advisory.affects(&name, &Version::from_str(version).unwrap()),
+                expected,
+                "{version}"
+            );
+        }
+
+        let other = PackageName::from_str("markupsafe").unwrap();
+        assert!(!advisory.affects(&other, &Version::from_str("2.10").unwrap()));
+    }
+
+    #[test]
+    fn identifiers() {
+        let advisory = advisory();
+        assert!(advisory.is_identified_by("PYSEC-2021-66"));
+        assert!(advisory.is_identified_by("cve-2020-28493"));
+        assert!(!advisory.is_identified_by("CVE-2020-28494"));
+    }
+
+    #[test]
+    fn fixed_versions() {
+        let advisory = advisory();
+        let name = PackageName::from_str("jinja2").unwrap();
+        assert_eq!(
+            advisory.fixed_versions(&name),
+            vec![Version::from_str("2.11.3").unwrap()]
+        );
+    }
+}
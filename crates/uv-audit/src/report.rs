@@ -0,0 +1,285 @@
+use std::fmt::Write;
+
+use serde::Serialize;
+
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+use crate::osv::{Advisory, Severity};
+
+/// A locked package to audit.
+#[derive(Debug, Clone)]
+pub struct AuditedPackage {
+    /// The name of the package.
+    pub name: PackageName,
+    /// The locked version of the package.
+    pub version: Version,
+    /// The marker under which the package is installed, if it isn't always installed.
+    pub marker: Option<String>,
+    /// Whether the package is reachable on any of the targeted environments.
+    pub reachable: bool,
+    /// The (one-based) line at which the package is defined in the lockfile, if known.
+    pub line: Option<usize>,
+}
+
+/// A known vulnerability affecting a locked package.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Finding {
+    /// The name of the vulnerable package.
+    pub name: PackageName,
+    /// The locked version of the vulnerable package.
+    pub version: Version,
+    /// The marker under which the package is installed, if it isn't always installed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub marker: Option<String>,
+    /// Whether the package is reachable on any of the targeted environments.
+    pub reachable: bool,
+    /// The advisory describing the vulnerability.
+    pub advisory: FindingAdvisory,
+    #[serde(skip)]
+    pub line: Option<usize>,
+}
+
+/// The subset of an [`Advisory`] that is included in audit reports.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct FindingAdvisory {
+    pub id: String,
+    pub aliases: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub scores: Vec<Severity>,
+    pub fixed_versions: Vec<Version>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip)]
+    pub details: Option<String>,
+}
+
+impl FindingAdvisory {
+    pub(crate) fn new(advisory: &Advisory, name: &PackageName) -> Self {
+        Self {
+            id: advisory.id.clone(),
+            aliases: advisory.aliases.clone(),
+            summary: advisory.summary.clone(),
+            severity: advisory.severity_label().map(ToString::to_string),
+            scores: advisory.severity.clone(),
+            fixed_versions: advisory.fixed_versions(name),
+            url: advisory.url().map(ToString::to_string),
+            details: advisory.details.clone(),
+        }
+    }
+}
+
+/// The result of auditing a set of locked packages against an advisory database.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct AuditReport {
+    /// The number of packages that were audited.
+    pub packages: usize,
+    /// The vulnerabilities found in the audited packages.
+    pub findings: Vec<Finding>,
+    /// The vulnerabilities that were found, but ignored by the user.
+    pub ignored: Vec<Finding>,
+}
+
+impl AuditReport {
+    /// Returns the findings that affect packages reachable on the targeted environments.
+    pub fn reachable(&self) -> impl Iterator<Item = &Finding> {
+        self.findings.iter().filter(|finding| finding.reachable)
+    }
+
+    /// Returns the findings that only affect packages outside the targeted environments.
+    pub fn unreachable(&self) -> impl Iterator<Item = &Finding> {
+        self.findings.iter().filter(|finding| !finding.reachable)
+    }
+
+    /// Returns `true` if any vulnerability affects a package on the targeted environments.
+    pub fn has_vulnerabilities(&self) -> bool {
+        self.reachable().next().is_some()
+    }
+
+    /// Serialize the report as JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Serialize the report in the [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html)
+    /// format, as consumed by code scanning services.
+    ///
+    /// Results are attributed to the given lockfile URI.
+    pub fn to_sarif(&self, lockfile: &str) -> Result<String, serde_json::Error> {
+        let mut rules: Vec<sarif::Rule> = Vec::new();
+        let mut results = Vec::new();
+
+        for finding in &self.findings {
+            let rule_index =
+                if let Some(index) = rules.iter().position(|rule| rule.id == finding.advisory.id) {
+                    index
+                } else {
+                    rules.push(sarif::Rule::from(&finding.advisory));
+                    rules.len() - 1
+                };
+
+            let mut message = format!(
+                "{} {} is affected by {}",
+                finding.name, finding.version, finding.advisory.id
+            );
+            if let Some(summary) = &finding.advisory.summary {
+                message.push_str(": ");
+                message.push_str(summary);
+            }
+            if !finding.advisory.fixed_versions.is_empty() {
+                let _ = write!(
+                    message,
+                    " (fixed in: {})",
+                    finding
+                        .advisory
+                        .fixed_versions
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+            }
+            if let Some(marker) = &finding.marker {
+                let _ = write!(message, " [installed when: {marker}]");
+            }
+
+            results.push(sarif::Result {
+                rule_id: finding.advisory.id.clone(),
+                rule_index,
+                level: if finding.reachable { "error" } else { "note" },
+                message: sarif::Message { text: message },
+                locations: vec![sarif::Location {
+                    physical_location: sarif::PhysicalLocation {
+                        artifact_location: sarif::ArtifactLocation {
+                            uri: lockfile.to_string(),
+                        },
+                        region: finding.line.map(|start_line| sarif::Region { start_line }),
+                    },
+                }],
+            });
+        }
+
+        let log = sarif::Log {
+            schema: "https://json.schemastore.org/sarif-2.1.0.json",
+            version: "2.1.0",
+            runs: vec![sarif::Run {
+                tool: sarif::Tool {
+                    driver: sarif::Driver {
+                        name: "uv",
+                        information_uri: "https://docs.astral.sh/uv/",
+                        version: uv_version::version(),
+                        rules,
+                    },
+                },
+                results,
+            }],
+        };
+
+        serde_json::to_string_pretty(&log)
+    }
+}
+
+/// A minimal model of the SARIF 2.1.0 log format.
+mod sarif {
+    use serde::Serialize;
+
+    use crate::report::FindingAdvisory;
+
+    #[derive(Debug, Serialize)]
+    pub(super) struct Log {
+        #[serde(rename = "$schema")]
+        pub(super) schema: &'static str,
+        pub(super) version: &'static str,
+        pub(super) runs: Vec<Run>,
+    }
+
+    #[derive(Debug, Serialize)]
+    pub(super) struct Run {
+        pub(super) tool: Tool,
+        pub(super) results: Vec<Result>,
+    }
+
+    #[derive(Debug, Serialize)]
+    pub(super) struct Tool {
+        pub(super) driver: Driver,
+    }
+
+    #[derive(Debug, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub(super) struct Driver {
+        pub(super) name: &'static str,
+        pub(super) information_uri: &'static str,
+        pub(super) version: &'static str,
+        pub(super) rules: Vec<Rule>,
+    }
+
+    #[derive(Debug, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub(super) struct Rule {
+        pub(super) id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub(super) short_description: Option<Message>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub(super) full_description: Option<Message>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub(super) help_uri: Option<String>,
+    }
+
+    impl From<&FindingAdvisory> for Rule {
+        fn from(advisory: &FindingAdvisory) -> Self {
+            Self {
+                id: advisory.id.clone(),
+                short_description: advisory.summary.clone().map(|text| Message { text }),
+                full_description: advisory.details.clone().map(|text| Message { text }),
+                help_uri: advisory.url.clone(),
+            }
+        }
+    }
+
+    #[derive(Debug, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub(super) struct Result {
+        pub(super) rule_id: String,
+        pub(super) rule_index: usize,
+        pub(super) level: &'static str,
+        pub(super) message: Message,
+        pub(super) locations: Vec<Location>,
+    }
+
+    #[derive(Debug, Serialize)]
+    pub(super) struct Message {
+        pub(super) text: String,
+    }
+
+    #[derive(Debug, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub(super) struct Location {
+        pub(super) physical_location: PhysicalLocation,
+    }
+
+    #[derive(Debug, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub(super) struct PhysicalLocation {
+        pub(super) artifact_location: ArtifactLocation,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub(super) region: Option<Region>,
+    }
+
+    #[derive(Debug, Serialize)]
+    pub(super) struct ArtifactLocation {
+        pub(super) uri: String,
+    }
+
+    #[derive(Debug, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub(super) struct Region {
+        pub(super) start_line: usize,
+    }
+}
@@ -0,0 +1,555 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use serde::Deserialize;
+use url::Url;
+
+use uv_pep440::Version;
+
+use crate::{ArchiveFormat, Error};
+
+/// The definition of a binary tool, as provided in `[tool.uv.binaries]`.
+///
+/// For built-in binaries (i.e., `ruff`), every field is optional, and overrides the corresponding
+/// part of the built-in definition. Other binaries must provide a `version`, a `url`, and either
+/// `checksums` or a `checksum-url`, and can be run with `uv bin`. Their archives are never installed
+/// without verifying the checksum.
+///
+/// The `url`, `executable`, and `checksum-url` fields are templates, in which `{name}`,
+/// `{version}`, `{platform}` (e.g., `x86_64-unknown-linux-gnu`), and `{ext}` (e.g., `tar.gz`) are
+/// replaced with the corresponding values.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BinarySource {
+    /// The version to install, if no version is requested explicitly.
+    pub version: Option<String>,
+    /// A template for the URL of the archive containing the binary.
+    ///
+    /// Local archives can be referenced with the `file://` scheme.
+    pub url: Option<String>,
+    /// The format of the archive.
+    ///
+    /// Defaults to `zip` on Windows, and `tar.gz` on all other platforms.
+    pub archive_format: Option<ArchiveFormat>,
+    /// A template for the path of the executable within the archive.
+    ///
+    /// The platform's executable suffix (e.g., `.exe`) is appended if missing. Defaults to
+    /// `{name}` for `zip` archives and `{name}-{platform}/{name}` for `tar.gz` archives.
+    pub executable: Option<String>,
+    /// A template for the URL of a file containing the SHA-256 checksum of the archive.
+    pub checksum_url: Option<String>,
+    /// The SHA-256 checksums of the archive, keyed by platform.
+    ///
+    /// Takes precedence over `checksum-url`.
+    pub checksums: Option<BTreeMap<String, String>>,
+}
+
+/// A binary tool that can be installed, described declaratively.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Binary {
+    name: String,
+    default_version: Version,
+    url: String,
+    archive_format: Option<ArchiveFormat>,
+    executable: Option<String>,
+    checksum_url: Option<String>,
+    checksums: BTreeMap<String, String>,
+    /// Whether the archive must be verified against a checksum before it's installed.
+    require_checksum: bool,
+}
+
+impl Binary {
+    /// The built-in definition for Ruff.
+    pub fn ruff() -> Self {
+        Self {
+            name: "ruff".to_string(),
+            // TODO(zanieb): Figure out a nice way to automate updating this
+            default_version: Version::new([0, 12, 5]),
+            url: "https://github.com/astral-sh/ruff/releases/download/{version}/{name}-{platform}.{ext}"
+                .to_string(),
+            archive_format: None,
+            executable: None,
+            checksum_url: None,
+            checksums: BTreeMap::new(),
+            require_checksum: false,
+        }
+    }
+
+    /// Create a [`Binary`] from a user-provided definition.
+    fn from_source(name: &str, source: &BinarySource) -> Result<Self, Error> {
+        let Some(version) = source.version.as_deref() else {
+            return Err(Error::InvalidBinary {
+                name: name.to_string(),
+                reason: "`version` is required".to_string(),
+            });
+        };
+        let Some(url) = source.url.as_deref() else {
+            return Err(Error::InvalidBinary {
+                name: name.to_string(),
+                reason: "`url` is required".to_string(),
+            });
+        };
+        if source.checksum_url.is_none() && source.checksums.is_none() {
+            return Err(Error::InvalidBinary {
+                name: name.to_string(),
+                reason: "`checksums` or `checksum-url` is required".to_string(),
+            });
+        }
+        Ok(Self {
+            name: name.to_string(),
+            default_version: parse_version(name, version)?,
+            url: url.to_string(),
+            archive_format: source.archive_format,
+            executable: source.executable.clone(),
+            checksum_url: source.checksum_url.clone(),
+            checksums: source.checksums.clone().unwrap_or_default(),
+            require_checksum: true,
+        })
+    }
+
+    /// Override the built-in definition with a user-provided definition.
+    fn with_source(self, source: &BinarySource) -> Result<Self, Error> {
+        Ok(Self {
+            default_version: source
+                .version
+                .as_deref()
+                .map(|version| parse_version(&self.name, version))
+                .transpose()?
+                .unwrap_or(self.default_version),
+            url: source.url.clone().unwrap_or(self.url),
+            archive_format: source.archive_format.or(self.archive_format),
+            executable: source.executable.clone().or(self.executable),
+            checksum_url: source.checksum_url.clone().or(self.checksum_url),
+            checksums: source.checksums.clone().unwrap_or(self.checksums),
+            require_checksum: self.require_checksum,
+            name: self.name,
+        })
+    }
+
+    /// Redirect the downloads to the given mirror.
+    ///
+    /// Archives are expected at `<mirror>/<name>/<version>/<filename>`, where the filename is that
+    /// of the upstream URL.
+    fn with_mirror(self, mirror: &Url) -> Self {
+        let base = mirror.as_str().trim_end_matches('/');
+        let relocate = |template: &str| {
+            let filename = template.rsplit('/').next().unwrap_or(template);
+            format!("{base}/{{name}}/{{version}}/{filename}")
+        };
+        Self {
+            url: relocate(&self.url),
+            checksum_url: self.checksum_url.as_deref().map(relocate),
+            ..self
+        }
+    }
+
+    /// Get the default version for this binary.
+    pub fn default_version(&self) -> &Version {
+        &self.default_version
+    }
+
+    /// The name of the binary.
+    ///
+    /// See [`Binary::executable`] for the platform-specific executable name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the archive format to use on the given platform.
+    pub fn archive_format(&self, windows: bool) -> ArchiveFormat {
+        self.archive_format.unwrap_or(if windows {
+            ArchiveFormat::Zip
+        } else {
+            ArchiveFormat::TarGz
+        })
+    }
+
+    /// Get the download URL for a specific version and platform.
+    pub fn download_url(
+        &self,
+        version: &Version,
+        platform: &str,
+        format: ArchiveFormat,
+    ) -> Result<Url, Error> {
+        let url = self.render(&self.url, version, platform, format);
+        Url::parse(&url).map_err(|err| Error::UrlParse { url, source: err })
+    }
+
+    /// Get the URL of the checksum file for a specific version and platform, if any.
+    pub fn checksum_url(
+        &self,
+        version: &Version,
+        platform: &str,
+        format: ArchiveFormat,
+    ) -> Result<Option<Url>, Error> {
+        let Some(template) = self.checksum_url.as_deref() else {
+            return Ok(None);
+        };
+        let url = self.render(template, version, platform, format);
+        Url::parse(&url)
+            .map(Some)
+            .map_err(|err| Error::UrlParse { url, source: err })
+    }
+
+    /// Get the known SHA-256 checksum of the archive for the given platform, if any.
+    pub fn checksum(&self, platform: &str) -> Option<&str> {
+        self.checksums.get(platform).map(String::as_str)
+    }
+
+    /// Whether the archive must be verified against a checksum before it's installed.
+    pub fn require_checksum(&self) -> bool {
+        self.require_checksum
+    }
+
+    /// Get the path of the executable within the extracted archive.
+    pub fn archive_path(
+        &self,
+        version: &Version,
+        platform: &str,
+        format: ArchiveFormat,
+    ) -> PathBuf {
+        let path = if let Some(template) = self.executable.as_deref() {
+            self.render(template, version, platform, format)
+        } else {
+            match format {
+                // ZIP archives contain the binary directly in the root
+                ArchiveFormat::Zip => self.name.clone(),
+                // tar.gz archives contain the binary in a subdirectory
+                ArchiveFormat::TarGz => format!("{}-{platform}/{}", self.name, self.name),
+            }
+        };
+        let path = if path.ends_with(std::env::consts::EXE_SUFFIX) {
+            path
+        } else {
+            format!("{path}{}", std::env::consts::EXE_SUFFIX)
+        };
+        PathBuf::from(path)
+    }
+
+    /// Get the executable name
+    pub fn executable(&self) -> String {
+        format!("{}{}", self.name(), std::env::consts::EXE_SUFFIX)
+    }
+
+    /// Replace the placeholders in the given template.
+    fn render(
+        &self,
+        template: &str,
+        version: &Version,
+        platform: &str,
+        format: ArchiveFormat,
+    ) -> String {
+        template
+            .replace("{name}", &self.name)
+            .replace("{version}", &version.to_string())
+            .replace("{platform}", platform)
+            .replace("{ext}", format.extension())
+    }
+}
+
+impl fmt::Display for Binary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The set of binaries that can be installed, including the built-in binaries and any binaries
+/// declared in the configuration.
+#[derive(Debug, Clone)]
+pub struct BinaryRegistry {
+    binaries: BTreeMap<String, Binary>,
+}
+
+impl Default for BinaryRegistry {
+    fn default() -> Self {
+        let ruff = Binary::ruff();
+        Self {
+            binaries: BTreeMap::from([(ruff.name.clone(), ruff)]),
+        }
+    }
+}
+
+impl BinaryRegistry {
+    /// Create a [`BinaryRegistry`] from the built-in binaries and the user-provided definitions,
+    /// optionally redirecting all downloads to a mirror.
+    ///
+    /// The mirror may be a URL or a path to a local directory.
+    pub fn from_sources(
+        sources: &BTreeMap<String, BinarySource>,
+        mirror: Option<&str>,
+    ) -> Result<Self, Error> {
+        let mut registry = Self::default();
+
+        for (name, source) in sources {
+            let binary = match registry.binaries.remove(name) {
+                Some(binary) => binary.with_source(source)?,
+                None => Binary::from_source(name, source)?,
+            };
+            registry.binaries.insert(name.clone(), binary);
+        }
+
+        if let Some(mirror) = mirror {
+            let mirror = parse_mirror(mirror)?;
+            registry.binaries = registry
+                .binaries
+                .into_iter()
+                .map(|(name, binary)| (name, binary.with_mirror(&mirror)))
+                .collect();
+        }
+
+        Ok(registry)
+    }
+
+    /// Get the binary with the given name.
+    pub fn get(&self, name: &str) -> Result<&Binary, Error> {
+        self.binaries
+            .get(name)
+            .ok_or_else(|| Error::UnknownBinary(name.to_string()))
+    }
+}
+
+/// Parse the version of a user-provided binary definition.
+fn parse_version(name: &str, version: &str) -> Result<Version, Error> {
+    Version::from_str(version).map_err(|err| Error::InvalidBinary {
+        name: name.to_string(),
+        reason: err.to_string(),
+    })
+}
+
+/// Parse a mirror, which may be a URL or a path to a local directory.
+fn parse_mirror(mirror: &str) -> Result<Url, Error> {
+    // Avoid treating Windows drive letters (e.g., `C:\mirror`) as URL schemes.
+    if let Ok(url) = Url::parse(mirror) {
+        if url.scheme().len() > 1 {
+            return Ok(url);
+        }
+    }
+    std::path::absolute(Path::new(mirror))
+        .ok()
+        .and_then(|path| Url::from_directory_path(path).ok())
+        .ok_or_else(|| Error::InvalidMirror(mirror.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::path::PathBuf;
+
+    use uv_pep440::Version;
+
+    use super::{Binary, BinaryRegistry, BinarySource};
+    use crate::ArchiveFormat;
+
+    #[test]
+    fn ruff_download_url() {
+        let registry = BinaryRegistry::default();
+        let ruff = registry.get("ruff").unwrap();
+        let url = ruff
+            .download_url(
+                &Version::new([0, 12, 5]),
+                "x86_64-unknown-linux-gnu",
+                ArchiveFormat::TarGz,
+            )
+            .unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://github.com/astral-sh/ruff/releases/download/0.12.5/ruff-x86_64-unknown-linux-gnu.tar.gz"
+        );
+    }
+
+    #[test]
+    fn override_builtin() {
+        let sources = BTreeMap::from([(
+            "ruff".to_string(),
+            BinarySource {
+                version: Some("0.13.0".to_string()),
+                ..BinarySource::default()
+            },
+        )]);
+        let registry = BinaryRegistry::from_sources(&sources, None).unwrap();
+        let ruff = registry.get("ruff").unwrap();
+        assert_eq!(ruff.default_version(), &Version::new([0, 13, 0]));
+        assert_eq!(ruff.url, Binary::ruff().url);
+    }
+
+    #[test]
+    fn override_templates() {
+        let sources = BTreeMap::from([(
+            "ruff".to_string(),
+            BinarySource {
+                version: Some("0.13.0".to_string()),
+                url: Some(
+                    "https://example.com/{name}/{version}/{name}-{platform}.{ext}".to_string(),
+                ),
+                executable: Some("{name}-{version}/{name}".to_string()),
+                checksum_url: Some(
+                    "https://example.com/{name}/{version}/{name}-{platform}.{ext}.sha256"
+                        .to_string(),
+                ),
+                ..BinarySource::default()
+            },
+        )]);
+        let registry = BinaryRegistry::from_sources(&sources, None).unwrap();
+        let ruff = registry.get("ruff").unwrap();
+        let version = Version::new([0, 13, 0]);
+        let platform = "aarch64-apple-darwin";
+        assert_eq!(
+            ruff.download_url(&version, platform, ArchiveFormat::TarGz)
+                .unwrap()
+                .as_str(),
+            "https://example.com/ruff/0.13.0/ruff-aarch64-apple-darwin.tar.gz"
+        );
+        assert_eq!(
+            ruff.checksum_url(&version, platform, ArchiveFormat::TarGz)
+                .unwrap()
+                .unwrap()
+                .as_str(),
+            "https://example.com/ruff/0.13.0/ruff-aarch64-apple-darwin.tar.gz.sha256"
+        );
+        assert_eq!(
+            ruff.archive_path(&version, platform, ArchiveFormat::TarGz),
+            PathBuf::from(format!("ruff-0.13.0/ruff{}", std::env::consts::EXE_SUFFIX))
+        );
+    }
+
+    #[test]
+    fn custom_binary() {
+        let sources = BTreeMap::from([(
+            "ty".to_string(),
+            BinarySource {
+                version: Some("0.0.1".to_string()),
+                url: Some(
+                    "https://github.com/astral-sh/ty/releases/download/{version}/ty-{platform}.{ext}"
+                        .to_string(),
+                ),
+                executable: Some("ty-{platform}/ty".to_string()),
+                checksums: Some(BTreeMap::from([(
+                    "aarch64-apple-darwin".to_string(),
+                    "0123abcd".to_string(),
+                )])),
+                ..BinarySource::default()
+            },
+        )]);
+        let registry = BinaryRegistry::from_sources(&sources, None).unwrap();
+
+        // The built-in binaries remain available alongside the custom binary.
+        assert!(registry.get("ruff").is_ok());
+
+        let ty = registry.get("ty").unwrap();
+        let version = Version::new([0, 0, 1]);
+        let platform = "aarch64-apple-darwin";
+        assert_eq!(ty.name(), "ty");
+        assert_eq!(ty.default_version(), &version);
+        assert_eq!(
+            ty.download_url(&version, platform, ArchiveFormat::TarGz)
+                .unwrap()
+                .as_str(),
+            "https://github.com/astral-sh/ty/releases/download/0.0.1/ty-aarch64-apple-darwin.tar.gz"
+        );
+        assert_eq!(
+            ty.archive_path(&version, platform, ArchiveFormat::TarGz),
+            PathBuf::from(format!(
+                "ty-aarch64-apple-darwin/ty{}",
+                std::env::consts::EXE_SUFFIX
+            ))
+        );
+        assert_eq!(ty.checksum(platform), Some("0123abcd"));
+        assert_eq!(ty.checksum("x86_64-unknown-linux-gnu"), None);
+    }
+
+    #[test]
+    fn custom_binary_requires_url() {
+        let sources = BTreeMap::from([(
+            "ty".to_string(),
+            BinarySource {
+                version: Some("0.0.1".to_string()),
+                ..BinarySource::default()
+            },
+        )]);
+        let err = BinaryRegistry::from_sources(&sources, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid definition for binary `ty`: `url` is required"
+        );
+    }
+
+    #[test]
+    fn custom_binary_requires_version() {
+        let sources = BTreeMap::from([(
+            "ty".to_string(),
+            BinarySource {
+                url: Some("https://example.com/ty-{platform}.{ext}".to_string()),
+                ..BinarySource::default()
+            },
+        )]);
+        let err = BinaryRegistry::from_sources(&sources, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid definition for binary `ty`: `version` is required"
+        );
+    }
+
+    #[test]
+    fn custom_binary_requires_checksum() {
+        let sources = BTreeMap::from([(
+            "ty".to_string(),
+            BinarySource {
+                version: Some("0.0.1".to_string()),
+                url: Some("https://example.com/ty-{platform}.{ext}".to_string()),
+                ..BinarySource::default()
+            },
+        )]);
+        let err = BinaryRegistry::from_sources(&sources, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid definition for binary `ty`: `checksums` or `checksum-url` is required"
+        );
+    }
+
+    #[test]
+    fn unknown_binary() {
+        let registry = BinaryRegistry::default();
+        let err = registry.get("mypy").unwrap_err();
+        assert_eq!(err.to_string(), "No binary named `mypy` is defined");
+    }
+
+    #[test]
+    fn mirror() {
+        let registry =
+            BinaryRegistry::from_sources(&BTreeMap::new(), Some("https://mirror.example.com/uv/"))
+                .unwrap();
+        let ruff = registry.get("ruff").unwrap();
+        let url = ruff
+            .download_url(
+                &Version::new([0, 12, 5]),
+                "x86_64-pc-windows-msvc",
+                ArchiveFormat::Zip,
+            )
+            .unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://mirror.example.com/uv/ruff/0.12.5/ruff-x86_64-pc-windows-msvc.zip"
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn local_mirror() {
+        let registry = BinaryRegistry::from_sources(&BTreeMap::new(), Some("/opt/mirror")).unwrap();
+        let ruff = registry.get("ruff").unwrap();
+        let url = ruff
+            .download_url(
+                &Version::new([0, 12, 5]),
+                "x86_64-unknown-linux-gnu",
+                ArchiveFormat::TarGz,
+            )
+            .unwrap();
+        assert_eq!(
+            url.as_str(),
+            "file:///opt/mirror/ruff/0.12.5/ruff-x86_64-unknown-linux-gnu.tar.gz"
+        );
+    }
+}
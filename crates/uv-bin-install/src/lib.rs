@@ -9,78 +9,34 @@ use std::task::{Context, Poll};
 
 use futures::TryStreamExt;
 use reqwest_retry::policies::ExponentialBackoff;
-use std::fmt;
+use serde::Deserialize;
 use thiserror::Error;
 use tokio::io::{AsyncRead, ReadBuf};
 use tokio_util::compat::FuturesAsyncReadCompatExt;
+use tracing::debug;
 use url::Url;
 use uv_distribution_filename::SourceDistExtension;
 
 use uv_cache::{Cache, CacheBucket, CacheEntry, Error as CacheError};
 use uv_client::{BaseClient, RetryState};
+use uv_extract::hash::{HashReader, Hasher};
 use uv_extract::{Error as ExtractError, stream};
 use uv_pep440::Version;
 use uv_platform::Platform;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
 use uv_redacted::DisplaySafeUrl;
 
-/// Binary tools that can be installed.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum Binary {
-    Ruff,
-}
-
-impl Binary {
-    /// Get the default version for this binary.
-    pub fn default_version(&self) -> Version {
-        match self {
-            // TODO(zanieb): Figure out a nice way to automate updating this
-            Self::Ruff => Version::new([0, 12, 5]),
-        }
-    }
-
-    /// The name of the binary.
-    ///
-    /// See [`Binary::executable`] for the platform-specific executable name.
-    pub fn name(&self) -> &'static str {
-        match self {
-            Self::Ruff => "ruff",
-        }
-    }
-
-    /// Get the download URL for a specific version and platform.
-    pub fn download_url(
-        &self,
-        version: &Version,
-        platform: &str,
-        format: ArchiveFormat,
-    ) -> Result<Url, Error> {
-        match self {
-            Self::Ruff => {
-                let url = format!(
-                    "https://github.com/astral-sh/ruff/releases/download/{version}/ruff-{platform}.{}",
-                    format.extension()
-                );
-                Url::parse(&url).map_err(|err| Error::UrlParse { url, source: err })
-            }
-        }
-    }
+pub use crate::binary::{Binary, BinaryRegistry, BinarySource};
 
-    /// Get the executable name
-    pub fn executable(&self) -> String {
-        format!("{}{}", self.name(), std::env::consts::EXE_SUFFIX)
-    }
-}
-
-impl fmt::Display for Binary {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(self.name())
-    }
-}
+mod binary;
 
 /// Archive formats for binary downloads.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum ArchiveFormat {
+    #[serde(rename = "zip")]
     Zip,
+    #[serde(rename = "tar.gz")]
     TarGz,
 }
 
@@ -129,6 +85,35 @@ pub enum Error {
     #[error("Binary not found in archive at expected location: {expected}")]
     BinaryNotFound { expected: PathBuf },
 
+    #[error("No binary named `{0}` is defined")]
+    UnknownBinary(String),
+
+    #[error("Invalid definition for binary `{name}`: {reason}")]
+    InvalidBinary { name: String, reason: String },
+
+    #[error("Invalid binary mirror: {0}")]
+    InvalidMirror(String),
+
+    #[error("Invalid file URL: {0}")]
+    InvalidFileUrl(Url),
+
+    #[error("Failed to read checksum from: {0}")]
+    InvalidChecksum(Url),
+
+    #[error(
+        "No checksum is defined for binary `{name}` on `{platform}`; add one to `checksums` or provide a `checksum-url`"
+    )]
+    MissingChecksum { name: String, platform: String },
+
+    #[error(
+        "Hash mismatch for `{url}`\n\nExpected:\n  sha256:{expected}\n\nComputed:\n  sha256:{actual}"
+    )]
+    HashMismatch {
+        url: Box<Url>,
+        expected: String,
+        actual: String,
+    },
+
     #[error(transparent)]
     Io(#[from] std::io::Error),
 
@@ -161,7 +146,7 @@ impl Error {
 
 /// Install the given binary.
 pub async fn bin_install(
-    binary: Binary,
+    binary: &Binary,
     version: &Version,
     client: &BaseClient,
     retry_policy: &ExponentialBackoff,
@@ -185,14 +170,24 @@ pub async fn bin_install(
         return Ok(cache_entry.into_path_buf());
     }
 
-    let format = if platform.os.is_windows() {
-        ArchiveFormat::Zip
-    } else {
-        ArchiveFormat::TarGz
-    };
+    let format = binary.archive_format(platform.os.is_windows());
 
     let download_url = binary.download_url(version, &platform_name, format)?;
 
+    // Determine the expected checksum of the archive, if known.
+    let checksum = if let Some(checksum) = binary.checksum(&platform_name) {
+        Some(checksum.trim().to_ascii_lowercase())
+    } else if let Some(checksum_url) = binary.checksum_url(version, &platform_name, format)? {
+        Some(fetch_checksum(client, &checksum_url).await?)
+    } else if binary.require_checksum() {
+        return Err(Error::MissingChecksum {
+            name: binary.name().to_string(),
+            platform: platform_name,
+        });
+    } else {
+        None
+    };
+
     let cache_dir = cache_entry.dir();
     fs_err::tokio::create_dir_all(&cache_dir).await?;
 
@@ -206,6 +201,7 @@ pub async fn bin_install(
         &platform_name,
         format,
         &download_url,
+        checksum.as_deref(),
         &cache_entry,
     )
     .await?;
@@ -228,9 +224,48 @@ pub async fn bin_install(
     Ok(path)
 }
 
+/// Fetch the expected SHA-256 checksum of an archive from a checksum file.
+///
+/// Checksum files are expected to start with the hex-encoded digest, optionally followed by the
+/// filename (as in the output of `sha256sum`).
+async fn fetch_checksum(client: &BaseClient, url: &Url) -> Result<String, Error> {
+    let contents = if url.scheme() == "file" {
+        let path = url
+            .to_file_path()
+            .map_err(|()| Error::InvalidFileUrl(url.clone()))?;
+        fs_err::tokio::read_to_string(path).await?
+    } else {
+        let download_error = |err: reqwest::Error| Error::Download {
+            url: url.clone(),
+            source: reqwest_middleware::Error::from(err),
+        };
+        client
+            .for_host(&DisplaySafeUrl::from_url(url.clone()))
+            .get(url.clone())
+            .send()
+            .await
+            .map_err(|err| Error::Download {
+                url: url.clone(),
+                source: err,
+            })?
+            .error_for_status()
+            .map_err(download_error)?
+            .text()
+            .await
+            .map_err(download_error)?
+    };
+
+    let checksum = contents
+        .split_whitespace()
+        .next()
+        .filter(|checksum| checksum.len() == 64 && checksum.chars().all(|c| c.is_ascii_hexdigit()))
+        .ok_or_else(|| Error::InvalidChecksum(url.clone()))?;
+    Ok(checksum.to_ascii_lowercase())
+}
+
 /// Download and unpack a binary with retry on stream failures.
 async fn download_and_unpack_with_retry(
-    binary: Binary,
+    binary: &Binary,
     version: &Version,
     client: &BaseClient,
     retry_policy: &ExponentialBackoff,
@@ -239,6 +274,7 @@ async fn download_and_unpack_with_retry(
     platform_name: &str,
     format: ArchiveFormat,
     download_url: &Url,
+    checksum: Option<&str>,
     cache_entry: &CacheEntry,
 ) -> Result<PathBuf, Error> {
     let mut retry_state = RetryState::start(*retry_policy, download_url.clone());
@@ -253,6 +289,7 @@ async fn download_and_unpack_with_retry(
             platform_name,
             format,
             download_url,
+            checksum,
             cache_entry,
         )
         .await;
@@ -281,7 +318,7 @@ async fn download_and_unpack_with_retry(
 ///
 /// NOTE [`download_and_unpack_with_retry`] should be used instead.
 async fn download_and_unpack(
-    binary: Binary,
+    binary: &Binary,
     version: &Version,
     client: &BaseClient,
     cache: &Cache,
@@ -289,75 +326,100 @@ async fn download_and_unpack(
     platform_name: &str,
     format: ArchiveFormat,
     download_url: &Url,
+    checksum: Option<&str>,
     cache_entry: &CacheEntry,
 ) -> Result<PathBuf, Error> {
     // Create a temporary directory for extraction
     let temp_dir = tempfile::tempdir_in(cache.bucket(CacheBucket::Binaries))?;
 
-    let response = client
-        .for_host(&DisplaySafeUrl::from_url(download_url.clone()))
-        .get(download_url.clone())
-        .send()
-        .await
-        .map_err(|err| Error::Download {
-            url: download_url.clone(),
-            source: err,
-        })?;
-
-    let inner_retries = response
-        .extensions()
-        .get::<reqwest_retry::RetryCount>()
-        .map(|retries| retries.value());
-
-    if let Err(status_error) = response.error_for_status_ref() {
-        let err = Error::Download {
-            url: download_url.clone(),
-            source: reqwest_middleware::Error::from(status_error),
-        };
-        if let Some(retries) = inner_retries {
-            return Err(Error::RetriedError {
-                err: Box::new(err),
-                retries,
-            });
-        }
-        return Err(err);
-    }
-
-    // Get the download size from headers if available
-    let size = response
-        .headers()
-        .get(reqwest::header::CONTENT_LENGTH)
-        .and_then(|val| val.to_str().ok())
-        .and_then(|val| val.parse::<u64>().ok());
+    let (reader, size): (Box<dyn AsyncRead + Unpin + Send>, Option<u64>) =
+        if download_url.scheme() == "file" {
+            // Read the archive from the local filesystem (e.g., from a local mirror).
+            let path = download_url
+                .to_file_path()
+                .map_err(|()| Error::InvalidFileUrl(download_url.clone()))?;
+            debug!("Reading {binary} from: {}", path.display());
+            let file = fs_err::tokio::File::open(&path).await?;
+            let size = file.metadata().await?.len();
+            (Box::new(file), Some(size))
+        } else {
+            let response = client
+                .for_host(&DisplaySafeUrl::from_url(download_url.clone()))
+                .get(download_url.clone())
+                .send()
+                .await
+                .map_err(|err| Error::Download {
+                    url: download_url.clone(),
+                    source: err,
+                })?;
+
+            let inner_retries = response
+                .extensions()
+                .get::<reqwest_retry::RetryCount>()
+                .map(|retries| retries.value());
+
+            if let Err(status_error) = response.error_for_status_ref() {
+                let err = Error::Download {
+                    url: download_url.clone(),
+                    source: reqwest_middleware::Error::from(status_error),
+                };
+                if let Some(retries) = inner_retries {
+                    return Err(Error::RetriedError {
+                        err: Box::new(err),
+                        retries,
+                    });
+                }
+                return Err(err);
+            }
 
-    // Stream download directly to extraction
-    let reader = response
-        .bytes_stream()
-        .map_err(std::io::Error::other)
-        .into_async_read()
-        .compat();
+            // Get the download size from headers if available
+            let size = response
+                .headers()
+                .get(reqwest::header::CONTENT_LENGTH)
+                .and_then(|val| val.to_str().ok())
+                .and_then(|val| val.parse::<u64>().ok());
+
+            // Stream download directly to extraction
+            let reader = response
+                .bytes_stream()
+                .map_err(std::io::Error::other)
+                .into_async_read()
+                .compat();
+            (Box::new(reader), size)
+        };
 
     let id = reporter.on_download_start(binary.name(), version, size);
     let mut progress_reader = ProgressReader::new(reader, id, reporter);
-    stream::archive(&mut progress_reader, format.into(), temp_dir.path())
+    let mut hashers = vec![Hasher::from(HashAlgorithm::Sha256)];
+    let mut hash_reader = HashReader::new(&mut progress_reader, &mut hashers);
+    stream::archive(&mut hash_reader, format.into(), temp_dir.path())
         .await
         .map_err(|e| Error::Extract { source: e })?;
+    hash_reader.finish().await?;
     reporter.on_download_complete(id);
 
-    // Find the binary in the extracted files
-    let extracted_binary = match format {
-        ArchiveFormat::Zip => {
-            // Windows ZIP archives contain the binary directly in the root
-            temp_dir.path().join(binary.executable())
-        }
-        ArchiveFormat::TarGz => {
-            // tar.gz archives contain the binary in a subdirectory
-            temp_dir
-                .path()
-                .join(format!("{}-{platform_name}", binary.name()))
-                .join(binary.executable())
+    // Verify the checksum of the archive, if known.
+    if let Some(expected) = checksum {
+        let actual = hashers
+            .into_iter()
+            .map(HashDigest::from)
+            .next()
+            .map(|digest| digest.digest.to_string())
+            .unwrap_or_default();
+        if actual != expected {
+            return Err(Error::HashMismatch {
+                url: Box::new(download_url.clone()),
+                expected: expected.to_string(),
+                actual,
+            });
         }
-    };
+    }
+
+    // Find the binary in the extracted files
+    let extracted_binary =
+        temp_dir
+            .path()
+            .join(binary.archive_path(version, platform_name, format));
 
     if !extracted_binary.exists() {
         return Err(Error::BinaryNotFound {
@@ -49,6 +49,7 @@ use uv_warnings::warn_user_once;
 use uv_workspace::WorkspaceCache;
 
 pub use crate::error::{Error, MissingHeaderCause};
+pub use crate::pipreqs::{MODULE_MAPPING, ModuleMap, is_stdlib_module};
 
 /// The default backend to use when PEP 517 is used without a `build-system` section.
 static DEFAULT_BACKEND: LazyLock<Pep517Backend> = LazyLock::new(|| Pep517Backend {
@@ -1,11 +1,11 @@
 use std::str::FromStr;
 use std::sync::LazyLock;
 
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use uv_normalize::PackageName;
 
 /// A mapping from module name to PyPI package name.
-pub(crate) struct ModuleMap<'a>(FxHashMap<&'a str, PackageName>);
+pub struct ModuleMap<'a>(FxHashMap<&'a str, PackageName>);
 
 impl<'a> ModuleMap<'a> {
     /// Generate a [`ModuleMap`] from a string representation, encoded in `${module}:{package}` format.
@@ -22,11 +22,20 @@ impl<'a> ModuleMap<'a> {
     }
 
     /// Look up a PyPI package name for a given module name.
-    pub(crate) fn lookup(&self, module: &str) -> Option<&PackageName> {
+    pub fn lookup(&self, module: &str) -> Option<&PackageName> {
         self.0.get(module)
     }
 }
 
 /// A mapping from module name to PyPI package name.
-pub(crate) static MODULE_MAPPING: LazyLock<ModuleMap> =
+pub static MODULE_MAPPING: LazyLock<ModuleMap> =
     LazyLock::new(|| ModuleMap::from_str(include_str!("pipreqs/mapping")));
+
+/// The top-level modules in the Python standard library, across all supported Python versions.
+static STDLIB_MODULES: LazyLock<FxHashSet<&str>> =
+    LazyLock::new(|| include_str!("pipreqs/stdlib").lines().collect());
+
+/// Returns `true` if the given top-level module is part of the Python standard library.
+pub fn is_stdlib_module(module: &str) -> bool {
+    STDLIB_MODULES.contains(module)
+}
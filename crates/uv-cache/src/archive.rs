@@ -2,7 +2,9 @@ use std::path::Path;
 use std::str::FromStr;
 
 /// A unique identifier for an archive (unzipped wheel) in the cache.
-#[derive(Debug, Clone, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+#[derive(
+    Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, serde::Serialize, serde::Deserialize,
+)]
 pub struct ArchiveId(String);
 
 impl Default for ArchiveId {
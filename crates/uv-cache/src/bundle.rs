@@ -0,0 +1,569 @@
+//! Portable, content-addressed bundles of cache entries.
+//!
+//! A bundle is a gzipped tarball containing a `manifest.json`, followed by the contents of every
+//! file in the bundle, stored once per SHA-256 digest under `objects/`. The manifest maps each
+//! cache-relative path to either an object or an archive link, such that the cache layout
+//! (including the links into the archive bucket) can be re-created on another machine.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+use std::io::{Read, Write};
+use std::path::{Component, Path, PathBuf};
+use std::str::FromStr;
+
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::debug;
+
+use uv_fs::{Simplified, directories};
+
+use crate::{ArchiveId, Cache, CacheBucket, LockedPackage};
+
+/// The version of the bundle format.
+const BUNDLE_VERSION: u8 = 1;
+
+/// The name of the manifest entry, which must be the first entry in the bundle.
+const MANIFEST: &str = "manifest.json";
+
+/// The directory in which file contents are stored, indexed by digest.
+const OBJECTS: &str = "objects";
+
+/// The cache buckets that can be included in a bundle.
+const BUCKETS: [CacheBucket; 4] = [
+    CacheBucket::Simple,
+    CacheBucket::Wheels,
+    CacheBucket::SourceDistributions,
+    CacheBucket::Archive,
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum BundleError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("Failed to serialize the bundle manifest")]
+    Serialize(#[source] serde_json::Error),
+    #[error("Failed to parse the bundle manifest")]
+    Deserialize(#[source] serde_json::Error),
+    #[error("Expected the bundle to start with a `{MANIFEST}`")]
+    MissingManifest,
+    #[error("Unsupported bundle version: {0} (expected {BUNDLE_VERSION})")]
+    UnsupportedVersion(u8),
+    #[error("Invalid path in bundle: `{0}`")]
+    InvalidPath(String),
+    #[error("Unexpected entry in bundle: `{0}`")]
+    UnexpectedEntry(String),
+    #[error("Bundle is missing the contents of `{0}`")]
+    MissingObject(String),
+    #[error("Hash mismatch for `{path}`: expected `{expected}`, found `{actual}`")]
+    HashMismatch {
+        path: String,
+        expected: String,
+        actual: String,
+    },
+}
+
+/// A summary of the entries written to or read from a bundle.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BundleSummary {
+    /// The number of files in the bundle.
+    pub files: usize,
+    /// The number of links into the archive bucket.
+    pub links: usize,
+    /// The number of entries in the archive bucket (i.e., unzipped wheels).
+    pub archives: usize,
+    /// The total size of the (deduplicated) file contents, in bytes.
+    pub bytes: u64,
+}
+
+/// The manifest of a bundle.
+#[derive(Debug, Serialize, Deserialize)]
+struct Manifest {
+    version: u8,
+    entries: Vec<ManifestEntry>,
+}
+
+/// An entry in the bundle manifest, with a `/`-separated path relative to the cache root.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+enum ManifestEntry {
+    /// A file, whose contents are stored under `objects/{digest}`.
+    File {
+        path: String,
+        digest: String,
+        size: u64,
+        #[serde(default)]
+        executable: bool,
+    },
+    /// A link to an entry in the archive bucket.
+    Link { path: String, archive: ArchiveId },
+}
+
+impl Cache {
+    /// Write the cache entries for the given packages to a bundle at the given path.
+    ///
+    /// Includes the Simple API responses, wheels, and built source distributions for each package,
+    /// along with any archives referenced by those entries.
+    pub fn export_bundle(
+        &self,
+        packages: &[LockedPackage],
+        output: &Path,
+    ) -> Result<BundleSummary, BundleError> {
+        let mut summary = BundleSummary::default();
+        let mut entries = Vec::new();
+        let mut objects = BTreeMap::<String, PathBuf>::new();
+        let mut archives = BTreeSet::new();
+
+        // Collect the files and links for each package.
+        for path in self.package_entries(packages)? {
+            for entry in walkdir::WalkDir::new(&path)
+                .follow_root_links(false)
+                .sort_by_file_name()
+            {
+                let entry = entry.map_err(io::Error::from)?;
+                if entry.file_type().is_dir() || is_transient(entry.path()) {
+                    continue;
+                }
+                let relative = self.bundle_path(entry.path())?;
+                if let Some(id) = self.read_archive_link(entry.path(), entry.file_type()) {
+                    archives.insert(id.clone());
+                    entries.push(ManifestEntry::Link {
+                        path: relative,
+                        archive: id,
+                    });
+                    summary.links += 1;
+                } else if entry.file_type().is_file() {
+                    entries.push(bundle_file(entry.path(), relative, &mut objects)?);
+                    summary.files += 1;
+                } else {
+                    debug!("Skipping dangling cache link: {}", entry.path().display());
+                }
+            }
+        }
+
+        // Collect the files for each referenced archive.
+        for id in &archives {
+            for entry in walkdir::WalkDir::new(self.archive(id))
+                .follow_links(true)
+                .sort_by_file_name()
+            {
+                let entry = entry.map_err(io::Error::from)?;
+                if !entry.file_type().is_file() {
+                    continue;
+                }
+                let relative = self.bundle_path(entry.path())?;
+                entries.push(bundle_file(entry.path(), relative, &mut objects)?);
+                summary.files += 1;
+            }
+        }
+        summary.archives = archives.len();
+
+        // Write the bundle to a temporary file, then move it into place.
+        let parent = output
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        let temp_file = tempfile::NamedTempFile::new_in(parent)?;
+        let mut builder =
+            tar::Builder::new(GzEncoder::new(temp_file.as_file(), Compression::default()));
+
+        let manifest = serde_json::to_vec_pretty(&Manifest {
+            version: BUNDLE_VERSION,
+            entries,
+        })
+        .map_err(BundleError::Serialize)?;
+        append(&mut builder, MANIFEST, manifest.len() as u64, &manifest[..])?;
+
+        for (digest, source) in &objects {
+            let file = fs_err::File::open(source)?;
+            let size = file.metadata()?.len();
+            append(&mut builder, &format!("{OBJECTS}/{digest}"), size, file)?;
+            summary.bytes += size;
+        }
+
+        builder.into_inner()?.finish()?;
+        temp_file
+            .persist(output)
+            .map_err(|err| BundleError::Io(err.error))?;
+
+        Ok(summary)
+    }
+
+    /// Import the cache entries from the bundle at the given path.
+    ///
+    /// The bundle is unpacked into a staging directory within the cache, and each file is
+    /// verified against its digest before any entries are moved into place.
+    pub fn import_bundle(&self, bundle: &Path) -> Result<BundleSummary, BundleError> {
+        let mut summary = BundleSummary::default();
+        let file = fs_err::File::open(bundle)?;
+        let mut archive = tar::Archive::new(GzDecoder::new(io::BufReader::new(file)));
+        let mut tar_entries = archive.entries()?;
+
+        // Read and validate the manifest.
+        let manifest = {
+            let mut entry = tar_entries.next().ok_or(BundleError::MissingManifest)??;
+            if entry.path()?.as_ref() != Path::new(MANIFEST) {
+                return Err(BundleError::MissingManifest);
+            }
+            let mut manifest = Vec::new();
+            entry.read_to_end(&mut manifest)?;
+            serde_json::from_slice::<Manifest>(&manifest).map_err(BundleError::Deserialize)?
+        };
+        if manifest.version != BUNDLE_VERSION {
+            return Err(BundleError::UnsupportedVersion(manifest.version));
+        }
+
+        let mut files = FxHashMap::<&str, Vec<(&str, bool)>>::default();
+        for entry in &manifest.entries {
+            match entry {
+                ManifestEntry::File {
+                    path,
+                    digest,
+                    executable,
+                    ..
+                } => {
+                    validate_path(path, true)?;
+                    files
+                        .entry(digest.as_str())
+                        .or_default()
+                        .push((path.as_str(), *executable));
+                }
+                ManifestEntry::Link { path, archive } => {
+                    validate_path(path, false)?;
+                    let mut components = archive.as_ref().components();
+                    if !matches!(
+                        (components.next(), components.next()),
+                        (Some(Component::Normal(_)), None)
+                    ) {
+                        return Err(BundleError::InvalidPath(archive.to_string()));
+                    }
+                }
+            }
+        }
+
+        // Unpack each object into the staging directory, verifying its digest.
+        let staging = tempfile::tempdir_in(self.root())?;
+        let mut remaining = files;
+        for entry in tar_entries {
+            let mut entry = entry?;
+            if entry.header().entry_type().is_dir() {
+                continue;
+            }
+            let name = entry.path()?.to_string_lossy().into_owned();
+            let Some(paths) = name
+                .strip_prefix(&format!("{OBJECTS}/"))
+                .and_then(|digest| remaining.remove_entry(digest))
+            else {
+                return Err(BundleError::UnexpectedEntry(name));
+            };
+            let (digest, paths) = paths;
+            let Some(((first, executable), rest)) = paths.split_first() else {
+                continue;
+            };
+
+            let target = staging.path().join(first);
+            fs_err::create_dir_all(target.parent().expect("bundle paths have a parent"))?;
+            let mut writer = HashingWriter::new(fs_err::File::create(&target)?);
+            summary.bytes += io::copy(&mut entry, &mut writer)?;
+            let actual = writer.finish();
+            if actual != digest {
+                return Err(BundleError::HashMismatch {
+                    path: (*first).to_string(),
+                    expected: digest.to_string(),
+                    actual,
+                });
+            }
+            set_executable(&target, *executable)?;
+
+            for (path, executable) in rest {
+                let copy = staging.path().join(path);
+                fs_err::create_dir_all(copy.parent().expect("bundle paths have a parent"))?;
+                fs_err::copy(&target, &copy)?;
+                set_executable(&copy, *executable)?;
+            }
+        }
+        if let Some((_, paths)) = remaining.into_iter().next() {
+            return Err(BundleError::MissingObject(paths[0].0.to_string()));
+        }
+
+        // Move the archives into place, retaining any that already exist.
+        let archive_bucket = staging.path().join(CacheBucket::Archive.to_str());
+        for source in directories(&archive_bucket)? {
+            let id = source.file_name().expect("archive entries have a name");
+            let target = self.bucket(CacheBucket::Archive).join(id);
+            if target.exists() {
+                debug!("Archive already exists: {}", target.display());
+            } else {
+                fs_err::create_dir_all(self.bucket(CacheBucket::Archive))?;
+                fs_err::rename(&source, &target)?;
+            }
+            summary.archives += 1;
+        }
+
+        // Move the remaining files into place, and re-create the archive links.
+        for entry in &manifest.entries {
+            match entry {
+                ManifestEntry::File { path, .. } => {
+                    summary.files += 1;
+                    if path.starts_with(&format!("{}/", CacheBucket::Archive.to_str())) {
+                        continue;
+                    }
+                    let target = self.root().join(path);
+                    fs_err::create_dir_all(target.parent().expect("bundle paths have a parent"))?;
+                    fs_err::rename(staging.path().join(path), &target)?;
+                }
+                ManifestEntry::Link { path, archive } => {
+                    summary.links += 1;
+                    let target = self.root().join(path);
+                    fs_err::create_dir_all(target.parent().expect("bundle paths have a parent"))?;
+                    if target.is_dir() && !target.is_symlink() {
+                        fs_err::remove_dir_all(&target)?;
+                    }
+                    self.create_link(archive, &target)?;
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Return the `/`-separated path of a cache entry, relative to the cache root.
+    fn bundle_path(&self, path: &Path) -> Result<String, BundleError> {
+        let relative = path
+            .strip_prefix(self.root())
+            .map_err(|_| BundleError::InvalidPath(path.user_display().to_string()))?;
+        let mut components = Vec::new();
+        for component in relative.components() {
+            let component = component
+                .as_os_str()
+                .to_str()
+                .ok_or_else(|| BundleError::InvalidPath(path.user_display().to_string()))?;
+            components.push(component);
+        }
+        Ok(components.join("/"))
+    }
+
+    /// If the given path is a link into the archive bucket, return the ID of the archive.
+    #[cfg(unix)]
+    fn read_archive_link(&self, path: &Path, file_type: std::fs::FileType) -> Option<ArchiveId> {
+        if !file_type.is_symlink() {
+            return None;
+        }
+        let target = self.resolve_link(path).ok()?;
+        let bucket = fs_err::canonicalize(self.bucket(CacheBucket::Archive)).ok()?;
+        let id = target.strip_prefix(bucket).ok()?.to_str()?;
+        ArchiveId::from_str(id).ok()
+    }
+
+    /// If the given path is a link into the archive bucket, return the ID of the archive.
+    #[cfg(windows)]
+    fn read_archive_link(&self, path: &Path, file_type: std::fs::FileType) -> Option<ArchiveId> {
+        if !file_type.is_file() {
+            return None;
+        }
+        // Links are small files containing structured data; avoid reading anything larger.
+        if fs_err::metadata(path).ok()?.len() > 256 {
+            return None;
+        }
+        let contents = fs_err::read_to_string(path).ok()?;
+        let link = crate::Link::from_str(&contents).ok()?;
+        self.resolve_link(path).ok()?;
+        Some(link.id)
+    }
+}
+
+/// Add a file to the bundle, returning its manifest entry.
+fn bundle_file(
+    path: &Path,
+    relative: String,
+    objects: &mut BTreeMap<String, PathBuf>,
+) -> Result<ManifestEntry, BundleError> {
+    let mut file = fs_err::File::open(path)?;
+    let mut writer = HashingWriter::new(io::sink());
+    let size = io::copy(&mut file, &mut writer)?;
+    let digest = writer.finish();
+    let executable = is_executable(&file.metadata()?);
+    objects
+        .entry(digest.clone())
+        .or_insert_with(|| path.to_path_buf());
+    Ok(ManifestEntry::File {
+        path: relative,
+        digest,
+        size,
+        executable,
+    })
+}
+
+/// Append an entry with the given contents to the bundle.
+fn append(
+    builder: &mut tar::Builder<impl Write>,
+    path: &str,
+    size: u64,
+    contents: impl Read,
+) -> io::Result<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(size);
+    header.set_mode(0o644);
+    header.set_entry_type(tar::EntryType::Regular);
+    builder.append_data(&mut header, path, contents)
+}
+
+/// Returns `true` if the path represents a lockfile or temporary file, which should be omitted
+/// from the bundle.
+fn is_transient(path: &Path) -> bool {
+    path.file_name().is_some_and(|name| {
+        name.to_str()
+            .is_some_and(|name| name.starts_with(".tmp") || name == ".lock")
+    }) || path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("lock"))
+}
+
+/// Validate a path from the bundle manifest, which must be relative to the cache root and
+/// within one of the bundled buckets.
+fn validate_path(path: &str, allow_archive: bool) -> Result<(), BundleError> {
+    let mut components = Path::new(path).components();
+    let valid = components.next().is_some_and(|bucket| {
+        BUCKETS.iter().any(|candidate| {
+            (allow_archive || *candidate != CacheBucket::Archive)
+                && bucket.as_os_str() == candidate.to_str()
+        })
+    }) && components.all(|component| matches!(component, Component::Normal(_)));
+    if valid && !path.contains('\\') {
+        Ok(())
+    } else {
+        Err(BundleError::InvalidPath(path.to_string()))
+    }
+}
+
+#[cfg(unix)]
+fn is_executable(metadata: &std::fs::Metadata) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    metadata.permissions().mode() & 0o111 != 0
+}
+
+#[cfg(not(unix))]
+fn is_executable(_metadata: &std::fs::Metadata) -> bool {
+    false
+}
+
+#[cfg(unix)]
+fn set_executable(path: &Path, executable: bool) -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    if executable {
+        fs_err::set_permissions(path, std::fs::Permissions::from_mode(0o755))?;
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn set_executable(_path: &Path, _executable: bool) -> io::Result<()> {
+    Ok(())
+}
+
+/// A writer that computes the SHA-256 digest of the data written to it.
+struct HashingWriter<W> {
+    inner: W,
+    hasher: Sha256,
+}
+
+impl<W: Write> HashingWriter<W> {
+    fn new(inner: W) -> Self {
+        Self {
+            inner,
+            hasher: Sha256::new(),
+        }
+    }
+
+    /// Return the hex-encoded digest of the data written so far.
+    fn finish(self) -> String {
+        format!("{:x}", self.hasher.finalize())
+    }
+}
+
+impl<W: Write> Write for HashingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.hasher.update(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+#[cfg(unix)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_normalize::PackageName;
+    use uv_pep440::Version;
+
+    use crate::{ArchiveId, Cache, CacheBucket};
+
+    use crate::LockedPackage;
+
+    #[test]
+    fn round_trip() {
+        let source = Cache::temp().unwrap();
+        let name = PackageName::from_str("example").unwrap();
+
+        // Create an unzipped wheel in the archive bucket, along with a link and pointer.
+        let id = ArchiveId::new();
+        let archive = source.archive(&id);
+        fs_err::create_dir_all(archive.join("example")).unwrap();
+        fs_err::write(archive.join("example").join("__init__.py"), "").unwrap();
+        fs_err::write(archive.join("example").join("data.txt"), "").unwrap();
+
+        let wheels = source.shard(CacheBucket::Wheels, "pypi").shard("example");
+        fs_err::create_dir_all(&wheels).unwrap();
+        fs_err::write(wheels.join("1.0.0-py3-none-any.http"), "pointer").unwrap();
+        fs_err::write(wheels.join("2.0.0-py3-none-any.http"), "pointer").unwrap();
+        source
+            .create_link(&id, wheels.join("1.0.0-py3-none-any"))
+            .unwrap();
+
+        let bundle = tempfile::tempdir().unwrap();
+        let bundle = bundle.path().join("bundle.tar.gz");
+        let summary = source
+            .export_bundle(
+                &[LockedPackage {
+                    name,
+                    version: Some(Version::from_str("1.0.0").unwrap()),
+                }],
+                &bundle,
+            )
+            .unwrap();
+        assert_eq!(summary.files, 3);
+        assert_eq!(summary.links, 1);
+        assert_eq!(summary.archives, 1);
+
+        let target = Cache::temp().unwrap();
+        target.import_bundle(&bundle).unwrap();
+
+        let wheels = target.shard(CacheBucket::Wheels, "pypi").shard("example");
+        assert_eq!(
+            fs_err::read_to_string(wheels.join("1.0.0-py3-none-any.http")).unwrap(),
+            "pointer"
+        );
+        assert!(!wheels.join("2.0.0-py3-none-any.http").exists());
+        assert_eq!(
+            target
+                .resolve_link(wheels.join("1.0.0-py3-none-any"))
+                .unwrap(),
+            fs_err::canonicalize(target.archive(&id)).unwrap()
+        );
+        assert!(
+            target
+                .archive(&id)
+                .join("example")
+                .join("data.txt")
+                .is_file()
+        );
+    }
+}
@@ -0,0 +1,416 @@
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime};
+
+use jiff::{Span, SpanRelativeTo};
+use tracing::debug;
+
+use uv_fs::{LockedFile, LockedFileMode, Simplified};
+
+use crate::removal::measure;
+use crate::{Cache, CacheBucket, Removal, rm_rf};
+
+/// The file used to record the last time the eviction policy was applied, relative to the cache
+/// root.
+pub(crate) const EVICTION_TIMESTAMP: &str = ".eviction";
+
+/// The minimum interval between opportunistic applications of the eviction policy.
+const EVICTION_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// A limit on the total size of the unzipped wheels in the cache, e.g., `20GiB`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CacheMaxSize(u64);
+
+impl CacheMaxSize {
+    /// Return the limit in bytes.
+    pub fn bytes(self) -> u64 {
+        self.0
+    }
+}
+
+impl FromStr for CacheMaxSize {
+    type Err = String;
+
+    /// Parse a size with an optional unit, e.g., `20GiB`, `500 MB`, or `1048576`.
+    ///
+    /// Both decimal (`KB`, `MB`, `GB`, `TB`) and binary (`KiB`, `MiB`, `GiB`, `TiB`) units are
+    /// supported; sizes without a unit are interpreted as bytes.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let trimmed = input.trim();
+        let split = trimmed
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(trimmed.len());
+        let (number, unit) = trimmed.split_at(split);
+        let number = f64::from_str(number).map_err(|_| {
+            format!("`{input}` could not be parsed as a size (expected a value like `20GiB`)")
+        })?;
+        let multiplier: u64 = match unit.trim().to_ascii_lowercase().as_str() {
+            "" | "b" => 1,
+            "k" | "kb" => 1000,
+            "m" | "mb" => 1000_u64.pow(2),
+            "g" | "gb" => 1000_u64.pow(3),
+            "t" | "tb" => 1000_u64.pow(4),
+            "kib" => 1 << 10,
+            "mib" => 1 << 20,
+            "gib" => 1 << 30,
+            "tib" => 1 << 40,
+            _ => {
+                return Err(format!(
+                    "`{input}` has an unknown unit `{}` (expected one of `B`, `KB`, `MB`, `GB`, `TB`, `KiB`, `MiB`, `GiB`, or `TiB`)",
+                    unit.trim()
+                ));
+            }
+        };
+        #[allow(
+            clippy::cast_precision_loss,
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss
+        )]
+        Ok(Self((number * multiplier as f64) as u64))
+    }
+}
+
+impl Display for CacheMaxSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}B", self.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CacheMaxSize {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CacheMaxSize {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("CacheMaxSize")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "A size with an optional unit, e.g., `20GiB` or `500MB`."
+        })
+    }
+}
+
+/// A limit on the time since an unzipped wheel in the cache was last used, e.g., `30 days`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CacheMaxAge(Duration);
+
+impl CacheMaxAge {
+    /// Return the limit as a [`Duration`].
+    pub fn duration(self) -> Duration {
+        self.0
+    }
+}
+
+impl FromStr for CacheMaxAge {
+    type Err = String;
+
+    /// Parse a "friendly" duration (e.g., `30 days`, `2 weeks`) or an ISO 8601 duration (e.g.,
+    /// `P30D`).
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let span = Span::from_str(input)
+            .map_err(|err| format!("`{input}` could not be parsed as a duration: {err}"))?;
+
+        // Years and months don't represent a fixed amount of time.
+        if span.get_years() != 0 || span.get_months() != 0 {
+            return Err(format!(
+                "Duration `{input}` uses years or months, which are not allowed; use days instead, e.g., `30 days`"
+            ));
+        }
+
+        let duration = span
+            .to_duration(SpanRelativeTo::days_are_24_hours())
+            .and_then(|duration| Duration::try_from(duration.abs()))
+            .map_err(|err| format!("`{input}` could not be converted to a duration: {err}"))?;
+        Ok(Self(duration))
+    }
+}
+
+impl Display for CacheMaxAge {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}s", self.0.as_secs())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CacheMaxAge {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CacheMaxAge {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("CacheMaxAge")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "A duration, e.g., `30 days` or `P30D`."
+        })
+    }
+}
+
+/// A policy for evicting the least recently used entries from the archive bucket.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct EvictionPolicy {
+    /// The maximum total size of the archive bucket.
+    pub max_size: Option<CacheMaxSize>,
+    /// The maximum time since an archive was last used.
+    pub max_age: Option<CacheMaxAge>,
+}
+
+impl EvictionPolicy {
+    /// Returns `true` if the policy doesn't impose any limits.
+    pub fn is_empty(&self) -> bool {
+        self.max_size.is_none() && self.max_age.is_none()
+    }
+}
+
+impl Cache {
+    /// Record a use of an entry in the archive bucket (e.g., when installing an unzipped wheel),
+    /// such that recently used entries are retained by the [`EvictionPolicy`].
+    ///
+    /// The time of last use is tracked as the modification time of the archive directory, which is
+    /// otherwise immutable once persisted.
+    pub fn record_access(&self, path: &Path) {
+        if !path.starts_with(self.bucket(CacheBucket::Archive)) {
+            return;
+        }
+        if let Err(err) = filetime::set_file_mtime(path, filetime::FileTime::now()) {
+            debug!(
+                "Failed to record access to cache entry `{}`: {err}",
+                path.display()
+            );
+        }
+    }
+
+    /// Evict the least recently used entries from the archive bucket, along with any links to
+    /// them, according to the given [`EvictionPolicy`].
+    ///
+    /// Entries that haven't been used within `max_age` are evicted first; then, the least recently
+    /// used entries are evicted until the archive bucket is smaller than `max_size`.
+    ///
+    /// The caller must hold an exclusive lock on the cache.
+    pub fn evict(&self, policy: &EvictionPolicy) -> Result<Removal, io::Error> {
+        let mut summary = Removal::default();
+        if policy.is_empty() {
+            return Ok(summary);
+        }
+
+        // Collect the size and time of last use for every archive.
+        let mut archives = Vec::new();
+        match fs_err::read_dir(self.bucket(CacheBucket::Archive)) {
+            Ok(entries) => {
+                for entry in entries {
+                    let entry = entry?;
+                    let metadata = fs_err::symlink_metadata(entry.path())?;
+                    if !metadata.is_dir() {
+                        continue;
+                    }
+                    // Measuring every archive is expensive, so only do so if there's a size limit.
+                    let size = if policy.max_size.is_some() {
+                        measure(entry.path())?.total_bytes
+                    } else {
+                        0
+                    };
+                    archives.push((entry.path(), metadata.modified()?, size));
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(summary),
+            Err(err) => return Err(err),
+        }
+
+        // Evict the least recently used archives first.
+        archives.sort_by_key(|(_, accessed, _)| *accessed);
+
+        let now = SystemTime::now();
+        let mut total = archives.iter().map(|(_, _, size)| size).sum::<u64>();
+        let mut evicted = Vec::new();
+        for (path, accessed, size) in archives {
+            let expired = policy.max_age.is_some_and(|max_age| {
+                now.duration_since(accessed)
+                    .is_ok_and(|elapsed| elapsed > max_age.duration())
+            });
+            let oversized = policy
+                .max_size
+                .is_some_and(|max_size| total > max_size.bytes());
+            if !expired && !oversized {
+                break;
+            }
+            total -= size;
+            evicted.push(path);
+        }
+
+        if evicted.is_empty() {
+            return Ok(summary);
+        }
+
+        // Remove the links to each evicted archive, followed by the archive itself.
+        let references = self.find_archive_references()?;
+        for path in evicted {
+            let path = fs_err::canonicalize(path)?;
+            for reference in references.get(&path).into_iter().flatten() {
+                debug!("Removing evicted cache link: {}", reference.display());
+                summary += rm_rf(reference)?;
+            }
+            debug!("Evicting cache archive: {}", path.display());
+            summary += rm_rf(path)?;
+        }
+
+        Ok(summary)
+    }
+
+    /// Apply the [`EvictionPolicy`], if it hasn't been applied recently and no other uv process is
+    /// using the cache.
+    ///
+    /// Must be called once this process no longer holds a shared lock on the cache (e.g., after
+    /// the command has completed), as the exclusive lock can't otherwise be acquired.
+    pub fn evict_opportunistically(&self, policy: &EvictionPolicy) -> Result<Removal, io::Error> {
+        if policy.is_empty() || self.is_temporary() || !self.root.is_dir() {
+            return Ok(Removal::default());
+        }
+
+        // Avoid re-applying the policy on every invocation.
+        let timestamp = self.root.join(EVICTION_TIMESTAMP);
+        if let Ok(modified) = fs_err::metadata(&timestamp).and_then(|metadata| metadata.modified())
+        {
+            if SystemTime::now()
+                .duration_since(modified)
+                .is_ok_and(|elapsed| elapsed < EVICTION_INTERVAL)
+            {
+                return Ok(Removal::default());
+            }
+        }
+
+        // Only evict entries if no other uv process is using the cache.
+        let Some(_lock_file) = LockedFile::acquire_no_wait(
+            self.root.join(".lock"),
+            LockedFileMode::Exclusive,
+            self.root.simplified_display(),
+        ) else {
+            debug!("Skipping cache eviction, since the cache is in use");
+            return Ok(Removal::default());
+        };
+
+        let summary = self.evict(policy)?;
+        fs_err::write(&timestamp, "")?;
+
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use super::{CacheMaxAge, CacheMaxSize, EvictionPolicy};
+    use crate::{ArchiveId, Cache, CacheBucket};
+
+    #[test]
+    fn max_size() {
+        assert_eq!(CacheMaxSize::from_str("1024").unwrap().bytes(), 1024);
+        assert_eq!(
+            CacheMaxSize::from_str("20GiB").unwrap().bytes(),
+            20 * 1024 * 1024 * 1024
+        );
+        assert_eq!(
+            CacheMaxSize::from_str("500 MB").unwrap().bytes(),
+            500_000_000
+        );
+        assert_eq!(CacheMaxSize::from_str("1.5kib").unwrap().bytes(), 1536);
+        assert!(CacheMaxSize::from_str("20 parsecs").is_err());
+        assert!(CacheMaxSize::from_str("GiB").is_err());
+    }
+
+    #[test]
+    fn max_age() {
+        assert_eq!(
+            CacheMaxAge::from_str("30 days").unwrap().duration(),
+            Duration::from_secs(30 * 24 * 60 * 60)
+        );
+        assert_eq!(
+            CacheMaxAge::from_str("2 weeks").unwrap().duration(),
+            Duration::from_secs(14 * 24 * 60 * 60)
+        );
+        assert_eq!(
+            CacheMaxAge::from_str("P1D").unwrap().duration(),
+            Duration::from_secs(24 * 60 * 60)
+        );
+        assert!(CacheMaxAge::from_str("1 month").is_err());
+        assert!(CacheMaxAge::from_str("soon").is_err());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn evict_least_recently_used() {
+        let cache = Cache::temp().unwrap();
+        let wheels = cache.shard(CacheBucket::Wheels, "pypi").shard("example");
+        fs_err::create_dir_all(&wheels).unwrap();
+
+        // Create three archives of 1KiB each, used one day apart.
+        let mut archives = Vec::new();
+        for (index, days) in [3, 2, 1].into_iter().enumerate() {
+            let id = ArchiveId::new();
+            let archive = cache.archive(&id);
+            fs_err::create_dir_all(&archive).unwrap();
+            fs_err::write(archive.join("data.bin"), vec![0; 1024]).unwrap();
+            cache
+                .create_link(&id, wheels.join(format!("{index}.0.0-py3-none-any")))
+                .unwrap();
+            let accessed = filetime::FileTime::from_unix_time(
+                filetime::FileTime::now().unix_seconds() - days * 24 * 60 * 60,
+                0,
+            );
+            filetime::set_file_mtime(&archive, accessed).unwrap();
+            archives.push(archive);
+        }
+
+        // An empty policy doesn't evict anything.
+        let removal = cache.evict(&EvictionPolicy::default()).unwrap();
+        assert_eq!(removal.num_files, 0);
+
+        // Evict the archive that hasn't been used in over two days, along with its link.
+        let policy = EvictionPolicy {
+            max_size: None,
+            max_age: Some(CacheMaxAge::from_str("2 days 1 hour").unwrap()),
+        };
+        cache.evict(&policy).unwrap();
+        assert!(!archives[0].exists());
+        assert!(
+            wheels
+                .join("0.0.0-py3-none-any")
+                .symlink_metadata()
+                .is_err()
+        );
+        assert!(archives[1].exists());
+
+        // Recording an access retains the older archive over the newer one.
+        cache.record_access(&archives[1]);
+        let policy = EvictionPolicy {
+            max_size: Some(CacheMaxSize::from_str("1KiB").unwrap()),
+            max_age: None,
+        };
+        cache.evict(&policy).unwrap();
+        assert!(archives[1].exists());
+        assert!(wheels.join("1.0.0-py3-none-any").exists());
+        assert!(!archives[2].exists());
+        assert!(
+            wheels
+                .join("2.0.0-py3-none-any")
+                .symlink_metadata()
+                .is_err()
+        );
+    }
+}
@@ -6,7 +6,7 @@ use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{debug, trace, warn};
 
 use uv_cache_info::Timestamp;
@@ -14,20 +14,33 @@ use uv_fs::{LockedFile, LockedFileError, LockedFileMode, Simplified, cachedir, d
 use uv_normalize::PackageName;
 use uv_pypi_types::ResolutionMetadata;
 
+pub use crate::bundle::{BundleError, BundleSummary};
 pub use crate::by_timestamp::CachedByTimestamp;
 #[cfg(feature = "clap")]
 pub use crate::cli::CacheArgs;
-use crate::removal::Remover;
+use crate::eviction::EVICTION_TIMESTAMP;
+pub use crate::eviction::{CacheMaxAge, CacheMaxSize, EvictionPolicy};
+pub use crate::locked::LockedPackage;
+pub use crate::remote::{RemoteBuildCache, RemoteBuildCacheLocation};
 pub use crate::removal::{Removal, rm_rf};
+use crate::removal::{Remover, measure};
+pub use crate::verify::{
+    BrokenEntry, BrokenReason, CacheEntryVerifier, Verification, VerifiedArchives,
+};
 pub use crate::wheel::WheelCache;
 use crate::wheel::WheelCacheKind;
 pub use archive::ArchiveId;
 
 mod archive;
+mod bundle;
 mod by_timestamp;
 #[cfg(feature = "clap")]
 mod cli;
+mod eviction;
+mod locked;
+mod remote;
 mod removal;
+mod verify;
 mod wheel;
 
 /// The version of the archive bucket.
@@ -174,6 +187,10 @@ pub struct Cache {
     /// Ensure that `uv cache` operations don't remove items from the cache that are used by another
     /// uv process.
     lock_file: Option<Arc<LockedFile>>,
+    /// A remote cache of built wheels, shared across machines.
+    remote: Option<Arc<RemoteBuildCache>>,
+    /// The policy for evicting the least recently used entries from the cache.
+    eviction: Option<Arc<EvictionPolicy>>,
 }
 
 impl Cache {
@@ -184,6 +201,8 @@ impl Cache {
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: None,
             lock_file: None,
+            remote: None,
+            eviction: None,
         }
     }
 
@@ -195,6 +214,8 @@ impl Cache {
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: Some(Arc::new(temp_dir)),
             lock_file: None,
+            remote: None,
+            eviction: None,
         })
     }
 
@@ -204,6 +225,24 @@ impl Cache {
         Self { refresh, ..self }
     }
 
+    /// Set the [`RemoteBuildCache`] to use for wheels built from source distributions.
+    #[must_use]
+    pub fn with_remote_build_cache(self, remote_build_cache: Option<RemoteBuildCache>) -> Self {
+        Self {
+            remote: remote_build_cache.map(Arc::new),
+            ..self
+        }
+    }
+
+    /// Set the [`EvictionPolicy`] to apply to the cache.
+    #[must_use]
+    pub fn with_eviction_policy(self, eviction: EvictionPolicy) -> Self {
+        Self {
+            eviction: (!eviction.is_empty()).then(|| Arc::new(eviction)),
+            ..self
+        }
+    }
+
     /// Acquire a lock that allows removing entries from the cache.
     pub async fn with_exclusive_lock(self) -> Result<Self, LockedFileError> {
         let Self {
@@ -211,6 +250,8 @@ impl Cache {
             refresh,
             temp_dir,
             lock_file,
+            remote,
+            eviction,
         } = self;
 
         // Release the existing lock, avoid deadlocks from a cloned cache.
@@ -233,6 +274,8 @@ impl Cache {
             refresh,
             temp_dir,
             lock_file: Some(Arc::new(lock_file)),
+            remote,
+            eviction,
         })
     }
 
@@ -245,6 +288,8 @@ impl Cache {
             refresh,
             temp_dir,
             lock_file,
+            remote,
+            eviction,
         } = self;
 
         match LockedFile::acquire_no_wait(
@@ -257,12 +302,16 @@ impl Cache {
                 refresh,
                 temp_dir,
                 lock_file: Some(Arc::new(lock_file)),
+                remote,
+                eviction,
             }),
             None => Err(Self {
                 root,
                 refresh,
                 temp_dir,
                 lock_file,
+                remote,
+                eviction,
             }),
         }
     }
@@ -277,6 +326,16 @@ impl Cache {
         &self.refresh
     }
 
+    /// Return the [`RemoteBuildCache`], if any.
+    pub fn remote_build_cache(&self) -> Option<&RemoteBuildCache> {
+        self.remote.as_deref()
+    }
+
+    /// Return the [`EvictionPolicy`] for the cache, if any limits are configured.
+    pub fn eviction_policy(&self) -> Option<&EvictionPolicy> {
+        self.eviction.as_deref()
+    }
+
     /// The folder for a specific cache bucket
     pub fn bucket(&self, cache_bucket: CacheBucket) -> PathBuf {
         self.root.join(cache_bucket.to_str())
@@ -579,6 +638,7 @@ impl Cache {
                 || entry.file_name() == ".gitignore"
                 || entry.file_name() == ".git"
                 || entry.file_name() == ".lock"
+                || entry.file_name() == EVICTION_TIMESTAMP
             {
                 continue;
             }
@@ -691,6 +751,88 @@ impl Cache {
         Ok(summary)
     }
 
+    /// Remove any wheels, source distribution builds, and archives that aren't required by the
+    /// given packages (e.g., the packages in a set of lockfiles).
+    ///
+    /// If `dry_run` is set, nothing is removed, and the returned summary instead reflects the
+    /// entries that would be removed.
+    ///
+    /// Returns a summary for each of the [`CacheBucket::Wheels`],
+    /// [`CacheBucket::SourceDistributions`], and [`CacheBucket::Archive`] buckets.
+    pub fn prune_unlocked(
+        &self,
+        packages: &[LockedPackage],
+        dry_run: bool,
+    ) -> Result<Vec<(CacheBucket, Removal)>, io::Error> {
+        let retain = self
+            .package_entries(packages)?
+            .into_iter()
+            .collect::<FxHashSet<_>>();
+        let is_retained = |path: &Path| path.ancestors().any(|ancestor| retain.contains(ancestor));
+
+        // Collect the references to each archive before removing anything.
+        let references = self.find_archive_references()?;
+
+        let mut removed = FxHashSet::default();
+        let mut summary = Vec::new();
+        for bucket in [CacheBucket::Wheels, CacheBucket::SourceDistributions] {
+            let mut removal = Removal::default();
+            for entry in bucket.package_entries(self)? {
+                if is_retained(&entry) {
+                    continue;
+                }
+                if dry_run {
+                    removal += measure(&entry)?;
+                } else {
+                    debug!("Removing unlocked cache entry: {}", entry.display());
+                    removal += rm_rf(&entry)?;
+
+                    // Remove the parent directory (e.g., the package directory), if it's now
+                    // empty.
+                    if let Some(parent) = entry.parent() {
+                        if fs_err::remove_dir(parent).is_ok() {
+                            removal.num_dirs += 1;
+                        }
+                    }
+                }
+                removed.insert(entry);
+            }
+            summary.push((bucket, removal));
+        }
+
+        // Remove any archives that are no longer referenced by a retained entry.
+        let mut removal = Removal::default();
+        match fs_err::read_dir(self.bucket(CacheBucket::Archive)) {
+            Ok(entries) => {
+                for entry in entries {
+                    let entry = entry?;
+                    let path = fs_err::canonicalize(entry.path())?;
+                    let retained = references.get(&path).is_some_and(|references| {
+                        references.iter().any(|reference| {
+                            !reference
+                                .ancestors()
+                                .any(|ancestor| removed.contains(ancestor))
+                        })
+                    });
+                    if retained {
+                        continue;
+                    }
+                    if dry_run {
+                        removal += measure(&path)?;
+                    } else {
+                        debug!("Removing unlocked cache archive: {}", path.display());
+                        removal += rm_rf(&path)?;
+                    }
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => (),
+            Err(err) => return Err(err),
+        }
+        summary.push((CacheBucket::Archive, removal));
+
+        Ok(summary)
+    }
+
     /// Find all references to entries in the archive bucket.
     ///
     /// Archive entries are often referenced by symlinks in other cache buckets. This method
@@ -1192,17 +1334,6 @@ impl CacheBucket {
     ///
     /// Returns the number of entries removed from the cache.
     fn remove(self, cache: &Cache, name: &PackageName) -> Result<Removal, io::Error> {
-        /// Returns `true` if the [`Path`] represents a built wheel for the given package.
-        fn is_match(path: &Path, name: &PackageName) -> bool {
-            let Ok(metadata) = fs_err::read(path.join("metadata.msgpack")) else {
-                return false;
-            };
-            let Ok(metadata) = rmp_serde::from_slice::<ResolutionMetadata>(&metadata) else {
-                return false;
-            };
-            metadata.name == *name
-        }
-
         let mut summary = Removal::default();
         match self {
             Self::Wheels => {
@@ -1241,7 +1372,7 @@ impl CacheBucket {
                 // search for a wheel matching the package name.
                 let root = cache.bucket(self).join(WheelCacheKind::Url);
                 for url in directories(root)? {
-                    if directories(&url)?.any(|version| is_match(&version, name)) {
+                    if directories(&url)?.any(|version| is_built_wheel(&version, name)) {
                         summary += rm_rf(url)?;
                     }
                 }
@@ -1251,7 +1382,7 @@ impl CacheBucket {
                 // search for a wheel matching the package name.
                 let root = cache.bucket(self).join(WheelCacheKind::Path);
                 for path in directories(root)? {
-                    if directories(&path)?.any(|version| is_match(&version, name)) {
+                    if directories(&path)?.any(|version| is_built_wheel(&version, name)) {
                         summary += rm_rf(path)?;
                     }
                 }
@@ -1262,7 +1393,7 @@ impl CacheBucket {
                 let root = cache.bucket(self).join(WheelCacheKind::Git);
                 for repository in directories(root)? {
                     for sha in directories(repository)? {
-                        if is_match(&sha, name) {
+                        if is_built_wheel(&sha, name) {
                             summary += rm_rf(sha)?;
                         }
                     }
@@ -1299,6 +1430,63 @@ impl CacheBucket {
         Ok(summary)
     }
 
+    /// Return the per-package entries in the cache bucket, i.e., the unit at which entries can be
+    /// removed without affecting other packages.
+    fn package_entries(self, cache: &Cache) -> Result<Vec<PathBuf>, io::Error> {
+        let mut entries = Vec::new();
+        match self {
+            Self::Wheels => {
+                // For wheels, every file (or link) within a package directory is an entry.
+                let root = cache.bucket(self);
+                for directory in std::iter::once(root.join(WheelCacheKind::Pypi))
+                    .chain(directories(root.join(WheelCacheKind::Index))?)
+                    .chain(directories(root.join(WheelCacheKind::Url))?)
+                {
+                    for package in directories(directory)? {
+                        entries.extend(uv_fs::entries(package)?);
+                    }
+                }
+            }
+            Self::SourceDistributions => {
+                // For registry source distributions, every version directory is an entry.
+                let root = cache.bucket(self);
+                for directory in std::iter::once(root.join(WheelCacheKind::Pypi))
+                    .chain(directories(root.join(WheelCacheKind::Index))?)
+                {
+                    for package in directories(directory)? {
+                        entries.extend(directories(package)?);
+                    }
+                }
+
+                // For direct URL, local, and editable source distributions, every URL is an entry.
+                for kind in [
+                    WheelCacheKind::Url,
+                    WheelCacheKind::Path,
+                    WheelCacheKind::Editable,
+                ] {
+                    entries.extend(directories(root.join(kind))?);
+                }
+
+                // For Git source distributions, every SHA is an entry.
+                for repository in directories(root.join(WheelCacheKind::Git))? {
+                    entries.extend(directories(repository)?);
+                }
+            }
+            Self::Simple
+            | Self::FlatIndex
+            | Self::Git
+            | Self::Interpreter
+            | Self::Archive
+            | Self::Builds
+            | Self::Environments
+            | Self::Python
+            | Self::Binaries => {
+                // Nothing to do.
+            }
+        }
+        Ok(entries)
+    }
+
     /// Return an iterator over all cache buckets.
     pub fn iter() -> impl Iterator<Item = Self> {
         [
@@ -1318,6 +1506,17 @@ impl CacheBucket {
     }
 }
 
+/// Returns `true` if the [`Path`] represents a built wheel for the given package.
+fn is_built_wheel(path: &Path, name: &PackageName) -> bool {
+    let Ok(metadata) = fs_err::read(path.join("metadata.msgpack")) else {
+        return false;
+    };
+    let Ok(metadata) = rmp_serde::from_slice::<ResolutionMetadata>(&metadata) else {
+        return false;
+    };
+    metadata.name == *name
+}
+
 impl Display for CacheBucket {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.write_str(self.to_str())
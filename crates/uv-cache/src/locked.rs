@@ -0,0 +1,148 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use uv_fs::{directories, entries};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+use crate::wheel::WheelCacheKind;
+use crate::{Cache, CacheBucket, is_built_wheel};
+
+/// A package pinned in a lockfile, used to select the cache entries that it requires.
+#[derive(Debug, Clone)]
+pub struct LockedPackage {
+    /// The name of the package.
+    pub name: PackageName,
+    /// The version of the package, if known.
+    ///
+    /// If omitted, entries for all cached versions of the package are selected.
+    pub version: Option<Version>,
+}
+
+impl Cache {
+    /// Return the cache entries (files or directories) for the given packages across the Simple,
+    /// wheel, and source distribution buckets.
+    pub(crate) fn package_entries(&self, packages: &[LockedPackage]) -> io::Result<Vec<PathBuf>> {
+        let mut roots = Vec::new();
+        for package in packages {
+            let name = package.name.to_string();
+
+            // For the Simple API, we expect a rkyv file per package, either at the root (for
+            // `pypi`) or within a directory per index.
+            let bucket = self.bucket(CacheBucket::Simple);
+            for directory in std::iter::once(bucket.join(WheelCacheKind::Pypi))
+                .chain(directories(bucket.join(WheelCacheKind::Index))?)
+            {
+                let path = directory.join(format!("{name}.rkyv"));
+                if path.is_file() {
+                    roots.push(path);
+                }
+            }
+
+            // For wheels, we expect a directory per package, containing entries keyed by the
+            // wheel's version and tags.
+            let bucket = self.bucket(CacheBucket::Wheels);
+            for directory in std::iter::once(bucket.join(WheelCacheKind::Pypi))
+                .chain(directories(bucket.join(WheelCacheKind::Index))?)
+                .chain(directories(bucket.join(WheelCacheKind::Url))?)
+            {
+                for entry in entries(directory.join(&name))? {
+                    if package
+                        .version
+                        .as_ref()
+                        .is_none_or(|version| is_wheel_version(&entry, version))
+                    {
+                        roots.push(entry);
+                    }
+                }
+            }
+
+            // For registry source distributions, we expect a directory per package, followed by
+            // a directory per version.
+            let bucket = self.bucket(CacheBucket::SourceDistributions);
+            for directory in std::iter::once(bucket.join(WheelCacheKind::Pypi))
+                .chain(directories(bucket.join(WheelCacheKind::Index))?)
+            {
+                let path = match &package.version {
+                    Some(version) => directory.join(&name).join(version.to_string()),
+                    None => directory.join(&name),
+                };
+                if path.is_dir() {
+                    roots.push(path);
+                }
+            }
+
+            // For direct URL, local, and editable source distributions, we need to search for a built wheel
+            // matching the package name.
+            for root in [
+                bucket.join(WheelCacheKind::Url),
+                bucket.join(WheelCacheKind::Path),
+                bucket.join(WheelCacheKind::Editable),
+            ] {
+                for url in directories(root)? {
+                    if directories(&url)?.any(|version| is_built_wheel(&version, &package.name)) {
+                        roots.push(url);
+                    }
+                }
+            }
+
+            // For Git source distributions, we expect a directory for every repository, followed
+            // by a directory for every SHA.
+            for repository in directories(bucket.join(WheelCacheKind::Git))? {
+                for sha in directories(repository)? {
+                    if is_built_wheel(&sha, &package.name) {
+                        roots.push(sha);
+                    }
+                }
+            }
+        }
+        roots.sort();
+        roots.dedup();
+        Ok(roots)
+    }
+}
+
+/// Returns `true` if the wheel cache entry at the given path matches the given version.
+///
+/// Wheel entries are keyed by `{version}-{tags}`, or, for long keys, by a truncated version
+/// followed by a digest of the tags (see `WheelFilename::cache_key`).
+fn is_wheel_version(path: &Path, version: &Version) -> bool {
+    let Some((prefix, rest)) = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.split_once('-'))
+    else {
+        return false;
+    };
+    let version = version.to_string();
+    if rest.contains('-') {
+        prefix == version
+    } else {
+        version.starts_with(prefix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_pep440::Version;
+
+    use super::is_wheel_version;
+
+    #[test]
+    fn wheel_version() {
+        let version = Version::from_str("1.0.0").unwrap();
+        assert!(is_wheel_version(
+            "1.0.0-py3-none-any.http".as_ref(),
+            &version
+        ));
+        assert!(is_wheel_version("1.0.0-py3-none-any".as_ref(), &version));
+        assert!(!is_wheel_version("1.0-py3-none-any".as_ref(), &version));
+        assert!(!is_wheel_version("1.0.0.1-py3-none-any".as_ref(), &version));
+        assert!(is_wheel_version(
+            "1.0-5a2adc379b2dc214.http".as_ref(),
+            &version
+        ));
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use uv_redacted::DisplaySafeUrl;
+
+/// The location of a remote cache of wheels built from source distributions, e.g.,
+/// `https://example.com/build-cache` or `/mnt/shared/build-cache`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteBuildCacheLocation {
+    /// An HTTP server that supports `GET` (and, to publish wheels, `PUT`) requests.
+    Url(DisplaySafeUrl),
+    /// A directory on the local filesystem (e.g., a network share).
+    Path(PathBuf),
+}
+
+impl FromStr for RemoteBuildCacheLocation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.contains("://") {
+            return Ok(Self::Path(PathBuf::from(s)));
+        }
+        let url = DisplaySafeUrl::parse(s)
+            .map_err(|err| format!("`{s}` is not a valid URL or path: {err}"))?;
+        match url.scheme() {
+            "http" | "https" => Ok(Self::Url(url)),
+            "file" => url
+                .to_file_path()
+                .map(Self::Path)
+                .map_err(|()| format!("`{s}` is not a valid file URL")),
+            scheme => Err(format!(
+                "`{s}` uses an unsupported scheme `{scheme}` (expected `http`, `https`, or `file`)"
+            )),
+        }
+    }
+}
+
+impl Display for RemoteBuildCacheLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Url(url) => write!(f, "{url}"),
+            Self::Path(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for RemoteBuildCacheLocation {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for RemoteBuildCacheLocation {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("RemoteBuildCacheLocation")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "An HTTP(S) URL or a path to a directory, e.g., `https://example.com/build-cache`."
+        })
+    }
+}
+
+/// A remote cache of wheels built from source distributions, shared across machines.
+///
+/// Entries are keyed by a digest of the source distribution's hash, the build settings, and the
+/// tags of the interpreter used to build the wheel. Each entry consists of a JSON pointer at
+/// `<name>/<digest>.json`, which records the wheel's filename and hash, and the wheel itself, at
+/// `<name>/<digest>/<filename>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteBuildCache {
+    location: RemoteBuildCacheLocation,
+    publish: bool,
+}
+
+impl RemoteBuildCache {
+    /// Create a [`RemoteBuildCache`] at the given location.
+    ///
+    /// If `publish` is `true`, wheels built locally will be uploaded to the remote cache.
+    pub fn new(location: RemoteBuildCacheLocation, publish: bool) -> Self {
+        Self { location, publish }
+    }
+
+    /// Return the location of the remote cache.
+    pub fn location(&self) -> &RemoteBuildCacheLocation {
+        &self.location
+    }
+
+    /// Returns `true` if wheels built locally should be uploaded to the remote cache.
+    pub fn publish(&self) -> bool {
+        self.publish
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::str::FromStr;
+
+    use super::RemoteBuildCacheLocation;
+
+    #[test]
+    fn location() {
+        assert!(matches!(
+            RemoteBuildCacheLocation::from_str("https://example.com/cache").unwrap(),
+            RemoteBuildCacheLocation::Url(_)
+        ));
+        assert_eq!(
+            RemoteBuildCacheLocation::from_str("/mnt/cache").unwrap(),
+            RemoteBuildCacheLocation::Path(PathBuf::from("/mnt/cache"))
+        );
+        assert!(RemoteBuildCacheLocation::from_str("s3://bucket/cache").is_err());
+    }
+}
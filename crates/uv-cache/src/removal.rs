@@ -13,6 +13,34 @@ pub fn rm_rf(path: impl AsRef<Path>) -> io::Result<Removal> {
     Remover::default().rm_rf(path, false)
 }
 
+/// Measure a file or directory and all its contents, returning the [`Removal`] that would result
+/// from removing it, without removing anything.
+pub(crate) fn measure(path: impl AsRef<Path>) -> io::Result<Removal> {
+    let mut removal = Removal::default();
+    for entry in walkdir::WalkDir::new(path.as_ref()).follow_root_links(false) {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(err)
+                if err
+                    .io_error()
+                    .is_some_and(|err| err.kind() == io::ErrorKind::NotFound) =>
+            {
+                return Ok(removal);
+            }
+            Err(err) => return Err(err.into()),
+        };
+        if entry.file_type().is_dir() {
+            removal.num_dirs += 1;
+        } else {
+            removal.num_files += 1;
+            if let Ok(metadata) = entry.metadata() {
+                removal.total_bytes += metadata.len();
+            }
+        }
+    }
+    Ok(removal)
+}
+
 /// A builder for a [`Remover`] that can remove files and directories.
 #[derive(Default)]
 pub(crate) struct Remover {
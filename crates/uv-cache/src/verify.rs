@@ -0,0 +1,289 @@
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use rustc_hash::FxHashSet;
+use tracing::debug;
+
+use crate::removal::measure;
+use crate::{ARCHIVE_VERSION, ArchiveId, Cache, CacheBucket, Link, Removal, rm_rf};
+
+/// Verifies the contents of individual cache entries.
+///
+/// The cache layout (buckets, archives, and links) is owned by [`Cache::verify`], while the
+/// formats of the entries themselves (e.g., HTTP cache policies and serialized index responses)
+/// are owned by the crates that write them.
+pub trait CacheEntryVerifier {
+    /// Verify a file in the given [`CacheBucket`], returning a description of the corruption, if
+    /// any.
+    ///
+    /// Any pointers to archives should be checked against the given [`VerifiedArchives`].
+    ///
+    /// Returns `None` if the file isn't in a format known to the verifier.
+    fn verify_file(
+        &self,
+        bucket: CacheBucket,
+        path: &Path,
+        archives: &VerifiedArchives,
+    ) -> Option<Result<(), String>>;
+
+    /// Verify an unzipped wheel in the [`CacheBucket::Archive`] bucket, returning a description of
+    /// the corruption, if any.
+    fn verify_archive(&self, path: &Path) -> Result<(), String>;
+}
+
+/// The unzipped wheels that passed verification in the first pass of [`Cache::verify`].
+///
+/// In a dry run, broken archives are left in place, so they're tracked here to ensure that any
+/// references to them are reported as broken, as they would be once the archives are removed.
+pub struct VerifiedArchives<'a> {
+    cache: &'a Cache,
+    broken: FxHashSet<ArchiveId>,
+}
+
+impl VerifiedArchives<'_> {
+    /// Returns `true` if the archive with the given ID exists and passed verification.
+    pub fn contains(&self, id: &ArchiveId) -> bool {
+        !self.broken.contains(id) && self.cache.archive(id).is_dir()
+    }
+}
+
+/// A corrupted entry found by [`Cache::verify`].
+#[derive(Debug)]
+pub struct BrokenEntry {
+    /// The bucket containing the entry.
+    pub bucket: CacheBucket,
+    /// The path to the entry.
+    pub path: PathBuf,
+    /// The reason the entry is considered corrupted.
+    pub reason: BrokenReason,
+}
+
+/// The reason a [`BrokenEntry`] is considered corrupted.
+#[derive(Debug)]
+pub enum BrokenReason {
+    /// The entry's contents failed verification.
+    Invalid(String),
+    /// The entry is a link to an archive that doesn't exist.
+    DanglingLink,
+}
+
+impl Display for BrokenReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Invalid(reason) => f.write_str(reason),
+            Self::DanglingLink => f.write_str("link target does not exist"),
+        }
+    }
+}
+
+/// A summary of a [`Cache::verify`] run.
+#[derive(Debug, Default)]
+pub struct Verification {
+    /// The number of entries that were verified.
+    pub checked: usize,
+    /// The entries that failed verification.
+    pub broken: Vec<BrokenEntry>,
+    /// The files and directories removed (or, in a dry run, that would be removed).
+    pub removal: Removal,
+}
+
+impl Cache {
+    /// Verify the integrity of the cache, removing any corrupted entries.
+    ///
+    /// Checks, in order:
+    ///
+    /// 1. That each unzipped wheel in the [`CacheBucket::Archive`] bucket passes
+    ///    [`CacheEntryVerifier::verify_archive`].
+    /// 2. That each file in the [`CacheBucket::Simple`], [`CacheBucket::FlatIndex`],
+    ///    [`CacheBucket::Wheels`], and [`CacheBucket::SourceDistributions`] buckets passes
+    ///    [`CacheEntryVerifier::verify_file`].
+    /// 3. That each link to an archive (in the [`CacheBucket::Wheels`] and
+    ///    [`CacheBucket::SourceDistributions`] buckets) resolves.
+    ///
+    /// Since archives are verified first, any links (or pointers, as checked by the verifier) to
+    /// an archive found to be broken in the first pass are treated as broken in the subsequent
+    /// passes, including in a dry run.
+    ///
+    /// If `dry_run` is set, nothing is removed, and the returned summary instead reflects the
+    /// entries that would be removed.
+    ///
+    /// The caller must hold an exclusive lock on the cache.
+    pub fn verify(
+        &self,
+        verifier: &impl CacheEntryVerifier,
+        dry_run: bool,
+    ) -> Result<Verification, io::Error> {
+        let mut verification = Verification::default();
+        let mut archives = VerifiedArchives {
+            cache: self,
+            broken: FxHashSet::default(),
+        };
+
+        // First, verify the unzipped wheels.
+        match fs_err::read_dir(self.bucket(CacheBucket::Archive)) {
+            Ok(entries) => {
+                let mut entries = entries
+                    .map(|entry| entry.map(|entry| entry.path()))
+                    .collect::<Result<Vec<_>, _>>()?;
+                entries.sort();
+                for path in entries {
+                    if !fs_err::symlink_metadata(&path)?.is_dir() {
+                        continue;
+                    }
+                    verification.checked += 1;
+                    if let Err(reason) = verifier.verify_archive(&path) {
+                        if let Some(id) = archive_id(&path) {
+                            archives.broken.insert(id);
+                        }
+                        verification.remove(
+                            CacheBucket::Archive,
+                            path,
+                            BrokenReason::Invalid(reason),
+                            dry_run,
+                        )?;
+                    }
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => (),
+            Err(err) => return Err(err),
+        }
+
+        // Second, verify the individual files in each bucket.
+        for bucket in [
+            CacheBucket::Simple,
+            CacheBucket::FlatIndex,
+            CacheBucket::Wheels,
+            CacheBucket::SourceDistributions,
+        ] {
+            for path in files(&self.bucket(bucket))? {
+                let Some(result) = verifier.verify_file(bucket, &path, &archives) else {
+                    continue;
+                };
+                verification.checked += 1;
+                if let Err(reason) = result {
+                    verification.remove(bucket, path, BrokenReason::Invalid(reason), dry_run)?;
+                }
+            }
+        }
+
+        // Third, verify that every link to an archive resolves.
+        for bucket in [CacheBucket::Wheels, CacheBucket::SourceDistributions] {
+            for path in links(&self.bucket(bucket))? {
+                verification.checked += 1;
+                let resolves = self
+                    .resolve_link(&path)
+                    .ok()
+                    .and_then(|target| archive_id(&target))
+                    .is_some_and(|id| archives.contains(&id));
+                if !resolves {
+                    verification.remove(bucket, path, BrokenReason::DanglingLink, dry_run)?;
+                }
+            }
+        }
+
+        Ok(verification)
+    }
+}
+
+impl Verification {
+    /// Record a broken entry, removing it unless `dry_run` is set.
+    fn remove(
+        &mut self,
+        bucket: CacheBucket,
+        path: PathBuf,
+        reason: BrokenReason,
+        dry_run: bool,
+    ) -> Result<(), io::Error> {
+        if dry_run {
+            self.removal += measure(&path)?;
+        } else {
+            debug!("Removing broken cache entry ({reason}): {}", path.display());
+            self.removal += rm_rf(&path)?;
+        }
+        self.broken.push(BrokenEntry {
+            bucket,
+            path,
+            reason,
+        });
+        Ok(())
+    }
+}
+
+/// Return the ID of the archive at the given path.
+fn archive_id(path: &Path) -> Option<ArchiveId> {
+    let name = path.file_name()?.to_str()?;
+    ArchiveId::from_str(name).ok()
+}
+
+/// Find all links to archives in the given bucket directory.
+fn links(root: &Path) -> Result<Vec<PathBuf>, io::Error> {
+    let mut links = Vec::new();
+    for entry in walk(root) {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(err) if is_not_found(&err) => continue,
+            Err(err) => return Err(err.into()),
+        };
+
+        // On Unix, archive references use symlinks.
+        if cfg!(unix) && entry.file_type().is_symlink() {
+            links.push(entry.into_path());
+            continue;
+        }
+
+        // On Windows, archive references are files containing structured data.
+        if cfg!(windows) && entry.file_type().is_file() && entry.path().extension().is_none() {
+            let is_link = fs_err::read_to_string(entry.path())
+                .ok()
+                .and_then(|contents| Link::from_str(&contents).ok())
+                .is_some_and(|link| link.version == ARCHIVE_VERSION);
+            if is_link {
+                links.push(entry.into_path());
+            }
+        }
+    }
+    links.sort();
+    Ok(links)
+}
+
+/// Find all regular files in the given bucket directory.
+fn files(root: &Path) -> Result<Vec<PathBuf>, io::Error> {
+    let mut files = Vec::new();
+    for entry in walk(root) {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(err) if is_not_found(&err) => continue,
+            Err(err) => return Err(err.into()),
+        };
+        if entry.file_type().is_file() {
+            files.push(entry.into_path());
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Walk a bucket directory, without following links, skipping any lockfiles and unpacked source
+/// distributions (the `src` directory).
+fn walk(root: &Path) -> impl Iterator<Item = walkdir::Result<walkdir::DirEntry>> {
+    walkdir::WalkDir::new(root)
+        .into_iter()
+        .filter_entry(|entry| {
+            !(entry.file_name() == "src"
+                || entry.file_name() == ".lock"
+                || entry.file_name() == ".gitignore"
+                || entry
+                    .path()
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("lock")))
+        })
+}
+
+/// Returns `true` if the error indicates that the path doesn't exist (e.g., the bucket hasn't
+/// been created yet).
+fn is_not_found(err: &walkdir::Error) -> bool {
+    err.io_error()
+        .is_some_and(|err| err.kind() == io::ErrorKind::NotFound)
+}
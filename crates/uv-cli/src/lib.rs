@@ -25,7 +25,7 @@ use uv_normalize::{ExtraName, GroupName, PackageName, PipGroupName};
 use uv_pep508::{MarkerTree, Requirement};
 use uv_preview::PreviewFeatures;
 use uv_pypi_types::VerbatimParsedUrl;
-use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
+use uv_python::{ImplementationName, PythonDownloads, PythonPreference, PythonVersion};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
     AnnotationStyle, ExcludeNewerPackageEntry, ExcludeNewerValue, ForkStrategy, PrereleaseMode,
@@ -67,6 +67,59 @@ pub enum SyncFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum LockFormat {
+    /// Display the result in a human-readable format.
+    #[default]
+    Text,
+    /// Display the result in JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum UpgradeLimit {
+    /// Allow upgrades to any version that satisfies the project's requirements.
+    #[default]
+    Major,
+    /// Only allow upgrades within the same major version, e.g., from `1.2.3` to `1.4.0`.
+    Minor,
+    /// Only allow upgrades within the same minor version, e.g., from `1.2.3` to `1.2.5`.
+    Patch,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum TreeFormat {
+    /// Display the dependency tree in a human-readable format.
+    #[default]
+    Text,
+    /// Display the dependency tree in JSON format, as a nested list of packages.
+    Json,
+    /// Display the dependency graph in the Graphviz DOT format.
+    Dot,
+    /// Display the dependency graph as a Mermaid flowchart.
+    Mermaid,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum PipCheckFormat {
+    /// Display the incompatibilities in a human-readable format.
+    #[default]
+    Text,
+    /// Display the incompatibilities in JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum AuditFormat {
+    /// Display the vulnerabilities in a human-readable format.
+    #[default]
+    Text,
+    /// Display the vulnerabilities in JSON format.
+    Json,
+    /// Display the vulnerabilities in the SARIF 2.1.0 format, for use with code scanning tools.
+    Sarif,
+}
+
 #[derive(Debug, Default, Clone, clap::ValueEnum)]
 pub enum ListFormat {
     /// Display the list of packages in a human-readable table.
@@ -517,6 +570,12 @@ pub enum Commands {
     Build(BuildArgs),
     /// Upload distributions to an index.
     Publish(PublishArgs),
+    /// Manage package indexes.
+    #[command(
+        after_help = "Use `uv help index` for more details.",
+        after_long_help = ""
+    )]
+    Index(IndexNamespace),
     /// Inspect uv workspaces.
     #[command(
         after_help = "Use `uv help workspace` for more details.",
@@ -889,6 +948,28 @@ pub enum CacheCommand {
     /// wheels, source distributions, and other cached data. By default, outputs the size in raw
     /// bytes; use `--human` for human-readable output.
     Size(SizeArgs),
+    /// Export the cache entries required by a lockfile to a portable bundle.
+    ///
+    /// Writes the Simple API responses, downloaded and built wheels, and unzipped wheel archives
+    /// for every package in the lockfile to a single tarball, which can be imported into another
+    /// cache with `uv cache import` (e.g., to warm a CI runner or an air-gapped machine).
+    ///
+    /// File contents are stored once per SHA-256 digest and verified on import.
+    Export(CacheExportArgs),
+    /// Import the cache entries from a bundle created by `uv cache export`.
+    ///
+    /// Re-creates the cache layout (including the links between cache entries), such that the
+    /// exported packages can be installed without network access, e.g., via `uv sync --offline`.
+    Import(CacheImportArgs),
+    /// Verify the integrity of the cache, removing any corrupted entries.
+    ///
+    /// Checks that unzipped wheels match the hashes recorded in their `RECORD` files, that cached
+    /// index responses and HTTP cache policies can be deserialized, and that links between cache
+    /// entries resolve.
+    ///
+    /// Each corrupted entry is removed individually (and re-fetched on next use), such that the
+    /// remainder of the cache is retained.
+    Verify(VerifyArgs),
 }
 
 #[derive(Args, Debug)]
@@ -929,6 +1010,23 @@ pub struct PruneArgs {
     /// `--force` is used, `uv cache prune` will proceed without taking a lock.
     #[arg(long)]
     pub force: bool,
+
+    /// Remove any wheels, source distribution builds, and unzipped wheels that aren't required by
+    /// the given lockfiles.
+    ///
+    /// Accepts paths to `uv.lock` files, or to directories containing a `uv.lock` (e.g., a
+    /// workspace root). Glob patterns are expanded, such that `--keep-lock 'projects/*'` retains
+    /// the entries required by every workspace in the `projects` directory.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_name = "PATH", value_hint = ValueHint::AnyPath)]
+    pub keep_lock: Vec<String>,
+
+    /// Report the cache entries that would be removed by `--keep-lock`, without removing them.
+    ///
+    /// Displays the reclaimable size for each cache bucket.
+    #[arg(long, requires = "keep_lock")]
+    pub dry_run: bool,
 }
 
 #[derive(Args, Debug)]
@@ -938,6 +1036,72 @@ pub struct SizeArgs {
     pub human: bool,
 }
 
+#[derive(Args, Debug)]
+pub struct CacheExportArgs {
+    /// The path to which the bundle should be written, e.g., `cache.tar.gz`.
+    #[arg(value_hint = ValueHint::FilePath)]
+    pub output: PathBuf,
+
+    /// The lockfile whose packages should be exported.
+    #[arg(long, default_value = "uv.lock", value_hint = ValueHint::FilePath)]
+    pub lockfile: PathBuf,
+}
+
+#[derive(Args, Debug)]
+pub struct VerifyArgs {
+    /// Force verification of the cache, ignoring in-use checks.
+    ///
+    /// By default, `uv cache verify` will block until no process is reading the cache. When
+    /// `--force` is used, `uv cache verify` will proceed without taking a lock.
+    #[arg(long)]
+    pub force: bool,
+
+    /// Report any corrupted cache entries, without removing them.
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct CacheImportArgs {
+    /// The path to the bundle to import.
+    #[arg(value_hint = ValueHint::FilePath)]
+    pub bundle: PathBuf,
+}
+
+#[derive(Args)]
+pub struct IndexNamespace {
+    #[command(subcommand)]
+    pub command: IndexCommand,
+}
+
+#[derive(Subcommand)]
+pub enum IndexCommand {
+    /// Build a local metadata index from the cache.
+    ///
+    /// The metadata index contains only the Simple API listings and `METADATA` files for the
+    /// packages in the cache, without any distributions. It can be passed to `uv lock
+    /// --metadata-index` to resolve without querying the registry, e.g., in a sandboxed CI
+    /// environment with `--offline`.
+    ///
+    /// The cache is typically populated by running `uv lock` beforehand. Metadata is included for
+    /// each version whose metadata was fetched from a wheel; other versions are listed, but
+    /// resolving to them requires network access.
+    BuildMetadata(IndexBuildMetadataArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct IndexBuildMetadataArgs {
+    /// The directory to which the metadata index should be written.
+    #[arg(value_hint = ValueHint::DirPath)]
+    pub output: PathBuf,
+
+    /// Only include the given packages in the metadata index.
+    ///
+    /// By default, all packages in the cache are included.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub package: Vec<PackageName>,
+}
+
 #[derive(Args)]
 pub struct PipNamespace {
     #[command(subcommand)]
@@ -1107,6 +1271,30 @@ pub enum ProjectCommand {
         after_long_help = ""
     )]
     Remove(RemoveArgs),
+    /// Raise the lower bounds of the project's requirements.
+    ///
+    /// The project is re-locked with upgrades enabled for its direct dependencies, and the lower
+    /// bounds of the requirements in `project.dependencies`, `project.optional-dependencies`, and
+    /// `dependency-groups` are raised to the locked versions, e.g., `requests>=2.20` becomes
+    /// `requests>=2.32.3`. The kind of each bound is preserved: `~=2.20` becomes `~=2.32`.
+    ///
+    /// Upper bounds and exact pins are left unchanged, so requirements are never raised beyond
+    /// the versions they already permit. Use `--limit` to further restrict upgrades to the same
+    /// major or minor version as the locked version.
+    ///
+    /// Requirements without a lower bound, and requirements on URLs or paths, are left
+    /// unchanged.
+    ///
+    /// To raise the lower bounds to the versions in the existing lockfile without re-locking, use
+    /// `--frozen`.
+    ///
+    /// uv will search for a project in the current directory or any parent directory. If a project
+    /// cannot be found, uv will exit with an error.
+    #[command(
+        after_help = "Use `uv help upgrade` for more details.",
+        after_long_help = ""
+    )]
+    Upgrade(UpgradeArgs),
     /// Read or update the project's version.
     Version(VersionArgs),
     /// Update the project's environment.
@@ -1166,6 +1354,23 @@ pub enum ProjectCommand {
     Export(ExportArgs),
     /// Display the project's dependency tree.
     Tree(TreeArgs),
+    /// Audit the project's lockfile for known vulnerabilities.
+    ///
+    /// Each package in the lockfile (`uv.lock`) is matched against an offline snapshot of an
+    /// advisory database in the Open Source Vulnerability (OSV) format, such as the export
+    /// published at <https://osv-vulnerabilities.storage.googleapis.com/PyPI/all.zip>. No network
+    /// requests are made, and the lockfile is not updated.
+    ///
+    /// Vulnerable packages that can only be installed on environments that the project does not
+    /// target (as determined by `--environment`, or the project's `required-environments` by
+    /// default) are reported separately, and do not cause the audit to fail.
+    ///
+    /// uv will exit with a non-zero status if any vulnerabilities are found.
+    #[command(
+        after_help = "Use `uv help audit` for more details.",
+        after_long_help = ""
+    )]
+    Audit(AuditArgs),
     /// Format Python code in the project.
     ///
     /// Formats Python code using the Ruff formatter. By default, all Python files in the project
@@ -1181,6 +1386,37 @@ pub enum ProjectCommand {
         after_long_help = ""
     )]
     Format(FormatArgs),
+    /// Lint Python code in the project.
+    ///
+    /// Lints Python code using the Ruff linter. By default, all Python files in the project are
+    /// checked. This command has the same behavior as running `ruff check` in the project root.
+    ///
+    /// To apply fixes for the reported violations, use `--fix`. To see a diff of the fixes without
+    /// applying them, use `--diff`.
+    ///
+    /// The version of Ruff can be set with `--version`, or with the `version` of the `ruff` entry in
+    /// the `binaries` setting.
+    ///
+    /// Additional arguments can be passed to Ruff after `--`.
+    #[command(
+        after_help = "Use `uv help lint` for more details.",
+        after_long_help = ""
+    )]
+    Lint(LintArgs),
+    /// Run a binary tool defined in the `binaries` setting.
+    ///
+    /// Installs the binary if necessary, then runs it in the project root. For example, with a
+    /// `[tool.uv.binaries.ty]` definition, `uv bin ty -- check` runs `ty check`.
+    ///
+    /// The version of the binary can be set with `--version`, or with the `version` of its entry in
+    /// the `binaries` setting.
+    ///
+    /// Additional arguments can be passed to the binary after `--`.
+    #[command(
+        after_help = "Use `uv help bin` for more details.",
+        after_long_help = ""
+    )]
+    Bin(BinArgs),
 }
 
 /// A re-implementation of `Option`, used to avoid Clap's automatic `Option` flattening in
@@ -2735,6 +2971,17 @@ pub struct PipCheckArgs {
     /// `ANDROID_API_LEVEL` to specify a different minimum version, e.g., `26`.
     #[arg(long)]
     pub python_platform: Option<TargetTriple>,
+
+    /// Verify the installed files of each package against the hashes in its `RECORD` file.
+    ///
+    /// Reports files that were modified or removed after installation. Requires reading and
+    /// hashing every installed file, so it's disabled by default.
+    #[arg(long)]
+    pub verify_record: bool,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = PipCheckFormat::default())]
+    pub output_format: PipCheckFormat,
 }
 
 #[derive(Args)]
@@ -4106,6 +4353,9 @@ pub struct SyncArgs {
 
 #[derive(Args)]
 pub struct LockArgs {
+    #[command(subcommand)]
+    pub command: Option<LockCommand>,
+
     /// Check if the lockfile is up-to-date.
     ///
     /// Asserts that the `uv.lock` would remain unchanged after a resolution. If the lockfile is
@@ -4142,6 +4392,140 @@ pub struct LockArgs {
     )]
     pub dry_run: bool,
 
+    /// Compare the lockfile to the lockfile at a Git revision, and report the differences.
+    ///
+    /// The `uv.lock` at the given revision (e.g., `main` or `HEAD~1`) is used as the base.
+    /// Defaults to `HEAD`. To compare against a lockfile on disk, use `--diff-file`.
+    ///
+    /// Reports added, removed, upgraded, and downgraded packages (per resolution fork), along with
+    /// changes to package sources, distribution hashes, and the `exclude-newer` setting. The
+    /// project is not re-locked, and the lockfile is left unchanged.
+    #[arg(
+        long,
+        num_args = 0..=1,
+        default_missing_value = "HEAD",
+        value_name = "REVISION",
+        value_hint = ValueHint::Other,
+        group = "lock_diff",
+        conflicts_with_all = ["check", "locked", "check_exists", "dry_run", "script", "record", "replay", "explain", "profile"],
+    )]
+    pub diff: Option<String>,
+
+    /// Compare the lockfile to the lockfile at the given path, and report the differences.
+    ///
+    /// The path is relative to the workspace root. See `--diff` for the reported differences.
+    #[arg(
+        long,
+        value_name = "PATH",
+        value_hint = ValueHint::FilePath,
+        group = "lock_diff",
+        conflicts_with_all = ["check", "locked", "check_exists", "dry_run", "script", "record", "replay", "explain", "profile"],
+    )]
+    pub diff_file: Option<PathBuf>,
+
+    /// The format in which to display the lockfile differences.
+    #[arg(long, value_enum, default_value_t = LockFormat::default(), requires = "lock_diff")]
+    pub output_format: LockFormat,
+
+    /// Record the package versions and metadata seen during resolution to a trace file.
+    ///
+    /// The trace can be passed to `--replay` to reproduce the resolution without network access,
+    /// e.g., when reporting a resolver bug. The project is re-resolved even if the lockfile is
+    /// up-to-date. The trace is written even if the resolution fails.
+    #[arg(
+        long,
+        value_name = "PATH",
+        value_hint = ValueHint::FilePath,
+        conflicts_with_all = ["check_exists", "replay"],
+    )]
+    pub record: Option<PathBuf>,
+
+    /// Resolve against a trace file written by `--record`, without network access.
+    ///
+    /// Package versions and metadata are read from the trace rather than from the configured
+    /// indexes. Local packages, such as workspace members, are read from disk.
+    #[arg(
+        long,
+        value_name = "PATH",
+        value_hint = ValueHint::FilePath,
+        conflicts_with = "check_exists"
+    )]
+    pub replay: Option<PathBuf>,
+
+    /// Explain why a package was resolved to its selected version, rather than a newer one.
+    ///
+    /// For each resolution fork, reports the constraints that excluded newer versions of the
+    /// package, such as requirements from other packages, `requires-python`, yanked versions,
+    /// `exclude-newer`, missing wheels for required environments, and lockfile preferences. The
+    /// project is re-resolved even if the lockfile is up-to-date.
+    #[arg(
+        long,
+        value_name = "PACKAGE",
+        value_hint = ValueHint::Other,
+        conflicts_with = "check_exists"
+    )]
+    pub explain: Option<PackageName>,
+
+    /// Write a profile of the resolution to the given directory.
+    ///
+    /// The profile includes the time spent fetching metadata and building source distributions
+    /// for each package, the number of resolver forks and why they were created, backtracking
+    /// counts, and batch prefetch hit rates. It's written as a JSON report (`profile.json`) and a
+    /// Chrome trace-event file (`trace.json`), which can be opened in `chrome://tracing` or
+    /// Perfetto. The project is re-resolved even if the lockfile is up-to-date. The profile is
+    /// written even if the resolution fails.
+    #[arg(
+        long,
+        value_name = "DIR",
+        value_hint = ValueHint::DirPath,
+        conflicts_with = "check_exists"
+    )]
+    pub profile: Option<PathBuf>,
+
+    /// Read Simple API listings and package metadata from a local metadata index.
+    ///
+    /// The metadata index can be built from the cache with `uv index build-metadata`. Packages and
+    /// wheels that are present in the metadata index are resolved without querying the registry;
+    /// combined with `--offline`, this allows locking without network access or downloaded
+    /// wheels.
+    #[arg(
+        long,
+        value_name = "DIR",
+        value_hint = ValueHint::DirPath,
+        env = EnvVars::UV_METADATA_INDEX
+    )]
+    pub metadata_index: Option<PathBuf>,
+
+    /// Report whether the lockfile can be installed on each of the given platforms.
+    ///
+    /// Each platform is a target triple (e.g., `x86_64-manylinux_2_28` or `aarch64-apple-darwin`),
+    /// optionally followed by `@` and a Python version (e.g., `x86_64-pc-windows-msvc@3.12`). The
+    /// Python version may be prefixed with an implementation, as in `cpython3.12` or `pypy3.10`.
+    /// If the Python version is omitted, the minimum version supported by the project is used.
+    ///
+    /// For each platform, uv reports the packages that lack a compatible wheel and would be built
+    /// from source, and the packages that can't be installed at all. If any package can't be
+    /// installed on a platform, uv exits with an error.
+    ///
+    /// May be provided multiple times, or as a comma-separated list.
+    #[arg(long, value_name = "PLATFORM", value_delimiter = ',')]
+    pub platforms: Vec<LockPlatform>,
+
+    /// Declare incompatible extras and dependency groups as conflicting.
+    ///
+    /// If resolution fails solely because two or more extras or dependency groups of workspace
+    /// members require incompatible versions of a package, uv will determine the
+    /// `tool.uv.conflicts` entries required for resolution to succeed (re-resolving as needed),
+    /// add them to the relevant `pyproject.toml`, and lock the project again.
+    ///
+    /// Without this flag, uv will instead suggest the entries to add, based on the failed
+    /// resolution alone.
+    #[arg(
+        long,
+        conflicts_with_all = ["check", "locked", "check_exists", "dry_run", "lock_diff", "script"]
+    )]
+    pub auto_conflicts: bool,
+
     /// Lock the specified Python script, rather than the current project.
     ///
     /// If provided, uv will lock the script (based on its inline metadata table, in adherence with
@@ -4179,6 +4563,132 @@ pub struct LockArgs {
     pub python: Option<Maybe<String>>,
 }
 
+/// A target platform against which to validate the lockfile, as in `x86_64-manylinux_2_28@3.12`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockPlatform {
+    /// The target triple.
+    pub triple: TargetTriple,
+    /// The Python implementation, if specified.
+    pub implementation: Option<ImplementationName>,
+    /// The Python version, if specified.
+    pub python_version: Option<PythonVersion>,
+}
+
+impl Display for LockPlatform {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let triple = self
+            .triple
+            .to_possible_value()
+            .expect("target triples are never skipped");
+        write!(f, "{}", triple.get_name())?;
+        match (self.implementation, &self.python_version) {
+            (Some(implementation), Some(version)) => write!(f, "@{implementation}{version}"),
+            (Some(implementation), None) => write!(f, "@{implementation}"),
+            (None, Some(version)) => write!(f, "@{version}"),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+impl FromStr for LockPlatform {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (triple, python) = match input.split_once('@') {
+            Some((triple, python)) => (triple, Some(python)),
+            None => (input, None),
+        };
+
+        let triple = TargetTriple::from_str(triple, false)
+            .map_err(|_| format!("unknown target triple `{triple}`"))?;
+
+        let Some(python) = python else {
+            return Ok(Self {
+                triple,
+                implementation: None,
+                python_version: None,
+            });
+        };
+
+        // Split the implementation prefix (e.g., `pypy` in `pypy3.10`) from the version.
+        let split = python
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(python.len());
+        let (implementation, version) = python.split_at(split);
+
+        let implementation = if implementation.is_empty() {
+            None
+        } else {
+            match ImplementationName::from_str(implementation) {
+                Ok(implementation @ (ImplementationName::CPython | ImplementationName::PyPy)) => {
+                    Some(implementation)
+                }
+                _ => {
+                    return Err(format!(
+                        "unsupported Python implementation `{implementation}` (expected `cpython` or `pypy`)"
+                    ));
+                }
+            }
+        };
+
+        let python_version = if version.is_empty() {
+            None
+        } else {
+            Some(PythonVersion::from_str(version)?)
+        };
+
+        Ok(Self {
+            triple,
+            implementation,
+            python_version,
+        })
+    }
+}
+
+#[derive(Subcommand)]
+pub enum LockCommand {
+    /// Merge two versions of the lockfile, for use as a Git merge driver.
+    ///
+    /// The project is re-resolved with the locked versions from both lockfiles as preferences,
+    /// and the merged lockfile is written to the `OURS` path. When both lockfiles pin different
+    /// versions of a package, the version preferred by the resolution strategy (e.g., the higher
+    /// version) is retained.
+    ///
+    /// If the lockfiles can't be parsed, if the project's requirements don't include the
+    /// requirement changes from both lockfiles, or if the resolution fails, the lockfile is
+    /// instead merged line-by-line, with conflict markers, and uv exits with an error.
+    ///
+    /// To use as a Git merge driver, add the following to `.git/config` (or `~/.gitconfig`):
+    ///
+    /// ```text
+    /// [merge "uv-lock"]
+    ///     name = uv lockfile merge driver
+    ///     driver = uv lock merge %O %A %B
+    /// ```
+    ///
+    /// And the following to `.gitattributes`:
+    ///
+    /// ```text
+    /// uv.lock merge=uv-lock
+    /// ```
+    Merge(LockMergeArgs),
+}
+
+#[derive(Args)]
+pub struct LockMergeArgs {
+    /// The lockfile from the common ancestor (`%O`).
+    #[arg(value_hint = ValueHint::FilePath)]
+    pub base: PathBuf,
+
+    /// The lockfile from the current branch (`%A`), which is overwritten with the merged lockfile.
+    #[arg(value_hint = ValueHint::FilePath)]
+    pub ours: PathBuf,
+
+    /// The lockfile from the branch being merged (`%B`).
+    #[arg(value_hint = ValueHint::FilePath)]
+    pub theirs: PathBuf,
+}
+
 #[derive(Args)]
 #[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
 pub struct AddArgs {
@@ -4218,6 +4728,19 @@ pub struct AddArgs {
     )]
     pub constraints: Vec<Maybe<PathBuf>>,
 
+    /// Add the third-party packages imported by the project's Python sources.
+    ///
+    /// uv will scan the project's Python files and Jupyter notebooks (or the script, if `--script`
+    /// is provided) for top-level imports, ignoring modules from the standard library, modules
+    /// defined by the project itself, and packages that are already declared as dependencies. The
+    /// remaining modules are mapped to package names (e.g., `yaml` to `PyYAML`) and added.
+    ///
+    /// Modules that aren't in the mapping are skipped with a warning, rather than added under the
+    /// module name, as a package of the same name may be unrelated to the module. The mapping from
+    /// module names to package names is heuristic, so the inferred packages should be reviewed.
+    #[arg(long, group = "sources")]
+    pub from_imports: bool,
+
     /// Apply this marker to all added packages.
     #[arg(long, short, value_parser = MarkerTree::from_str, value_hint = ValueHint::Other)]
     pub marker: Option<MarkerTree>,
@@ -4615,6 +5138,80 @@ pub struct RemoveArgs {
     pub python: Option<Maybe<String>>,
 }
 
+#[derive(Args)]
+pub struct UpgradeArgs {
+    /// The names of the dependencies to upgrade (e.g., `ruff`).
+    ///
+    /// If no packages are provided, all direct dependencies in the selected sections are upgraded.
+    #[arg(value_hint = ValueHint::Other)]
+    pub packages: Vec<PackageName>,
+
+    /// The largest version change to allow when upgrading.
+    ///
+    /// Limits are relative to the currently locked version of each package.
+    #[arg(long, value_enum, default_value_t = UpgradeLimit::default())]
+    pub limit: UpgradeLimit,
+
+    /// Only upgrade the requirements in the development dependency group.
+    ///
+    /// This option is an alias for `--group dev`.
+    #[arg(long, value_parser = clap::builder::BoolishValueParser::new())]
+    pub dev: bool,
+
+    /// Only upgrade the requirements in the project's optional dependencies for the specified
+    /// extra.
+    ///
+    /// May be provided more than once. Can be combined with `--group` and `--dev`.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub optional: Vec<ExtraName>,
+
+    /// Only upgrade the requirements in the specified dependency group.
+    ///
+    /// May be provided more than once. Can be combined with `--optional` and `--dev`.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub group: Vec<GroupName>,
+
+    /// Upgrade the requirements of all packages in the workspace.
+    #[arg(long, conflicts_with = "package")]
+    pub all_packages: bool,
+
+    /// Upgrade the requirements of a specific package in the workspace.
+    ///
+    /// If the workspace member does not exist, uv will exit with an error.
+    #[arg(long, conflicts_with = "all_packages", value_hint = ValueHint::Other)]
+    pub package: Option<PackageName>,
+
+    /// Perform a dry run, without writing the lockfile or modifying the `pyproject.toml`.
+    ///
+    /// In dry-run mode, uv will resolve the project's dependencies and report the requirements
+    /// that would be changed.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to use during resolution.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+        value_hint = ValueHint::Other,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
 #[derive(Args)]
 pub struct TreeArgs {
     /// Show a platform-independent dependency tree.
@@ -4626,6 +5223,13 @@ pub struct TreeArgs {
     #[arg(long)]
     pub universal: bool,
 
+    /// The format in which to display the dependency tree.
+    ///
+    /// The `json` format renders the tree as nested packages, while the `dot` and `mermaid`
+    /// formats render the dependency graph, with each package shown once.
+    #[arg(long, value_enum, default_value_t = TreeFormat::default())]
+    pub format: TreeFormat,
+
     #[command(flatten)]
     pub tree: DisplayTreeArgs,
 
@@ -4760,7 +5364,8 @@ pub struct ExportArgs {
     #[allow(clippy::doc_markdown)]
     /// The format to which `uv.lock` should be exported.
     ///
-    /// Supports `requirements.txt`, `pylock.toml` (PEP 751) and CycloneDX v1.5 JSON output formats.
+    /// Supports `requirements.txt`, `pylock.toml` (PEP 751), CycloneDX v1.5 and v1.6 JSON, SPDX v2.3
+    /// JSON, and SPDX v3.0 JSON-LD output formats.
     ///
     /// uv will infer the output format from the file extension of the output file, if
     /// provided. Otherwise, defaults to `requirements.txt`.
@@ -5050,6 +5655,47 @@ pub struct ExportArgs {
     pub python: Option<Maybe<String>>,
 }
 
+#[derive(Args)]
+pub struct AuditArgs {
+    /// The path to the advisory database.
+    ///
+    /// Accepts a directory containing OSV advisories as JSON files, or a `.zip` archive of such
+    /// files.
+    #[arg(long, env = EnvVars::UV_AUDIT_DATABASE, value_hint = ValueHint::AnyPath)]
+    pub database: PathBuf,
+
+    /// Ignore the advisory with the given identifier or alias (e.g., `PYSEC-2021-66` or
+    /// `CVE-2020-28493`).
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub ignore: Vec<String>,
+
+    /// Ignore all advisories for the given package.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub ignore_package: Vec<PackageName>,
+
+    /// The environments to audit, as PEP 508 markers (e.g., `sys_platform == 'linux'`).
+    ///
+    /// Vulnerable packages that are only installed outside these environments are reported
+    /// separately. Defaults to the project's `required-environments`, or all environments if none
+    /// are declared.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, value_parser = MarkerTree::from_str, value_hint = ValueHint::Other)]
+    pub environment: Vec<MarkerTree>,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = AuditFormat::default())]
+    pub output_format: AuditFormat,
+
+    /// Write the audit report to the given file.
+    #[arg(long, short, value_hint = ValueHint::FilePath)]
+    pub output_file: Option<PathBuf>,
+}
+
 #[derive(Args)]
 pub struct FormatArgs {
     /// Check if files are formatted without applying changes.
@@ -5084,6 +5730,64 @@ pub struct FormatArgs {
     pub no_project: bool,
 }
 
+#[derive(Args)]
+pub struct LintArgs {
+    /// Apply fixes to resolve lint violations, where possible.
+    #[arg(long)]
+    pub fix: bool,
+
+    /// Show a diff of the fixes that would be applied, without modifying any files.
+    #[arg(long)]
+    pub diff: bool,
+
+    /// The version of Ruff to use for linting.
+    ///
+    /// By default, a version of Ruff pinned by uv will be used.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub version: Option<String>,
+
+    /// Additional arguments to pass to Ruff.
+    ///
+    /// For example, use `uv lint -- --select I` to only check import sorting or
+    /// `uv lint -- src/module/foo.py` to lint a specific file.
+    #[arg(last = true, value_hint = ValueHint::Other)]
+    pub extra_args: Vec<String>,
+
+    /// Avoid discovering a project or workspace.
+    ///
+    /// Instead of running the linter in the context of the current project, run it in the context
+    /// of the current directory. This is useful when the current directory is not a project.
+    #[arg(long)]
+    pub no_project: bool,
+}
+
+#[derive(Args)]
+pub struct BinArgs {
+    /// The name of the binary to run, e.g., `ruff`, or any binary defined in the `binaries`
+    /// setting.
+    #[arg(value_hint = ValueHint::Other)]
+    pub name: String,
+
+    /// The version of the binary to run.
+    ///
+    /// By default, the version from the binary's definition will be used.
+    #[arg(long, value_hint = ValueHint::Other)]
+    pub version: Option<String>,
+
+    /// Additional arguments to pass to the binary.
+    ///
+    /// For example, use `uv bin ty -- check src` to type check the `src` directory.
+    #[arg(last = true, value_hint = ValueHint::Other)]
+    pub extra_args: Vec<String>,
+
+    /// Avoid discovering a project or workspace.
+    ///
+    /// Instead of running the binary in the project root, run it in the current directory. This is
+    /// useful when the current directory is not a project.
+    #[arg(long)]
+    pub no_project: bool,
+}
+
 #[derive(Args)]
 pub struct AuthNamespace {
     #[command(subcommand)]
@@ -5795,6 +6499,9 @@ pub struct ToolUpgradeArgs {
     /// Under `fewest`, uv will minimize the number of selected versions for each package,
     /// preferring older versions that are compatible with a wider range of supported Python
     /// versions or platforms.
+    ///
+    /// Under `single`, uv will select a single version of each package across all supported
+    /// Python versions and platforms, and fail if the requirements force a package to diverge.
     #[arg(
         long,
         value_enum,
@@ -7055,6 +7762,9 @@ pub struct ResolverArgs {
     /// Under `fewest`, uv will minimize the number of selected versions for each package,
     /// preferring older versions that are compatible with a wider range of supported Python
     /// versions or platforms.
+    ///
+    /// Under `single`, uv will select a single version of each package across all supported
+    /// Python versions and platforms, and fail if the requirements force a package to diverge.
     #[arg(
         long,
         value_enum,
@@ -7289,6 +7999,9 @@ pub struct ResolverInstallerArgs {
     /// Under `fewest`, uv will minimize the number of selected versions for each package,
     /// preferring older versions that are compatible with a wider range of supported Python
     /// versions or platforms.
+    ///
+    /// Under `single`, uv will select a single version of each package across all supported
+    /// Python versions and platforms, and fail if the requirements force a package to diverge.
     #[arg(
         long,
         value_enum,
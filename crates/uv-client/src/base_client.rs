@@ -721,6 +721,18 @@ impl BaseClient {
         }
     }
 
+    /// Selects the appropriate client without middleware based on the host's trustworthiness.
+    ///
+    /// Requests with a streaming body can't be cloned, and so can't be retried, redirected, or
+    /// re-authenticated by the middleware; callers are responsible for handling retries.
+    pub fn raw_for_host(&self, url: &DisplaySafeUrl) -> &Client {
+        if self.disable_ssl(url) {
+            &self.raw_dangerous_client
+        } else {
+            &self.raw_client
+        }
+    }
+
     /// Executes a request, applying redirect policy.
     pub async fn execute(&self, req: Request) -> reqwest_middleware::Result<Response> {
         let client = self.for_host(&DisplaySafeUrl::from_url(req.url().clone()));
@@ -782,6 +794,11 @@ impl RedirectClientWithMiddleware {
         RequestBuilder::new(self.client.post(url), self)
     }
 
+    /// Convenience method to make a `PUT` request to a URL.
+    pub fn put<U: IntoUrl>(&self, url: U) -> RequestBuilder<'_> {
+        RequestBuilder::new(self.client.put(url), self)
+    }
+
     /// Convenience method to make a `HEAD` request to a URL.
     pub fn head<U: IntoUrl>(&self, url: U) -> RequestBuilder<'_> {
         RequestBuilder::new(self.client.head(url), self)
@@ -1057,6 +1074,12 @@ impl<'a> RequestBuilder<'a> {
         self
     }
 
+    /// Set the request body.
+    pub fn body<T: Into<reqwest::Body>>(mut self, body: T) -> Self {
+        self.builder = self.builder.body(body);
+        self
+    }
+
     #[cfg_attr(docsrs, doc(cfg(feature = "multipart")))]
     pub fn multipart(mut self, multipart: multipart::Form) -> Self {
         self.builder = self.builder.multipart(multipart);
@@ -11,7 +11,7 @@ use uv_normalize::PackageName;
 use uv_redacted::DisplaySafeUrl;
 
 use crate::middleware::OfflineError;
-use crate::{FlatIndexError, html};
+use crate::{FlatIndexError, MetadataIndexError, html};
 
 /// RFC 9457 Problem Details for HTTP APIs
 ///
@@ -286,6 +286,9 @@ pub enum ErrorKind {
     #[error(transparent)]
     Flat(#[from] FlatIndexError),
 
+    #[error(transparent)]
+    MetadataIndex(#[from] MetadataIndexError),
+
     #[error("Expected a file URL, but received: {0}")]
     NonFileUrl(DisplaySafeUrl),
 
@@ -7,6 +7,7 @@ pub use cached_client::{CacheControl, CachedClient, CachedClientError, DataWithC
 pub use error::{Error, ErrorKind, WrappedReqwestError};
 pub use flat_index::{FlatIndexClient, FlatIndexEntries, FlatIndexEntry, FlatIndexError};
 pub use linehaul::LineHaul;
+pub use metadata_index::{MetadataIndex, MetadataIndexError, MetadataIndexSummary};
 pub use registry_client::{
     Connectivity, MetadataFormat, RegistryClient, RegistryClientBuilder, SimpleDetailMetadata,
     SimpleDetailMetadatum, SimpleIndexMetadata, VersionFiles,
@@ -20,6 +21,7 @@ mod flat_index;
 mod html;
 mod httpcache;
 mod linehaul;
+mod metadata_index;
 mod middleware;
 mod registry_client;
 mod remote_metadata;
@@ -0,0 +1,487 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use jiff::Timestamp;
+use serde::Serialize;
+use tracing::{debug, trace, warn};
+
+use uv_cache::{Cache, CacheBucket, WheelCache};
+use uv_distribution_filename::WheelFilename;
+use uv_distribution_types::{File, IndexUrl};
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pypi_types::{PypiSimpleDetail, ResolutionMetadata, Yanked};
+use uv_redacted::DisplaySafeUrl;
+
+use crate::registry_client::{SimpleDetailMetadatum, WheelMetadata};
+use crate::{DataWithCachePolicy, Error, ErrorKind, OwnedArchive, SimpleDetailMetadata};
+
+/// The name of the file containing the Simple API listing for a package.
+const LISTING: &str = "index.json";
+
+#[derive(Debug, thiserror::Error)]
+pub enum MetadataIndexError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to parse Simple API listing at: `{}`", _0.user_display())]
+    Listing(PathBuf, #[source] serde_json::Error),
+
+    #[error("Failed to parse metadata at: `{}`", _0.user_display())]
+    Metadata(PathBuf, #[source] uv_pypi_types::MetadataError),
+
+    #[error("Failed to serialize Simple API listing for `{0}`")]
+    Serialize(PackageName, #[source] serde_json::Error),
+
+    #[error("Failed to read cache entry at: `{}`", _0.user_display())]
+    Cache(PathBuf, #[source] Error),
+}
+
+/// A local, offline index of package metadata, containing only the Simple API listings and the
+/// `METADATA` files for a set of packages.
+///
+/// The index mirrors the layout of the cache, with a directory for each index URL (`pypi` or
+/// `index/{digest}`). Each package directory contains a [PEP 691](https://peps.python.org/pep-0691/)
+/// listing (`index.json`) and, for each wheel whose metadata is available, a
+/// [PEP 658](https://peps.python.org/pep-0658/) `{filename}.metadata` file. Listings retain the
+/// original file URLs, such that resolutions against the index match those against the
+/// underlying registry.
+#[derive(Debug, Clone)]
+pub struct MetadataIndex {
+    root: PathBuf,
+}
+
+/// A summary of the entries written to a [`MetadataIndex`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MetadataIndexSummary {
+    /// The number of package listings written to the index.
+    pub packages: usize,
+    /// The number of `METADATA` files written to the index.
+    pub metadata: usize,
+}
+
+impl MetadataIndex {
+    /// Create a [`MetadataIndex`] rooted at the given directory.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Return the root directory of the index.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Return the directory for a given package on a given index.
+    fn package_dir(&self, index: &IndexUrl, package_name: &PackageName) -> PathBuf {
+        self.root
+            .join(WheelCache::Index(index).wheel_dir(package_name.as_ref()))
+    }
+
+    /// Read the Simple API listing for a package, if it's present in the index.
+    ///
+    /// Relative file URLs are resolved against the given listing URL.
+    pub(crate) async fn simple_detail(
+        &self,
+        package_name: &PackageName,
+        index: &IndexUrl,
+        url: &DisplaySafeUrl,
+    ) -> Result<Option<SimpleDetailMetadata>, MetadataIndexError> {
+        let path = self.package_dir(index, package_name).join(LISTING);
+        let bytes = match fs_err::tokio::read(&path).await {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let data: PypiSimpleDetail =
+            serde_json::from_slice(&bytes).map_err(|err| MetadataIndexError::Listing(path, err))?;
+        Ok(Some(SimpleDetailMetadata::from_pypi_files(
+            data.files,
+            package_name,
+            url,
+        )))
+    }
+
+    /// Read the metadata for a wheel, if it's present in the index.
+    pub(crate) async fn wheel_metadata<T: WheelMetadata>(
+        &self,
+        index: &IndexUrl,
+        filename: &WheelFilename,
+    ) -> Result<Option<T>, MetadataIndexError> {
+        let path = self
+            .package_dir(index, &filename.name)
+            .join(format!("{filename}.metadata"));
+        let bytes = match fs_err::tokio::read(&path).await {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let metadata =
+            T::parse_metadata(&bytes).map_err(|err| MetadataIndexError::Metadata(path, err))?;
+        Ok(Some(metadata))
+    }
+
+    /// Populate the index from the Simple API listings and wheel metadata in the cache.
+    ///
+    /// If `packages` is non-empty, only the listings for the given packages are included.
+    ///
+    /// Since all wheels for a given version are assumed to share the same metadata, the cached
+    /// metadata for any wheel is written for every wheel of that version. Versions without any
+    /// cached metadata are retained in the listing, but resolving to them requires network
+    /// access.
+    pub fn build_from_cache(
+        &self,
+        cache: &Cache,
+        packages: &[PackageName],
+    ) -> Result<MetadataIndexSummary, MetadataIndexError> {
+        let simple = cache.bucket(CacheBucket::Simple);
+        let wheels = cache.bucket(CacheBucket::Wheels);
+
+        // Collect the per-index directories, e.g., `pypi` and `index/{digest}`.
+        let mut shards = Vec::new();
+        if simple.join("pypi").is_dir() {
+            shards.push(PathBuf::from("pypi"));
+        }
+        match fs_err::read_dir(simple.join("index")) {
+            Ok(entries) => {
+                for entry in entries {
+                    let entry = entry?;
+                    if entry.file_type()?.is_dir() {
+                        shards.push(Path::new("index").join(entry.file_name()));
+                    }
+                }
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+        shards.sort();
+
+        let mut summary = MetadataIndexSummary::default();
+        for shard in shards {
+            let mut entries = fs_err::read_dir(simple.join(&shard))?
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect::<Result<Vec<_>, _>>()?;
+            entries.sort();
+
+            for path in entries {
+                if path.extension().is_none_or(|ext| ext != "rkyv") {
+                    continue;
+                }
+                let Some(package_name) = path
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .and_then(|stem| PackageName::from_str(stem).ok())
+                else {
+                    continue;
+                };
+                if !packages.is_empty() && !packages.contains(&package_name) {
+                    continue;
+                }
+
+                let listing = read_simple_detail(&path)?;
+                let target = self.root.join(&shard).join(package_name.as_ref());
+                let written = write_package(
+                    &listing,
+                    &package_name,
+                    &wheels.join(&shard).join(package_name.as_ref()),
+                    &target,
+                )?;
+
+                debug!(
+                    "Wrote listing for `{package_name}` with {written} metadata file(s) to: {}",
+                    target.user_display()
+                );
+                summary.packages += 1;
+                summary.metadata += written;
+            }
+        }
+
+        Ok(summary)
+    }
+}
+
+/// Read a cached Simple API listing.
+fn read_simple_detail(path: &Path) -> Result<SimpleDetailMetadata, MetadataIndexError> {
+    let read = || -> Result<SimpleDetailMetadata, Error> {
+        let file = fs_err::File::open(path).map_err(ErrorKind::Io)?;
+        let data = DataWithCachePolicy::from_reader(file)?.data;
+        let archive = OwnedArchive::<SimpleDetailMetadata>::new(data)?;
+        Ok(OwnedArchive::deserialize(&archive))
+    };
+    read().map_err(|err| MetadataIndexError::Cache(path.to_path_buf(), err))
+}
+
+/// Read the cached metadata for a wheel, if any.
+fn read_wheel_metadata(
+    cache_dir: &Path,
+    filename: &WheelFilename,
+) -> Result<Option<ResolutionMetadata>, MetadataIndexError> {
+    let path = cache_dir.join(format!("{}.msgpack", filename.cache_key()));
+    let file = match fs_err::File::open(&path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    let data = DataWithCachePolicy::from_reader(file)
+        .map_err(|err| MetadataIndexError::Cache(path.clone(), err))?
+        .data;
+    match rmp_serde::from_slice::<ResolutionMetadata>(&data) {
+        Ok(metadata) => Ok(Some(metadata)),
+        Err(err) => {
+            // The entry may have been written by an incompatible version of uv.
+            warn!(
+                "Ignoring unreadable metadata at `{}`: {err}",
+                path.user_display()
+            );
+            Ok(None)
+        }
+    }
+}
+
+/// Write the listing and metadata for a single package, returning the number of `METADATA` files
+/// written.
+fn write_package(
+    listing: &SimpleDetailMetadata,
+    package_name: &PackageName,
+    cache_dir: &Path,
+    target: &Path,
+) -> Result<usize, MetadataIndexError> {
+    fs_err::create_dir_all(target)?;
+
+    let mut files = Vec::new();
+    let mut written = 0;
+    for SimpleDetailMetadatum {
+        version,
+        files: version_files,
+        metadata,
+    } in listing.iter()
+    {
+        // Find the metadata for any wheel in this version.
+        let mut metadata = metadata.clone();
+        for wheel in &version_files.wheels {
+            if metadata.is_some() {
+                break;
+            }
+            metadata = read_wheel_metadata(cache_dir, &wheel.name)?;
+        }
+        if metadata.is_none() && !version_files.wheels.is_empty() {
+            trace!("No cached metadata for `{package_name}=={version}`");
+        }
+        let contents = metadata
+            .as_ref()
+            .map(ResolutionMetadata::core_metadata_format);
+
+        for wheel in &version_files.wheels {
+            if let Some(contents) = &contents {
+                fs_err::write(target.join(format!("{}.metadata", wheel.name)), contents)?;
+                written += 1;
+            }
+            files.push(FileJson::new(&wheel.file, contents.is_some()));
+        }
+        for source_dist in &version_files.source_dists {
+            files.push(FileJson::new(&source_dist.file, false));
+        }
+    }
+    files.sort_by(|a, b| a.filename.cmp(b.filename));
+
+    let listing = ListingJson {
+        meta: MetaJson { api_version: "1.1" },
+        name: package_name,
+        files,
+    };
+    let contents = serde_json::to_string_pretty(&listing)
+        .map_err(|err| MetadataIndexError::Serialize(package_name.clone(), err))?;
+    fs_err::write(target.join(LISTING), contents)?;
+
+    Ok(written)
+}
+
+/// A [PEP 691](https://peps.python.org/pep-0691/) project detail response.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct ListingJson<'a> {
+    meta: MetaJson,
+    name: &'a PackageName,
+    files: Vec<FileJson<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct MetaJson {
+    api_version: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct FileJson<'a> {
+    filename: &'a str,
+    url: String,
+    hashes: BTreeMap<String, &'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requires_python: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    upload_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    yanked: Option<&'a Yanked>,
+    core_metadata: bool,
+}
+
+impl<'a> FileJson<'a> {
+    fn new(file: &'a File, core_metadata: bool) -> Self {
+        // Use absolute URLs, omitting any credentials, which are re-applied by the client.
+        let url = match file.url.to_url() {
+            Ok(url) => url.without_credentials().to_string(),
+            Err(_) => file.url.to_string(),
+        };
+        Self {
+            filename: file.filename.as_ref(),
+            url,
+            hashes: file
+                .hashes
+                .iter()
+                .map(|digest| (digest.algorithm.to_string(), digest.digest.as_ref()))
+                .collect(),
+            requires_python: file.requires_python.as_ref().map(ToString::to_string),
+            size: file.size,
+            upload_time: file
+                .upload_time_utc_ms
+                .and_then(|ms| Timestamp::from_millisecond(ms).ok())
+                .map(|timestamp| timestamp.to_string()),
+            yanked: file.yanked.as_deref(),
+            core_metadata,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn write_and_read_package() {
+        let dir = tempdir().unwrap();
+        let index = IndexUrl::parse("https://example.org/simple", None).unwrap();
+        let url = DisplaySafeUrl::parse("https://example.org/simple/example/").unwrap();
+        let package_name = PackageName::from_str("example").unwrap();
+
+        let data: PypiSimpleDetail = serde_json::from_str(
+            r#"{
+                "files": [
+                    {
+                        "filename": "example-1.0.0-py3-none-any.whl",
+                        "url": "../../files/example-1.0.0-py3-none-any.whl",
+                        "hashes": {"sha256": "0000"},
+                        "requires-python": ">=3.8",
+                        "upload-time": "2024-01-01T00:00:00Z"
+                    },
+                    {
+                        "filename": "example-1.0.0.tar.gz",
+                        "url": "https://files.example.org/example-1.0.0.tar.gz",
+                        "hashes": {"sha256": "1111"},
+                        "yanked": "Broken"
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        // Attach the metadata to the version, as if it had been read from the cache.
+        let metadata = ResolutionMetadata::parse_metadata(
+            b"Metadata-Version: 2.1\nName: example\nVersion: 1.0.0\nRequires-Dist: anyio>=4",
+        )
+        .unwrap();
+        let listing = SimpleDetailMetadata::from_pypi_files(data.files, &package_name, &url)
+            .into_iter()
+            .map(|datum| SimpleDetailMetadatum {
+                metadata: Some(metadata.clone()),
+                ..datum
+            })
+            .collect::<SimpleDetailMetadata>();
+
+        let metadata_index = MetadataIndex::new(dir.path());
+        let written = write_package(
+            &listing,
+            &package_name,
+            &dir.path().join("cache"),
+            &metadata_index.package_dir(&index, &package_name),
+        )
+        .unwrap();
+        assert_eq!(written, 1);
+
+        // Relative URLs are written as absolute URLs, and only the wheel provides metadata.
+        let listing = fs_err::read_to_string(
+            metadata_index
+                .package_dir(&index, &package_name)
+                .join(LISTING),
+        )
+        .unwrap();
+        insta::assert_snapshot!(listing, @r#"
+        {
+          "meta": {
+            "api-version": "1.1"
+          },
+          "name": "example",
+          "files": [
+            {
+              "filename": "example-1.0.0-py3-none-any.whl",
+              "url": "https://example.org/files/example-1.0.0-py3-none-any.whl",
+              "hashes": {
+                "sha256": "0000"
+              },
+              "requires-python": ">=3.8",
+              "upload-time": "2024-01-01T00:00:00Z",
+              "core-metadata": true
+            },
+            {
+              "filename": "example-1.0.0.tar.gz",
+              "url": "https://files.example.org/example-1.0.0.tar.gz",
+              "hashes": {
+                "sha256": "1111"
+              },
+              "yanked": "Broken",
+              "core-metadata": false
+            }
+          ]
+        }
+        "#);
+
+        let detail = metadata_index
+            .simple_detail(&package_name, &index, &url)
+            .await
+            .unwrap()
+            .unwrap();
+        let files = detail
+            .into_iter()
+            .flat_map(|datum| datum.files.all())
+            .map(|(filename, file)| (filename.to_string(), file.dist_info_metadata))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            files,
+            [
+                ("example-1.0.0.tar.gz".to_string(), false),
+                ("example-1.0.0-py3-none-any.whl".to_string(), true),
+            ]
+        );
+
+        let filename = WheelFilename::from_str("example-1.0.0-py3-none-any.whl").unwrap();
+        let metadata = metadata_index
+            .wheel_metadata::<ResolutionMetadata>(&index, &filename)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(metadata.requires_dist.len(), 1);
+
+        // Packages that are absent from the index are reported as such.
+        let other = PackageName::from_str("other").unwrap();
+        assert!(
+            metadata_index
+                .simple_detail(&other, &index, &url)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+}
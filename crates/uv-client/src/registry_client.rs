@@ -11,6 +11,8 @@ use http::{HeaderMap, StatusCode};
 use itertools::Either;
 use reqwest::{Proxy, Response};
 use rustc_hash::FxHashMap;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
 use tokio::sync::{Mutex, Semaphore};
 use tracing::{Instrument, debug, info_span, instrument, trace, warn};
 use url::Url;
@@ -30,7 +32,8 @@ use uv_pep440::Version;
 use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::Platform;
 use uv_pypi_types::{
-    PypiSimpleDetail, PypiSimpleIndex, PyxSimpleDetail, PyxSimpleIndex, ResolutionMetadata,
+    MetadataError, PackageLicense, PypiSimpleDetail, PypiSimpleIndex, PyxSimpleDetail,
+    PyxSimpleIndex, ResolutionMetadata,
 };
 use uv_redacted::DisplaySafeUrl;
 use uv_small_str::SmallString;
@@ -40,6 +43,7 @@ use crate::base_client::{BaseClientBuilder, ExtraMiddleware, RedirectPolicy};
 use crate::cached_client::CacheControl;
 use crate::flat_index::FlatIndexEntry;
 use crate::html::SimpleDetailHTML;
+use crate::metadata_index::MetadataIndex;
 use crate::remote_metadata::wheel_metadata_from_remote_zip;
 use crate::rkyvutil::OwnedArchive;
 use crate::{
@@ -53,6 +57,7 @@ pub struct RegistryClientBuilder<'a> {
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
     torch_backend: Option<TorchStrategy>,
+    metadata_index: Option<MetadataIndex>,
     cache: Cache,
     base_client_builder: BaseClientBuilder<'a>,
 }
@@ -63,6 +68,7 @@ impl<'a> RegistryClientBuilder<'a> {
             index_locations: IndexLocations::default(),
             index_strategy: IndexStrategy::default(),
             torch_backend: None,
+            metadata_index: None,
             cache,
             base_client_builder,
         }
@@ -92,6 +98,13 @@ impl<'a> RegistryClientBuilder<'a> {
         self
     }
 
+    /// Read Simple API listings and wheel metadata from a local [`MetadataIndex`], when available.
+    #[must_use]
+    pub fn metadata_index(mut self, metadata_index: Option<MetadataIndex>) -> Self {
+        self.metadata_index = metadata_index;
+        self
+    }
+
     #[must_use]
     pub fn keyring(mut self, keyring_type: KeyringProviderType) -> Self {
         self.base_client_builder = self.base_client_builder.keyring(keyring_type);
@@ -192,6 +205,7 @@ impl<'a> RegistryClientBuilder<'a> {
             index_urls,
             index_strategy: self.index_strategy,
             torch_backend: self.torch_backend,
+            metadata_index: self.metadata_index,
             cache: self.cache,
             connectivity,
             client,
@@ -222,6 +236,7 @@ impl<'a> RegistryClientBuilder<'a> {
             index_urls,
             index_strategy: self.index_strategy,
             torch_backend: self.torch_backend,
+            metadata_index: self.metadata_index,
             cache: self.cache,
             connectivity,
             client,
@@ -241,6 +256,8 @@ pub struct RegistryClient {
     index_strategy: IndexStrategy,
     /// The strategy to use when selecting a PyTorch backend, if any.
     torch_backend: Option<TorchStrategy>,
+    /// A local index of Simple API listings and wheel metadata to consult before the registry.
+    metadata_index: Option<MetadataIndex>,
     /// The underlying HTTP client.
     client: CachedClient,
     /// Used for the remote wheel METADATA cache.
@@ -513,6 +530,20 @@ impl RegistryClient {
             // ref https://github.com/servo/rust-url/issues/333
             .push("");
 
+        // If the listing is available in the metadata index, avoid querying the registry.
+        if let Some(metadata_index) = &self.metadata_index {
+            if let Some(metadata) = metadata_index
+                .simple_detail(package_name, index, &url)
+                .await
+                .map_err(ErrorKind::MetadataIndex)?
+            {
+                trace!("Using metadata index for {package_name} from {url}");
+                return Ok(SimpleMetadataSearchOutcome::Found(
+                    OwnedArchive::from_unarchived(&metadata)?,
+                ));
+            }
+        }
+
         trace!("Fetching metadata for {package_name} from {url}");
 
         let cache_entry = self.cache.entry(
@@ -905,6 +936,40 @@ impl RegistryClient {
         built_dist: &BuiltDist,
         capabilities: &IndexCapabilities,
     ) -> Result<ResolutionMetadata, Error> {
+        let metadata = self
+            .read_wheel_metadata::<ResolutionMetadata>(built_dist, capabilities)
+            .await?;
+
+        if metadata.name != *built_dist.name() {
+            return Err(Error::from(ErrorKind::NameMismatch {
+                metadata: metadata.name,
+                given: built_dist.name().clone(),
+            }));
+        }
+
+        Ok(metadata)
+    }
+
+    /// Fetch the license declared in the `METADATA` file of a remote wheel, if any.
+    ///
+    /// The license is read in the same way as the rest of the metadata (see
+    /// [`RegistryClient::wheel_metadata`]), but is cached in its own entry.
+    #[instrument(skip_all, fields(% built_dist))]
+    pub async fn wheel_license(
+        &self,
+        built_dist: &BuiltDist,
+        capabilities: &IndexCapabilities,
+    ) -> Result<Option<PackageLicense>, Error> {
+        self.read_wheel_metadata::<Option<PackageLicense>>(built_dist, capabilities)
+            .await
+    }
+
+    /// Read and parse the `METADATA` file of a wheel.
+    async fn read_wheel_metadata<T: WheelMetadata>(
+        &self,
+        built_dist: &BuiltDist,
+        capabilities: &IndexCapabilities,
+    ) -> Result<T, Error> {
         let metadata = match &built_dist {
             BuiltDist::Registry(wheels) => {
                 #[derive(Debug, Clone)]
@@ -938,7 +1003,7 @@ impl RegistryClient {
                             .map_err(|err| {
                                 ErrorKind::Metadata(path.to_string_lossy().to_string(), err)
                             })?;
-                        ResolutionMetadata::parse_metadata(&contents).map_err(|err| {
+                        T::parse_metadata(&contents).map_err(|err| {
                             ErrorKind::MetadataParseError(
                                 wheel.filename.clone(),
                                 built_dist.to_string(),
@@ -972,7 +1037,7 @@ impl RegistryClient {
                     .map_err(|err| {
                         ErrorKind::Metadata(wheel.install_path.to_string_lossy().to_string(), err)
                     })?;
-                ResolutionMetadata::parse_metadata(&contents).map_err(|err| {
+                T::parse_metadata(&contents).map_err(|err| {
                     ErrorKind::MetadataParseError(
                         wheel.filename.clone(),
                         built_dist.to_string(),
@@ -982,26 +1047,32 @@ impl RegistryClient {
             }
         };
 
-        if metadata.name != *built_dist.name() {
-            return Err(Error::from(ErrorKind::NameMismatch {
-                metadata: metadata.name,
-                given: built_dist.name().clone(),
-            }));
-        }
-
         Ok(metadata)
     }
 
     /// Fetch the metadata from a wheel file.
-    async fn wheel_metadata_registry(
+    async fn wheel_metadata_registry<T: WheelMetadata>(
         &self,
         index: &IndexUrl,
         file: &File,
         url: &DisplaySafeUrl,
         capabilities: &IndexCapabilities,
-    ) -> Result<ResolutionMetadata, Error> {
+    ) -> Result<T, Error> {
         // If the metadata file is available at its own url (PEP 658), download it from there.
         let filename = WheelFilename::from_str(&file.filename).map_err(ErrorKind::WheelFilename)?;
+
+        // If the metadata is available in the metadata index, avoid querying the registry.
+        if let Some(metadata_index) = &self.metadata_index {
+            if let Some(metadata) = metadata_index
+                .wheel_metadata::<T>(index, &filename)
+                .await
+                .map_err(ErrorKind::MetadataIndex)?
+            {
+                trace!("Using metadata index for {filename}");
+                return Ok(metadata);
+            }
+        }
+
         if file.dist_info_metadata {
             let mut url = url.clone();
             let path = format!("{}.metadata", url.path());
@@ -1010,7 +1081,7 @@ impl RegistryClient {
             let cache_entry = self.cache.entry(
                 CacheBucket::Wheels,
                 WheelCache::Index(index).wheel_dir(filename.name.as_ref()),
-                format!("{}.msgpack", filename.cache_key()),
+                format!("{}.{}", filename.cache_key(), T::CACHE_EXTENSION),
             );
             let cache_control = match self.connectivity {
                 Connectivity::Online => {
@@ -1041,7 +1112,7 @@ impl RegistryClient {
                     .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
 
                 info_span!("parse_metadata21")
-                    .in_scope(|| ResolutionMetadata::parse_metadata(bytes.as_ref()))
+                    .in_scope(|| T::parse_metadata(bytes.as_ref()))
                     .map_err(|err| {
                         Error::from(ErrorKind::MetadataParseError(
                             filename.clone(),
@@ -1075,18 +1146,18 @@ impl RegistryClient {
     }
 
     /// Get the wheel metadata if it isn't available in an index through PEP 658
-    async fn wheel_metadata_no_pep658<'data>(
+    async fn wheel_metadata_no_pep658<'data, T: WheelMetadata>(
         &self,
         filename: &'data WheelFilename,
         url: &'data DisplaySafeUrl,
         index: Option<&'data IndexUrl>,
         cache_shard: WheelCache<'data>,
         capabilities: &'data IndexCapabilities,
-    ) -> Result<ResolutionMetadata, Error> {
+    ) -> Result<T, Error> {
         let cache_entry = self.cache.entry(
             CacheBucket::Wheels,
             cache_shard.wheel_dir(filename.name.as_ref()),
-            format!("{}.msgpack", filename.cache_key()),
+            format!("{}.{}", filename.cache_key(), T::CACHE_EXTENSION),
         );
         let cache_control = match self.connectivity {
             Connectivity::Online => {
@@ -1150,7 +1221,7 @@ impl RegistryClient {
                     .map_err(|err| ErrorKind::AsyncHttpRangeReader(url.clone(), err))?;
                     trace!("Getting metadata for {filename} by range request");
                     let text = wheel_metadata_from_remote_zip(filename, url, &mut reader).await?;
-                    ResolutionMetadata::parse_metadata(text.as_bytes()).map_err(|err| {
+                    T::parse_metadata(text.as_bytes()).map_err(|err| {
                         Error::from(ErrorKind::MetadataParseError(
                             filename.clone(),
                             url.to_string(),
@@ -1214,9 +1285,15 @@ impl RegistryClient {
                     .map_err(|err| self.handle_response_errors(err))
                     .into_async_read();
 
-                read_metadata_async_stream(filename, url.as_ref(), reader)
+                let contents = read_metadata_async_stream(filename, reader)
                     .await
-                    .map_err(|err| ErrorKind::Metadata(url.to_string(), err))
+                    .map_err(|err| ErrorKind::Metadata(url.to_string(), err))?;
+                T::parse_metadata(&contents).map_err(|err| {
+                    Error::from(ErrorKind::Metadata(
+                        url.to_string(),
+                        uv_metadata::Error::InvalidMetadata(url.to_string(), Box::new(err)),
+                    ))
+                })
             }
             .instrument(info_span!("read_metadata_stream", wheel = %filename))
         };
@@ -1243,6 +1320,31 @@ impl RegistryClient {
     }
 }
 
+/// Data that's parsed from the `METADATA` file of a wheel, and cached in its own entry.
+pub(crate) trait WheelMetadata: Serialize + DeserializeOwned + Send + 'static {
+    /// The extension of the cache entry, following the wheel's cache key.
+    const CACHE_EXTENSION: &'static str;
+
+    /// Parse the data from the contents of a `METADATA` file.
+    fn parse_metadata(content: &[u8]) -> Result<Self, MetadataError>;
+}
+
+impl WheelMetadata for ResolutionMetadata {
+    const CACHE_EXTENSION: &'static str = "msgpack";
+
+    fn parse_metadata(content: &[u8]) -> Result<Self, MetadataError> {
+        Self::parse_metadata(content)
+    }
+}
+
+impl WheelMetadata for Option<PackageLicense> {
+    const CACHE_EXTENSION: &'static str = "license.msgpack";
+
+    fn parse_metadata(content: &[u8]) -> Result<Self, MetadataError> {
+        PackageLicense::parse_metadata(content)
+    }
+}
+
 #[derive(Debug)]
 pub(crate) enum SimpleMetadataSearchOutcome {
     /// Simple metadata was found
@@ -1292,7 +1394,7 @@ pub struct VersionFiles {
 }
 
 impl VersionFiles {
-    fn push(&mut self, filename: DistFilename, file: File) {
+    pub fn push(&mut self, filename: DistFilename, file: File) {
         match filename {
             DistFilename::WheelFilename(name) => self.wheels.push(VersionWheel { name, file }),
             DistFilename::SourceDistFilename(name) => {
@@ -1386,7 +1488,7 @@ impl SimpleDetailMetadata {
         self.0.iter()
     }
 
-    fn from_pypi_files(
+    pub(crate) fn from_pypi_files(
         files: Vec<uv_pypi_types::PypiFile>,
         package_name: &PackageName,
         base: &Url,
@@ -1524,6 +1626,12 @@ impl IntoIterator for SimpleDetailMetadata {
     }
 }
 
+impl FromIterator<SimpleDetailMetadatum> for SimpleDetailMetadata {
+    fn from_iter<T: IntoIterator<Item = SimpleDetailMetadatum>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
 impl ArchivedSimpleDetailMetadata {
     pub fn iter(&self) -> impl DoubleEndedIterator<Item = &rkyv::Archived<SimpleDetailMetadatum>> {
         self.0.iter()
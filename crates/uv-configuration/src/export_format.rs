@@ -22,6 +22,31 @@ pub enum ExportFormat {
         clap(name = "cyclonedx1.5", alias = "cyclonedx1.5+json")
     )]
     CycloneDX1_5,
+    /// Export in `CycloneDX` v1.6 JSON format.
+    #[serde(rename = "cyclonedx1.6")]
+    #[cfg_attr(
+        feature = "clap",
+        clap(name = "cyclonedx1.6", alias = "cyclonedx1.6+json")
+    )]
+    CycloneDX1_6,
+    /// Export in `SPDX` v2.3 JSON format.
+    #[serde(rename = "spdx2.3")]
+    #[cfg_attr(feature = "clap", clap(name = "spdx2.3", alias = "spdx2.3+json"))]
+    Spdx2_3,
+    /// Export in `SPDX` v3.0 JSON-LD format.
+    #[serde(rename = "spdx3.0")]
+    #[cfg_attr(feature = "clap", clap(name = "spdx3.0", alias = "spdx3.0+json"))]
+    Spdx3_0,
+}
+
+impl ExportFormat {
+    /// Returns `true` if the format is a software bill of materials (SBOM).
+    pub fn is_sbom(self) -> bool {
+        matches!(
+            self,
+            Self::CycloneDX1_5 | Self::CycloneDX1_6 | Self::Spdx2_3 | Self::Spdx3_0
+        )
+    }
 }
 
 /// The output format to use in `uv pip compile`.
@@ -11,8 +11,8 @@ use uv_platform_tags::{AbiTag, IncompatibleTag, LanguageTag, PlatformTag, TagPri
 use uv_pypi_types::{HashDigest, Yanked};
 
 use crate::{
-    File, InstalledDist, KnownPlatform, RegistryBuiltDist, RegistryBuiltWheel, RegistrySourceDist,
-    ResolvedDistRef,
+    File, IndexUrl, InstalledDist, KnownPlatform, RegistryBuiltDist, RegistryBuiltWheel,
+    RegistrySourceDist, ResolvedDistRef,
 };
 
 /// A collection of distributions that have been filtered by relevance.
@@ -583,6 +583,21 @@ impl PrioritizedDist {
             )
     }
 
+    /// Returns an iterator of all wheels and the source distribution, if any, along with the
+    /// index from which each was sourced.
+    pub fn indexed_files(&self) -> impl Iterator<Item = (&File, &IndexUrl)> {
+        self.0
+            .wheels
+            .iter()
+            .map(|(wheel, _)| (wheel.file.as_ref(), &wheel.index))
+            .chain(
+                self.0
+                    .source
+                    .as_ref()
+                    .map(|(source_dist, _)| (source_dist.file.as_ref(), &source_dist.index)),
+            )
+    }
+
     /// Returns an iterator over all Python tags for the distribution.
     pub fn python_tags(&self) -> impl Iterator<Item = LanguageTag> + '_ {
         self.0
@@ -13,7 +13,7 @@ use tokio_util::compat::FuturesAsyncReadCompatExt;
 use tracing::{Instrument, info_span, instrument, warn};
 use url::Url;
 
-use uv_cache::{ArchiveId, CacheBucket, CacheEntry, WheelCache};
+use uv_cache::{ArchiveId, Cache, CacheBucket, CacheEntry, WheelCache};
 use uv_cache_info::{CacheInfo, Timestamp};
 use uv_client::{
     CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RegistryClient,
@@ -26,7 +26,7 @@ use uv_distribution_types::{
 use uv_extract::hash::Hasher;
 use uv_fs::write_atomic;
 use uv_platform_tags::Tags;
-use uv_pypi_types::{HashDigest, HashDigests, PyProjectToml};
+use uv_pypi_types::{HashDigest, HashDigests, PackageLicense, PyProjectToml};
 use uv_redacted::DisplaySafeUrl;
 use uv_types::{BuildContext, BuildStack};
 
@@ -167,6 +167,36 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         }
     }
 
+    /// Read the license declared by a distribution, if any.
+    ///
+    /// For wheels, the license is read from the `METADATA` file (directly from the index or with
+    /// range requests). For local source trees, it's read from the `[project]` table in
+    /// `pyproject.toml`. Other source distributions are never built just to read their license;
+    /// instead, their license is treated as unknown.
+    #[instrument(skip_all, fields(%dist))]
+    pub async fn get_license(&self, dist: &Dist) -> Result<Option<PackageLicense>, Error> {
+        match dist {
+            Dist::Built(built) => Ok(self
+                .client
+                .managed(|client| {
+                    client
+                        .wheel_license(built, self.build_context.capabilities())
+                        .boxed_local()
+                })
+                .await?),
+            Dist::Source(SourceDist::Directory(source)) => {
+                let path = source.install_path.join("pyproject.toml");
+                let content = match fs_err::tokio::read_to_string(&path).await {
+                    Ok(content) => content,
+                    Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+                    Err(err) => return Err(Error::CacheRead(err)),
+                };
+                PackageLicense::parse_pyproject_toml(&content).map_err(Error::PyprojectToml)
+            }
+            Dist::Source(_) => Ok(None),
+        }
+    }
+
     /// Fetch a wheel from the cache or download it from the index.
     ///
     /// While hashes will be generated in all cases, hash-checking is _not_ enforced and should
@@ -982,11 +1012,12 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         let pointer_entry = wheel_entry.with_file(format!("{}.rev", filename.cache_key()));
         let pointer = LocalArchivePointer::read_from(&pointer_entry)?;
 
-        // Extract the archive from the pointer.
+        // Extract the archive from the pointer, ignoring it if the archive has since been removed.
         let archive = pointer
             .filter(|pointer| pointer.is_up_to_date(modified))
             .map(LocalArchivePointer::into_archive)
-            .filter(|archive| archive.has_digests(hashes));
+            .filter(|archive| archive.has_digests(hashes))
+            .filter(|archive| archive.exists(self.build_context.cache()));
 
         // If the file is already unzipped, and the cache is up-to-date, return it.
         if let Some(archive) = archive {
@@ -1252,6 +1283,11 @@ impl HttpArchivePointer {
         }
     }
 
+    /// Returns `true` if the archive referenced by the pointer exists in the cache.
+    pub fn exists(&self, cache: &Cache) -> bool {
+        self.archive.exists(cache)
+    }
+
     /// Return the [`Archive`] from the pointer.
     pub fn into_archive(self) -> Archive {
         self.archive
@@ -1299,6 +1335,11 @@ impl LocalArchivePointer {
         self.timestamp == modified
     }
 
+    /// Returns `true` if the archive referenced by the pointer exists in the cache.
+    pub fn exists(&self, cache: &Cache) -> bool {
+        self.archive.exists(cache)
+    }
+
     /// Return the [`Archive`] from the pointer.
     pub fn into_archive(self) -> Archive {
         self.archive
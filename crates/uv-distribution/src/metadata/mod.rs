@@ -53,7 +53,8 @@ pub enum MetadataError {
     IncompleteSourceGroup(PackageName, GroupName),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct Metadata {
     // Mandatory fields
     pub name: PackageName,
@@ -132,7 +133,8 @@ impl Metadata {
 }
 
 /// The metadata associated with an archive.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct ArchiveMetadata {
     /// The [`Metadata`] for the underlying distribution.
     pub metadata: Metadata,
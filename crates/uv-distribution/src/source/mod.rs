@@ -52,10 +52,12 @@ use crate::distribution_database::ManagedClient;
 use crate::error::Error;
 use crate::metadata::{ArchiveMetadata, GitWorkspaceMember, Metadata};
 use crate::source::built_wheel_metadata::{BuiltWheelFile, BuiltWheelMetadata};
+use crate::source::remote::RemoteBuildKey;
 use crate::source::revision::Revision;
 use crate::{Reporter, RequiresDist};
 
 mod built_wheel_metadata;
+mod remote;
 mod revision;
 
 /// Fetch and build a source distribution from a remote source, or from a local cache.
@@ -142,6 +144,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                             &cache_shard,
                             tags,
                             hashes,
+                            client,
                         )
                         .boxed_local()
                         .await;
@@ -203,6 +206,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     &cache_shard,
                     tags,
                     hashes,
+                    client,
                 )
                 .boxed_local()
                 .await?
@@ -243,7 +247,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     CacheBucket::SourceDistributions,
                     WheelCache::Path(resource.url).root(),
                 );
-                self.archive(source, resource, &cache_shard, tags, hashes)
+                self.archive(source, resource, &cache_shard, tags, hashes, client)
                     .boxed_local()
                     .await?
             }
@@ -507,6 +511,29 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             ));
         }
 
+        // If a compatible wheel was published to the remote build cache, fetch it.
+        let remote_key = RemoteBuildKey::new(source, revision.hashes(), &build_info, tags);
+        if let Some(key) = remote_key.as_ref() {
+            if let Some((disk_filename, wheel_filename, metadata)) = self
+                .fetch_remote_build(source, key, &cache_shard, tags, client)
+                .await
+            {
+                let metadata_entry = cache_shard.entry(METADATA);
+                write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+                    .await
+                    .map_err(Error::CacheWrite)?;
+
+                return Ok(BuiltWheelMetadata {
+                    path: cache_shard.join(&disk_filename).into_boxed_path(),
+                    target: cache_shard.join(wheel_filename.stem()).into_boxed_path(),
+                    filename: wheel_filename,
+                    hashes: revision.into_hashes(),
+                    cache_info,
+                    build_info,
+                });
+            }
+        }
+
         // Otherwise, we need to build a wheel. Before building, ensure that the source is present.
         let revision = if source_dist_entry.path().is_dir() {
             revision
@@ -556,6 +583,18 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
+        // Share the built wheel via the remote build cache.
+        if let Some(key) = remote_key.as_ref() {
+            self.publish_remote_build(
+                source,
+                key,
+                &cache_shard.join(&disk_filename),
+                &disk_filename,
+                client,
+            )
+            .await;
+        }
+
         // Store the metadata.
         let metadata_entry = cache_shard.entry(METADATA);
         write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
@@ -858,6 +897,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         cache_shard: &CacheShard,
         tags: &Tags,
         hashes: HashPolicy<'_>,
+        client: &ManagedClient<'_>,
     ) -> Result<BuiltWheelMetadata, Error> {
         let _lock = cache_shard.lock().await.map_err(Error::CacheLock)?;
 
@@ -908,6 +948,29 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             ));
         }
 
+        // If a compatible wheel was published to the remote build cache, fetch it.
+        let remote_key = RemoteBuildKey::new(source, revision.hashes(), &build_info, tags);
+        if let Some(key) = remote_key.as_ref() {
+            if let Some((disk_filename, filename, metadata)) = self
+                .fetch_remote_build(source, key, &cache_shard, tags, client)
+                .await
+            {
+                let metadata_entry = cache_shard.entry(METADATA);
+                write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
+                    .await
+                    .map_err(Error::CacheWrite)?;
+
+                return Ok(BuiltWheelMetadata {
+                    path: cache_shard.join(&disk_filename).into_boxed_path(),
+                    target: cache_shard.join(filename.stem()).into_boxed_path(),
+                    filename,
+                    hashes: revision.into_hashes(),
+                    cache_info,
+                    build_info,
+                });
+            }
+        }
+
         // Otherwise, we need to build a wheel, which requires a source distribution.
         let revision = if source_entry.path().is_dir() {
             revision
@@ -937,6 +1000,18 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             }
         }
 
+        // Share the built wheel via the remote build cache.
+        if let Some(key) = remote_key.as_ref() {
+            self.publish_remote_build(
+                source,
+                key,
+                &cache_shard.join(&disk_filename),
+                &disk_filename,
+                client,
+            )
+            .await;
+        }
+
         // Store the metadata.
         let metadata_entry = cache_shard.entry(METADATA);
         write_atomic(metadata_entry.path(), rmp_serde::to_vec(&metadata)?)
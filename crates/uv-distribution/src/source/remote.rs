@@ -0,0 +1,450 @@
+//! Fetch and publish wheels built from source distributions via a [`RemoteBuildCache`].
+
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{Context, Result, bail};
+use fs_err::tokio as fs;
+use futures::TryStreamExt;
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use tokio_util::compat::FuturesAsyncReadCompatExt;
+use tokio_util::io::ReaderStream;
+use tracing::{debug, warn};
+use url::Url;
+
+use uv_cache::{CacheShard, RemoteBuildCache, RemoteBuildCacheLocation};
+use uv_cache_key::{CacheKey, CacheKeyHasher, cache_digest};
+use uv_client::RetryState;
+use uv_distribution_filename::WheelFilename;
+use uv_distribution_types::{BuildInfo, BuildableSource, SourceDist};
+use uv_extract::hash::{HashReader, Hasher};
+use uv_fs::{copy_atomic_sync, rename_with_retry, write_atomic};
+use uv_normalize::PackageName;
+use uv_platform_tags::Tags;
+use uv_pypi_types::{HashAlgorithm, HashDigest, ResolutionMetadata};
+use uv_redacted::DisplaySafeUrl;
+use uv_types::BuildContext;
+
+use crate::distribution_database::ManagedClient;
+use crate::source::{
+    SourceDistributionBuilder, read_wheel_metadata, validate_filename, validate_metadata,
+};
+
+/// The key for a built wheel in a [`RemoteBuildCache`].
+#[derive(Debug)]
+pub(super) struct RemoteBuildKey {
+    name: PackageName,
+    digest: String,
+}
+
+impl RemoteBuildKey {
+    /// Compute the [`RemoteBuildKey`] for a source distribution.
+    ///
+    /// The key is a digest of the source distribution's SHA-256 hash, the build settings (e.g.,
+    /// `config-settings`), and the tags of the interpreter that builds the wheel. Returns `None` if
+    /// the SHA-256 hash of the source distribution is unknown, as the source can't be identified
+    /// across machines.
+    pub(super) fn new(
+        source: &BuildableSource<'_>,
+        hashes: &[HashDigest],
+        build_info: &BuildInfo,
+        tags: &Tags,
+    ) -> Option<Self> {
+        let name = source.name()?.clone();
+
+        // Prefer the hash computed locally, falling back to the hash reported by the registry.
+        let registry_hashes = match source {
+            BuildableSource::Dist(SourceDist::Registry(dist)) => dist.file.hashes.as_slice(),
+            _ => &[],
+        };
+        let sha256 = hashes
+            .iter()
+            .chain(registry_hashes)
+            .find(|digest| digest.algorithm == HashAlgorithm::Sha256)?;
+
+        let tag = format!(
+            "{}-{}-{}",
+            tags.python_tag()?,
+            tags.abi_tag()?,
+            tags.platform_tag()?
+        );
+
+        let digest = cache_digest(&RemoteBuildKeyInputs {
+            sha256: &sha256.digest,
+            build_info,
+            tag: &tag,
+        });
+
+        Some(Self { name, digest })
+    }
+
+    /// The path to the [`RemotePointer`], relative to the root of the remote cache.
+    fn pointer(&self) -> String {
+        format!("{}/{}.json", self.name, self.digest)
+    }
+
+    /// The path to the wheel, relative to the root of the remote cache.
+    fn wheel(&self, filename: &str) -> String {
+        format!("{}/{}/{filename}", self.name, self.digest)
+    }
+}
+
+/// The inputs to a [`RemoteBuildKey`] digest.
+struct RemoteBuildKeyInputs<'a> {
+    sha256: &'a str,
+    build_info: &'a BuildInfo,
+    tag: &'a str,
+}
+
+impl CacheKey for RemoteBuildKeyInputs<'_> {
+    fn cache_key(&self, state: &mut CacheKeyHasher) {
+        self.sha256.cache_key(state);
+        self.build_info.cache_key(state);
+        self.tag.cache_key(state);
+    }
+}
+
+/// A pointer to a wheel in a [`RemoteBuildCache`], stored alongside the wheel itself.
+///
+/// The pointer is written after the wheel, such that its presence implies the wheel is complete.
+#[derive(Debug, Serialize, Deserialize)]
+struct RemotePointer {
+    /// The filename of the wheel.
+    filename: String,
+    /// The SHA-256 hash of the wheel.
+    sha256: String,
+}
+
+impl<T: BuildContext> SourceDistributionBuilder<'_, T> {
+    /// Fetch a built wheel for the source distribution from the [`RemoteBuildCache`], if any,
+    /// storing it in the given cache shard.
+    ///
+    /// Returns the wheel's filename on disk, its parsed filename, and its metadata. Failures are
+    /// logged rather than propagated, such that the source distribution is built locally instead.
+    pub(super) async fn fetch_remote_build(
+        &self,
+        source: &BuildableSource<'_>,
+        key: &RemoteBuildKey,
+        cache_shard: &CacheShard,
+        tags: &Tags,
+        client: &ManagedClient<'_>,
+    ) -> Option<(String, WheelFilename, ResolutionMetadata)> {
+        let remote = self.build_context.cache().remote_build_cache()?;
+        match self
+            .try_fetch_remote_build(remote, source, key, cache_shard, tags, client)
+            .await
+        {
+            Ok(Some(built)) => {
+                debug!(
+                    "Fetched `{source}` from remote build cache: {}",
+                    remote.location()
+                );
+                Some(built)
+            }
+            Ok(None) => {
+                debug!("No entry for `{source}` in remote build cache");
+                None
+            }
+            Err(err) => {
+                warn!("Failed to fetch `{source}` from remote build cache: {err:#}");
+                None
+            }
+        }
+    }
+
+    async fn try_fetch_remote_build(
+        &self,
+        remote: &RemoteBuildCache,
+        source: &BuildableSource<'_>,
+        key: &RemoteBuildKey,
+        cache_shard: &CacheShard,
+        tags: &Tags,
+        client: &ManagedClient<'_>,
+    ) -> Result<Option<(String, WheelFilename, ResolutionMetadata)>> {
+        let Some(pointer) = read(remote.location(), &key.pointer(), client).await? else {
+            return Ok(None);
+        };
+        let pointer: RemotePointer =
+            serde_json::from_slice(&pointer).context("Failed to parse remote build cache entry")?;
+
+        // Validate the filename before using it as a path component.
+        if pointer.filename.contains(['/', '\\']) {
+            bail!("Invalid wheel filename: `{}`", pointer.filename);
+        }
+        let filename = WheelFilename::from_str(&pointer.filename)?;
+        if !filename.is_compatible(tags) {
+            bail!("Wheel `{filename}` is not compatible with the current platform");
+        }
+
+        // Download the wheel to a temporary directory, then validate it before moving it into the
+        // cache.
+        let temp_dir = self.build_context.cache().build_dir()?;
+        let temp_path = temp_dir.path().join(&pointer.filename);
+        let Some(sha256) = download(
+            remote.location(),
+            &key.wheel(&pointer.filename),
+            &temp_path,
+            client,
+        )
+        .await?
+        else {
+            return Ok(None);
+        };
+        if sha256 != pointer.sha256 {
+            bail!(
+                "Hash mismatch for `{filename}`: expected `{}`, found `{sha256}`",
+                pointer.sha256
+            );
+        }
+
+        let metadata = read_wheel_metadata(&filename, &temp_path)?;
+        validate_metadata(source, &metadata)?;
+        validate_filename(&filename, &metadata)?;
+
+        fs::create_dir_all(&cache_shard).await?;
+        rename_with_retry(&temp_path, cache_shard.join(&pointer.filename)).await?;
+
+        Ok(Some((pointer.filename, filename, metadata)))
+    }
+
+    /// Publish a wheel built from the source distribution to the [`RemoteBuildCache`], if
+    /// publishing is enabled.
+    ///
+    /// Failures are logged rather than propagated, since the wheel was built successfully.
+    pub(super) async fn publish_remote_build(
+        &self,
+        source: &BuildableSource<'_>,
+        key: &RemoteBuildKey,
+        wheel: &Path,
+        filename: &str,
+        client: &ManagedClient<'_>,
+    ) {
+        let Some(remote) = self
+            .build_context
+            .cache()
+            .remote_build_cache()
+            .filter(|remote| remote.publish())
+        else {
+            return;
+        };
+        match try_publish_remote_build(remote, key, wheel, filename, client).await {
+            Ok(()) => debug!(
+                "Published `{source}` to remote build cache: {}",
+                remote.location()
+            ),
+            Err(err) => warn!("Failed to publish `{source}` to remote build cache: {err:#}"),
+        }
+    }
+}
+
+async fn try_publish_remote_build(
+    remote: &RemoteBuildCache,
+    key: &RemoteBuildKey,
+    wheel: &Path,
+    filename: &str,
+    client: &ManagedClient<'_>,
+) -> Result<()> {
+    let pointer = RemotePointer {
+        filename: filename.to_string(),
+        sha256: sha256(wheel).await?,
+    };
+
+    // Write the wheel before the pointer, such that readers never observe a partial entry.
+    upload(remote.location(), &key.wheel(filename), wheel, client).await?;
+    write(
+        remote.location(),
+        &key.pointer(),
+        serde_json::to_vec(&pointer)?,
+        client,
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Read a file from the remote cache, returning `None` if it doesn't exist.
+async fn read(
+    location: &RemoteBuildCacheLocation,
+    path: &str,
+    client: &ManagedClient<'_>,
+) -> Result<Option<Vec<u8>>> {
+    match location {
+        RemoteBuildCacheLocation::Path(root) => match fs::read(root.join(path)).await {
+            Ok(contents) => Ok(Some(contents)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        },
+        RemoteBuildCacheLocation::Url(base) => {
+            let Some(response) = get(base, path, client).await? else {
+                return Ok(None);
+            };
+            Ok(Some(response.bytes().await?.to_vec()))
+        }
+    }
+}
+
+/// Download a file from the remote cache to the given path, returning its hex-encoded SHA-256
+/// hash, or `None` if it doesn't exist.
+///
+/// The file is streamed to disk and hashed as it's read, rather than buffered in memory.
+async fn download(
+    location: &RemoteBuildCacheLocation,
+    path: &str,
+    target: &Path,
+    client: &ManagedClient<'_>,
+) -> Result<Option<String>> {
+    let mut hashers = [Hasher::from(HashAlgorithm::Sha256)];
+    match location {
+        RemoteBuildCacheLocation::Path(root) => {
+            let reader = match fs::File::open(root.join(path)).await {
+                Ok(reader) => reader,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+                Err(err) => return Err(err.into()),
+            };
+            let mut reader = HashReader::new(reader, &mut hashers);
+            let mut writer = fs::File::create(target).await?;
+            tokio::io::copy(&mut reader, &mut writer).await?;
+        }
+        RemoteBuildCacheLocation::Url(base) => {
+            let Some(response) = get(base, path, client).await? else {
+                return Ok(None);
+            };
+            let reader = response
+                .bytes_stream()
+                .map_err(std::io::Error::other)
+                .into_async_read()
+                .compat();
+            let mut reader = HashReader::new(reader, &mut hashers);
+            let mut writer = fs::File::create(target).await?;
+            tokio::io::copy(&mut reader, &mut writer).await?;
+        }
+    }
+    let [hasher] = hashers;
+    Ok(Some(HashDigest::from(hasher).digest.to_string()))
+}
+
+/// Send a `GET` request for a file in the remote cache, returning `None` if it doesn't exist.
+async fn get(
+    base: &DisplaySafeUrl,
+    path: &str,
+    client: &ManagedClient<'_>,
+) -> Result<Option<reqwest::Response>> {
+    let url = join(base, path)?;
+    let response = client
+        .managed(|client| {
+            client
+                .uncached_client(&url)
+                .get(Url::from(url.clone()))
+                .send()
+        })
+        .await?;
+    if response.status() == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    Ok(Some(response.error_for_status()?))
+}
+
+/// Write a file to the remote cache.
+async fn write(
+    location: &RemoteBuildCacheLocation,
+    path: &str,
+    contents: Vec<u8>,
+    client: &ManagedClient<'_>,
+) -> Result<()> {
+    match location {
+        RemoteBuildCacheLocation::Path(root) => {
+            let path = root.join(path);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).await?;
+            }
+            write_atomic(&path, contents).await?;
+        }
+        RemoteBuildCacheLocation::Url(base) => {
+            let url = join(base, path)?;
+            client
+                .managed(|client| {
+                    client
+                        .uncached_client(&url)
+                        .put(Url::from(url.clone()))
+                        .body(contents)
+                        .send()
+                })
+                .await?
+                .error_for_status()?;
+        }
+    }
+    Ok(())
+}
+
+/// Upload a file to the remote cache from the given path.
+///
+/// The file is streamed from disk, rather than buffered in memory. As a streaming request can't be
+/// cloned, it bypasses the client middleware: credentials must be embedded in the URL of the
+/// remote cache, and transient failures are retried here.
+async fn upload(
+    location: &RemoteBuildCacheLocation,
+    path: &str,
+    source: &Path,
+    client: &ManagedClient<'_>,
+) -> Result<()> {
+    match location {
+        RemoteBuildCacheLocation::Path(root) => {
+            let path = root.join(path);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).await?;
+            }
+            let source = source.to_path_buf();
+            tokio::task::spawn_blocking(move || copy_atomic_sync(source, path)).await??;
+        }
+        RemoteBuildCacheLocation::Url(base) => {
+            let url = join(base, path)?;
+            let base_client = client.unmanaged.cached_client().uncached();
+            let mut retry_state = RetryState::start(base_client.retry_policy(), url.clone());
+            loop {
+                let file = fs::File::open(source).await?;
+                let size = file.metadata().await?.len();
+                let result = client
+                    .managed(|_| {
+                        base_client
+                            .raw_for_host(&url)
+                            .put(Url::from(url.clone()))
+                            .header(reqwest::header::CONTENT_LENGTH, size)
+                            .body(reqwest::Body::wrap_stream(ReaderStream::new(file)))
+                            .send()
+                    })
+                    .await
+                    .and_then(reqwest::Response::error_for_status);
+                match result {
+                    Ok(_) => break,
+                    Err(err) => {
+                        if let Some(backoff) = retry_state.should_retry(&err, 0) {
+                            retry_state.sleep_backoff(backoff).await;
+                            continue;
+                        }
+                        return Err(err.into());
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Join a relative path onto the base URL of the remote cache.
+fn join(base: &DisplaySafeUrl, path: &str) -> Result<DisplaySafeUrl> {
+    Ok(DisplaySafeUrl::parse(&format!(
+        "{}/{path}",
+        base.as_str().trim_end_matches('/')
+    ))?)
+}
+
+/// Compute the hex-encoded SHA-256 hash of the file at the given path.
+async fn sha256(path: &Path) -> Result<String> {
+    let mut hashers = [Hasher::from(HashAlgorithm::Sha256)];
+    HashReader::new(fs::File::open(path).await?, &mut hashers)
+        .finish()
+        .await?;
+    let [hasher] = hashers;
+    Ok(HashDigest::from(hasher).digest.to_string())
+}
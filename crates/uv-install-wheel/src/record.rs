@@ -1,4 +1,15 @@
+use std::io;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use blake2::Blake2b512;
+use data_encoding::BASE64URL_NOPAD;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
+use tracing::debug;
+
+use crate::Error;
+use crate::wheel::read_record_file;
 
 /// Line in a RECORD file
 /// <https://www.python.org/dev/peps/pep-0376/#record>
@@ -11,6 +22,238 @@ use serde::{Deserialize, Serialize};
 pub struct RecordEntry {
     pub path: String,
     pub hash: Option<String>,
-    #[allow(dead_code)]
     pub size: Option<u64>,
 }
+
+/// An installed file that doesn't match its entry in the RECORD file.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RecordMismatch {
+    /// The file is listed in the RECORD file, but doesn't exist.
+    Missing(PathBuf),
+    /// The file's size or hash differs from that listed in the RECORD file.
+    Modified(PathBuf),
+}
+
+impl RecordMismatch {
+    /// Return the path to the file, relative to the `site-packages` directory.
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Missing(path) | Self::Modified(path) => path,
+        }
+    }
+}
+
+/// The result of verifying an installed distribution against its RECORD file.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RecordVerification {
+    /// The files that don't match their entries in the RECORD file.
+    pub mismatches: Vec<RecordMismatch>,
+    /// The files that weren't verified, as their entries use an unsupported hash algorithm, along
+    /// with the name of that algorithm.
+    pub skipped: Vec<(PathBuf, String)>,
+}
+
+/// A hash algorithm that may be used in a RECORD file.
+///
+/// The wheel specification allows any algorithm from Python's `hashlib.algorithms_guaranteed`,
+/// other than MD5 and SHA-1; only the SHA-2 algorithms and `blake2b` are supported here.
+#[derive(Debug, Clone, Copy)]
+enum RecordHashAlgorithm {
+    Sha224,
+    Sha256,
+    Sha384,
+    Sha512,
+    Blake2b,
+}
+
+impl RecordHashAlgorithm {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "sha224" => Some(Self::Sha224),
+            "sha256" => Some(Self::Sha256),
+            "sha384" => Some(Self::Sha384),
+            "sha512" => Some(Self::Sha512),
+            // Python's `hashlib.blake2b` produces a 64-byte digest by default.
+            "blake2b" => Some(Self::Blake2b),
+            _ => None,
+        }
+    }
+
+    /// Hash the contents of the reader, returning the URL-safe, unpadded base64 digest used in
+    /// RECORD files.
+    fn hash(self, reader: &mut impl Read) -> io::Result<String> {
+        fn digest<D: Digest>(reader: &mut impl Read) -> io::Result<Vec<u8>> {
+            let mut hasher = D::new();
+            let mut buf = vec![0; 8 * 1024];
+            loop {
+                let len = match reader.read(&mut buf) {
+                    Ok(0) => break,
+                    Ok(len) => len,
+                    Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                    Err(err) => return Err(err),
+                };
+                hasher.update(&buf[..len]);
+            }
+            Ok(hasher.finalize().to_vec())
+        }
+
+        let digest = match self {
+            Self::Sha224 => digest::<Sha224>(reader)?,
+            Self::Sha256 => digest::<Sha256>(reader)?,
+            Self::Sha384 => digest::<Sha384>(reader)?,
+            Self::Sha512 => digest::<Sha512>(reader)?,
+            Self::Blake2b => digest::<Blake2b512>(reader)?,
+        };
+        Ok(BASE64URL_NOPAD.encode(&digest))
+    }
+}
+
+/// Verify the files of an installed distribution against the sizes and hashes in its RECORD file.
+///
+/// Entries without a hash (like the RECORD file itself, or bytecode compiled after installation)
+/// are ignored. Entries that use an unsupported hash algorithm are returned as skipped.
+pub fn verify_record(dist_info: &Path) -> Result<RecordVerification, Error> {
+    let Some(site_packages) = dist_info.parent() else {
+        return Err(Error::BrokenVenv(
+            "dist-info directory is not in a site-packages directory".to_string(),
+        ));
+    };
+
+    let record_path = dist_info.join("RECORD");
+    let mut record_file = match fs_err::File::open(&record_path) {
+        Ok(record_file) => record_file,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Err(Error::MissingRecord(record_path));
+        }
+        Err(err) => return Err(err.into()),
+    };
+    let record = read_record_file(&mut record_file)?;
+
+    let mut verification = RecordVerification::default();
+    for entry in record {
+        let Some((name, expected)) = entry.hash.as_deref().and_then(|hash| hash.split_once('='))
+        else {
+            continue;
+        };
+        let Some(algorithm) = RecordHashAlgorithm::from_name(name) else {
+            debug!(
+                "Skipping verification of `{}` with unsupported hash algorithm: {name}",
+                entry.path
+            );
+            verification
+                .skipped
+                .push((PathBuf::from(entry.path), name.to_string()));
+            continue;
+        };
+
+        let path = site_packages.join(&entry.path);
+        let mut file = match fs_err::File::open(&path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                verification
+                    .mismatches
+                    .push(RecordMismatch::Missing(PathBuf::from(entry.path)));
+                continue;
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        // Compare the sizes first, to avoid hashing files that have obviously changed.
+        if let Some(size) = entry.size {
+            if file.metadata()?.len() != size {
+                verification
+                    .mismatches
+                    .push(RecordMismatch::Modified(PathBuf::from(entry.path)));
+                continue;
+            }
+        }
+
+        if algorithm.hash(&mut file)? != expected {
+            verification
+                .mismatches
+                .push(RecordMismatch::Modified(PathBuf::from(entry.path)));
+        }
+    }
+
+    verification.mismatches.sort();
+    verification.skipped.sort();
+    Ok(verification)
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use anyhow::Result;
+    use assert_fs::prelude::*;
+    use indoc::indoc;
+
+    use super::{RecordMismatch, verify_record};
+    use crate::Error;
+
+    #[test]
+    fn test_verify_record() -> Result<()> {
+        let site_packages = assert_fs::TempDir::new()?;
+        site_packages
+            .child("foo/__init__.py")
+            .write_str("print('hello')\n")?;
+        site_packages
+            .child("foo/bar.py")
+            .write_str("print('modified')\n")?;
+        site_packages
+            .child("foo/qux.py")
+            .write_str("print('HELLO')\n")?;
+        site_packages
+            .child("foo/sha512.py")
+            .write_str("print('hello')\n")?;
+        site_packages
+            .child("foo/blake2b.py")
+            .write_str("print('hello')\n")?;
+        site_packages
+            .child("foo/sha3.py")
+            .write_str("print('hello')\n")?;
+        site_packages
+            .child("foo/__pycache__/__init__.cpython-312.pyc")
+            .write_str("")?;
+        let dist_info = site_packages.child("foo-1.0.dist-info");
+        dist_info.child("RECORD").write_str(indoc! {"
+            foo/__init__.py,sha256=A-aT2fL2h-D0Djao33_LTRwil0ASt8KlXAAOsw8wWCQ,15
+            foo/bar.py,sha256=A-aT2fL2h-D0Djao33_LTRwil0ASt8KlXAAOsw8wWCQ,15
+            foo/baz.py,sha256=A-aT2fL2h-D0Djao33_LTRwil0ASt8KlXAAOsw8wWCQ,15
+            foo/qux.py,sha256=A-aT2fL2h-D0Djao33_LTRwil0ASt8KlXAAOsw8wWCQ,15
+            foo/sha512.py,sha512=o6LWaU_l5d43uN9CFwh3g6x_1qlSX6ZYadw6_tITaACyc6JP55edFheReG0cCNKMvUf4lXIEyIM4WGHcT7Mwug,15
+            foo/blake2b.py,blake2b=G5rfZEIDCSEzHIjXQbptgMD9sB6_0odWyOgNmShY5ZkbkjjeI8pTRtCd8LZtdgy_5hfjOah07ITR1hKEOACEWw,15
+            foo/sha3.py,sha3_256=OgZv8ePk9Ulfp1QZKbQ4cz-XRsNOPbkeKOdalOmWLuw,15
+            foo/__pycache__/__init__.cpython-312.pyc,,
+            foo-1.0.dist-info/RECORD,,
+        "})?;
+
+        let verification = verify_record(dist_info.path())?;
+        assert_eq!(
+            verification.mismatches,
+            vec![
+                RecordMismatch::Missing(PathBuf::from("foo/baz.py")),
+                RecordMismatch::Modified(PathBuf::from("foo/bar.py")),
+                RecordMismatch::Modified(PathBuf::from("foo/qux.py")),
+            ]
+        );
+        assert_eq!(
+            verification.skipped,
+            vec![(PathBuf::from("foo/sha3.py"), "sha3_256".to_string())]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_missing_record() -> Result<()> {
+        let site_packages = assert_fs::TempDir::new()?;
+        let dist_info = site_packages.child("foo-1.0.dist-info");
+        dist_info.create_dir_all()?;
+
+        let err = verify_record(dist_info.path()).unwrap_err();
+        assert!(matches!(err, Error::MissingRecord(_)));
+
+        Ok(())
+    }
+}
@@ -12,6 +12,7 @@ use uv_distribution_types::CachedDist;
 use uv_install_wheel::{Layout, LinkMode};
 use uv_preview::Preview;
 use uv_python::PythonEnvironment;
+use uv_warnings::warn_user_once;
 
 pub struct Installer<'a> {
     venv: &'a PythonEnvironment,
@@ -102,6 +103,16 @@ impl<'a> Installer<'a> {
                 ));
             }
         }
+        if link_mode.is_symlink() {
+            warn_on_eviction_policy(cache);
+        }
+
+        // Record the use of each cached wheel, to inform the cache's eviction policy.
+        if let Some(cache) = cache {
+            for wheel in &wheels {
+                cache.record_access(wheel.path());
+            }
+        }
 
         let (tx, rx) = oneshot::channel();
 
@@ -140,6 +151,16 @@ impl<'a> Installer<'a> {
                 ));
             }
         }
+        if self.link_mode.is_symlink() {
+            warn_on_eviction_policy(self.cache);
+        }
+
+        // Record the use of each cached wheel, to inform the cache's eviction policy.
+        if let Some(cache) = self.cache {
+            for wheel in &wheels {
+                cache.record_access(wheel.path());
+            }
+        }
 
         install(
             wheels,
@@ -154,6 +175,15 @@ impl<'a> Installer<'a> {
     }
 }
 
+/// Warn if symlinked installations may be broken by evicting the linked cache entries.
+fn warn_on_eviction_policy(cache: Option<&Cache>) {
+    if cache.is_some_and(|cache| cache.eviction_policy().is_some()) {
+        warn_user_once!(
+            "Symlink-based installation is not recommended with `cache-max-size` or `cache-max-age`. Environments will be rendered unusable if the linked cache entries are evicted; use `--link-mode copy` or `--link-mode hardlink` instead."
+        );
+    }
+}
+
 /// Install a set of wheels into a Python virtual environment synchronously.
 #[instrument(skip_all, fields(num_wheels = %wheels.len()))]
 fn install(
@@ -250,6 +250,12 @@ impl<'a> Planner<'a> {
 
                     // Read the HTTP pointer.
                     match HttpArchivePointer::read_from(&cache_entry) {
+                        // Ignore stale pointers, i.e., to archives that have since been removed.
+                        Ok(Some(pointer)) if !pointer.exists(cache) => {
+                            debug!(
+                                "Cached URL wheel requirement was removed from the cache: {wheel}"
+                            );
+                        }
                         Ok(Some(pointer)) => {
                             let cache_info = pointer.to_cache_info();
                             let build_info = pointer.to_build_info();
@@ -323,6 +329,12 @@ impl<'a> Planner<'a> {
                         .entry(format!("{}.rev", wheel.filename.cache_key()));
 
                     match LocalArchivePointer::read_from(&cache_entry) {
+                        // Ignore stale pointers, i.e., to archives that have since been removed.
+                        Ok(Some(pointer)) if !pointer.exists(cache) => {
+                            debug!(
+                                "Cached path wheel requirement was removed from the cache: {wheel}"
+                            );
+                        }
                         Ok(Some(pointer)) => match Timestamp::from_path(&wheel.install_path) {
                             Ok(timestamp) => {
                                 if pointer.is_up_to_date(timestamp) {
@@ -414,7 +414,10 @@ fn extra_build_variables_for<'settings>(
 
 /// Generate a hint for explaining tag compatibility issues.
 // TODO(zanieb): We should refactor this to share logic with `generate_wheel_compatibility_hint`
-fn generate_dist_compatibility_hint(wheel_tags: &ExpandedTags, tags: &Tags) -> Option<String> {
+pub(crate) fn generate_dist_compatibility_hint(
+    wheel_tags: &ExpandedTags,
+    tags: &Tags,
+) -> Option<String> {
     let TagCompatibility::Incompatible(incompatible_tag) = wheel_tags.compatibility(tags) else {
         return None;
     };
@@ -550,3 +553,36 @@ fn generate_dist_compatibility_hint(wheel_tags: &ExpandedTags, tags: &Tags) -> O
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use uv_distribution_filename::ExpandedTags;
+    use uv_platform_tags::{Arch, Os, Platform, Tags};
+
+    use super::generate_dist_compatibility_hint;
+
+    fn linux_tags() -> Tags {
+        let platform = Platform::new(
+            Os::Manylinux {
+                major: 2,
+                minor: 28,
+            },
+            Arch::X86_64,
+        );
+        Tags::from_env(&platform, (3, 12), "cpython", (3, 12), true, false, false).unwrap()
+    }
+
+    #[test]
+    fn test_platform_hint() {
+        let wheel_tags = ExpandedTags::parse(["cp312-cp312-win_amd64"]).unwrap();
+        let hint = generate_dist_compatibility_hint(&wheel_tags, &linux_tags()).unwrap();
+        insta::assert_snapshot!(hint, @"The distribution is compatible with Windows (`win_amd64`), but you're on Linux (`manylinux_2_28_x86_64`)");
+    }
+
+    #[test]
+    fn test_abi_hint() {
+        let wheel_tags = ExpandedTags::parse(["cp311-cp311-manylinux_2_17_x86_64"]).unwrap();
+        let hint = generate_dist_compatibility_hint(&wheel_tags, &linux_tags()).unwrap();
+        insta::assert_snapshot!(hint, @"The distribution is compatible with CPython 3.11 (`cp311`), but you're using CPython 3.12 (`cp312`)");
+    }
+}
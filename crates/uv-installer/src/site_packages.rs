@@ -5,7 +5,9 @@ use std::path::PathBuf;
 
 use anyhow::{Context, Result};
 use fs_err as fs;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
+use tracing::debug;
 
 use uv_distribution_types::{
     ConfigSettings, Diagnostic, ExtraBuildRequires, ExtraBuildVariables, InstalledDist,
@@ -13,17 +15,18 @@ use uv_distribution_types::{
     UnresolvedRequirement, UnresolvedRequirementSpecification,
 };
 use uv_fs::Simplified;
+use uv_install_wheel::RecordMismatch;
 use uv_normalize::PackageName;
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::VersionOrUrl;
-use uv_platform_tags::Tags;
+use uv_platform_tags::{IncompatibleTag, TagCompatibility, Tags};
 use uv_pypi_types::{ResolverMarkerEnvironment, VerbatimParsedUrl};
 use uv_python::{Interpreter, PythonEnvironment};
 use uv_redacted::DisplaySafeUrl;
 use uv_types::InstalledPackagesProvider;
 use uv_warnings::warn_user;
 
-use crate::satisfies::RequirementSatisfaction;
+use crate::satisfies::{RequirementSatisfaction, generate_dist_compatibility_hint};
 
 /// An index over the packages installed in an environment.
 ///
@@ -248,10 +251,31 @@ impl SitePackages {
                 match distribution.read_tags() {
                     Ok(Some(wheel_tags)) => {
                         if !wheel_tags.is_compatible(tags) {
-                            // TODO(charlie): Show the expanded tag hint, that explains _why_ it doesn't match.
-                            diagnostics.push(SitePackagesDiagnostic::IncompatiblePlatform {
-                                package: package.clone(),
-                            });
+                            let hint = generate_dist_compatibility_hint(wheel_tags, tags);
+                            match wheel_tags.compatibility(tags) {
+                                TagCompatibility::Incompatible(
+                                    IncompatibleTag::Python
+                                    | IncompatibleTag::Abi
+                                    | IncompatibleTag::FreethreadedAbi
+                                    | IncompatibleTag::AbiPythonVersion,
+                                ) => {
+                                    diagnostics.push(SitePackagesDiagnostic::IncompatibleAbi {
+                                        package: package.clone(),
+                                        hint,
+                                    });
+                                }
+                                TagCompatibility::Incompatible(
+                                    IncompatibleTag::Invalid | IncompatibleTag::Platform,
+                                )
+                                | TagCompatibility::Compatible(_) => {
+                                    diagnostics.push(
+                                        SitePackagesDiagnostic::IncompatiblePlatform {
+                                            package: package.clone(),
+                                            hint,
+                                        },
+                                    );
+                                }
+                            }
                         }
                     }
                     Ok(None) => {}
@@ -308,6 +332,82 @@ impl SitePackages {
         Ok(diagnostics)
     }
 
+    /// Verify the files of each installed package against its `RECORD` file, to detect files that
+    /// were modified or removed after installation.
+    ///
+    /// Only packages installed from wheels (i.e., with a `.dist-info` directory) are verified. Files
+    /// whose hashes use an unsupported algorithm are skipped with a warning.
+    pub fn verify_records(&self) -> Vec<SitePackagesDiagnostic> {
+        let results = self
+            .distributions
+            .par_iter()
+            .flatten()
+            .filter(|distribution| {
+                matches!(
+                    distribution.kind,
+                    InstalledDistKind::Registry(_) | InstalledDistKind::Url(_)
+                )
+            })
+            .map(|distribution| {
+                match uv_install_wheel::verify_record(distribution.install_path()) {
+                    Ok(verification) => {
+                        let skipped = (!verification.skipped.is_empty()).then(|| {
+                            let algorithms = verification
+                                .skipped
+                                .iter()
+                                .map(|(_, algorithm)| algorithm.clone())
+                                .collect::<BTreeSet<_>>();
+                            (distribution.name(), verification.skipped.len(), algorithms)
+                        });
+                        let diagnostic = if verification.mismatches.is_empty() {
+                            None
+                        } else {
+                            Some(SitePackagesDiagnostic::ModifiedFiles {
+                                package: distribution.name().clone(),
+                                mismatches: verification.mismatches,
+                            })
+                        };
+                        (diagnostic, skipped)
+                    }
+                    Err(err) => {
+                        debug!(
+                            "Failed to verify `RECORD` for {}: {err}",
+                            distribution.install_path().user_display()
+                        );
+                        let diagnostic = SitePackagesDiagnostic::RecordUnavailable {
+                            package: distribution.name().clone(),
+                            path: distribution.install_path().to_owned(),
+                        };
+                        (Some(diagnostic), None)
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let (diagnostics, skipped): (Vec<_>, Vec<_>) = results.into_iter().unzip();
+
+        // Warn about the skipped files after the parallel pass, such that the warnings are emitted
+        // in a deterministic order.
+        let mut skipped = skipped.into_iter().flatten().collect::<Vec<_>>();
+        skipped.sort_by(|(a, ..), (b, ..)| a.cmp(b));
+        for (package, files, algorithms) in skipped {
+            warn_user!(
+                "Skipped verification of {files} file{} in `{package}` with unsupported hash algorithm{}: {}",
+                if files == 1 { "" } else { "s" },
+                if algorithms.len() == 1 { "" } else { "s" },
+                algorithms
+                    .into_iter()
+                    .map(|algorithm| format!("`{algorithm}`"))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            );
+        }
+
+        let mut diagnostics = diagnostics.into_iter().flatten().collect::<Vec<_>>();
+        diagnostics.sort_by(|a, b| a.package().cmp(b.package()));
+        diagnostics
+    }
+
     /// Returns if the installed packages satisfy the given requirements.
     pub fn satisfies_spec(
         &self,
@@ -635,6 +735,26 @@ pub enum SitePackagesDiagnostic {
     IncompatiblePlatform {
         /// The package that was built for a different platform.
         package: PackageName,
+        /// A hint explaining why the package's tags are incompatible, if available.
+        hint: Option<String>,
+    },
+    IncompatibleAbi {
+        /// The package that was built for a different Python implementation or ABI.
+        package: PackageName,
+        /// A hint explaining why the package's tags are incompatible, if available.
+        hint: Option<String>,
+    },
+    RecordUnavailable {
+        /// The package that is missing a `RECORD` file.
+        package: PackageName,
+        /// The path to the package.
+        path: PathBuf,
+    },
+    ModifiedFiles {
+        /// The package with files that differ from its `RECORD` file.
+        package: PackageName,
+        /// The files that were modified or removed, relative to the `site-packages` directory.
+        mismatches: Vec<RecordMismatch>,
     },
     MissingDependency {
         /// The package that is missing a dependency.
@@ -677,9 +797,37 @@ impl Diagnostic for SitePackagesDiagnostic {
             } => format!(
                 "The package `{package}` requires Python {requires_python}, but `{version}` is installed"
             ),
-            Self::IncompatiblePlatform { package } => {
-                format!("The package `{package}` was built for a different platform")
+            Self::IncompatiblePlatform { package, hint } => {
+                if let Some(hint) = hint {
+                    format!("The package `{package}` was built for a different platform. {hint}")
+                } else {
+                    format!("The package `{package}` was built for a different platform")
+                }
             }
+            Self::IncompatibleAbi { package, hint } => {
+                if let Some(hint) = hint {
+                    format!("The package `{package}` was built for a different Python ABI. {hint}")
+                } else {
+                    format!("The package `{package}` was built for a different Python ABI")
+                }
+            }
+            Self::RecordUnavailable { package, path } => format!(
+                "The package `{package}` is broken or incomplete (unable to read `RECORD` file). Consider recreating the virtualenv, or removing the package directory at: {}.",
+                path.display(),
+            ),
+            Self::ModifiedFiles {
+                package,
+                mismatches,
+            } => format!(
+                "The package `{package}` has files that don't match its `RECORD` file:{}",
+                mismatches.iter().fold(String::new(), |acc, mismatch| {
+                    let reason = match mismatch {
+                        RecordMismatch::Missing(_) => "missing",
+                        RecordMismatch::Modified(_) => "modified",
+                    };
+                    acc + &format!("\n  - {} ({reason})", mismatch.path().portable_display())
+                })
+            ),
             Self::MissingDependency {
                 package,
                 requirement,
@@ -711,7 +859,10 @@ impl Diagnostic for SitePackagesDiagnostic {
             Self::MetadataUnavailable { package, .. } => name == package,
             Self::TagsUnavailable { package, .. } => name == package,
             Self::IncompatiblePythonVersion { package, .. } => name == package,
-            Self::IncompatiblePlatform { package } => name == package,
+            Self::IncompatiblePlatform { package, .. } => name == package,
+            Self::IncompatibleAbi { package, .. } => name == package,
+            Self::RecordUnavailable { package, .. } => name == package,
+            Self::ModifiedFiles { package, .. } => name == package,
             Self::MissingDependency { package, .. } => name == package,
             Self::IncompatibleDependency {
                 package,
@@ -723,6 +874,40 @@ impl Diagnostic for SitePackagesDiagnostic {
     }
 }
 
+impl SitePackagesDiagnostic {
+    /// Returns the package that this diagnostic is about.
+    pub fn package(&self) -> &PackageName {
+        match self {
+            Self::MetadataUnavailable { package, .. }
+            | Self::TagsUnavailable { package, .. }
+            | Self::IncompatiblePythonVersion { package, .. }
+            | Self::IncompatiblePlatform { package, .. }
+            | Self::IncompatibleAbi { package, .. }
+            | Self::RecordUnavailable { package, .. }
+            | Self::ModifiedFiles { package, .. }
+            | Self::MissingDependency { package, .. }
+            | Self::IncompatibleDependency { package, .. }
+            | Self::DuplicatePackage { package, .. } => package,
+        }
+    }
+
+    /// Returns a stable, machine-readable identifier for the kind of diagnostic.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::MetadataUnavailable { .. } => "metadata-unavailable",
+            Self::TagsUnavailable { .. } => "tags-unavailable",
+            Self::IncompatiblePythonVersion { .. } => "incompatible-python-version",
+            Self::IncompatiblePlatform { .. } => "incompatible-platform",
+            Self::IncompatibleAbi { .. } => "incompatible-abi",
+            Self::RecordUnavailable { .. } => "record-unavailable",
+            Self::ModifiedFiles { .. } => "modified-files",
+            Self::MissingDependency { .. } => "missing-dependency",
+            Self::IncompatibleDependency { .. } => "incompatible-dependency",
+            Self::DuplicatePackage { .. } => "duplicate-package",
+        }
+    }
+}
+
 impl InstalledPackagesProvider for SitePackages {
     fn iter(&self) -> impl Iterator<Item = &InstalledDist> {
         self.iter()
@@ -237,9 +237,8 @@ pub async fn read_metadata_async_seek(
 /// Like [`read_metadata_async_seek`], but doesn't use seek.
 pub async fn read_metadata_async_stream<R: futures::AsyncRead + Unpin>(
     filename: &WheelFilename,
-    debug_path: &str,
     reader: R,
-) -> Result<ResolutionMetadata, Error> {
+) -> Result<Vec<u8>, Error> {
     let reader = futures::io::BufReader::with_capacity(128 * 1024, reader);
     let mut zip = async_zip::base::read::stream::ZipFileReader::new(reader);
 
@@ -275,9 +274,7 @@ pub async fn read_metadata_async_stream<R: futures::AsyncRead + Unpin>(
                 }
             }
 
-            let metadata = ResolutionMetadata::parse_metadata(&contents)
-                .map_err(|err| Error::InvalidMetadata(debug_path.to_string(), Box::new(err)))?;
-            return Ok(metadata);
+            return Ok(contents);
         }
 
         // Close current file to get access to the next one. See docs:
@@ -32,6 +32,15 @@ bitflags::bitflags! {
         const METADATA_JSON = 1 << 20;
         const GCS_ENDPOINT = 1 << 21;
         const ADJUST_ULIMIT = 1 << 22;
+        const AUDIT = 1 << 23;
+        const LINT = 1 << 24;
+        const METADATA_INDEX = 1 << 25;
+        const LOCK_PLATFORMS = 1 << 26;
+        const CACHE_BUNDLE = 1 << 27;
+        const CACHE_PRUNE_LOCK = 1 << 28;
+        const REMOTE_BUILD_CACHE = 1 << 29;
+        const CACHE_VERIFY = 1 << 30;
+        const BIN = 1 << 31;
     }
 }
 
@@ -64,6 +73,15 @@ impl PreviewFeatures {
             Self::METADATA_JSON => "metadata-json",
             Self::GCS_ENDPOINT => "gcs-endpoint",
             Self::ADJUST_ULIMIT => "adjust-ulimit",
+            Self::AUDIT => "audit",
+            Self::LINT => "lint",
+            Self::METADATA_INDEX => "metadata-index",
+            Self::LOCK_PLATFORMS => "lock-platforms",
+            Self::CACHE_BUNDLE => "cache-bundle",
+            Self::CACHE_PRUNE_LOCK => "cache-prune-lock",
+            Self::REMOTE_BUILD_CACHE => "remote-build-cache",
+            Self::CACHE_VERIFY => "cache-verify",
+            Self::BIN => "bin",
             _ => panic!("`flag_as_str` can only be used for exactly one feature flag"),
         }
     }
@@ -124,6 +142,15 @@ impl FromStr for PreviewFeatures {
                 "target-workspace-discovery" => Self::TARGET_WORKSPACE_DISCOVERY,
                 "metadata-json" => Self::METADATA_JSON,
                 "adjust-ulimit" => Self::ADJUST_ULIMIT,
+                "audit" => Self::AUDIT,
+                "lint" => Self::LINT,
+                "metadata-index" => Self::METADATA_INDEX,
+                "lock-platforms" => Self::LOCK_PLATFORMS,
+                "cache-bundle" => Self::CACHE_BUNDLE,
+                "cache-prune-lock" => Self::CACHE_PRUNE_LOCK,
+                "remote-build-cache" => Self::REMOTE_BUILD_CACHE,
+                "cache-verify" => Self::CACHE_VERIFY,
+                "bin" => Self::BIN,
                 _ => {
                     warn_user_once!("Unknown preview feature: `{part}`");
                     continue;
@@ -317,6 +344,27 @@ mod tests {
             PreviewFeatures::ADJUST_ULIMIT.flag_as_str(),
             "adjust-ulimit"
         );
+        assert_eq!(PreviewFeatures::AUDIT.flag_as_str(), "audit");
+        assert_eq!(PreviewFeatures::LINT.flag_as_str(), "lint");
+        assert_eq!(
+            PreviewFeatures::METADATA_INDEX.flag_as_str(),
+            "metadata-index"
+        );
+        assert_eq!(
+            PreviewFeatures::LOCK_PLATFORMS.flag_as_str(),
+            "lock-platforms"
+        );
+        assert_eq!(PreviewFeatures::CACHE_BUNDLE.flag_as_str(), "cache-bundle");
+        assert_eq!(
+            PreviewFeatures::CACHE_PRUNE_LOCK.flag_as_str(),
+            "cache-prune-lock"
+        );
+        assert_eq!(
+            PreviewFeatures::REMOTE_BUILD_CACHE.flag_as_str(),
+            "remote-build-cache"
+        );
+        assert_eq!(PreviewFeatures::CACHE_VERIFY.flag_as_str(), "cache-verify");
+        assert_eq!(PreviewFeatures::BIN.flag_as_str(), "bin");
     }
 
     #[test]
@@ -253,6 +253,20 @@ impl ConflictSet {
         self.set.contains(conflict_item)
     }
 
+    /// Convert this set to its `pyproject.toml` representation, in which the package name is
+    /// omitted for items that belong to the given package.
+    pub fn to_schema(&self, package: &PackageName) -> SchemaConflictSet {
+        SchemaConflictSet(
+            self.set
+                .iter()
+                .map(|item| SchemaConflictItem {
+                    package: (item.package != *package).then(|| item.package.clone()),
+                    kind: item.kind.clone(),
+                })
+                .collect(),
+        )
+    }
+
     /// Replace an old [`ConflictItem`] with a new one.
     pub fn replaced_item(
         &self,
@@ -620,6 +634,13 @@ impl SchemaConflicts {
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SchemaConflictSet(Vec<SchemaConflictItem>);
 
+impl SchemaConflictSet {
+    /// Returns an iterator over all conflicting items.
+    pub fn iter(&self) -> impl Iterator<Item = &'_ SchemaConflictItem> + Clone + '_ {
+        self.0.iter()
+    }
+}
+
 /// Like [`ConflictItem`], but for deserialization in `pyproject.toml`.
 ///
 /// The schema format is different from the in-memory format. Specifically, the
@@ -639,6 +660,20 @@ pub struct SchemaConflictItem {
     kind: ConflictKind,
 }
 
+impl SchemaConflictItem {
+    /// Returns the explicit package name of this conflicting item, if any.
+    pub fn package(&self) -> Option<&PackageName> {
+        self.package.as_ref()
+    }
+
+    /// Returns the package-specific conflict.
+    ///
+    /// i.e., Either an extra or a group name.
+    pub fn kind(&self) -> &ConflictKind {
+        &self.kind
+    }
+}
+
 #[cfg(feature = "schemars")]
 impl schemars::JsonSchema for SchemaConflictItem {
     fn schema_name() -> Cow<'static, str> {
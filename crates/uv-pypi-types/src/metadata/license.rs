@@ -0,0 +1,357 @@
+use std::borrow::Cow;
+use std::str::FromStr;
+
+use serde::de::IntoDeserializer;
+use serde::{Deserialize, Serialize};
+
+use crate::MetadataError;
+use crate::metadata::Headers;
+
+/// `License ::` Trove classifiers that map unambiguously onto an SPDX license identifier.
+///
+/// Classifiers that cover a family of licenses (e.g., `License :: OSI Approved :: BSD License`)
+/// are intentionally omitted.
+const CLASSIFIERS: &[(&str, &str)] = &[
+    (
+        "License :: CC0 1.0 Universal (CC0 1.0) Public Domain Dedication",
+        "CC0-1.0",
+    ),
+    (
+        "License :: OSI Approved :: Apache Software License",
+        "Apache-2.0",
+    ),
+    (
+        "License :: OSI Approved :: Boost Software License 1.0 (BSL-1.0)",
+        "BSL-1.0",
+    ),
+    (
+        "License :: OSI Approved :: Eclipse Public License 2.0 (EPL-2.0)",
+        "EPL-2.0",
+    ),
+    (
+        "License :: OSI Approved :: GNU Affero General Public License v3",
+        "AGPL-3.0-only",
+    ),
+    (
+        "License :: OSI Approved :: GNU Affero General Public License v3 or later (AGPLv3+)",
+        "AGPL-3.0-or-later",
+    ),
+    (
+        "License :: OSI Approved :: GNU General Public License v2 (GPLv2)",
+        "GPL-2.0-only",
+    ),
+    (
+        "License :: OSI Approved :: GNU General Public License v2 or later (GPLv2+)",
+        "GPL-2.0-or-later",
+    ),
+    (
+        "License :: OSI Approved :: GNU General Public License v3 (GPLv3)",
+        "GPL-3.0-only",
+    ),
+    (
+        "License :: OSI Approved :: GNU General Public License v3 or later (GPLv3+)",
+        "GPL-3.0-or-later",
+    ),
+    (
+        "License :: OSI Approved :: GNU Lesser General Public License v2 (LGPLv2)",
+        "LGPL-2.0-only",
+    ),
+    (
+        "License :: OSI Approved :: GNU Lesser General Public License v2 or later (LGPLv2+)",
+        "LGPL-2.0-or-later",
+    ),
+    (
+        "License :: OSI Approved :: GNU Lesser General Public License v3 (LGPLv3)",
+        "LGPL-3.0-only",
+    ),
+    (
+        "License :: OSI Approved :: GNU Lesser General Public License v3 or later (LGPLv3+)",
+        "LGPL-3.0-or-later",
+    ),
+    ("License :: OSI Approved :: ISC License (ISCL)", "ISC"),
+    ("License :: OSI Approved :: MIT License", "MIT"),
+    (
+        "License :: OSI Approved :: MIT No Attribution License (MIT-0)",
+        "MIT-0",
+    ),
+    (
+        "License :: OSI Approved :: Mozilla Public License 2.0 (MPL 2.0)",
+        "MPL-2.0",
+    ),
+    (
+        "License :: OSI Approved :: Python Software Foundation License",
+        "PSF-2.0",
+    ),
+    (
+        "License :: OSI Approved :: The Unlicense (Unlicense)",
+        "Unlicense",
+    ),
+    (
+        "License :: OSI Approved :: Universal Permissive License (UPL)",
+        "UPL-1.0",
+    ),
+    ("License :: OSI Approved :: zlib/libpng License", "Zlib"),
+];
+
+/// Well-known SPDX license identifiers that have no unambiguous Trove classifier, but are commonly
+/// used as the value of the legacy `License` field.
+const IDENTIFIERS: &[&str] = &["BSD-2-Clause", "BSD-3-Clause"];
+
+/// The license information declared in a package's core metadata.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct PackageLicense {
+    /// The SPDX license expression, from the `License-Expression` field (PEP 639).
+    pub expression: Option<String>,
+    /// The free-form license text, from the legacy `License` field.
+    pub text: Option<String>,
+    /// The `License ::` Trove classifiers.
+    pub classifiers: Vec<String>,
+}
+
+impl PackageLicense {
+    /// Parse the license information from a `METADATA` file, as included in a built distribution
+    /// (wheel).
+    ///
+    /// Returns `None` if the metadata doesn't declare a license.
+    pub fn parse_metadata(content: &[u8]) -> Result<Option<Self>, MetadataError> {
+        let headers = Headers::parse(content)?;
+        Ok(Self::from_fields(
+            headers.get_first_value("License-Expression").as_deref(),
+            headers.get_first_value("License").as_deref(),
+            headers
+                .get_all_values("Classifier")
+                .collect::<Vec<_>>()
+                .iter()
+                .map(String::as_str),
+        ))
+    }
+
+    /// Parse the license information from the `[project]` table of a `pyproject.toml` file.
+    ///
+    /// Returns `None` if the project doesn't declare a license, or if its license or classifiers
+    /// are dynamic.
+    pub fn parse_pyproject_toml(content: &str) -> Result<Option<Self>, MetadataError> {
+        let pyproject_toml = toml_edit::Document::from_str(content)
+            .map_err(MetadataError::InvalidPyprojectTomlSyntax)?;
+        let pyproject_toml = PyProjectToml::deserialize(pyproject_toml.into_deserializer())
+            .map_err(MetadataError::InvalidPyprojectTomlSchema)?;
+        let Some(project) = pyproject_toml.project else {
+            return Ok(None);
+        };
+        if project
+            .dynamic
+            .iter()
+            .flatten()
+            .any(|field| field == "license" || field == "classifiers")
+        {
+            return Ok(None);
+        }
+        let (expression, text) = match &project.license {
+            Some(ProjectLicense::Expression(expression)) => (Some(expression.as_str()), None),
+            Some(ProjectLicense::Table { text }) => (None, text.as_deref()),
+            None => (None, None),
+        };
+        Ok(Self::from_fields(
+            expression,
+            text,
+            project.classifiers.iter().flatten().map(String::as_str),
+        ))
+    }
+
+    fn from_fields<'a>(
+        expression: Option<&str>,
+        text: Option<&str>,
+        classifiers: impl Iterator<Item = &'a str>,
+    ) -> Option<Self> {
+        let license = Self {
+            expression: expression
+                .map(str::trim)
+                .filter(|expression| !expression.is_empty())
+                .map(ToString::to_string),
+            text: text
+                .map(str::trim)
+                .filter(|text| !text.is_empty())
+                .map(ToString::to_string),
+            classifiers: classifiers
+                .filter(|classifier| classifier.starts_with("License ::"))
+                .map(ToString::to_string)
+                .collect(),
+        };
+        if license == Self::default() {
+            None
+        } else {
+            Some(license)
+        }
+    }
+
+    /// Return the SPDX license expression for the package, if it can be determined.
+    ///
+    /// Prefers the `License-Expression` field. Otherwise, falls back to the `License` field if
+    /// it consists of a single well-known SPDX identifier, and then to the license classifiers if
+    /// all of them map onto an SPDX identifier.
+    pub fn spdx_expression(&self) -> Option<Cow<'_, str>> {
+        if let Some(expression) = &self.expression {
+            return Some(Cow::Borrowed(expression));
+        }
+
+        if let Some(text) = &self.text {
+            if let Some(id) = CLASSIFIERS
+                .iter()
+                .map(|(_, id)| id)
+                .chain(IDENTIFIERS)
+                .find(|id| *id == text)
+            {
+                return Some(Cow::Borrowed(id));
+            }
+        }
+
+        let mut ids = self
+            .classifiers
+            .iter()
+            .map(|classifier| {
+                CLASSIFIERS
+                    .iter()
+                    .find(|(known, _)| known == classifier)
+                    .map(|(_, id)| *id)
+            })
+            .collect::<Option<Vec<_>>>()?;
+        ids.sort_unstable();
+        ids.dedup();
+        match ids.as_slice() {
+            [] => None,
+            [id] => Some(Cow::Borrowed(id)),
+            ids => Some(Cow::Owned(ids.join(" OR "))),
+        }
+    }
+}
+
+/// The subset of a `pyproject.toml` file that declares the project's license.
+#[derive(Deserialize, Debug)]
+struct PyProjectToml {
+    project: Option<Project>,
+}
+
+#[derive(Deserialize, Debug)]
+struct Project {
+    license: Option<ProjectLicense>,
+    classifiers: Option<Vec<String>>,
+    dynamic: Option<Vec<String>>,
+}
+
+/// The `project.license` field, as either an SPDX expression (PEP 639) or a legacy table.
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+enum ProjectLicense {
+    /// An SPDX license expression.
+    Expression(String),
+    /// A table with the license text, or a reference to a license file.
+    Table { text: Option<String> },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PackageLicense;
+
+    #[test]
+    fn spdx_expression() {
+        let license = PackageLicense {
+            expression: Some("MIT OR Apache-2.0".to_string()),
+            text: Some("MIT".to_string()),
+            classifiers: vec!["License :: OSI Approved :: BSD License".to_string()],
+        };
+        assert_eq!(
+            license.spdx_expression().as_deref(),
+            Some("MIT OR Apache-2.0")
+        );
+
+        let license = PackageLicense {
+            expression: None,
+            text: Some("Apache-2.0".to_string()),
+            classifiers: vec![],
+        };
+        assert_eq!(license.spdx_expression().as_deref(), Some("Apache-2.0"));
+
+        let license = PackageLicense {
+            expression: None,
+            text: Some("BSD-3-Clause".to_string()),
+            classifiers: vec!["License :: OSI Approved :: BSD License".to_string()],
+        };
+        assert_eq!(license.spdx_expression().as_deref(), Some("BSD-3-Clause"));
+
+        let license = PackageLicense {
+            expression: None,
+            text: Some("BSD".to_string()),
+            classifiers: vec![
+                "License :: OSI Approved :: MIT License".to_string(),
+                "License :: OSI Approved :: Apache Software License".to_string(),
+            ],
+        };
+        assert_eq!(
+            license.spdx_expression().as_deref(),
+            Some("Apache-2.0 OR MIT")
+        );
+
+        let license = PackageLicense {
+            expression: None,
+            text: Some("BSD".to_string()),
+            classifiers: vec![
+                "License :: OSI Approved :: MIT License".to_string(),
+                "License :: OSI Approved :: BSD License".to_string(),
+            ],
+        };
+        assert_eq!(license.spdx_expression(), None);
+    }
+
+    #[test]
+    fn parse_metadata() {
+        let s = "Metadata-Version: 2.1\nName: asdf\nVersion: 1.0\nLicense: BSD\nClassifier: License :: OSI Approved :: MIT License\nClassifier: Operating System :: OS Independent";
+        let license = PackageLicense::parse_metadata(s.as_bytes())
+            .unwrap()
+            .unwrap();
+        assert_eq!(license.text.as_deref(), Some("BSD"));
+        assert_eq!(
+            license.classifiers,
+            ["License :: OSI Approved :: MIT License"]
+        );
+        assert_eq!(license.spdx_expression().as_deref(), Some("MIT"));
+
+        let s = "Metadata-Version: 2.1\nName: asdf\nVersion: 1.0\nClassifier: Operating System :: OS Independent";
+        let license = PackageLicense::parse_metadata(s.as_bytes()).unwrap();
+        assert!(license.is_none());
+    }
+
+    #[test]
+    fn parse_pyproject_toml() {
+        let s = r#"
+        [project]
+        name = "asdf"
+        version = "1.0"
+        license = "MIT OR Apache-2.0"
+    "#;
+        let license = PackageLicense::parse_pyproject_toml(s).unwrap().unwrap();
+        assert_eq!(
+            license.spdx_expression().as_deref(),
+            Some("MIT OR Apache-2.0")
+        );
+
+        let s = r#"
+        [project]
+        name = "asdf"
+        version = "1.0"
+        license = { file = "LICENSE" }
+        classifiers = ["Operating System :: OS Independent"]
+    "#;
+        let license = PackageLicense::parse_pyproject_toml(s).unwrap();
+        assert!(license.is_none());
+
+        let s = r#"
+        [project]
+        name = "asdf"
+        version = "1.0"
+        license = "MIT"
+        dynamic = ["classifiers"]
+    "#;
+        let license = PackageLicense::parse_pyproject_toml(s).unwrap();
+        assert!(license.is_none());
+    }
+}
@@ -1,5 +1,6 @@
 //! Derived from `pypi_types_crate`.
 
+use std::fmt::Write;
 use std::str::FromStr;
 
 use itertools::Itertools;
@@ -266,6 +267,30 @@ impl ResolutionMetadata {
             dynamic,
         })
     }
+
+    /// Convert to the pseudo-email format used by a `METADATA` file.
+    ///
+    /// Only the fields relevant to dependency resolution are included, such that the output can be
+    /// read back via [`ResolutionMetadata::parse_metadata`].
+    pub fn core_metadata_format(&self) -> String {
+        let mut writer = String::new();
+        let _ = writeln!(writer, "Metadata-Version: 2.2");
+        let _ = writeln!(writer, "Name: {}", self.name);
+        let _ = writeln!(writer, "Version: {}", self.version);
+        if self.dynamic {
+            let _ = writeln!(writer, "Dynamic: Version");
+        }
+        if let Some(requires_python) = &self.requires_python {
+            let _ = writeln!(writer, "Requires-Python: {requires_python}");
+        }
+        for requirement in &self.requires_dist {
+            let _ = writeln!(writer, "Requires-Dist: {requirement}");
+        }
+        for extra in &self.provides_extra {
+            let _ = writeln!(writer, "Provides-Extra: {extra}");
+        }
+        writer
+    }
 }
 
 #[cfg(test)]
@@ -341,6 +366,29 @@ mod tests {
         assert_eq!(*meta.requires_dist, ["foo".parse().unwrap()]);
     }
 
+    #[test]
+    fn test_core_metadata_format() {
+        let s = "Metadata-Version: 2.1\nName: asdf\nVersion: 1.0\nSummary: A package\nRequires-Python: >=3.8\nRequires-Dist: foo>=1.0\nRequires-Dist: bar; extra == \"bar\"\nProvides-Extra: bar";
+        let meta = ResolutionMetadata::parse_metadata(s.as_bytes()).unwrap();
+        let formatted = meta.core_metadata_format();
+        insta::assert_snapshot!(formatted, @r#"
+        Metadata-Version: 2.2
+        Name: asdf
+        Version: 1.0
+        Requires-Python: >=3.8
+        Requires-Dist: foo>=1.0
+        Requires-Dist: bar ; extra == 'bar'
+        Provides-Extra: bar
+        "#);
+
+        let roundtrip = ResolutionMetadata::parse_metadata(formatted.as_bytes()).unwrap();
+        assert_eq!(roundtrip.name, meta.name);
+        assert_eq!(roundtrip.version, meta.version);
+        assert_eq!(roundtrip.requires_python, meta.requires_python);
+        assert_eq!(roundtrip.requires_dist, meta.requires_dist);
+        assert_eq!(roundtrip.provides_extra, meta.provides_extra);
+    }
+
     #[test]
     fn test_parse_pyproject_toml() {
         let s = r#"
@@ -1,4 +1,5 @@
 mod build_requires;
+mod license;
 mod metadata10;
 mod metadata23;
 mod metadata_resolver;
@@ -18,6 +19,7 @@ use uv_pep508::Pep508Error;
 use crate::VerbatimParsedUrl;
 
 pub use build_requires::BuildRequires;
+pub use license::PackageLicense;
 pub use metadata_resolver::ResolutionMetadata;
 pub use metadata10::Metadata10;
 pub use metadata23::{Keywords, Metadata23, ProjectUrls};
@@ -134,7 +134,12 @@ impl CandidateSelector {
         }
 
         // Otherwise, find the best candidate from the version maps.
-        let compatible = self.select_no_preference(package_name, range, version_maps, env);
+        let compatible = if let Some(locked) = self.resolution_strategy.locked_version(package_name)
+        {
+            self.select_minimal_step(package_name, locked, range, version_maps, env)
+        } else {
+            self.select_no_preference(package_name, range, version_maps, env)
+        };
 
         // Cross-reference against the already-installed distribution.
         //
@@ -424,7 +429,38 @@ impl CandidateSelector {
             version_maps.iter().map(VersionMap::len).sum::<usize>(),
         );
         let highest = self.use_highest_version(package_name, env);
+        self.select_from_version_maps(package_name, range, version_maps, env, highest)
+    }
+
+    /// Select a [`Candidate`] for a package whose version pinned by the existing lockfile is no
+    /// longer compatible, moving it by the smallest possible step.
+    ///
+    /// Prefers the lowest compatible version above the locked version; if there is none, falls
+    /// back to the highest compatible version below it.
+    fn select_minimal_step<'a>(
+        &'a self,
+        package_name: &'a PackageName,
+        locked: &Version,
+        range: &Range<Version>,
+        version_maps: &'a [VersionMap],
+        env: &ResolverEnvironment,
+    ) -> Option<Candidate<'a>> {
+        trace!("Selecting the smallest step from locked {package_name}=={locked} within {range}");
+        let above = range.intersection(&Range::strictly_higher_than(locked.clone()));
+        self.select_from_version_maps(package_name, &above, version_maps, env, false)
+            .or_else(|| self.select_from_version_maps(package_name, range, version_maps, env, true))
+    }
 
+    /// Select a [`Candidate`] from the version maps, preferring either the highest or the lowest
+    /// compatible version.
+    fn select_from_version_maps<'a>(
+        &'a self,
+        package_name: &'a PackageName,
+        range: &Range<Version>,
+        version_maps: &'a [VersionMap],
+        env: &ResolverEnvironment,
+        highest: bool,
+    ) -> Option<Candidate<'a>> {
         let allow_prerelease = match self.prerelease_strategy.allows(package_name, env) {
             AllowPrerelease::Yes => true,
             AllowPrerelease::No => false,
@@ -517,6 +553,7 @@ impl CandidateSelector {
             ResolutionStrategy::LowestDirect(direct_dependencies) => {
                 !direct_dependencies.contains(package_name, env)
             }
+            ResolutionStrategy::Security(locked) => !locked.contains_key(package_name),
         }
     }
 
@@ -18,7 +18,7 @@ use uv_normalize::{ExtraName, InvalidNameError, PackageName};
 use uv_pep440::{LocalVersionSlice, LowerBound, Version, VersionSpecifier};
 use uv_pep508::{MarkerEnvironment, MarkerExpression, MarkerTree, MarkerValueVersion};
 use uv_platform_tags::Tags;
-use uv_pypi_types::ParsedUrl;
+use uv_pypi_types::{ConflictItem, ParsedUrl};
 use uv_redacted::DisplaySafeUrl;
 use uv_static::EnvVars;
 
@@ -29,7 +29,7 @@ use crate::fork_urls::ForkUrls;
 use crate::prerelease::AllowPrerelease;
 use crate::pubgrub::{PubGrubPackage, PubGrubPackageInner, PubGrubReportFormatter};
 use crate::python_requirement::PythonRequirement;
-use crate::resolution::ConflictingDistributionError;
+use crate::resolution::{ConflictingDistributionError, DivergentVersionsError};
 use crate::resolver::{
     MetadataUnavailable, ResolverEnvironment, UnavailablePackage, UnavailableReason,
 };
@@ -129,6 +129,9 @@ pub enum ResolveError {
     #[error("found conflicting distribution in resolution: {0}")]
     ConflictingDistribution(ConflictingDistributionError),
 
+    #[error(transparent)]
+    DivergentVersions(DivergentVersionsError),
+
     #[error("Package `{0}` is unavailable")]
     PackageUnavailable(PackageName),
 
@@ -424,6 +427,21 @@ impl NoSolutionError {
             .filter_map(|p| p.name())
             .unique()
     }
+
+    /// Get the extras and dependency groups of workspace members that are involved in this error.
+    ///
+    /// If two or more items are returned, the error may be resolvable by declaring the items as
+    /// conflicting (i.e., in `tool.uv.conflicts`).
+    pub fn conflict_items(&self) -> BTreeSet<ConflictItem> {
+        self.error
+            .packages()
+            .into_iter()
+            .filter(|package| package.extra().is_some() || package.group().is_some())
+            .filter_map(PubGrubPackage::conflicting_item)
+            .filter(|item| self.workspace_members.contains(item.package()))
+            .map(|item| item.to_owned())
+            .collect()
+    }
 }
 
 impl std::fmt::Debug for NoSolutionError {
@@ -910,7 +928,7 @@ fn collapse_redundant_depends_on_no_versions_inner(
 /// the `python_version >= '3.11'` marker since it's implied to be true by
 /// the `requires-python` setting. This simplifies error messages by reducing
 /// noise.
-fn simplify_derivation_tree_markers(
+pub(crate) fn simplify_derivation_tree_markers(
     python_requirement: &PythonRequirement,
     tree: &mut DerivationTree<PubGrubPackage, Range<Version>, UnavailableReason>,
 ) {
@@ -951,7 +969,7 @@ fn simplify_derivation_tree_markers(
 /// Given a [`DerivationTree`], collapse incompatibilities for versions of a package that are
 /// unavailable for the same reason to avoid repeating the same message for every unavailable
 /// version.
-fn collapse_unavailable_versions(
+pub(crate) fn collapse_unavailable_versions(
     tree: &mut DerivationTree<PubGrubPackage, Range<Version>, UnavailableReason>,
 ) {
     match tree {
@@ -11,6 +11,10 @@ pub enum ForkStrategy {
     /// version.
     #[default]
     RequiresPython,
+    /// Select a single version of each package across all supported Python versions and
+    /// platforms. Resolution fails if the requirements force a package to diverge across
+    /// environments.
+    Single,
 }
 
 impl std::fmt::Display for ForkStrategy {
@@ -18,6 +22,7 @@ impl std::fmt::Display for ForkStrategy {
         match self {
             Self::Fewest => write!(f, "fewest"),
             Self::RequiresPython => write!(f, "requires-python"),
+            Self::Single => write!(f, "single"),
         }
     }
 }
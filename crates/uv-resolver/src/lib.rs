@@ -9,9 +9,11 @@ pub use exclusions::Exclusions;
 pub use flat_index::{FlatDistributions, FlatIndex};
 pub use fork_strategy::ForkStrategy;
 pub use lock::{
-    Installable, Lock, LockError, LockVersion, Package, PackageMap, PylockToml,
-    PylockTomlErrorKind, RequirementsTxtExport, ResolverManifest, SatisfiesResult, TreeDisplay,
-    VERSION, cyclonedx_json,
+    HashChange, Installable, LicensePolicyError, LicenseViolations, Lock, LockDiff, LockError,
+    LockVersion, Package, PackageAvailability, PackageChange, PackageLicense, PackageLicenses,
+    PackageMap, PylockToml, PylockTomlErrorKind, ReachablePackage, RequirementsTxtExport,
+    ResolverManifest, SatisfiesResult, TreeDisplay, VERSION, cyclonedx_json, reachable_packages,
+    spdx_json,
 };
 pub use manifest::Manifest;
 pub use options::{Flexibility, Options, OptionsBuilder};
@@ -19,13 +21,16 @@ pub use preferences::{Preference, PreferenceError, Preferences};
 pub use prerelease::PrereleaseMode;
 pub use python_requirement::PythonRequirement;
 pub use resolution::{
-    AnnotationStyle, ConflictingDistributionError, DisplayResolutionGraph, ResolverOutput,
+    AnnotationStyle, ConflictingDistributionError, DisplayResolutionGraph, DivergentVersionsError,
+    ResolverOutput,
 };
 pub use resolution_mode::ResolutionMode;
 pub use resolver::{
     BuildId, DefaultResolverProvider, DerivationChainBuilder, InMemoryIndex, MetadataResponse,
-    PackageVersionsResult, Reporter as ResolverReporter, Resolver, ResolverEnvironment,
-    ResolverProvider, VersionsResponse, WheelMetadataResult,
+    PROFILE_REPORT, PROFILE_TRACE, PackageVersionsResult, RecordingResolverProvider,
+    ReplayResolverProvider, Reporter as ResolverReporter, Resolver, ResolverEnvironment,
+    ResolverProfile, ResolverProfileError, ResolverProvider, ResolverTrace, ResolverTraceError,
+    TraceMode, VersionExplanation, VersionsResponse, WheelMetadataResult,
 };
 pub use universal_marker::{ConflictMarker, UniversalMarker};
 pub use version_map::VersionMap;
@@ -0,0 +1,554 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+
+use uv_distribution_types::{RemoteSource, SimplifiedMarkerTree};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pep508::MarkerTree;
+
+use crate::lock::{Lock, Package, Source, WheelWireSource};
+
+/// The differences between two lockfiles, e.g., the lockfile in the working tree and the lockfile
+/// at a given Git revision.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct LockDiff {
+    /// The change to the `exclude-newer` setting used to resolve the lockfile, if any.
+    #[serde(rename = "exclude-newer", skip_serializing_if = "Option::is_none")]
+    pub exclude_newer: Option<String>,
+    /// The changes to the locked packages, ordered by package name.
+    pub changes: Vec<PackageChange>,
+}
+
+/// A change to a locked package.
+///
+/// When a package is locked at multiple versions (i.e., across resolver forks), each version is
+/// compared against the version locked for the same fork, and `marker` captures the fork markers
+/// under which the package is locked.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum PackageChange {
+    /// The package was added to the lockfile.
+    Added {
+        name: PackageName,
+        version: Option<Version>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        marker: Option<String>,
+    },
+    /// The package was removed from the lockfile.
+    Removed {
+        name: PackageName,
+        version: Option<Version>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        marker: Option<String>,
+    },
+    /// The package was upgraded to a newer version.
+    Upgraded {
+        name: PackageName,
+        from: Version,
+        to: Version,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        marker: Option<String>,
+    },
+    /// The package was downgraded to an older version.
+    Downgraded {
+        name: PackageName,
+        from: Version,
+        to: Version,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        marker: Option<String>,
+    },
+    /// The package is locked at the same version, but from a different source.
+    SourceChanged {
+        name: PackageName,
+        version: Option<Version>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        marker: Option<String>,
+        from: String,
+        to: String,
+    },
+    /// The package is locked at the same version and from the same source, but the hashes of its
+    /// distributions changed.
+    HashesChanged {
+        name: PackageName,
+        version: Option<Version>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        marker: Option<String>,
+        files: Vec<HashChange>,
+    },
+}
+
+impl PackageChange {
+    /// The name of the changed package.
+    pub fn name(&self) -> &PackageName {
+        match self {
+            Self::Added { name, .. }
+            | Self::Removed { name, .. }
+            | Self::Upgraded { name, .. }
+            | Self::Downgraded { name, .. }
+            | Self::SourceChanged { name, .. }
+            | Self::HashesChanged { name, .. } => name,
+        }
+    }
+
+    /// The fork markers under which the changed package is locked, if any.
+    pub fn marker(&self) -> Option<&str> {
+        match self {
+            Self::Added { marker, .. }
+            | Self::Removed { marker, .. }
+            | Self::Upgraded { marker, .. }
+            | Self::Downgraded { marker, .. }
+            | Self::SourceChanged { marker, .. }
+            | Self::HashesChanged { marker, .. } => marker.as_deref(),
+        }
+    }
+}
+
+/// A change to the hash of a distribution (i.e., a source distribution or wheel) of a package.
+///
+/// A missing `from` hash indicates that the distribution was added; a missing `to` hash indicates
+/// that the distribution was removed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HashChange {
+    pub filename: String,
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+impl Display for HashChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (&self.from, &self.to) {
+            (Some(from), Some(to)) => write!(f, "{}: {from} -> {to}", self.filename),
+            (None, Some(to)) => write!(f, "{}: added ({to})", self.filename),
+            (Some(from), None) => write!(f, "{}: removed ({from})", self.filename),
+            (None, None) => write!(f, "{}", self.filename),
+        }
+    }
+}
+
+impl LockDiff {
+    /// Compute the differences between a base lockfile and an updated lockfile.
+    pub fn between(base: &Lock, updated: &Lock) -> Self {
+        let mut base_packages = packages_by_name(base);
+        let mut updated_packages = packages_by_name(updated);
+
+        let names = base_packages
+            .keys()
+            .chain(updated_packages.keys())
+            .copied()
+            .cloned()
+            .collect::<std::collections::BTreeSet<_>>();
+
+        let mut changes = Vec::new();
+        for name in names {
+            let base_entries = base_packages.remove(&name).unwrap_or_default();
+            let updated_entries = updated_packages.remove(&name).unwrap_or_default();
+            diff_package(
+                &name,
+                base,
+                base_entries,
+                updated,
+                updated_entries,
+                &mut changes,
+            );
+        }
+
+        let exclude_newer = base
+            .exclude_newer()
+            .compare(&updated.exclude_newer())
+            .map(|change| change.to_string());
+
+        Self {
+            exclude_newer,
+            changes,
+        }
+    }
+
+    /// Returns `true` if the lockfiles lock the same packages with the same `exclude-newer`
+    /// setting.
+    pub fn is_empty(&self) -> bool {
+        self.exclude_newer.is_none() && self.changes.is_empty()
+    }
+}
+
+/// Group the packages in a lockfile by name.
+fn packages_by_name(lock: &Lock) -> FxHashMap<&PackageName, Vec<&Package>> {
+    let mut packages = FxHashMap::<_, Vec<_>>::default();
+    for package in lock.packages() {
+        packages.entry(package.name()).or_default().push(package);
+    }
+    packages
+}
+
+/// Compare the entries for a single package name across the two lockfiles.
+fn diff_package<'lock>(
+    name: &PackageName,
+    base: &'lock Lock,
+    mut base_entries: Vec<&'lock Package>,
+    updated: &'lock Lock,
+    mut updated_entries: Vec<&'lock Package>,
+    changes: &mut Vec<PackageChange>,
+) {
+    // First, pair up the entries that are locked at the same version, preferring entries from the
+    // same source.
+    let mut pairs = Vec::new();
+    for exact_source in [true, false] {
+        base_entries.retain(|base_package| {
+            let position = updated_entries.iter().position(|updated_package| {
+                base_package.id.version == updated_package.id.version
+                    && (!exact_source || base_package.id.source == updated_package.id.source)
+            });
+            if let Some(position) = position {
+                pairs.push((*base_package, updated_entries.remove(position)));
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    // Next, pair up the entries that are locked for the same fork.
+    base_entries.retain(|base_package| {
+        let base_marker = fork_marker(base, base_package);
+        let position = updated_entries
+            .iter()
+            .position(|updated_package| fork_marker(updated, updated_package) == base_marker);
+        if let Some(position) = position {
+            pairs.push((*base_package, updated_entries.remove(position)));
+            false
+        } else {
+            true
+        }
+    });
+
+    // Finally, if a single entry remains on either side, treat it as a change in version.
+    if let ([base_package], [updated_package]) =
+        (base_entries.as_slice(), updated_entries.as_slice())
+    {
+        pairs.push((*base_package, *updated_package));
+        base_entries.clear();
+        updated_entries.clear();
+    }
+
+    for base_package in base_entries {
+        changes.push(PackageChange::Removed {
+            name: name.clone(),
+            version: base_package.id.version.clone(),
+            marker: fork_marker(base, base_package),
+        });
+    }
+
+    for updated_package in updated_entries {
+        changes.push(PackageChange::Added {
+            name: name.clone(),
+            version: updated_package.id.version.clone(),
+            marker: fork_marker(updated, updated_package),
+        });
+    }
+
+    pairs.sort_by(|(_, a), (_, b)| a.id.version.cmp(&b.id.version));
+    for (base_package, updated_package) in pairs {
+        let marker = fork_marker(updated, updated_package);
+        match (&base_package.id.version, &updated_package.id.version) {
+            (Some(from), Some(to)) if from < to => {
+                changes.push(PackageChange::Upgraded {
+                    name: name.clone(),
+                    from: from.clone(),
+                    to: to.clone(),
+                    marker,
+                });
+                continue;
+            }
+            (Some(from), Some(to)) if from > to => {
+                changes.push(PackageChange::Downgraded {
+                    name: name.clone(),
+                    from: from.clone(),
+                    to: to.clone(),
+                    marker,
+                });
+                continue;
+            }
+            (from, to) if from != to => {
+                // Dynamic versions can't be ordered, so report them as a removal and addition.
+                changes.push(PackageChange::Removed {
+                    name: name.clone(),
+                    version: from.clone(),
+                    marker: fork_marker(base, base_package),
+                });
+                changes.push(PackageChange::Added {
+                    name: name.clone(),
+                    version: to.clone(),
+                    marker,
+                });
+                continue;
+            }
+            _ => {}
+        }
+
+        let from = source_string(&base_package.id.source);
+        let to = source_string(&updated_package.id.source);
+        if from != to {
+            changes.push(PackageChange::SourceChanged {
+                name: name.clone(),
+                version: updated_package.id.version.clone(),
+                marker,
+                from,
+                to,
+            });
+            continue;
+        }
+
+        let files = diff_hashes(base_package, updated_package);
+        if !files.is_empty() {
+            changes.push(PackageChange::HashesChanged {
+                name: name.clone(),
+                version: updated_package.id.version.clone(),
+                marker,
+                files,
+            });
+        }
+    }
+}
+
+/// Return the (simplified) fork markers under which the package is locked, if any.
+fn fork_marker(lock: &Lock, package: &Package) -> Option<String> {
+    let marker = package
+        .fork_markers()
+        .iter()
+        .fold(MarkerTree::FALSE, |mut acc, marker| {
+            acc.or(marker.pep508());
+            acc
+        });
+    if marker.is_false() {
+        return None;
+    }
+    SimplifiedMarkerTree::new(lock.requires_python(), marker).try_to_string()
+}
+
+/// Render the source of a package, including the commit for Git sources.
+fn source_string(source: &Source) -> String {
+    match source {
+        Source::Git(_, git) => format!("{source}#{}", git.precise),
+        _ => source.to_string(),
+    }
+}
+
+/// Compare the hashes of the distributions of two packages.
+fn diff_hashes(base: &Package, updated: &Package) -> Vec<HashChange> {
+    let base_hashes = distribution_hashes(base);
+    let mut updated_hashes = distribution_hashes(updated);
+
+    let mut changes = Vec::new();
+    for (filename, from) in base_hashes {
+        let to = updated_hashes.remove(&filename).flatten();
+        if let Some(to) = to.as_ref().filter(|to| from.as_ref() != Some(*to)) {
+            changes.push(HashChange {
+                filename,
+                from,
+                to: Some(to.clone()),
+            });
+        } else if to.is_none() && from.is_some() {
+            changes.push(HashChange {
+                filename,
+                from,
+                to: None,
+            });
+        }
+    }
+    for (filename, to) in updated_hashes {
+        if to.is_some() {
+            changes.push(HashChange {
+                filename,
+                from: None,
+                to,
+            });
+        }
+    }
+
+    changes.sort_by(|a, b| a.filename.cmp(&b.filename));
+    changes
+}
+
+/// Return the hashes of the distributions of a package, keyed by filename.
+fn distribution_hashes(package: &Package) -> BTreeMap<String, Option<String>> {
+    let mut hashes = BTreeMap::new();
+    if let Some(sdist) = &package.sdist {
+        if let Some(filename) = sdist.filename() {
+            hashes.insert(filename.into_owned(), sdist.hash().map(ToString::to_string));
+        }
+    }
+    for wheel in &package.wheels {
+        let filename = match &wheel.url {
+            WheelWireSource::Url { url } => url
+                .filename()
+                .map(Cow::into_owned)
+                .unwrap_or_else(|_| wheel.filename.to_string()),
+            WheelWireSource::Path { .. } | WheelWireSource::Filename { .. } => {
+                wheel.filename.to_string()
+            }
+        };
+        hashes.insert(filename, wheel.hash.as_ref().map(ToString::to_string));
+    }
+    hashes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LockDiff;
+    use crate::Lock;
+
+    #[test]
+    fn between() {
+        let base: Lock = toml::from_str(
+            r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+resolution-markers = [
+    "sys_platform == 'win32'",
+    "sys_platform != 'win32'",
+]
+
+[[package]]
+name = "anyio"
+version = "4.3.0"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/anyio-4.3.0.tar.gz", hash = "sha256:1111111111111111111111111111111111111111111111111111111111111111", size = 1 }
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/idna-3.6.tar.gz", hash = "sha256:2222222222222222222222222222222222222222222222222222222222222222", size = 1 }
+
+[[package]]
+name = "numpy"
+version = "1.26.4"
+source = { registry = "https://pypi.org/simple" }
+resolution-markers = [
+    "sys_platform == 'win32'",
+]
+sdist = { url = "https://files.pythonhosted.org/packages/numpy-1.26.4.tar.gz", hash = "sha256:3333333333333333333333333333333333333333333333333333333333333333", size = 1 }
+
+[[package]]
+name = "numpy"
+version = "2.0.0"
+source = { registry = "https://pypi.org/simple" }
+resolution-markers = [
+    "sys_platform != 'win32'",
+]
+sdist = { url = "https://files.pythonhosted.org/packages/numpy-2.0.0.tar.gz", hash = "sha256:4444444444444444444444444444444444444444444444444444444444444444", size = 1 }
+
+[[package]]
+name = "sniffio"
+version = "1.3.1"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/sniffio-1.3.1.tar.gz", hash = "sha256:5555555555555555555555555555555555555555555555555555555555555555", size = 1 }
+"#,
+        )
+        .unwrap();
+
+        let updated: Lock = toml::from_str(
+            r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+resolution-markers = [
+    "sys_platform == 'win32'",
+    "sys_platform != 'win32'",
+]
+
+[[package]]
+name = "anyio"
+version = "4.3.0"
+source = { registry = "https://test.pypi.org/simple" }
+sdist = { url = "https://test-files.pythonhosted.org/packages/anyio-4.3.0.tar.gz", hash = "sha256:1111111111111111111111111111111111111111111111111111111111111111", size = 1 }
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/idna-3.6.tar.gz", hash = "sha256:6666666666666666666666666666666666666666666666666666666666666666", size = 1 }
+
+[[package]]
+name = "iniconfig"
+version = "2.0.0"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/iniconfig-2.0.0.tar.gz", hash = "sha256:7777777777777777777777777777777777777777777777777777777777777777", size = 1 }
+
+[[package]]
+name = "numpy"
+version = "1.26.3"
+source = { registry = "https://pypi.org/simple" }
+resolution-markers = [
+    "sys_platform == 'win32'",
+]
+sdist = { url = "https://files.pythonhosted.org/packages/numpy-1.26.3.tar.gz", hash = "sha256:8888888888888888888888888888888888888888888888888888888888888888", size = 1 }
+
+[[package]]
+name = "numpy"
+version = "2.1.0"
+source = { registry = "https://pypi.org/simple" }
+resolution-markers = [
+    "sys_platform != 'win32'",
+]
+sdist = { url = "https://files.pythonhosted.org/packages/numpy-2.1.0.tar.gz", hash = "sha256:9999999999999999999999999999999999999999999999999999999999999999", size = 1 }
+"#,
+        )
+        .unwrap();
+
+        let diff = LockDiff::between(&base, &updated);
+        insta::assert_json_snapshot!(diff, @r#"
+        {
+          "changes": [
+            {
+              "kind": "source-changed",
+              "name": "anyio",
+              "version": "4.3.0",
+              "from": "registry+https://pypi.org/simple",
+              "to": "registry+https://test.pypi.org/simple"
+            },
+            {
+              "kind": "hashes-changed",
+              "name": "idna",
+              "version": "3.6",
+              "files": [
+                {
+                  "filename": "idna-3.6.tar.gz",
+                  "from": "sha256:2222222222222222222222222222222222222222222222222222222222222222",
+                  "to": "sha256:6666666666666666666666666666666666666666666666666666666666666666"
+                }
+              ]
+            },
+            {
+              "kind": "added",
+              "name": "iniconfig",
+              "version": "2.0.0"
+            },
+            {
+              "kind": "downgraded",
+              "name": "numpy",
+              "from": "1.26.4",
+              "to": "1.26.3",
+              "marker": "sys_platform == 'win32'"
+            },
+            {
+              "kind": "upgraded",
+              "name": "numpy",
+              "from": "2.0.0",
+              "to": "2.1.0",
+              "marker": "sys_platform != 'win32'"
+            },
+            {
+              "kind": "removed",
+              "name": "sniffio",
+              "version": "1.3.1"
+            }
+          ]
+        }
+        "#);
+    }
+}
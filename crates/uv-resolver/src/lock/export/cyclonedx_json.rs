@@ -1,60 +1,83 @@
 use std::collections::HashMap;
+use std::io::Write;
 use std::path::Path;
 
-use cyclonedx_bom::models::component::Classification;
+use cyclonedx_bom::errors::JsonWriteError;
+use cyclonedx_bom::external_models::uri::Uri;
+use cyclonedx_bom::models::component::{
+    Classification, ComponentEvidence, ConfidenceScore, Identity, IdentityField, Method, Methods,
+};
 use cyclonedx_bom::models::dependency::{Dependencies, Dependency};
+use cyclonedx_bom::models::external_reference::{
+    ExternalReference, ExternalReferenceType, ExternalReferences,
+};
+use cyclonedx_bom::models::hash::{Hash, HashAlgorithm, HashValue, Hashes};
+use cyclonedx_bom::models::license::{LicenseChoice, Licenses};
 use cyclonedx_bom::models::metadata::Metadata;
 use cyclonedx_bom::models::property::{Properties, Property};
 use cyclonedx_bom::models::tool::{Tool, Tools};
 use cyclonedx_bom::prelude::{Bom, Component, Components, NormalizedString};
+use indexmap::IndexMap;
 use itertools::Itertools;
-use percent_encoding::{AsciiSet, CONTROLS, percent_encode};
 use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
 
 use uv_configuration::{
     DependencyGroupsWithDefaults, ExtrasSpecificationWithDefaults, InstallOptions,
 };
+use uv_distribution_types::RemoteSource;
 use uv_fs::PortablePath;
 use uv_normalize::PackageName;
 use uv_pep508::MarkerTree;
 use uv_preview::{Preview, PreviewFeatures};
+use uv_pypi_types::HashDigest;
 use uv_warnings::warn_user;
 
+use crate::lock::export::purl::{artifact_url, package_url};
 use crate::lock::export::{ExportableRequirement, ExportableRequirements};
-use crate::lock::{LockErrorKind, Package, PackageId, RegistrySource, Source};
+use crate::lock::license::PackageLicenses;
+use crate::lock::{LockErrorKind, Package, PackageId, RegistrySource, Source, WheelWireSource};
 use crate::{Installable, LockError};
 
-/// Character set for percent-encoding PURL components, copied from packageurl.rs (<https://github.com/scm-rs/packageurl.rs/blob/a725aa0ab332934c350641508017eb09ddfa0813/src/purl.rs#L18>).
-const PURL_ENCODE_SET: &AsciiSet = &CONTROLS
-    .add(b' ')
-    .add(b'"')
-    .add(b'#')
-    .add(b'%')
-    .add(b'<')
-    .add(b'>')
-    .add(b'`')
-    .add(b'?')
-    .add(b'{')
-    .add(b'}')
-    .add(b';')
-    .add(b'=')
-    .add(b'+')
-    .add(b'@')
-    .add(b'\\')
-    .add(b'[')
-    .add(b']')
-    .add(b'^')
-    .add(b'|');
+/// The version of the `CycloneDX` specification to export.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CycloneDxVersion {
+    /// `CycloneDX` v1.5.
+    V1_5,
+    /// `CycloneDX` v1.6, which additionally records the declared licenses of each package and
+    /// its locked distributions as nested components.
+    V1_6,
+}
+
+impl CycloneDxVersion {
+    /// The name of the corresponding `--format` value.
+    fn format_name(self) -> &'static str {
+        match self {
+            Self::V1_5 => "cyclonedx1.5",
+            Self::V1_6 => "cyclonedx1.6",
+        }
+    }
+}
 
 /// Creates `CycloneDX` components, registering them in a `HashMap` so that they can be retrieved by `PackageId`.
 /// Also ensures uniqueness when generating bom-refs by using a numeric prefix which is incremented for each component.
-#[derive(Default)]
 struct ComponentBuilder<'a> {
     id_counter: usize, // Used as prefix in bom-ref generation, to ensure uniqueness
     package_to_component_map: HashMap<&'a PackageId, Component>,
+    version: CycloneDxVersion,
+    licenses: &'a PackageLicenses,
 }
 
 impl<'a> ComponentBuilder<'a> {
+    fn new(version: CycloneDxVersion, licenses: &'a PackageLicenses) -> Self {
+        Self {
+            id_counter: 0,
+            package_to_component_map: HashMap::default(),
+            version,
+            licenses,
+        }
+    }
+
     /// Creates a bom-ref string in the format "{package_name}-{id}@{version}" or "{package_name}-{id}" if no version is provided.
     fn create_bom_ref(&mut self, name: &str, version: Option<&str>) -> String {
         self.id_counter += 1;
@@ -80,64 +103,6 @@ impl<'a> ComponentBuilder<'a> {
         package.id.name.as_str()
     }
 
-    /// Generate a Package URL (purl) from a package. Returns `None` for local sources.
-    fn create_purl(package: &Package) -> Option<String> {
-        let name = percent_encode(Self::get_package_name(package).as_bytes(), PURL_ENCODE_SET);
-
-        let version = Self::get_version_string(package)
-            .map(|v| format!("@{}", percent_encode(v.as_bytes(), PURL_ENCODE_SET)))
-            .unwrap_or_default();
-
-        let (purl_type, qualifiers) = match &package.id.source {
-            // By convention all Python packages use the "pypi" purl type, regardless of their source. For packages
-            // from non-default repositories, we add a qualifier to indicate their source explicitly.
-            // See the specs at
-            // https://github.com/package-url/purl-spec/blob/9041aa7/types/pypi-definition.json
-            // and https://github.com/package-url/purl-spec/blob/9041aa7/purl-specification.md
-            Source::Registry(registry_source) => {
-                let qualifiers = match registry_source {
-                    RegistrySource::Url(url) => {
-                        // Only add repository_url qualifier for non-default registries
-                        if !url.as_ref().starts_with("https://pypi.org/") {
-                            vec![("repository_url", url.as_ref())]
-                        } else {
-                            vec![]
-                        }
-                    }
-                    RegistrySource::Path(_) => vec![],
-                };
-                ("pypi", qualifiers)
-            }
-            Source::Git(url, _) => ("pypi", vec![("vcs_url", url.as_ref())]),
-            Source::Direct(url, _) => ("pypi", vec![("download_url", url.as_ref())]),
-            // No purl for local sources
-            Source::Path(_) | Source::Directory(_) | Source::Editable(_) | Source::Virtual(_) => {
-                return None;
-            }
-        };
-
-        let qualifiers = if qualifiers.is_empty() {
-            String::new()
-        } else {
-            Self::format_qualifiers(&qualifiers)
-        };
-
-        Some(format!("pkg:{purl_type}/{name}{version}{qualifiers}"))
-    }
-
-    fn format_qualifiers(qualifiers: &[(&str, &str)]) -> String {
-        let joined_qualifiers = qualifiers
-            .iter()
-            .map(|(key, value)| {
-                format!(
-                    "{key}={}",
-                    percent_encode(value.as_bytes(), PURL_ENCODE_SET)
-                )
-            })
-            .join("&");
-        format!("?{joined_qualifiers}")
-    }
-
     fn create_component(
         &mut self,
         package: &'a Package,
@@ -194,7 +159,7 @@ impl<'a> ComponentBuilder<'a> {
         let name = Self::get_package_name(package);
         let version = Self::get_version_string(package);
         let bom_ref = self.create_bom_ref(name, version.as_deref());
-        let purl = Self::create_purl(package).and_then(|purl_string| purl_string.parse().ok());
+        let purl = package_url(package).and_then(|purl_string| purl_string.parse().ok());
         let mut properties = vec![];
 
         match package_type {
@@ -214,6 +179,16 @@ impl<'a> ComponentBuilder<'a> {
             ));
         }
 
+        let (licenses, artifacts, evidence) = match self.version {
+            CycloneDxVersion::V1_5 => (None, None, None),
+            CycloneDxVersion::V1_6 => (
+                self.create_licenses(package),
+                self.create_artifact_components(package),
+                purl.as_ref()
+                    .map(|_| lockfile_evidence(IdentityField::Purl)),
+            ),
+        };
+
         Component {
             component_type: Classification::Library,
             name: NormalizedString::new(name),
@@ -228,7 +203,7 @@ impl<'a> ComponentBuilder<'a> {
             description: None,
             scope: None,
             hashes: None,
-            licenses: None,
+            licenses,
             copyright: None,
             cpe: None,
             swid: None,
@@ -240,8 +215,142 @@ impl<'a> ComponentBuilder<'a> {
             } else {
                 None
             },
+            components: artifacts,
+            evidence,
+            signature: None,
+            model_card: None,
+            data: None,
+        }
+    }
+
+    /// Create the licenses declared in the metadata of the package, if known.
+    ///
+    /// Uses an SPDX expression where one can be determined, and otherwise falls back to the
+    /// free-form `License` field, provided it's a short name rather than the full license text.
+    fn create_licenses(&self, package: &Package) -> Option<Licenses> {
+        let license = self
+            .licenses
+            .get(&package.id.name, package.id.version.as_ref()?)?;
+        let choice = if let Some(expression) = license.spdx_expression() {
+            LicenseChoice::expression(&expression)
+        } else {
+            let text = license.text.as_deref()?;
+            if text.contains('\n') || text.len() > 100 {
+                return None;
+            }
+            LicenseChoice::license(text)
+        };
+        Some(Licenses(vec![choice]))
+    }
+
+    /// Create a `file` component for each locked distribution of the package, i.e., its source
+    /// distribution and wheels, along with their hashes.
+    fn create_artifact_components(&mut self, package: &Package) -> Option<Components> {
+        let index = match &package.id.source {
+            Source::Registry(RegistrySource::Url(url)) => Some(url.to_string()),
+            _ => None,
+        };
+
+        let mut components = Vec::new();
+        if let Some(sdist) = &package.sdist {
+            if let Some(filename) = sdist.filename() {
+                let url = sdist.url().map(ToString::to_string);
+                components.push(self.create_artifact_component(
+                    package,
+                    &filename,
+                    "sdist",
+                    url,
+                    sdist.hash().map(|hash| &hash.0),
+                ));
+            }
+        }
+        for wheel in &package.wheels {
+            // Prefer the filename from the URL, since the parsed filename is normalized.
+            let (filename, url) = match &wheel.url {
+                WheelWireSource::Url { url } => (
+                    url.filename().map_or_else(
+                        |_| wheel.filename.to_string(),
+                        |filename| filename.to_string(),
+                    ),
+                    Some(url.to_string()),
+                ),
+                WheelWireSource::Path { .. } | WheelWireSource::Filename { .. } => {
+                    (wheel.filename.to_string(), None)
+                }
+            };
+            components.push(self.create_artifact_component(
+                package,
+                &filename,
+                "wheel",
+                url.or_else(|| index.clone()),
+                wheel.hash.as_ref().map(|hash| &hash.0),
+            ));
+        }
+
+        if components.is_empty() {
+            None
+        } else {
+            Some(Components(components))
+        }
+    }
+
+    fn create_artifact_component(
+        &mut self,
+        package: &Package,
+        filename: &str,
+        kind: &str,
+        url: Option<String>,
+        hash: Option<&HashDigest>,
+    ) -> Component {
+        let bom_ref = self.create_bom_ref(filename, None);
+        let purl = artifact_url(package, filename).and_then(|purl_string| purl_string.parse().ok());
+        let hashes = hash.map(|hash| {
+            Hashes(vec![Hash {
+                alg: match hash.algorithm {
+                    uv_pypi_types::HashAlgorithm::Md5 => HashAlgorithm::MD5,
+                    uv_pypi_types::HashAlgorithm::Sha256 => HashAlgorithm::SHA_256,
+                    uv_pypi_types::HashAlgorithm::Sha384 => HashAlgorithm::SHA_384,
+                    uv_pypi_types::HashAlgorithm::Sha512 => HashAlgorithm::SHA_512,
+                    uv_pypi_types::HashAlgorithm::Blake2b => HashAlgorithm::BLAKE2b_512,
+                },
+                content: HashValue(hash.digest.to_string()),
+            }])
+        });
+        let evidence = hashes
+            .as_ref()
+            .map(|_| lockfile_evidence(IdentityField::Hash));
+
+        Component {
+            component_type: Classification::File,
+            name: NormalizedString::new(filename),
+            version: Self::get_version_string(package)
+                .as_deref()
+                .map(NormalizedString::new),
+            bom_ref: Some(bom_ref),
+            purl,
+            mime_type: None,
+            supplier: None,
+            author: None,
+            publisher: None,
+            group: None,
+            description: None,
+            scope: None,
+            hashes,
+            licenses: None,
+            copyright: None,
+            cpe: None,
+            swid: None,
+            modified: None,
+            pedigree: None,
+            external_references: url.map(|url| {
+                ExternalReferences(vec![ExternalReference::new(
+                    ExternalReferenceType::Distribution,
+                    Uri::new(&url),
+                )])
+            }),
+            properties: Some(Properties(vec![Property::new("uv:artifact:type", kind)])),
             components: None,
-            evidence: None,
+            evidence,
             signature: None,
             model_card: None,
             data: None,
@@ -260,12 +369,15 @@ pub fn from_lock<'lock>(
     groups: &DependencyGroupsWithDefaults,
     annotate: bool,
     install_options: &'lock InstallOptions,
+    licenses: &PackageLicenses,
+    version: CycloneDxVersion,
     preview: Preview,
     all_packages: bool,
 ) -> Result<Bom, LockError> {
     if !preview.is_enabled(PreviewFeatures::SBOM_EXPORT) {
         warn_user!(
-            "`uv export --format=cyclonedx1.5` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            "`uv export --format={}` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            version.format_name(),
             PreviewFeatures::SBOM_EXPORT
         );
     }
@@ -294,7 +406,7 @@ pub fn from_lock<'lock>(
     }
     .or_else(|| target.lock().root()); // Fallback to project root
 
-    let mut component_builder = ComponentBuilder::default();
+    let mut component_builder = ComponentBuilder::new(version, licenses);
 
     let mut metadata = Metadata {
         component: root
@@ -432,6 +544,100 @@ fn create_dependencies(
         .collect()
 }
 
+/// Record that a component was identified from the lockfile.
+fn lockfile_evidence(field: IdentityField) -> ComponentEvidence {
+    ComponentEvidence {
+        licenses: None,
+        copyright: None,
+        occurrences: None,
+        callstack: None,
+        identity: Some(Identity {
+            field,
+            confidence: Some(ConfidenceScore::new(1.0)),
+            methods: Some(Methods(vec![Method {
+                technique: "manifest-analysis".to_string(),
+                confidence: ConfidenceScore::new(1.0),
+                value: Some("uv.lock".to_string()),
+            }])),
+            tools: None,
+        }),
+    }
+}
+
+/// Write the BOM as `CycloneDX` v1.6 JSON.
+///
+/// `cyclonedx-bom` only supports up to v1.5, which v1.6 is backwards-compatible with, so the BOM
+/// is serialized as v1.5 and then upgraded: licenses are marked as declared (rather than
+/// concluded), and component identity evidence is converted to the array form.
+pub fn output_as_json_v1_6<W: Write>(bom: Bom, writer: &mut W) -> Result<(), JsonWriteError> {
+    let mut buffer = Vec::new();
+    bom.output_as_json_v1_5(&mut buffer)?;
+
+    let mut document: JsonValue = serde_json::from_slice(&buffer)?;
+    if let JsonValue::Object(document) = &mut document {
+        if let Some(spec_version) = document.get_mut("specVersion") {
+            *spec_version = JsonValue::Scalar(serde_json::Value::from("1.6"));
+        }
+        if let Some(JsonValue::Object(metadata)) = document.get_mut("metadata") {
+            if let Some(component) = metadata.get_mut("component") {
+                upgrade_component(component);
+            }
+        }
+        if let Some(JsonValue::Array(components)) = document.get_mut("components") {
+            components.iter_mut().for_each(upgrade_component);
+        }
+    }
+
+    serde_json::to_writer_pretty(writer, &document)?;
+    Ok(())
+}
+
+/// Upgrade a v1.5 component (and its nested components) to v1.6.
+fn upgrade_component(component: &mut JsonValue) {
+    let JsonValue::Object(component) = component else {
+        return;
+    };
+
+    if let Some(JsonValue::Array(licenses)) = component.get_mut("licenses") {
+        for choice in licenses {
+            let JsonValue::Object(choice) = choice else {
+                continue;
+            };
+            let acknowledgement = (
+                "acknowledgement".to_string(),
+                JsonValue::Scalar(serde_json::Value::from("declared")),
+            );
+            if let Some(JsonValue::Object(license)) = choice.get_mut("license") {
+                license.extend([acknowledgement]);
+            } else {
+                choice.extend([acknowledgement]);
+            }
+        }
+    }
+
+    if let Some(JsonValue::Object(evidence)) = component.get_mut("evidence") {
+        if let Some(identity) = evidence.get_mut("identity") {
+            if !matches!(identity, JsonValue::Array(_)) {
+                let object = std::mem::replace(identity, JsonValue::Array(Vec::new()));
+                *identity = JsonValue::Array(vec![object]);
+            }
+        }
+    }
+
+    if let Some(JsonValue::Array(components)) = component.get_mut("components") {
+        components.iter_mut().for_each(upgrade_component);
+    }
+}
+
+/// A JSON value that, unlike [`serde_json::Value`], preserves the order of object keys.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum JsonValue {
+    Object(IndexMap<String, JsonValue>),
+    Array(Vec<JsonValue>),
+    Scalar(serde_json::Value),
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 enum PackageType<'a> {
     Root,
@@ -24,8 +24,41 @@ use crate::universal_marker::resolve_conflicts;
 use crate::{Installable, LockError, Package};
 
 pub mod cyclonedx_json;
+mod purl;
 mod pylock_toml;
 mod requirements_txt;
+pub mod spdx_json;
+
+/// A package in the lockfile, along with the marker under which it's installed.
+#[derive(Debug, Clone)]
+pub struct ReachablePackage<'lock> {
+    /// The [`Package`] from the lockfile.
+    pub package: &'lock Package,
+    /// The marker that must be satisfied to install the package.
+    pub marker: MarkerTree,
+}
+
+/// Determine the set of packages that are installed for the given target, along with the markers
+/// under which they're installed.
+///
+/// Unlike [`Installable::to_resolution`], this operates on the universal lockfile, rather than a
+/// specific marker environment.
+pub fn reachable_packages<'lock>(
+    target: &impl Installable<'lock>,
+    extras: &ExtrasSpecificationWithDefaults,
+    groups: &DependencyGroupsWithDefaults,
+    install_options: &'lock InstallOptions,
+) -> Result<Vec<ReachablePackage<'lock>>, LockError> {
+    let ExportableRequirements(nodes) =
+        ExportableRequirements::from_lock(target, &[], extras, groups, false, install_options)?;
+    Ok(nodes
+        .into_iter()
+        .map(|node| ReachablePackage {
+            package: node.package,
+            marker: node.marker,
+        })
+        .collect())
+}
 
 /// A flat requirement, with its associated marker.
 #[derive(Debug, Clone, PartialEq, Eq)]
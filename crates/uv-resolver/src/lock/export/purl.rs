@@ -0,0 +1,110 @@
+//! Package URL (purl) generation for locked packages, shared by the SBOM exporters.
+
+use itertools::Itertools;
+use percent_encoding::{AsciiSet, CONTROLS, percent_encode};
+
+use crate::lock::{Package, RegistrySource, Source};
+
+/// Character set for percent-encoding PURL components, copied from packageurl.rs (<https://github.com/scm-rs/packageurl.rs/blob/a725aa0ab332934c350641508017eb09ddfa0813/src/purl.rs#L18>).
+const PURL_ENCODE_SET: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'<')
+    .add(b'>')
+    .add(b'`')
+    .add(b'?')
+    .add(b'{')
+    .add(b'}')
+    .add(b';')
+    .add(b'=')
+    .add(b'+')
+    .add(b'@')
+    .add(b'\\')
+    .add(b'[')
+    .add(b']')
+    .add(b'^')
+    .add(b'|');
+
+/// Generate a Package URL (purl) from a package. Returns `None` for local sources.
+pub(crate) fn package_url(package: &Package) -> Option<String> {
+    format_package_url(package, None)
+}
+
+/// Generate a Package URL (purl) for a distribution of a package, identified by its filename via
+/// the `file_name` qualifier. Returns `None` for local sources.
+pub(crate) fn artifact_url(package: &Package, filename: &str) -> Option<String> {
+    format_package_url(package, Some(filename))
+}
+
+fn format_package_url(package: &Package, filename: Option<&str>) -> Option<String> {
+    let name = percent_encode(package.id.name.as_str().as_bytes(), PURL_ENCODE_SET);
+
+    let version = package
+        .id
+        .version
+        .as_ref()
+        .map(|v| {
+            format!(
+                "@{}",
+                percent_encode(v.to_string().as_bytes(), PURL_ENCODE_SET)
+            )
+        })
+        .unwrap_or_default();
+
+    let (purl_type, mut qualifiers) = match &package.id.source {
+        // By convention all Python packages use the "pypi" purl type, regardless of their source. For packages
+        // from non-default repositories, we add a qualifier to indicate their source explicitly.
+        // See the specs at
+        // https://github.com/package-url/purl-spec/blob/9041aa7/types/pypi-definition.json
+        // and https://github.com/package-url/purl-spec/blob/9041aa7/purl-specification.md
+        Source::Registry(registry_source) => {
+            let qualifiers = match registry_source {
+                RegistrySource::Url(url) => {
+                    // Only add repository_url qualifier for non-default registries
+                    if !url.as_ref().starts_with("https://pypi.org/") {
+                        vec![("repository_url", url.as_ref())]
+                    } else {
+                        vec![]
+                    }
+                }
+                RegistrySource::Path(_) => vec![],
+            };
+            ("pypi", qualifiers)
+        }
+        Source::Git(url, _) => ("pypi", vec![("vcs_url", url.as_ref())]),
+        Source::Direct(url, _) => ("pypi", vec![("download_url", url.as_ref())]),
+        // No purl for local sources
+        Source::Path(_) | Source::Directory(_) | Source::Editable(_) | Source::Virtual(_) => {
+            return None;
+        }
+    };
+
+    if let Some(filename) = filename {
+        qualifiers.push(("file_name", filename));
+        // Qualifier keys must be sorted lexicographically.
+        qualifiers.sort_unstable_by_key(|(key, _)| *key);
+    }
+
+    let qualifiers = if qualifiers.is_empty() {
+        String::new()
+    } else {
+        format_qualifiers(&qualifiers)
+    };
+
+    Some(format!("pkg:{purl_type}/{name}{version}{qualifiers}"))
+}
+
+fn format_qualifiers(qualifiers: &[(&str, &str)]) -> String {
+    let joined_qualifiers = qualifiers
+        .iter()
+        .map(|(key, value)| {
+            format!(
+                "{key}={}",
+                percent_encode(value.as_bytes(), PURL_ENCODE_SET)
+            )
+        })
+        .join("&");
+    format!("?{joined_qualifiers}")
+}
@@ -0,0 +1,752 @@
+//! Export a lockfile as a software bill of materials in the [SPDX](https://spdx.dev/) format.
+//!
+//! Both the SPDX 2.3 JSON serialization and the SPDX 3.0 JSON-LD serialization are supported.
+
+use std::collections::BTreeSet;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use uv_configuration::{
+    DependencyGroupsWithDefaults, ExtrasSpecificationWithDefaults, InstallOptions,
+};
+use uv_normalize::PackageName;
+use uv_pep508::MarkerTree;
+use uv_preview::{Preview, PreviewFeatures};
+use uv_pypi_types::HashDigest;
+use uv_warnings::warn_user;
+
+use crate::lock::export::ExportableRequirements;
+use crate::lock::export::purl::package_url;
+use crate::lock::license::PackageLicenses;
+use crate::lock::{Package, PackageId, RegistrySource, Source, WheelWireSource};
+use crate::{Installable, LockError};
+
+/// The version of the SPDX specification to target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpdxVersion {
+    /// SPDX 2.3, serialized as JSON.
+    V2_3,
+    /// SPDX 3.0, serialized as JSON-LD.
+    V3_0,
+}
+
+impl SpdxVersion {
+    /// The name of the corresponding `uv export --format` value.
+    fn format_name(self) -> &'static str {
+        match self {
+            Self::V2_3 => "spdx2.3",
+            Self::V3_0 => "spdx3.0",
+        }
+    }
+}
+
+/// An SPDX document describing the packages in a lockfile.
+#[derive(Debug)]
+pub enum SpdxDocument {
+    V2_3(v2_3::Document),
+    V3_0(v3_0::Document),
+}
+
+impl SpdxDocument {
+    /// Serialize the document as (pretty-printed) JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        match self {
+            Self::V2_3(document) => serde_json::to_string_pretty(document),
+            Self::V3_0(document) => serde_json::to_string_pretty(document),
+        }
+    }
+}
+
+/// A package to include in the document, along with its SPDX identifier.
+#[derive(Debug)]
+struct Entry<'lock> {
+    package: &'lock Package,
+    marker: MarkerTree,
+    id: String,
+}
+
+/// A relationship between two packages in the document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum RelationshipKind {
+    /// The package depends on the related package at runtime.
+    DependsOn,
+    /// The related package is a development dependency (i.e., a member of a dependency group) of
+    /// the package.
+    DevDependency,
+}
+
+pub fn from_lock<'lock>(
+    target: &impl Installable<'lock>,
+    prune: &[PackageName],
+    extras: &ExtrasSpecificationWithDefaults,
+    groups: &DependencyGroupsWithDefaults,
+    install_options: &'lock InstallOptions,
+    licenses: &PackageLicenses,
+    version: SpdxVersion,
+    preview: Preview,
+    all_packages: bool,
+) -> Result<SpdxDocument, LockError> {
+    if !preview.is_enabled(PreviewFeatures::SBOM_EXPORT) {
+        warn_user!(
+            "`uv export --format={}` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            version.format_name(),
+            PreviewFeatures::SBOM_EXPORT
+        );
+    }
+
+    // Extract the packages from the lock file.
+    let ExportableRequirements(mut nodes) =
+        ExportableRequirements::from_lock(target, prune, extras, groups, false, install_options)?;
+
+    nodes.sort_unstable_by_key(|node| &node.package.id);
+
+    // Assign a unique identifier to each package.
+    let mut seen = FxHashSet::default();
+    let entries = nodes
+        .iter()
+        .map(|node| {
+            let mut id = spdx_id(&node.package.id);
+            let mut suffix = 1;
+            while !seen.insert(id.clone()) {
+                suffix += 1;
+                id = format!("{}-{suffix}", spdx_id(&node.package.id));
+            }
+            Entry {
+                package: node.package,
+                marker: node.marker,
+                id,
+            }
+        })
+        .collect::<Vec<_>>();
+    let ids = entries
+        .iter()
+        .map(|entry| (&entry.package.id, entry.id.as_str()))
+        .collect::<FxHashMap<_, _>>();
+
+    // Determine the packages described by the document. With `--all-packages`, or if there's no
+    // single root, the document describes every workspace member that's included in the export.
+    let root = match target.roots().collect::<Vec<_>>().as_slice() {
+        [single_root] => nodes
+            .iter()
+            .find(|node| &node.package.id.name == *single_root)
+            .map(|node| node.package),
+        _ => None,
+    }
+    .or_else(|| target.lock().root());
+    let described = if let Some(root) = root.filter(|_| !all_packages) {
+        ids.get(&root.id).copied().into_iter().collect::<Vec<_>>()
+    } else {
+        entries
+            .iter()
+            .filter(|entry| {
+                target.lock().members().contains(&entry.package.id.name)
+                    || root.is_some_and(|root| root.id == entry.package.id)
+            })
+            .map(|entry| entry.id.as_str())
+            .collect()
+    };
+
+    // Collect the relationships between the exported packages.
+    let mut relationships = BTreeSet::default();
+    for entry in &entries {
+        let package = entry.package;
+        let runtime = package
+            .dependencies
+            .iter()
+            .chain(package.optional_dependencies.values().flatten())
+            .filter_map(|dep| ids.get(&dep.package_id).copied())
+            .collect::<BTreeSet<_>>();
+        let development = package
+            .dependency_groups
+            .values()
+            .flatten()
+            .filter_map(|dep| ids.get(&dep.package_id).copied())
+            .filter(|id| !runtime.contains(id))
+            .collect::<BTreeSet<_>>();
+        for id in runtime {
+            relationships.insert((entry.id.as_str(), RelationshipKind::DependsOn, id));
+        }
+        for id in development {
+            relationships.insert((entry.id.as_str(), RelationshipKind::DevDependency, id));
+        }
+    }
+
+    let name = root.map_or("uv-workspace", |root| root.id.name.as_str());
+    let namespace = format!("https://spdx.org/spdxdocs/{name}-{}", uuid::Uuid::new_v4());
+    let created = jiff::Timestamp::now()
+        .strftime("%Y-%m-%dT%H:%M:%SZ")
+        .to_string();
+
+    match version {
+        SpdxVersion::V2_3 => Ok(SpdxDocument::V2_3(v2_3::Document::new(
+            name,
+            namespace,
+            created,
+            &entries,
+            &described,
+            &relationships,
+            licenses,
+        ))),
+        SpdxVersion::V3_0 => Ok(SpdxDocument::V3_0(v3_0::Document::new(
+            name,
+            &namespace,
+            created,
+            &entries,
+            &described,
+            &relationships,
+            licenses,
+        ))),
+    }
+}
+
+/// Generate an SPDX identifier for a package.
+///
+/// SPDX identifiers may only contain letters, numbers, `.`, and `-`.
+fn spdx_id(id: &PackageId) -> String {
+    let mut spdx_id = format!("SPDXRef-Package-{}", id.name);
+    if let Some(version) = &id.version {
+        spdx_id.push('-');
+        spdx_id.push_str(&version.to_string());
+    }
+    spdx_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '.' || c == '-' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+/// Determine the location from which a package can be downloaded, along with the hashes of the
+/// corresponding artifact.
+///
+/// Source distributions are preferred. If a package has no source distribution and multiple
+/// wheels, the package is attributed to its index, without any hashes.
+fn download_location(package: &Package) -> (Option<String>, Option<&HashDigest>) {
+    let index = match &package.id.source {
+        Source::Registry(RegistrySource::Url(url)) => Some(url.to_string()),
+        Source::Registry(RegistrySource::Path(_)) => None,
+        Source::Direct(url, _) => Some(url.to_string()),
+        Source::Git(url, git) => return (Some(format!("git+{url}@{}", git.precise)), None),
+        Source::Path(_) | Source::Directory(_) | Source::Editable(_) | Source::Virtual(_) => {
+            return (None, None);
+        }
+    };
+
+    if let Some(sdist) = &package.sdist {
+        let url = sdist.url().map(ToString::to_string).or(index);
+        return (url, sdist.hash().map(|hash| &hash.0));
+    }
+
+    if let [wheel] = package.wheels.as_slice() {
+        let url = match &wheel.url {
+            WheelWireSource::Url { url } => Some(url.to_string()),
+            WheelWireSource::Path { .. } | WheelWireSource::Filename { .. } => index,
+        };
+        return (url, wheel.hash.as_ref().map(|hash| &hash.0));
+    }
+
+    (index, None)
+}
+
+/// Determine the declared license of a package, as an SPDX license expression.
+///
+/// Expressions that aren't valid SPDX (e.g., a free-form `License-Expression`) are omitted, such
+/// that they're reported as `NOASSERTION`.
+fn declared_license(package: &Package, licenses: &PackageLicenses) -> Option<String> {
+    let version = package.id.version.as_ref()?;
+    let expression = licenses.get(&package.id.name, version)?.spdx_expression()?;
+    spdx::Expression::parse(&expression).ok()?;
+    Some(expression.into_owned())
+}
+
+/// The SPDX 2.3 JSON serialization.
+///
+/// See: <https://spdx.github.io/spdx-spec/v2.3/>
+pub mod v2_3 {
+    use std::collections::BTreeSet;
+
+    use serde::Serialize;
+
+    use uv_pypi_types::HashAlgorithm;
+
+    use super::{Entry, RelationshipKind, declared_license, download_location, package_url};
+    use crate::lock::license::PackageLicenses;
+
+    const NOASSERTION: &str = "NOASSERTION";
+
+    #[derive(Debug, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    #[allow(clippy::struct_field_names)]
+    pub struct Document {
+        spdx_version: &'static str,
+        data_license: &'static str,
+        #[serde(rename = "SPDXID")]
+        spdx_id: &'static str,
+        name: String,
+        document_namespace: String,
+        creation_info: CreationInfo,
+        packages: Vec<Package>,
+        relationships: Vec<Relationship>,
+    }
+
+    #[derive(Debug, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct CreationInfo {
+        created: String,
+        creators: Vec<String>,
+    }
+
+    #[derive(Debug, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Package {
+        name: String,
+        #[serde(rename = "SPDXID")]
+        spdx_id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        version_info: Option<String>,
+        download_location: String,
+        files_analyzed: bool,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        checksums: Vec<Checksum>,
+        license_concluded: &'static str,
+        license_declared: String,
+        copyright_text: &'static str,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        external_refs: Vec<ExternalRef>,
+        primary_package_purpose: &'static str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        comment: Option<String>,
+    }
+
+    #[derive(Debug, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Checksum {
+        algorithm: &'static str,
+        checksum_value: String,
+    }
+
+    #[derive(Debug, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    #[allow(clippy::struct_field_names)]
+    struct ExternalRef {
+        reference_category: &'static str,
+        reference_type: &'static str,
+        reference_locator: String,
+    }
+
+    #[derive(Debug, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    #[allow(clippy::struct_field_names)]
+    struct Relationship {
+        spdx_element_id: String,
+        relationship_type: &'static str,
+        related_spdx_element: String,
+    }
+
+    impl Document {
+        pub(super) fn new(
+            name: &str,
+            namespace: String,
+            created: String,
+            entries: &[Entry<'_>],
+            described: &[&str],
+            relationships: &BTreeSet<(&str, RelationshipKind, &str)>,
+            licenses: &PackageLicenses,
+        ) -> Self {
+            let packages = entries
+                .iter()
+                .map(|entry| {
+                    let package = entry.package;
+                    let (download_location, hash) = download_location(package);
+                    let license_declared = declared_license(package, licenses)
+                        .unwrap_or_else(|| NOASSERTION.to_string());
+                    Package {
+                        name: package.id.name.to_string(),
+                        spdx_id: entry.id.clone(),
+                        version_info: package.id.version.as_ref().map(ToString::to_string),
+                        download_location: download_location
+                            .unwrap_or_else(|| NOASSERTION.to_string()),
+                        files_analyzed: false,
+                        checksums: hash
+                            .map(|hash| Checksum {
+                                algorithm: match hash.algorithm {
+                                    HashAlgorithm::Md5 => "MD5",
+                                    HashAlgorithm::Sha256 => "SHA256",
+                                    HashAlgorithm::Sha384 => "SHA384",
+                                    HashAlgorithm::Sha512 => "SHA512",
+                                    HashAlgorithm::Blake2b => "BLAKE2b-512",
+                                },
+                                checksum_value: hash.digest.to_string(),
+                            })
+                            .into_iter()
+                            .collect(),
+                        license_concluded: NOASSERTION,
+                        license_declared,
+                        copyright_text: NOASSERTION,
+                        external_refs: package_url(package)
+                            .map(|purl| ExternalRef {
+                                reference_category: "PACKAGE-MANAGER",
+                                reference_type: "purl",
+                                reference_locator: purl,
+                            })
+                            .into_iter()
+                            .collect(),
+                        primary_package_purpose: "LIBRARY",
+                        comment: entry
+                            .marker
+                            .contents()
+                            .map(|marker| format!("Installed when: {marker}")),
+                    }
+                })
+                .collect();
+
+            let relationships = described
+                .iter()
+                .map(|id| Relationship {
+                    spdx_element_id: "SPDXRef-DOCUMENT".to_string(),
+                    relationship_type: "DESCRIBES",
+                    related_spdx_element: (*id).to_string(),
+                })
+                .chain(relationships.iter().map(|(from, kind, to)| match kind {
+                    RelationshipKind::DependsOn => Relationship {
+                        spdx_element_id: (*from).to_string(),
+                        relationship_type: "DEPENDS_ON",
+                        related_spdx_element: (*to).to_string(),
+                    },
+                    RelationshipKind::DevDependency => Relationship {
+                        spdx_element_id: (*to).to_string(),
+                        relationship_type: "DEV_DEPENDENCY_OF",
+                        related_spdx_element: (*from).to_string(),
+                    },
+                }))
+                .collect();
+
+            Self {
+                spdx_version: "SPDX-2.3",
+                data_license: "CC0-1.0",
+                spdx_id: "SPDXRef-DOCUMENT",
+                name: name.to_string(),
+                document_namespace: namespace,
+                creation_info: CreationInfo {
+                    created,
+                    creators: vec![
+                        "Organization: Astral Software Inc.".to_string(),
+                        format!("Tool: uv-{}", uv_version::version()),
+                    ],
+                },
+                packages,
+                relationships,
+            }
+        }
+    }
+}
+
+/// The SPDX 3.0 JSON-LD serialization.
+///
+/// See: <https://spdx.github.io/spdx-spec/v3.0.1/>
+pub mod v3_0 {
+    use std::collections::BTreeSet;
+
+    use serde::Serialize;
+
+    use uv_pypi_types::HashAlgorithm;
+
+    use super::{Entry, RelationshipKind, declared_license, download_location, package_url};
+    use crate::lock::license::PackageLicenses;
+
+    /// The identifier of the (blank node) creation information shared by all elements.
+    const CREATION_INFO: &str = "_:creationinfo";
+
+    #[derive(Debug, Serialize)]
+    pub struct Document {
+        #[serde(rename = "@context")]
+        context: &'static str,
+        #[serde(rename = "@graph")]
+        graph: Vec<Element>,
+    }
+
+    #[derive(Debug, Serialize)]
+    #[serde(tag = "type")]
+    enum Element {
+        CreationInfo {
+            #[serde(rename = "@id")]
+            id: &'static str,
+            #[serde(rename = "specVersion")]
+            spec_version: &'static str,
+            created: String,
+            #[serde(rename = "createdBy")]
+            created_by: Vec<String>,
+            #[serde(rename = "createdUsing")]
+            created_using: Vec<String>,
+        },
+        Organization {
+            #[serde(rename = "spdxId")]
+            spdx_id: String,
+            #[serde(rename = "creationInfo")]
+            creation_info: &'static str,
+            name: &'static str,
+        },
+        Tool {
+            #[serde(rename = "spdxId")]
+            spdx_id: String,
+            #[serde(rename = "creationInfo")]
+            creation_info: &'static str,
+            name: String,
+        },
+        SpdxDocument {
+            #[serde(rename = "spdxId")]
+            spdx_id: String,
+            #[serde(rename = "creationInfo")]
+            creation_info: &'static str,
+            name: String,
+            #[serde(rename = "dataLicense")]
+            data_license: String,
+            #[serde(rename = "rootElement")]
+            root_element: Vec<String>,
+            element: Vec<String>,
+        },
+        #[serde(rename = "software_Sbom")]
+        Sbom {
+            #[serde(rename = "spdxId")]
+            spdx_id: String,
+            #[serde(rename = "creationInfo")]
+            creation_info: &'static str,
+            #[serde(rename = "software_sbomType")]
+            sbom_type: Vec<&'static str>,
+            #[serde(rename = "rootElement")]
+            root_element: Vec<String>,
+            element: Vec<String>,
+        },
+        #[serde(rename = "software_Package")]
+        Package(Package),
+        #[serde(rename = "simplelicensing_LicenseExpression")]
+        LicenseExpression {
+            #[serde(rename = "spdxId")]
+            spdx_id: String,
+            #[serde(rename = "creationInfo")]
+            creation_info: &'static str,
+            #[serde(rename = "simplelicensing_licenseExpression")]
+            license_expression: String,
+        },
+        Relationship(Relationship),
+        LifecycleScopedRelationship {
+            #[serde(flatten)]
+            relationship: Relationship,
+            scope: &'static str,
+        },
+    }
+
+    #[derive(Debug, Serialize)]
+    #[allow(clippy::struct_field_names)]
+    struct Package {
+        #[serde(rename = "spdxId")]
+        spdx_id: String,
+        #[serde(rename = "creationInfo")]
+        creation_info: &'static str,
+        name: String,
+        #[serde(
+            rename = "software_packageVersion",
+            skip_serializing_if = "Option::is_none"
+        )]
+        version: Option<String>,
+        #[serde(
+            rename = "software_downloadLocation",
+            skip_serializing_if = "Option::is_none"
+        )]
+        download_location: Option<String>,
+        #[serde(
+            rename = "software_packageUrl",
+            skip_serializing_if = "Option::is_none"
+        )]
+        package_url: Option<String>,
+        #[serde(rename = "verifiedUsing", skip_serializing_if = "Vec::is_empty")]
+        verified_using: Vec<Hash>,
+        #[serde(rename = "software_primaryPurpose")]
+        primary_purpose: &'static str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        comment: Option<String>,
+    }
+
+    #[derive(Debug, Serialize)]
+    #[allow(clippy::struct_field_names)]
+    struct Hash {
+        #[serde(rename = "type")]
+        kind: &'static str,
+        algorithm: &'static str,
+        #[serde(rename = "hashValue")]
+        hash_value: String,
+    }
+
+    #[derive(Debug, Serialize)]
+    #[allow(clippy::struct_field_names)]
+    struct Relationship {
+        #[serde(rename = "spdxId")]
+        spdx_id: String,
+        #[serde(rename = "creationInfo")]
+        creation_info: &'static str,
+        from: String,
+        #[serde(rename = "relationshipType")]
+        relationship_type: &'static str,
+        to: Vec<String>,
+    }
+
+    impl Document {
+        pub(super) fn new(
+            name: &str,
+            namespace: &str,
+            created: String,
+            entries: &[Entry<'_>],
+            described: &[&str],
+            relationships: &BTreeSet<(&str, RelationshipKind, &str)>,
+            licenses: &PackageLicenses,
+        ) -> Self {
+            let iri = |id: &str| format!("{namespace}#{id}");
+
+            let organization = iri("SPDXRef-Organization-Astral");
+            let tool = iri("SPDXRef-Tool-uv");
+            let data_license = iri("SPDXRef-License-CC0-1.0");
+
+            let mut graph = vec![
+                Element::CreationInfo {
+                    id: CREATION_INFO,
+                    spec_version: "3.0.1",
+                    created,
+                    created_by: vec![organization.clone()],
+                    created_using: vec![tool.clone()],
+                },
+                Element::Organization {
+                    spdx_id: organization,
+                    creation_info: CREATION_INFO,
+                    name: "Astral Software Inc.",
+                },
+                Element::Tool {
+                    spdx_id: tool,
+                    creation_info: CREATION_INFO,
+                    name: format!("uv {}", uv_version::version()),
+                },
+                Element::LicenseExpression {
+                    spdx_id: data_license.clone(),
+                    creation_info: CREATION_INFO,
+                    license_expression: "CC0-1.0".to_string(),
+                },
+            ];
+
+            let mut elements = Vec::new();
+            let mut counter = 0;
+            let mut relationship_id = || {
+                counter += 1;
+                iri(&format!("SPDXRef-Relationship-{counter}"))
+            };
+
+            for entry in entries {
+                let package = entry.package;
+                let (download_location, hash) = download_location(package);
+                let spdx_id = iri(&entry.id);
+                elements.push(spdx_id.clone());
+                graph.push(Element::Package(Package {
+                    spdx_id: spdx_id.clone(),
+                    creation_info: CREATION_INFO,
+                    name: package.id.name.to_string(),
+                    version: package.id.version.as_ref().map(ToString::to_string),
+                    download_location,
+                    package_url: package_url(package),
+                    verified_using: hash
+                        .map(|hash| Hash {
+                            kind: "Hash",
+                            algorithm: match hash.algorithm {
+                                HashAlgorithm::Md5 => "md5",
+                                HashAlgorithm::Sha256 => "sha256",
+                                HashAlgorithm::Sha384 => "sha384",
+                                HashAlgorithm::Sha512 => "sha512",
+                                HashAlgorithm::Blake2b => "blake2b512",
+                            },
+                            hash_value: hash.digest.to_string(),
+                        })
+                        .into_iter()
+                        .collect(),
+                    primary_purpose: "library",
+                    comment: entry
+                        .marker
+                        .contents()
+                        .map(|marker| format!("Installed when: {marker}")),
+                }));
+
+                // Attach the declared license, if known.
+                if let Some(expression) = declared_license(package, licenses) {
+                    let license_id = iri(&format!("{}-License", entry.id));
+                    elements.push(license_id.clone());
+                    graph.push(Element::LicenseExpression {
+                        spdx_id: license_id.clone(),
+                        creation_info: CREATION_INFO,
+                        license_expression: expression,
+                    });
+                    let id = relationship_id();
+                    elements.push(id.clone());
+                    graph.push(Element::Relationship(Relationship {
+                        spdx_id: id,
+                        creation_info: CREATION_INFO,
+                        from: spdx_id,
+                        relationship_type: "hasDeclaredLicense",
+                        to: vec![license_id],
+                    }));
+                }
+            }
+
+            // Group the dependency relationships by their source and kind.
+            let mut grouped: Vec<(&str, RelationshipKind, Vec<String>)> = Vec::new();
+            for (from, kind, to) in relationships {
+                if let Some((last_from, last_kind, targets)) = grouped.last_mut() {
+                    if last_from == from && last_kind == kind {
+                        targets.push(iri(to));
+                        continue;
+                    }
+                }
+                grouped.push((from, *kind, vec![iri(to)]));
+            }
+            for (from, kind, to) in grouped {
+                let id = relationship_id();
+                elements.push(id.clone());
+                let relationship = Relationship {
+                    spdx_id: id,
+                    creation_info: CREATION_INFO,
+                    from: iri(from),
+                    relationship_type: "dependsOn",
+                    to,
+                };
+                graph.push(match kind {
+                    RelationshipKind::DependsOn => Element::Relationship(relationship),
+                    RelationshipKind::DevDependency => Element::LifecycleScopedRelationship {
+                        relationship,
+                        scope: "development",
+                    },
+                });
+            }
+
+            let root_element = described.iter().map(|id| iri(id)).collect::<Vec<_>>();
+            let sbom = iri("SPDXRef-Sbom");
+            graph.push(Element::Sbom {
+                spdx_id: sbom.clone(),
+                creation_info: CREATION_INFO,
+                sbom_type: vec!["build"],
+                root_element,
+                element: elements,
+            });
+            graph.push(Element::SpdxDocument {
+                spdx_id: iri("SPDXRef-DOCUMENT"),
+                creation_info: CREATION_INFO,
+                name: name.to_string(),
+                data_license,
+                root_element: vec![sbom.clone()],
+                element: vec![sbom],
+            });
+
+            Self {
+                context: "https://spdx.org/rdf/3.0.1/spdx-context.jsonld",
+                graph,
+            }
+        }
+    }
+}
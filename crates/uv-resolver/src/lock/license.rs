@@ -0,0 +1,123 @@
+use futures::future::try_join_all;
+use rustc_hash::FxHashMap;
+
+use std::path::Path;
+
+use uv_configuration::BuildOptions;
+use uv_distribution::DistributionDatabase;
+use uv_distribution_types::{Dist, SourceDist};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pep508::MarkerEnvironment;
+use uv_platform_tags::Tags;
+pub use uv_pypi_types::PackageLicense;
+use uv_types::BuildContext;
+
+use crate::InMemoryIndex;
+use crate::lock::{HashedDist, Lock, LockError, LockErrorKind, TagPolicy};
+
+/// The license information for a set of locked packages, keyed by name and version.
+///
+/// The lockfile itself doesn't record license information, so this is populated from the core
+/// metadata of the locked distributions.
+#[derive(Debug, Clone, Default)]
+pub struct PackageLicenses(FxHashMap<(PackageName, Version), PackageLicense>);
+
+impl PackageLicenses {
+    /// Collect the licenses of the locked packages that were read into the [`InMemoryIndex`].
+    ///
+    /// Packages whose license hasn't been read are omitted; packages that don't declare a
+    /// license are included with an empty [`PackageLicense`].
+    pub fn from_index(lock: &Lock, index: &InMemoryIndex) -> Self {
+        let mut licenses = Self::default();
+        for package in lock.packages() {
+            let Some(version) = package.version() else {
+                continue;
+            };
+            let key = (package.name().clone(), version.clone());
+            if let Some(license) = index.licenses().get(&key) {
+                licenses.insert(key.0, key.1, license.unwrap_or_default());
+            }
+        }
+        licenses
+    }
+
+    /// Record the license for the given package version.
+    pub fn insert(&mut self, name: PackageName, version: Version, license: PackageLicense) {
+        self.0.insert((name, version), license);
+    }
+
+    /// Return the license for the given package version, if known.
+    pub fn get(&self, name: &PackageName, version: &Version) -> Option<&PackageLicense> {
+        self.0.get(&(name.clone(), version.clone()))
+    }
+
+    /// Returns `true` if no licenses are known.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Lock {
+    /// Read the license of every locked package into the [`InMemoryIndex`].
+    ///
+    /// Only packages whose license isn't already in the index are queried. For each package, the
+    /// license is read from the `METADATA` file of its best wheel for the given tags (falling back
+    /// to any wheel), or from the `pyproject.toml` of a local source tree. Source distributions
+    /// are never built to read their license; such packages are left out of the index, to
+    /// distinguish them from packages that don't declare a license.
+    pub async fn read_licenses<Context: BuildContext>(
+        &self,
+        root: &Path,
+        tags: &Tags,
+        markers: &MarkerEnvironment,
+        index: &InMemoryIndex,
+        database: &DistributionDatabase<'_, Context>,
+    ) -> Result<(), LockError> {
+        let mut dists = Vec::new();
+        for package in &self.packages {
+            let Some(version) = package.version() else {
+                continue;
+            };
+            let HashedDist { dist, .. } = package.to_dist(
+                root,
+                TagPolicy::Preferred(tags),
+                &BuildOptions::default(),
+                markers,
+            )?;
+            if matches!(
+                dist,
+                Dist::Source(SourceDist::Directory(_)) | Dist::Built(_)
+            ) {
+                dists.push((package, version, dist));
+            }
+        }
+
+        try_join_all(
+            dists
+                .into_iter()
+                .filter(|(package, version, _)| {
+                    index
+                        .licenses()
+                        .register((package.name().clone(), (*version).clone()))
+                })
+                .map(async |(package, version, dist)| {
+                    let license = database.get_license(&dist).await.map_err(|err| {
+                        LockError::from(LockErrorKind::License {
+                            id: package.id.clone(),
+                            err,
+                        })
+                    });
+
+                    // Release any waiters, even if the license couldn't be read.
+                    index.licenses().done(
+                        (package.name().clone(), version.clone()),
+                        license.as_ref().ok().cloned().flatten(),
+                    );
+                    license
+                }),
+        )
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,378 @@
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use spdx::{Expression, LicenseReq, Licensee, ParseMode};
+
+use uv_normalize::{ExtraName, GroupName, PackageName};
+use uv_pep440::Version;
+use uv_workspace::pyproject::LicensePolicy;
+
+use crate::lock::{Dependency, Lock, Package, PackageId};
+use crate::{PackageLicense, PackageLicenses};
+
+#[derive(Debug, thiserror::Error)]
+pub enum LicensePolicyError {
+    #[error("Invalid license identifier `{license}` in `tool.uv.license-policy.{field}`: {reason}")]
+    InvalidLicense {
+        field: &'static str,
+        license: String,
+        reason: spdx::error::Reason,
+    },
+
+    #[error("{0}")]
+    Violations(LicenseViolations),
+}
+
+/// The set of locked packages that violate the license policy.
+#[derive(Debug)]
+pub struct LicenseViolations(Vec<LicenseViolation>);
+
+impl LicenseViolations {
+    /// Returns the names of the packages that violate the license policy.
+    pub fn packages(&self) -> impl Iterator<Item = &PackageName> {
+        self.0.iter().map(|violation| &violation.name)
+    }
+}
+
+/// Render each violation as a derivation from a workspace member to the offending package, in
+/// the style of a resolver error report.
+impl Display for LicenseViolations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, violation) in self.0.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+                writeln!(f)?;
+            }
+            write!(f, "{violation}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for LicenseViolations {}
+
+/// A locked package that violates the license policy.
+#[derive(Debug)]
+struct LicenseViolation {
+    name: PackageName,
+    version: Option<Version>,
+    reason: ViolationReason,
+    /// The chain of dependents from a workspace member to the offending package, starting with
+    /// the workspace member.
+    chain: Vec<ChainStep>,
+}
+
+#[derive(Debug)]
+enum ViolationReason {
+    /// The package's license can't be satisfied without a denied license.
+    Denied(String),
+    /// The package's license can't be satisfied using the allowed licenses.
+    NotAllowed(String),
+    /// The package doesn't declare a recognized license, but only specific licenses are allowed.
+    Unknown,
+    /// The package's license wasn't read, but only specific licenses are allowed.
+    Unread,
+}
+
+/// A package in a dependency chain, along with the extra or group through which it depends on the
+/// next package in the chain.
+#[derive(Debug, Clone)]
+struct ChainStep {
+    name: PackageName,
+    version: Option<Version>,
+    member: bool,
+    via: Option<Via>,
+}
+
+#[derive(Debug, Clone)]
+enum Via {
+    Extra(ExtraName),
+    Group(GroupName),
+}
+
+impl Display for ChainStep {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        match &self.via {
+            Some(Via::Extra(extra)) => write!(f, "[{extra}]")?,
+            Some(Via::Group(group)) => write!(f, ":{group}")?,
+            None => {}
+        }
+        if !self.member {
+            if let Some(version) = &self.version {
+                write!(f, "=={version}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for LicenseViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let package = if let Some(version) = &self.version {
+            format!("{}=={version}", self.name)
+        } else {
+            self.name.to_string()
+        };
+        let reason = match &self.reason {
+            ViolationReason::Denied(license) => {
+                format!("is licensed under {license}, which is denied by the license policy")
+            }
+            ViolationReason::NotAllowed(license) => {
+                format!("is licensed under {license}, which is not allowed by the license policy")
+            }
+            ViolationReason::Unknown => {
+                "has no recognized license, which is not allowed by the license policy".to_string()
+            }
+            ViolationReason::Unread => {
+                "has an unknown license (it's only available as a source distribution), which is not allowed by the license policy".to_string()
+            }
+        };
+
+        // Ex) Because foo==1.0.0 is licensed under GPL-3.0-only, which is denied by the license
+        // policy, we can conclude that foo==1.0.0 cannot be used.
+        let Some((parent, dependents)) = self.chain.split_last() else {
+            return write!(
+                f,
+                "Because {package} {reason}, we can conclude that {package} cannot be used."
+            );
+        };
+
+        // Ex) Because foo==1.0.0 is licensed under GPL-3.0-only, which is denied by the license
+        // policy, and bar==2.0.0 depends on foo, we can conclude that bar==2.0.0 cannot be used.
+        let conclusion = |step: &ChainStep| {
+            if step.member {
+                format!("{step}'s dependencies violate the license policy")
+            } else {
+                format!("{step} cannot be used")
+            }
+        };
+        write!(
+            f,
+            "Because {package} {reason}, and {parent} depends on {}, we can conclude that {}.",
+            self.name,
+            conclusion(parent)
+        )?;
+
+        // Ex) And because project depends on bar, we can conclude that project's dependencies
+        // violate the license policy.
+        let mut dependency = &parent.name;
+        for step in dependents.iter().rev() {
+            write!(
+                f,
+                "\nAnd because {step} depends on {dependency}, we can conclude that {}.",
+                conclusion(step)
+            )?;
+            dependency = &step.name;
+        }
+
+        Ok(())
+    }
+}
+
+/// The license policy, with its license identifiers parsed.
+struct Policy<'a> {
+    allow: Option<Vec<Licensee>>,
+    deny: Vec<Licensee>,
+    exceptions: FxHashSet<&'a PackageName>,
+}
+
+impl<'a> Policy<'a> {
+    fn from_settings(policy: &'a LicensePolicy) -> Result<Self, LicensePolicyError> {
+        let parse = |field: &'static str, licenses: &[String]| {
+            licenses
+                .iter()
+                .map(|license| {
+                    Licensee::parse(license.trim()).map_err(|err| {
+                        LicensePolicyError::InvalidLicense {
+                            field,
+                            license: license.clone(),
+                            reason: err.reason,
+                        }
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()
+        };
+        Ok(Self {
+            allow: policy
+                .allow
+                .as_deref()
+                .map(|allow| parse("allow", allow))
+                .transpose()?,
+            deny: parse("deny", policy.deny.as_deref().unwrap_or_default())?,
+            exceptions: policy.exceptions.iter().flatten().collect(),
+        })
+    }
+
+    /// Returns `true` if the license requirement is explicitly denied.
+    fn is_denied(&self, req: &LicenseReq) -> bool {
+        self.deny.iter().any(|licensee| licensee.satisfies(req))
+    }
+
+    /// Returns `true` if the license requirement may be used.
+    fn is_permitted(&self, req: &LicenseReq) -> bool {
+        if self.is_denied(req) {
+            return false;
+        }
+        match &self.allow {
+            Some(allow) => allow.iter().any(|licensee| licensee.satisfies(req)),
+            None => true,
+        }
+    }
+
+    /// Evaluate a package's license against the policy, returning the reason for the violation,
+    /// if any.
+    fn evaluate(&self, license: &PackageLicense) -> Option<ViolationReason> {
+        let Some(expression) = license
+            .spdx_expression()
+            .and_then(|expression| Expression::parse_mode(&expression, ParseMode::LAX).ok())
+        else {
+            return self.allow.is_some().then_some(ViolationReason::Unknown);
+        };
+        if expression.evaluate(|req| self.is_permitted(req)) {
+            return None;
+        }
+        if expression.evaluate(|req| !self.is_denied(req)) {
+            Some(ViolationReason::NotAllowed(expression.to_string()))
+        } else {
+            Some(ViolationReason::Denied(expression.to_string()))
+        }
+    }
+}
+
+impl Lock {
+    /// Check the license of every locked package against the given [`LicensePolicy`].
+    ///
+    /// Workspace members and packages listed as exceptions are skipped. Packages without a
+    /// recognized license are only accepted if the policy doesn't define an allowlist.
+    ///
+    /// Packages whose license wasn't read (e.g., packages that are only available as a source
+    /// distribution) are rejected if the policy defines an allowlist. Otherwise, they're skipped,
+    /// and returned so that the caller can report them.
+    pub fn check_license_policy(
+        &self,
+        policy: &LicensePolicy,
+        licenses: &PackageLicenses,
+    ) -> Result<Vec<&Package>, LicensePolicyError> {
+        let policy = Policy::from_settings(policy)?;
+        let members = self.member_ids();
+
+        let mut unread = Vec::new();
+        let mut violations = Vec::new();
+        for package in &self.packages {
+            if members.contains(&package.id) || policy.exceptions.contains(package.name()) {
+                continue;
+            }
+            let Some(version) = package.version() else {
+                continue;
+            };
+            let reason = match licenses.get(package.name(), version) {
+                Some(license) => policy.evaluate(license),
+                None if policy.allow.is_some() => Some(ViolationReason::Unread),
+                None => {
+                    unread.push(package);
+                    continue;
+                }
+            };
+            if let Some(reason) = reason {
+                violations.push(LicenseViolation {
+                    name: package.name().clone(),
+                    version: Some(version.clone()),
+                    reason,
+                    chain: self.dependents_chain(&members, &package.id),
+                });
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(unread)
+        } else {
+            Err(LicensePolicyError::Violations(LicenseViolations(
+                violations,
+            )))
+        }
+    }
+
+    /// Returns the IDs of the workspace members, including the root package.
+    fn member_ids(&self) -> FxHashSet<&PackageId> {
+        self.packages
+            .iter()
+            .filter(|package| {
+                self.members().contains(package.name())
+                    || self.root().is_some_and(|root| std::ptr::eq(root, *package))
+            })
+            .map(|package| &package.id)
+            .collect()
+    }
+
+    /// Find the shortest chain of dependents from a workspace member to the given package.
+    fn dependents_chain(
+        &self,
+        members: &FxHashSet<&PackageId>,
+        target: &PackageId,
+    ) -> Vec<ChainStep> {
+        // Perform a BFS from the workspace members, recording the dependent of each package.
+        let mut parents: FxHashMap<&PackageId, (&Package, Option<Via>)> = FxHashMap::default();
+        let mut queue = self
+            .packages
+            .iter()
+            .filter(|package| members.contains(&package.id))
+            .collect::<VecDeque<_>>();
+        let mut seen = queue
+            .iter()
+            .map(|package| &package.id)
+            .collect::<FxHashSet<_>>();
+
+        while let Some(package) = queue.pop_front() {
+            if &package.id == target {
+                break;
+            }
+            let edges =
+                package
+                    .dependencies
+                    .iter()
+                    .map(|dependency| (dependency, None))
+                    .chain(package.optional_dependencies.iter().flat_map(
+                        |(extra, dependencies)| {
+                            dependencies
+                                .iter()
+                                .map(|dependency| (dependency, Some(Via::Extra(extra.clone()))))
+                        },
+                    ))
+                    .chain(
+                        package
+                            .dependency_groups
+                            .iter()
+                            .flat_map(|(group, dependencies)| {
+                                dependencies
+                                    .iter()
+                                    .map(|dependency| (dependency, Some(Via::Group(group.clone()))))
+                            }),
+                    );
+            for (dependency, via) in edges {
+                let Dependency { package_id, .. } = dependency;
+                if !seen.insert(package_id) {
+                    continue;
+                }
+                parents.insert(package_id, (package, via));
+                queue.push_back(self.find_by_id(package_id));
+            }
+        }
+
+        // Walk back up to the workspace member.
+        let mut chain = Vec::new();
+        let mut current = target;
+        while let Some((parent, via)) = parents.get(current) {
+            chain.push(ChainStep {
+                name: parent.name().clone(),
+                version: parent.version().cloned(),
+                member: members.contains(&parent.id),
+                via: via.clone(),
+            });
+            current = &parent.id;
+        }
+        chain.reverse();
+        chain
+    }
+}
@@ -51,10 +51,16 @@ use uv_workspace::{Editability, WorkspaceMember};
 
 use crate::exclude_newer::ExcludeNewerSpan;
 use crate::fork_strategy::ForkStrategy;
+pub use crate::lock::diff::{HashChange, LockDiff, PackageChange};
 pub(crate) use crate::lock::export::PylockTomlPackage;
 pub use crate::lock::export::RequirementsTxtExport;
-pub use crate::lock::export::{PylockToml, PylockTomlErrorKind, cyclonedx_json};
+pub use crate::lock::export::{
+    PylockToml, PylockTomlErrorKind, ReachablePackage, cyclonedx_json, reachable_packages,
+    spdx_json,
+};
 pub use crate::lock::installable::Installable;
+pub use crate::lock::license::{PackageLicense, PackageLicenses};
+pub use crate::lock::license_policy::{LicensePolicyError, LicenseViolations};
 pub use crate::lock::map::PackageMap;
 pub use crate::lock::tree::TreeDisplay;
 use crate::resolution::{AnnotatedDist, ResolutionGraphNode};
@@ -64,8 +70,11 @@ use crate::{
     PackageExcludeNewer, PrereleaseMode, ResolutionMode, ResolverOutput,
 };
 
+mod diff;
 mod export;
 mod installable;
+mod license;
+mod license_policy;
 mod map;
 mod tree;
 
@@ -892,6 +901,13 @@ impl Lock {
         self
     }
 
+    /// Record the resolution mode under which this lock should be validated.
+    #[must_use]
+    pub fn with_resolution_mode(mut self, resolution_mode: ResolutionMode) -> Self {
+        self.options.resolution_mode = resolution_mode;
+        self
+    }
+
     /// Record the conflicting groups that were used to generate this lock.
     #[must_use]
     pub fn with_conflicts(mut self, conflicts: Conflicts) -> Self {
@@ -2258,6 +2274,17 @@ impl<'tags> TagPolicy<'tags> {
     }
 }
 
+/// How a locked [`Package`] would be installed on a given platform.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PackageAvailability {
+    /// The package has a wheel that is compatible with the platform.
+    Wheel,
+    /// The package has no compatible wheel, and would be built from source.
+    Source,
+    /// The package has neither a compatible wheel nor a buildable source distribution.
+    Unavailable,
+}
+
 /// The result of checking if a lockfile satisfies a set of requirements.
 #[derive(Debug)]
 pub enum SatisfiesResult<'lock> {
@@ -3368,6 +3395,33 @@ impl Package {
         }
     }
 
+    /// Determine how the [`Package`] would be installed on a platform with the given tags.
+    ///
+    /// Mirrors the selection performed when installing from the lockfile: a compatible wheel is
+    /// preferred, followed by the source distribution, subject to the `--no-binary` and
+    /// `--no-build` settings.
+    pub fn availability(&self, tags: &Tags, build_options: &BuildOptions) -> PackageAvailability {
+        let no_binary = build_options.no_binary_package(&self.id.name);
+        let no_build = build_options.no_build_package(&self.id.name);
+
+        if !no_binary && self.find_best_wheel(TagPolicy::Required(tags)).is_some() {
+            return PackageAvailability::Wheel;
+        }
+
+        let has_source = match &self.id.source {
+            Source::Registry(_) => self.sdist.is_some(),
+            Source::Path(_) | Source::Direct(_, _) => !self.id.source.is_wheel(),
+            Source::Git(_, _) | Source::Directory(_) | Source::Editable(_) => true,
+            // Virtual packages are installed from their source tree, even with `--no-build`.
+            Source::Virtual(_) => return PackageAvailability::Source,
+        };
+        if has_source && !no_build {
+            PackageAvailability::Source
+        } else {
+            PackageAvailability::Unavailable
+        }
+    }
+
     /// Returns the [`PackageName`] of the package.
     pub fn name(&self) -> &PackageName {
         &self.id.name
@@ -3459,6 +3513,11 @@ impl Package {
         &self.metadata.provides_extra
     }
 
+    /// Returns the requirements of the package, if it is a source tree.
+    pub fn requires_dist(&self) -> &BTreeSet<Requirement> {
+        &self.metadata.requires_dist
+    }
+
     /// Returns the dependency groups the package provides, if any.
     pub fn dependency_groups(&self) -> &BTreeMap<GroupName, BTreeSet<Requirement>> {
         &self.metadata.dependency_groups
@@ -6200,6 +6259,15 @@ enum LockErrorKind {
         #[source]
         err: uv_distribution::Error,
     },
+    /// An error that occurs when reading the license of a package.
+    #[error("Failed to read the license of `{id}`", id = id.cyan())]
+    License {
+        /// The ID of the distribution whose license couldn't be read.
+        id: PackageId,
+        /// The inner error we forward.
+        #[source]
+        err: uv_distribution::Error,
+    },
     /// A package has inconsistent versions in a single entry
     // Using name instead of id since the version in the id is part of the conflict.
     #[error("The entry for package `{name}` ({version}) has wheel `{wheel_filename}` with inconsistent version ({wheel_version}), which indicates a malformed wheel. If this is intentional, set `{env_var}`.", name = name.cyan(), wheel_filename = wheel.filename, wheel_version = wheel.filename.version, env_var = "UV_SKIP_WHEEL_FILENAME_CHECK=1".green())]
@@ -6670,4 +6738,86 @@ source = { editable = "path/to/dir" }
         let result: Result<Lock, _> = toml::from_str(data);
         insta::assert_debug_snapshot!(result);
     }
+
+    #[test]
+    fn package_availability() {
+        let data = r#"
+version = 1
+requires-python = ">=3.12"
+
+[[package]]
+name = "a"
+version = "0.1.0"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://example.com/a-0.1.0.tar.gz", hash = "sha256:37dd54208da7e1cd875388217d5e00ebd4179249f90fb72437e91a35459a0ad3", size = 0 }
+wheels = [{ url = "https://example.com/a-0.1.0-cp312-cp312-manylinux_2_17_x86_64.whl", hash = "sha256:37dd54208da7e1cd875388217d5e00ebd4179249f90fb72437e91a35459a0ad3", size = 0 }]
+
+[[package]]
+name = "b"
+version = "0.1.0"
+source = { registry = "https://pypi.org/simple" }
+wheels = [{ url = "https://example.com/b-0.1.0-cp312-cp312-manylinux_2_17_x86_64.whl", hash = "sha256:37dd54208da7e1cd875388217d5e00ebd4179249f90fb72437e91a35459a0ad3", size = 0 }]
+"#;
+        let lock: Lock = toml::from_str(data).unwrap();
+        let [a, b] = lock.packages() else {
+            panic!("expected two packages");
+        };
+
+        let linux = Tags::from_env(
+            &uv_platform_tags::Platform::new(
+                uv_platform_tags::Os::Manylinux {
+                    major: 2,
+                    minor: 28,
+                },
+                uv_platform_tags::Arch::X86_64,
+            ),
+            (3, 12),
+            "cpython",
+            (3, 12),
+            true,
+            false,
+            true,
+        )
+        .unwrap();
+        let windows = Tags::from_env(
+            &uv_platform_tags::Platform::new(
+                uv_platform_tags::Os::Windows,
+                uv_platform_tags::Arch::X86_64,
+            ),
+            (3, 12),
+            "cpython",
+            (3, 12),
+            false,
+            false,
+            true,
+        )
+        .unwrap();
+
+        let build_options = BuildOptions::default();
+        assert_eq!(
+            a.availability(&linux, &build_options),
+            PackageAvailability::Wheel
+        );
+        assert_eq!(
+            b.availability(&linux, &build_options),
+            PackageAvailability::Wheel
+        );
+        assert_eq!(
+            a.availability(&windows, &build_options),
+            PackageAvailability::Source
+        );
+        assert_eq!(
+            b.availability(&windows, &build_options),
+            PackageAvailability::Unavailable
+        );
+
+        let no_build = BuildOptions::new(
+            uv_configuration::NoBinary::None,
+            uv_configuration::NoBuild::All,
+        );
+        assert_eq!(
+            a.availability(&windows, &no_build),
+            PackageAvailability::Unavailable
+        );
+    }
 }
@@ -474,14 +474,10 @@ impl<'env> TreeDisplay<'env> {
             }
 
             // Append compressed wheel size, if available in the lockfile.
-            // Keep it simple: use the first wheel entry that includes a size.
-            if self.show_sizes {
-                let package = self.lock.find_by_id(package_id);
-                if let Some(size_bytes) = package.wheels.iter().find_map(|wheel| wheel.size) {
-                    let (bytes, unit) = human_readable_bytes(size_bytes);
-                    line.push(' ');
-                    line.push_str(format!("{}", format!("({bytes:.1}{unit})").dimmed()).as_str());
-                }
+            if let Some(size_bytes) = self.size(package_id) {
+                let (bytes, unit) = human_readable_bytes(size_bytes);
+                line.push(' ');
+                line.push_str(format!("{}", format!("({bytes:.1}{unit})").dimmed()).as_str());
             }
 
             line
@@ -507,22 +503,7 @@ impl<'env> TreeDisplay<'env> {
             line
         };
 
-        let mut dependencies = self
-            .graph
-            .edges_directed(cursor.node(), Direction::Outgoing)
-            .filter_map(|edge| match self.graph[edge.target()] {
-                Node::Root => None,
-                Node::Package(_) => Some(Cursor::new(edge.target(), edge.id())),
-            })
-            .collect::<Vec<_>>();
-        dependencies.sort_by_key(|cursor| {
-            let node = &self.graph[cursor.node()];
-            let edge = cursor
-                .edge()
-                .map(|edge_id| &self.graph[edge_id])
-                .map(Edge::kind);
-            (edge, node)
-        });
+        let dependencies = self.dependencies(cursor.node());
 
         let mut lines = vec![line];
 
@@ -580,6 +561,257 @@ impl<'env> TreeDisplay<'env> {
         lines
     }
 
+    /// Return the dependencies of the given node, in display order.
+    fn dependencies(&self, node: NodeIndex) -> Vec<Cursor> {
+        let mut dependencies = self
+            .graph
+            .edges_directed(node, Direction::Outgoing)
+            .filter_map(|edge| match self.graph[edge.target()] {
+                Node::Root => None,
+                Node::Package(_) => Some(Cursor::new(edge.target(), edge.id())),
+            })
+            .collect::<Vec<_>>();
+        dependencies.sort_by_key(|cursor| {
+            let node = &self.graph[cursor.node()];
+            let edge = cursor
+                .edge()
+                .map(|edge_id| &self.graph[edge_id])
+                .map(Edge::kind);
+            (edge, node)
+        });
+        dependencies
+    }
+
+    /// Return the compressed wheel size of the package, if sizes are enabled and available in the
+    /// lockfile.
+    ///
+    /// Keep it simple: use the first wheel entry that includes a size.
+    fn size(&self, package_id: &PackageId) -> Option<u64> {
+        if !self.show_sizes {
+            return None;
+        }
+        let package = self.lock.find_by_id(package_id);
+        package.wheels.iter().find_map(|wheel| wheel.size)
+    }
+
+    /// Return the top-level entries of the tree, i.e., the roots, with the synthetic root node
+    /// replaced by its dependencies.
+    fn top_level(&self) -> Vec<Cursor> {
+        self.roots
+            .iter()
+            .flat_map(|node| match self.graph[*node] {
+                Node::Root => Either::Left(
+                    self.graph
+                        .edges_directed(*node, Direction::Outgoing)
+                        .map(|edge| Cursor::new(edge.target(), edge.id())),
+                ),
+                Node::Package(_) => Either::Right(std::iter::once(Cursor::root(*node))),
+            })
+            .collect()
+    }
+
+    /// Perform a depth-first traversal of the given package and its dependencies, mirroring
+    /// [`TreeDisplay::visit`], to build a serializable tree.
+    fn visit_json(
+        &'env self,
+        cursor: Cursor,
+        visited: &mut FxHashSet<&'env PackageId>,
+        path: &mut Vec<&'env PackageId>,
+    ) -> Option<JsonNode<'env>> {
+        // Short-circuit if the current path is longer than the provided depth.
+        if path.len() > self.depth {
+            return None;
+        }
+
+        let Node::Package(package_id) = self.graph[cursor.node()] else {
+            return None;
+        };
+        let edge = cursor.edge().map(|edge_id| &self.graph[edge_id]);
+        let dependencies = self.dependencies(cursor.node());
+
+        let mut node = JsonNode {
+            name: &package_id.name,
+            version: package_id.version.as_ref(),
+            extras: edge.and_then(Edge::extras).into_iter().flatten().collect(),
+            extra: match edge {
+                Some(Edge::Optional(extra, _)) => Some(extra),
+                _ => None,
+            },
+            group: match edge {
+                Some(Edge::Dev(group, _)) => Some(group),
+                _ => None,
+            },
+            latest: None,
+            size: self.size(package_id),
+            deduplicated: false,
+            dependencies: Vec::new(),
+        };
+
+        // Skip the traversal if the package is in the current traversal path, or if it has been
+        // visited and de-duplication is enabled.
+        if visited.contains(package_id) && (!self.no_dedupe || path.contains(&package_id)) {
+            node.deduplicated = !dependencies.is_empty();
+            return Some(node);
+        }
+
+        node.latest = self.latest.get(package_id);
+
+        visited.insert(package_id);
+        path.push(package_id);
+        node.dependencies = dependencies
+            .into_iter()
+            .filter_map(|dep| self.visit_json(dep, visited, path))
+            .collect();
+        path.pop();
+
+        Some(node)
+    }
+
+    /// Render the dependency tree as JSON, as a list of nested packages.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        let mut path = Vec::new();
+        let mut visited =
+            FxHashSet::with_capacity_and_hasher(self.graph.node_count(), FxBuildHasher);
+
+        let mut nodes = Vec::with_capacity(self.roots.len());
+        for cursor in self.top_level() {
+            path.clear();
+            nodes.extend(self.visit_json(cursor, &mut visited, &mut path));
+        }
+
+        serde_json::to_string_pretty(&nodes)
+    }
+
+    /// Collect the packages and dependency edges to include in a graph rendering, in breadth-first
+    /// order from the roots, up to the provided depth.
+    fn flatten(&self) -> (Vec<&'env PackageId>, Vec<GraphEdge>) {
+        let mut packages = Vec::with_capacity(self.graph.node_count());
+        let mut indices =
+            FxHashMap::with_capacity_and_hasher(self.graph.node_count(), FxBuildHasher);
+        let mut edges = Vec::new();
+        let mut seen = FxHashSet::default();
+        let mut queue = VecDeque::new();
+
+        for cursor in self.top_level() {
+            let Node::Package(package_id) = self.graph[cursor.node()] else {
+                continue;
+            };
+            if let std::collections::hash_map::Entry::Vacant(entry) = indices.entry(cursor.node()) {
+                entry.insert(packages.len());
+                packages.push(package_id);
+                queue.push_back((cursor.node(), 0));
+            }
+        }
+
+        while let Some((node, depth)) = queue.pop_front() {
+            if depth >= self.depth {
+                continue;
+            }
+            for cursor in self.dependencies(node) {
+                let Node::Package(package_id) = self.graph[cursor.node()] else {
+                    continue;
+                };
+                let target = *indices.entry(cursor.node()).or_insert_with(|| {
+                    queue.push_back((cursor.node(), depth + 1));
+                    packages.push(package_id);
+                    packages.len() - 1
+                });
+                let label = cursor
+                    .edge()
+                    .and_then(|edge_id| self.graph[edge_id].label());
+                let edge = GraphEdge {
+                    source: indices[&node],
+                    target,
+                    label,
+                };
+                if seen.insert(edge.clone()) {
+                    edges.push(edge);
+                }
+            }
+        }
+
+        (packages, edges)
+    }
+
+    /// Return the label for a package in a graph rendering.
+    fn label(&self, package_id: &PackageId) -> String {
+        let mut label = package_id.name.to_string();
+        if let Some(version) = package_id.version.as_ref() {
+            let _ = write!(label, " v{version}");
+        }
+        if let Some(size_bytes) = self.size(package_id) {
+            let (bytes, unit) = human_readable_bytes(size_bytes);
+            let _ = write!(label, " ({bytes:.1}{unit})");
+        }
+        if let Some(version) = self.latest.get(package_id) {
+            let _ = write!(label, " (latest: v{version})");
+        }
+        label
+    }
+
+    /// Render the dependency graph in the Graphviz DOT format.
+    pub fn to_dot(&self) -> String {
+        let (packages, edges) = self.flatten();
+
+        let mut output = String::from("digraph {\n");
+        for (index, package_id) in packages.iter().enumerate() {
+            let _ = writeln!(
+                output,
+                "    n{index} [label=\"{}\"]",
+                self.label(package_id).replace('"', "\\\"")
+            );
+        }
+        for GraphEdge {
+            source,
+            target,
+            label,
+        } in edges
+        {
+            if let Some(label) = label {
+                let _ = writeln!(
+                    output,
+                    "    n{source} -> n{target} [label=\"{}\"]",
+                    label.replace('"', "\\\"")
+                );
+            } else {
+                let _ = writeln!(output, "    n{source} -> n{target}");
+            }
+        }
+        output.push_str("}\n");
+        output
+    }
+
+    /// Render the dependency graph as a Mermaid flowchart.
+    pub fn to_mermaid(&self) -> String {
+        let (packages, edges) = self.flatten();
+
+        let mut output = String::from("flowchart TD\n");
+        for (index, package_id) in packages.iter().enumerate() {
+            let _ = writeln!(
+                output,
+                "    n{index}[\"{}\"]",
+                self.label(package_id).replace('"', "#quot;")
+            );
+        }
+        for GraphEdge {
+            source,
+            target,
+            label,
+        } in edges
+        {
+            if let Some(label) = label {
+                let _ = writeln!(
+                    output,
+                    "    n{source} -->|\"{}\"| n{target}",
+                    label.replace('"', "#quot;")
+                );
+            } else {
+                let _ = writeln!(output, "    n{source} --> n{target}");
+            }
+        }
+        output
+    }
+
     /// Depth-first traverse the nodes to render the tree.
     fn render(&self) -> Vec<String> {
         let mut path = Vec::new();
@@ -635,6 +867,23 @@ impl<'env> Edge<'env> {
         }
     }
 
+    /// Return the label for the edge in a graph rendering, e.g., `extra: socks` or
+    /// `group: dev [socks]`.
+    fn label(&self) -> Option<String> {
+        let mut label = match self {
+            Self::Prod(_) => String::new(),
+            Self::Optional(extra, _) => format!("extra: {extra}"),
+            Self::Dev(group, _) => format!("group: {group}"),
+        };
+        if let Some(extras) = self.extras().filter(|extras| !extras.is_empty()) {
+            if !label.is_empty() {
+                label.push(' ');
+            }
+            let _ = write!(label, "[{}]", extras.iter().join(", "));
+        }
+        (!label.is_empty()).then_some(label)
+    }
+
     fn kind(&self) -> EdgeKind<'env> {
         match self {
             Self::Prod(_) => EdgeKind::Prod,
@@ -651,6 +900,42 @@ enum EdgeKind<'env> {
     Dev(&'env GroupName),
 }
 
+/// A package in the JSON rendering of the dependency tree.
+#[derive(Debug, serde::Serialize)]
+struct JsonNode<'env> {
+    name: &'env PackageName,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<&'env Version>,
+    /// The extras of the package that are enabled by the dependent.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    extras: Vec<&'env ExtraName>,
+    /// The extra of the dependent through which the package is required.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    extra: Option<&'env ExtraName>,
+    /// The dependency group of the dependent through which the package is required.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<&'env GroupName>,
+    /// The latest version of the package, if known and newer than the locked version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latest: Option<&'env Version>,
+    /// The compressed wheel size of the package, in bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<u64>,
+    /// Whether the package's dependencies were omitted, as they're displayed elsewhere in the
+    /// tree (or form a cycle).
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    deduplicated: bool,
+    dependencies: Vec<JsonNode<'env>>,
+}
+
+/// An edge in a graph rendering of the dependency tree, between the indices of two packages.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct GraphEdge {
+    source: usize,
+    target: usize,
+    label: Option<String>,
+}
+
 /// A node in the dependency graph along with the edge that led to it, or `None` for root nodes.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd)]
 struct Cursor(NodeIndex, Option<EdgeIndex>);
@@ -329,6 +329,13 @@ impl Preferences {
         })
     }
 
+    /// Returns an iterator over the entries for each package.
+    pub(crate) fn entries(&self) -> impl Iterator<Item = (&PackageName, &[Entry])> {
+        self.0
+            .iter()
+            .map(|(name, entries)| (name, entries.as_slice()))
+    }
+
     /// Return the pinned version for a package, if any.
     pub(crate) fn get(&self, package_name: &PackageName) -> &[Entry] {
         self.0
@@ -11,7 +11,9 @@ use uv_pypi_types::HashDigests;
 
 pub use crate::resolution::display::{AnnotationStyle, DisplayResolutionGraph};
 pub(crate) use crate::resolution::output::ResolutionGraphNode;
-pub use crate::resolution::output::{ConflictingDistributionError, ResolverOutput};
+pub use crate::resolution::output::{
+    ConflictingDistributionError, DivergentVersionsError, ResolverOutput,
+};
 pub(crate) use crate::resolution::requirements_txt::RequirementsTxtDist;
 use crate::universal_marker::UniversalMarker;
 
@@ -30,7 +30,8 @@ use crate::resolution_mode::ResolutionStrategy;
 use crate::resolver::{Resolution, ResolutionDependencyEdge, ResolutionPackage};
 use crate::universal_marker::{ConflictMarker, UniversalMarker};
 use crate::{
-    InMemoryIndex, MetadataResponse, Options, PythonRequirement, ResolveError, VersionsResponse,
+    ForkStrategy, InMemoryIndex, MetadataResponse, Options, PythonRequirement, ResolveError,
+    VersionExplanation, VersionsResponse,
 };
 
 /// The output of a successful resolution.
@@ -56,6 +57,8 @@ pub struct ResolverOutput {
     pub(crate) overrides: Overrides,
     /// The options that were used to build the graph.
     pub(crate) options: Options,
+    /// The explanations for the version selected for a package in each fork, if requested.
+    pub(crate) explanations: Vec<VersionExplanation>,
 }
 
 #[derive(Debug, Clone)]
@@ -134,6 +137,7 @@ impl ResolverOutput {
         conflicts: &Conflicts,
         resolution_strategy: &ResolutionStrategy,
         options: Options,
+        explanations: Vec<VersionExplanation>,
     ) -> Result<Self, ResolveError> {
         let size_guess = resolutions[0].nodes.len();
         let mut graph: Graph<ResolutionGraphNode, UniversalMarker, Directed> =
@@ -237,8 +241,19 @@ impl ResolverOutput {
             overrides: overrides.clone(),
             options,
             fork_markers,
+            explanations,
         };
 
+        // Under the `single` fork strategy, every package must be resolved to a single version.
+        if matches!(output.options.fork_strategy, ForkStrategy::Single) {
+            let packages = output.find_divergent_versions();
+            if !packages.is_empty() {
+                return Err(ResolveError::DivergentVersions(DivergentVersionsError {
+                    packages,
+                }));
+            }
+        }
+
         // We only do conflicting distribution detection when no
         // conflicting groups have been specified. The reason here
         // is that when there are conflicting groups, then from the
@@ -618,6 +633,12 @@ impl ResolverOutput {
         &self.diagnostics
     }
 
+    /// Return the [`VersionExplanation`]s for the requested package, one per fork in which it was
+    /// selected.
+    pub fn explanations(&self) -> &[VersionExplanation] {
+        &self.explanations
+    }
+
     /// Return the marker tree specific to this resolution.
     ///
     /// This accepts an in-memory-index and marker environment, all
@@ -811,6 +832,75 @@ impl ResolverOutput {
         }
         dupes
     }
+
+    /// Returns the packages that were resolved to more than one version, along with the markers
+    /// under which each version is selected.
+    fn find_divergent_versions(&self) -> Vec<DivergentPackage> {
+        let mut name_to_versions: BTreeMap<&PackageName, BTreeMap<&Version, UniversalMarker>> =
+            BTreeMap::new();
+        for node in self.graph.node_weights() {
+            let ResolutionGraphNode::Dist(annotated_dist) = node else {
+                continue;
+            };
+            if !annotated_dist.is_base() {
+                continue;
+            }
+            name_to_versions
+                .entry(&annotated_dist.name)
+                .or_default()
+                .entry(&annotated_dist.version)
+                .or_insert(UniversalMarker::FALSE)
+                .or(annotated_dist.marker);
+        }
+        name_to_versions
+            .into_iter()
+            .filter(|(_, versions)| versions.len() > 1)
+            .map(|(name, versions)| DivergentPackage {
+                name: name.clone(),
+                versions: versions
+                    .into_iter()
+                    .map(|(version, marker)| (version.clone(), marker))
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+/// An error that occurs when the `single` fork strategy is in effect, but the requirements force
+/// one or more packages to be resolved to different versions in different environments.
+#[derive(Debug)]
+pub struct DivergentVersionsError {
+    packages: Vec<DivergentPackage>,
+}
+
+/// A package that was resolved to different versions in different environments.
+#[derive(Debug)]
+struct DivergentPackage {
+    name: PackageName,
+    /// Each selected version, along with the marker under which it's selected.
+    versions: Vec<(Version, UniversalMarker)>,
+}
+
+impl std::error::Error for DivergentVersionsError {}
+
+impl Display for DivergentVersionsError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "The `single` fork strategy requires a single version of each package, but the \
+             requirements diverge across environments:"
+        )?;
+        for package in &self.packages {
+            write!(f, "\n  - `{}`:", package.name)?;
+            for (version, marker) in &package.versions {
+                match marker.combined().try_to_string() {
+                    Some(marker) => write!(f, "\n    - `{version}` for `{marker}`")?,
+                    None => write!(f, "\n    - `{version}` for all environments")?,
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 /// An error that occurs for conflicting versions of the same package.
@@ -1,3 +1,9 @@
+use rustc_hash::FxHashMap;
+
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+use crate::preferences::PreferenceSource;
 use crate::resolver::{ForkMap, ForkSet};
 use crate::{DependencyMode, Manifest, ResolverEnvironment};
 
@@ -14,6 +20,21 @@ pub enum ResolutionMode {
     /// Resolve the lowest compatible version of any direct dependencies, and the highest
     /// compatible version of any transitive dependencies.
     LowestDirect,
+    /// Make the smallest possible change to an existing lockfile.
+    ///
+    /// Versions pinned by the existing lockfile are retained unless a constraint (e.g.,
+    /// `--upgrade-package urllib3>=2.2.2`) rules them out, in which case the package moves to the
+    /// lowest compatible version above the pinned version. Packages that are not pinned by the
+    /// lockfile resolve to the highest compatible version.
+    Security,
+}
+
+impl ResolutionMode {
+    /// Returns `true` if the resolution mode retains the versions from an existing lockfile,
+    /// even when upgrading.
+    pub fn is_minimal(self) -> bool {
+        matches!(self, Self::Security)
+    }
 }
 
 impl std::fmt::Display for ResolutionMode {
@@ -22,6 +43,7 @@ impl std::fmt::Display for ResolutionMode {
             Self::Highest => write!(f, "highest"),
             Self::Lowest => write!(f, "lowest"),
             Self::LowestDirect => write!(f, "lowest-direct"),
+            Self::Security => write!(f, "security"),
         }
     }
 }
@@ -37,6 +59,10 @@ pub(crate) enum ResolutionStrategy {
     /// Resolve the lowest compatible version of any direct dependencies, and the highest
     /// compatible version of any transitive dependencies.
     LowestDirect(ForkSet),
+    /// Retain the versions pinned by an existing lockfile, moving any package that can't retain
+    /// its pin by the smallest possible step; resolve the highest compatible version of any other
+    /// package.
+    Security(FxHashMap<PackageName, Version>),
 }
 
 impl ResolutionStrategy {
@@ -56,6 +82,40 @@ impl ResolutionStrategy {
                 }
                 Self::LowestDirect(first_party)
             }
+            ResolutionMode::Security => {
+                // Track the lowest pinned version of each package in the existing lockfile (or output
+                // file).
+                let mut locked = FxHashMap::<PackageName, Version>::default();
+                for (name, entries) in manifest.preferences.entries() {
+                    for entry in entries {
+                        if !matches!(
+                            entry.source(),
+                            PreferenceSource::Lock | PreferenceSource::RequirementsTxt
+                        ) {
+                            continue;
+                        }
+                        let version = entry.pin().version();
+                        locked
+                            .entry(name.clone())
+                            .and_modify(|locked| {
+                                if version < locked {
+                                    *locked = version.clone();
+                                }
+                            })
+                            .or_insert_with(|| version.clone());
+                    }
+                }
+                Self::Security(locked)
+            }
+        }
+    }
+
+    /// Return the version of the package pinned by the existing lockfile, if the strategy
+    /// retains locked versions.
+    pub(crate) fn locked_version(&self, package_name: &PackageName) -> Option<&Version> {
+        match self {
+            Self::Security(locked) => locked.get(package_name),
+            _ => None,
         }
     }
 }
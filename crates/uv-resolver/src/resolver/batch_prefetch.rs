@@ -9,7 +9,7 @@ use tracing::{debug, trace};
 
 use crate::candidate_selector::CandidateSelector;
 use crate::pubgrub::{PubGrubPackage, PubGrubPackageInner};
-use crate::resolver::Request;
+use crate::resolver::{Request, ResolverProfile};
 use crate::{
     InMemoryIndex, PythonRequirement, ResolveError, ResolverEnvironment, VersionsResponse,
 };
@@ -60,6 +60,7 @@ pub(crate) struct BatchPrefetcherRunner {
     capabilities: IndexCapabilities,
     index: InMemoryIndex,
     request_sink: Sender<Request>,
+    profile: Option<Arc<ResolverProfile>>,
 }
 
 impl BatchPrefetcher {
@@ -67,6 +68,7 @@ impl BatchPrefetcher {
         capabilities: IndexCapabilities,
         index: InMemoryIndex,
         request_sink: Sender<Request>,
+        profile: Option<Arc<ResolverProfile>>,
     ) -> Self {
         Self {
             tried_versions: FxHashMap::default(),
@@ -75,6 +77,7 @@ impl BatchPrefetcher {
                 capabilities,
                 index,
                 request_sink,
+                profile,
             },
         }
     }
@@ -316,6 +319,9 @@ impl BatchPrefetcherRunner {
             prefetch_count += 1;
 
             if self.index.distributions().register(candidate.version_id()) {
+                if let Some(profile) = &self.profile {
+                    profile.on_prefetch(name, candidate.version());
+                }
                 let request = Request::from(dist);
                 self.request_sink.blocking_send(request)?;
             }
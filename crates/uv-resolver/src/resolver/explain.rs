@@ -0,0 +1,446 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use pubgrub::{
+    DefaultStringReporter, DerivationTree, Derived, External, Id, IncompId, Kind, Range, Reporter,
+    State, Term,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use uv_distribution_types::{
+    IncompatibleDist, IncompatibleSource, IncompatibleWheel, PrioritizedDist,
+};
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_platform_tags::Tags;
+
+use crate::candidate_selector::CandidateDist;
+use crate::dependency_provider::UvDependencyProvider;
+use crate::error::{
+    ErrorTree, NoSolutionError, collapse_unavailable_versions, simplify_derivation_tree_markers,
+};
+use crate::preferences::{Entry, PreferenceSource, Preferences};
+use crate::pubgrub::{PubGrubPackage, PubGrubReportFormatter};
+use crate::python_requirement::PythonRequirement;
+use crate::resolver::{ResolverEnvironment, UnavailableReason, UnavailableVersion};
+use crate::version_map::VersionMap;
+
+type IncompatibilityId = IncompId<PubGrubPackage, Range<Version>, UnavailableReason>;
+
+/// An explanation of why a package was resolved to a given version in a fork, rather than to a
+/// newer version.
+#[derive(Debug, Clone)]
+pub struct VersionExplanation {
+    /// The name of the explained package.
+    name: PackageName,
+    /// The version that was selected.
+    version: Version,
+    /// The fork in which the version was selected.
+    env: ResolverEnvironment,
+    /// The newer versions that were available, but not selected.
+    newer: Vec<Version>,
+    /// The incompatibilities that excluded the newer versions, formatted as derivation trees.
+    causes: Vec<String>,
+    /// The source of the preference for the selected version, if newer versions were skipped in
+    /// favor of it.
+    preference: Option<PreferenceSource>,
+    /// Whether newer versions were skipped because the resolution strategy prefers the lowest
+    /// compatible version.
+    lowest: bool,
+}
+
+impl VersionExplanation {
+    /// Explain the version of `name` that was selected in a solved fork.
+    ///
+    /// Returns `None` if the package is not part of the fork's solution.
+    pub(crate) fn from_state(
+        name: &PackageName,
+        state: &State<UvDependencyProvider>,
+        env: &ResolverEnvironment,
+        version_maps: &[VersionMap],
+        preferences: &Preferences,
+        lowest: bool,
+        python_requirement: &PythonRequirement,
+        workspace_members: &BTreeSet<PackageName>,
+        tags: Option<&Tags>,
+    ) -> Option<Self> {
+        let solution: FxHashMap<Id<PubGrubPackage>, Version> =
+            state.partial_solution.extract_solution().collect();
+
+        let base = PubGrubPackage::base(name);
+        let (id, version) = solution
+            .iter()
+            .find(|(id, _)| state.package_store[**id] == base)?;
+
+        // Collect the newer versions, omitting pre-releases unless a pre-release was selected.
+        let mut candidates: BTreeMap<&Version, &PrioritizedDist> = BTreeMap::new();
+        for version_map in version_maps {
+            for (candidate, dist) in version_map.iter(&Range::strictly_higher_than(version.clone()))
+            {
+                if candidate.any_prerelease() && !version.any_prerelease() {
+                    continue;
+                }
+                if let Some(dist) = dist.prioritized_dist() {
+                    candidates.entry(candidate).or_insert(dist);
+                }
+            }
+        }
+
+        // Find the incompatibilities that exclude the nearest rejected version. Any newer versions
+        // between the selected version and the nearest rejected version were never considered.
+        let mut newer = Vec::new();
+        let mut rejected = false;
+        let mut incompatibilities: Vec<IncompatibilityId> = Vec::new();
+        let mut unavailable: Option<(IncompatibleDist, Range<Version>)> = None;
+        let mut exclude_newer: Option<(IncompatibleDist, &Version)> = None;
+        let mut unexplained = false;
+        for (candidate, dist) in candidates {
+            let incompatible_dist = match CandidateDist::from(dist) {
+                CandidateDist::Compatible(_) => None,
+                CandidateDist::Incompatible {
+                    incompatible_dist, ..
+                } => Some(incompatible_dist),
+            };
+
+            // Like the candidate selector, treat versions excluded by `exclude-newer` as if they
+            // don't exist, but summarize them as a single cause starting at the lowest such
+            // version.
+            if let Some(
+                incompatible_dist @ (IncompatibleDist::Source(IncompatibleSource::ExcludeNewer(_))
+                | IncompatibleDist::Wheel(IncompatibleWheel::ExcludeNewer(_))),
+            ) = incompatible_dist
+            {
+                if !rejected {
+                    rejected = true;
+                    exclude_newer = Some((incompatible_dist, candidate));
+                }
+                continue;
+            }
+
+            newer.push(candidate.clone());
+
+            // Beyond the nearest rejected version, only extend the range of its cause.
+            if rejected {
+                if let (Some((reason, range)), Some(incompatible_dist)) =
+                    (unavailable.as_mut(), incompatible_dist)
+                {
+                    if *reason == incompatible_dist {
+                        *range = range.union(&Range::singleton(candidate.clone()));
+                    }
+                }
+                continue;
+            }
+
+            let mut visited = FxHashSet::default();
+            visited.insert(*id);
+            let causes = find_causes(*id, candidate, state, &solution, &mut visited);
+            if !causes.is_empty() {
+                rejected = true;
+                for cause in causes {
+                    if !incompatibilities.contains(&cause) {
+                        incompatibilities.push(cause);
+                    }
+                }
+                continue;
+            }
+
+            // Versions that were never tried (e.g., due to a preference) aren't tracked by
+            // PubGrub, so fall back to the distribution's own incompatibility, if any.
+            if let Some(incompatible_dist) =
+                incompatible_dist.filter(|dist| !matches!(dist, IncompatibleDist::Unavailable))
+            {
+                rejected = true;
+                unavailable = Some((incompatible_dist, Range::singleton(candidate.clone())));
+                continue;
+            }
+
+            unexplained = true;
+        }
+
+        let trees = incompatibilities
+            .into_iter()
+            .map(|incompatibility| derivation_tree(incompatibility, state))
+            .chain(
+                unavailable
+                    .into_iter()
+                    .chain(
+                        exclude_newer
+                            .map(|(reason, lowest)| (reason, Range::higher_than(lowest.clone()))),
+                    )
+                    .map(|(reason, range)| {
+                        DerivationTree::External(External::Custom(
+                            base.clone(),
+                            range,
+                            UnavailableReason::Version(UnavailableVersion::IncompatibleDist(
+                                reason,
+                            )),
+                        ))
+                    }),
+            );
+
+        let available_versions = FxHashMap::from_iter([(
+            name.clone(),
+            version_maps
+                .iter()
+                .flat_map(VersionMap::versions)
+                .cloned()
+                .collect::<BTreeSet<_>>(),
+        )]);
+        let formatter = PubGrubReportFormatter {
+            available_versions: &available_versions,
+            python_requirement,
+            workspace_members,
+            tags,
+        };
+        let causes = trees
+            .map(|tree| {
+                let mut tree = NoSolutionError::collapse_local_version_segments(
+                    NoSolutionError::collapse_proxies(tree),
+                );
+                simplify_derivation_tree_markers(python_requirement, &mut tree);
+                collapse_unavailable_versions(&mut tree);
+                DefaultStringReporter::report_with_formatter(&tree, &formatter)
+            })
+            .unique()
+            .collect();
+
+        // If any newer version was never considered, it was skipped in favor of a preference (e.g.,
+        // from the lockfile) or due to the resolution strategy.
+        let preference = unexplained
+            .then(|| {
+                preferences
+                    .get(name)
+                    .iter()
+                    .find(|entry| {
+                        entry.pin().version() == version
+                            && env.included_by_marker(entry.marker().pep508())
+                    })
+                    .map(Entry::source)
+            })
+            .flatten();
+
+        Some(Self {
+            name: name.clone(),
+            version: version.clone(),
+            env: env.clone(),
+            newer,
+            causes,
+            preference,
+            lowest: unexplained && lowest,
+        })
+    }
+
+    /// Return the name of the explained package.
+    pub fn name(&self) -> &PackageName {
+        &self.name
+    }
+
+    /// Return the version that was selected.
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+}
+
+impl Display for VersionExplanation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let package = format!("{}=={}", self.name, self.version);
+        let split = self
+            .env
+            .end_user_fork_display()
+            .map(|split| format!(" for {split}"))
+            .unwrap_or_default();
+
+        if self.newer.is_empty() && self.causes.is_empty() {
+            return write!(
+                f,
+                "{} is the newest available version{split}",
+                package.bold()
+            );
+        }
+
+        let newer = match self.newer.as_slice() {
+            [] => String::new(),
+            [version] => format!(" a newer version ({version})"),
+            versions @ [.., last] if versions.len() > 5 => format!(
+                " {} newer versions ({}, ..., {last})",
+                versions.len(),
+                versions.iter().take(3).join(", ")
+            ),
+            versions => format!(" newer versions ({})", versions.iter().join(", ")),
+        };
+        write!(
+            f,
+            "{} was selected over{}{split} because:",
+            package.bold(),
+            if newer.is_empty() {
+                " newer versions"
+            } else {
+                &newer
+            }
+        )?;
+
+        for cause in &self.causes {
+            let mut lines = cause.lines();
+            if let Some(first) = lines.next() {
+                write!(f, "\n  - {first}")?;
+            }
+            for line in lines {
+                write!(f, "\n    {line}")?;
+            }
+        }
+
+        if let Some(source) = self.preference {
+            let reason = match source {
+                PreferenceSource::Lock => "the existing lockfile",
+                PreferenceSource::Environment => "the installed environment",
+                PreferenceSource::RequirementsTxt => "the output file",
+                PreferenceSource::Resolver => "another fork of the resolution",
+            };
+            write!(f, "\n  - {package} is preferred by {reason}")?;
+            if matches!(
+                source,
+                PreferenceSource::Lock | PreferenceSource::RequirementsTxt
+            ) {
+                write!(
+                    f,
+                    " (use `{}` to ignore it)",
+                    format!("--upgrade-package {}", self.name).green()
+                )?;
+            }
+        } else if self.lowest {
+            write!(
+                f,
+                "\n  - the resolution strategy prefers the lowest compatible version of {}",
+                self.name
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Find the incompatibilities that exclude `version` of the package `id`, given the solution of
+/// the fork.
+///
+/// An incompatibility excludes a version if its term for the package contains the version, and all
+/// of its other terms are satisfied by the solution. Incompatibilities that arise from a proxy of
+/// the same package (e.g., `foo[bar]` requiring `foo`) are followed to the constraint on the proxy.
+fn find_causes(
+    id: Id<PubGrubPackage>,
+    version: &Version,
+    state: &State<UvDependencyProvider>,
+    solution: &FxHashMap<Id<PubGrubPackage>, Version>,
+    visited: &mut FxHashSet<Id<PubGrubPackage>>,
+) -> Vec<IncompatibilityId> {
+    let Some(incompatibilities) = state.incompatibilities.get(&id) else {
+        return Vec::new();
+    };
+
+    let name = state.package_store[id].name_no_root();
+
+    let mut external = Vec::new();
+    let mut derived = Vec::new();
+    for index in incompatibilities {
+        let incompat = &state.incompatibility_store[*index];
+
+        let mut excludes = false;
+        let mut satisfied = true;
+        let mut proxy = None;
+        for (package, term) in incompat.iter() {
+            if package == id {
+                excludes = term_contains(term, version);
+            } else if term_contains_solution(term, solution.get(&package)) {
+                let package_ref = &state.package_store[package];
+                if package_ref.is_proxy() && package_ref.name_no_root() == name {
+                    proxy = Some(package);
+                }
+            } else {
+                satisfied = false;
+                break;
+            }
+        }
+        if !excludes || !satisfied {
+            continue;
+        }
+
+        let causes = if let Some(proxy) = proxy {
+            if !visited.insert(proxy) {
+                continue;
+            }
+            find_causes(proxy, version, state, solution, visited)
+        } else {
+            vec![*index]
+        };
+
+        if matches!(incompat.kind, Kind::DerivedFrom(..)) {
+            derived.extend(causes);
+        } else {
+            external.extend(causes);
+        }
+    }
+
+    // Prefer external incompatibilities, which are more direct than those derived during conflict
+    // resolution.
+    if external.is_empty() {
+        derived
+    } else {
+        external
+    }
+}
+
+/// Returns `true` if the term contains the given version.
+fn term_contains(term: &Term<Range<Version>>, version: &Version) -> bool {
+    match term {
+        Term::Positive(range) => range.contains(version),
+        Term::Negative(range) => !range.contains(version),
+    }
+}
+
+/// Returns `true` if the term is satisfied by the version selected in the solution, if any.
+fn term_contains_solution(term: &Term<Range<Version>>, version: Option<&Version>) -> bool {
+    match (term, version) {
+        (term, Some(version)) => term_contains(term, version),
+        (Term::Positive(_), None) => false,
+        (Term::Negative(_), None) => true,
+    }
+}
+
+/// Build a [`DerivationTree`] for an incompatibility in the PubGrub state.
+fn derivation_tree(id: IncompatibilityId, state: &State<UvDependencyProvider>) -> ErrorTree {
+    let incompat = &state.incompatibility_store[id];
+    match &incompat.kind {
+        Kind::DerivedFrom(cause1, cause2) => DerivationTree::Derived(Derived {
+            terms: incompat
+                .iter()
+                .map(|(package, term)| (state.package_store[package].clone(), term.clone()))
+                .collect(),
+            shared_id: None,
+            cause1: Arc::new(derivation_tree(*cause1, state)),
+            cause2: Arc::new(derivation_tree(*cause2, state)),
+        }),
+        Kind::NotRoot(package, version) => DerivationTree::External(External::NotRoot(
+            state.package_store[*package].clone(),
+            version.clone(),
+        )),
+        Kind::NoVersions(package, set) => DerivationTree::External(External::NoVersions(
+            state.package_store[*package].clone(),
+            set.clone(),
+        )),
+        Kind::FromDependencyOf(package, set, dependency, dependency_set) => {
+            DerivationTree::External(External::FromDependencyOf(
+                state.package_store[*package].clone(),
+                set.clone(),
+                state.package_store[*dependency].clone(),
+                dependency_set.clone(),
+            ))
+        }
+        Kind::Custom(package, set, reason) => DerivationTree::External(External::Custom(
+            state.package_store[*package].clone(),
+            set.clone(),
+            reason.clone(),
+        )),
+    }
+}
@@ -5,6 +5,8 @@ use rustc_hash::FxHasher;
 use uv_distribution_types::{IndexUrl, VersionId};
 use uv_normalize::PackageName;
 use uv_once_map::OnceMap;
+use uv_pep440::Version;
+use uv_pypi_types::PackageLicense;
 
 use crate::resolver::provider::{MetadataResponse, VersionsResponse};
 
@@ -22,6 +24,9 @@ struct SharedInMemoryIndex {
 
     /// A map from package ID to metadata for that distribution.
     distributions: FxOnceMap<VersionId, Arc<MetadataResponse>>,
+
+    /// A map from package name and version to the license declared by that package.
+    licenses: FxOnceMap<(PackageName, Version), Option<PackageLicense>>,
 }
 
 pub(crate) type FxOnceMap<K, V> = OnceMap<K, V, BuildHasherDefault<FxHasher>>;
@@ -41,4 +46,9 @@ impl InMemoryIndex {
     pub fn distributions(&self) -> &FxOnceMap<VersionId, Arc<MetadataResponse>> {
         &self.0.distributions
     }
+
+    /// Returns a reference to the package license map.
+    pub fn licenses(&self) -> &FxOnceMap<(PackageName, Version), Option<PackageLicense>> {
+        &self.0.licenses
+    }
 }
@@ -66,15 +66,21 @@ pub use crate::resolver::environment::ResolverEnvironment;
 use crate::resolver::environment::{
     ForkingPossibility, fork_version_by_marker, fork_version_by_python_requirement,
 };
+pub use crate::resolver::explain::VersionExplanation;
 pub(crate) use crate::resolver::fork_map::{ForkMap, ForkSet};
 pub use crate::resolver::index::InMemoryIndex;
 use crate::resolver::indexes::Indexes;
+use crate::resolver::profile::{ForkReason, SpanCategory};
+pub use crate::resolver::profile::{
+    PROFILE_REPORT, PROFILE_TRACE, ResolverProfile, ResolverProfileError,
+};
 pub use crate::resolver::provider::{
-    DefaultResolverProvider, MetadataResponse, PackageVersionsResult, ResolverProvider,
-    VersionsResponse, WheelMetadataResult,
+    DefaultResolverProvider, MetadataResponse, PackageVersionsResult, RecordingResolverProvider,
+    ReplayResolverProvider, ResolverProvider, VersionsResponse, WheelMetadataResult,
 };
 pub use crate::resolver::reporter::{BuildId, Reporter};
 use crate::resolver::system::SystemDependency;
+pub use crate::resolver::trace::{ResolverTrace, ResolverTraceError, TraceMode};
 pub(crate) use crate::resolver::urls::Urls;
 use crate::universal_marker::{ConflictMarker, UniversalMarker};
 use crate::yanks::AllowedYanks;
@@ -88,12 +94,15 @@ mod availability;
 mod batch_prefetch;
 mod derivation;
 mod environment;
+mod explain;
 mod fork_map;
 mod index;
 mod indexes;
+mod profile;
 mod provider;
 mod reporter;
 mod system;
+mod trace;
 mod urls;
 
 /// The number of conflicts a package may accumulate before we re-prioritize and backtrack.
@@ -137,8 +146,12 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     incomplete_packages: DashMap<PackageName, DashMap<Version, MetadataUnavailable>>,
     /// The options that were used to configure this resolver.
     options: Options,
+    /// The package for which to explain the selected version in each fork, if any.
+    explain: Option<PackageName>,
     /// The reporter to use for this resolver.
     reporter: Option<Arc<dyn Reporter>>,
+    /// The profile in which to record where time is spent, if any.
+    profile: Option<Arc<ResolverProfile>>,
 }
 
 impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
@@ -255,7 +268,9 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             unavailable_packages: DashMap::default(),
             incomplete_packages: DashMap::default(),
             options,
+            explain: None,
             reporter: None,
+            profile: None,
         };
         Ok(Self { state, provider })
     }
@@ -274,10 +289,60 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
         }
     }
 
+    /// Explain why the given package was resolved to its selected version in each fork.
+    #[must_use]
+    pub fn with_explain(self, package: PackageName) -> Self {
+        Self {
+            state: ResolverState {
+                explain: Some(package),
+                ..self.state
+            },
+            provider: self.provider,
+        }
+    }
+
+    /// Record where time is spent during resolution in the given [`ResolverProfile`].
+    #[must_use]
+    pub fn with_profile(self, profile: Arc<ResolverProfile>) -> Self {
+        Self {
+            state: ResolverState {
+                profile: Some(profile),
+                ..self.state
+            },
+            provider: self.provider,
+        }
+    }
+
+    /// Wrap the [`ResolverProvider`], e.g., to record or replay its responses.
+    pub fn map_provider<WrappedProvider: ResolverProvider>(
+        self,
+        f: impl FnOnce(Provider) -> WrappedProvider,
+    ) -> Resolver<WrappedProvider, InstalledPackages> {
+        Resolver {
+            state: self.state,
+            provider: f(self.provider),
+        }
+    }
+
     /// Resolve a set of requirements into a set of pinned versions.
     pub async fn resolve(self) -> Result<ResolverOutput, ResolveError> {
-        let state = Arc::new(self.state);
-        let provider = Arc::new(self.provider);
+        // If profiling, wrap the reporter to time builds and downloads.
+        let (state, provider) = match &self.state.profile {
+            Some(profile) => {
+                let reporter = profile.reporter(self.state.reporter.clone());
+                (
+                    ResolverState {
+                        reporter: Some(reporter.clone()),
+                        ..self.state
+                    },
+                    self.provider
+                        .with_reporter(reporter.into_distribution_reporter()),
+                )
+            }
+            None => (self.state, self.provider),
+        };
+        let state = Arc::new(state);
+        let provider = Arc::new(provider);
 
         // A channel to fetch package metadata (e.g., given `flask`, fetch all versions) and version
         // metadata (e.g., given `flask==1.0.0`, fetch the metadata for that version).
@@ -333,16 +398,22 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             self.capabilities.clone(),
             self.index.clone(),
             request_sink.clone(),
+            self.profile.clone(),
         );
         let state = ForkState::new(
             pubgrub,
             self.env.clone(),
             self.python_requirement.clone(),
             prefetcher,
+            self.profile.clone(),
         );
         let mut preferences = self.preferences.clone();
         let mut forked_states = self.env.initial_forked_states(state)?;
+        if forked_states.len() > 1 {
+            self.on_fork(ForkReason::Initial, None, &[], forked_states.len());
+        }
         let mut resolutions = vec![];
+        let mut explanations = vec![];
 
         'FORK: while let Some(mut state) = forked_states.pop() {
             if let Some(split) = state.env.end_user_fork_display() {
@@ -363,6 +434,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                         match result {
                             Err(err) => {
                                 // If unit propagation failed, there is no solution.
+                                self.on_fork_complete(&state.env, start);
                                 return Err(self.convert_no_solution_err(
                                     err,
                                     state.fork_urls,
@@ -397,7 +469,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                             )?;
                         }
 
-                        Self::reprioritize_conflicts(&mut state);
+                        self.reprioritize_conflicts(&mut state);
 
                         trace!(
                             "Assigned packages: {}",
@@ -426,6 +498,11 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                 state.env,
                                 start.elapsed().as_secs_f32()
                             );
+                            self.on_fork_complete(&state.env, start);
+
+                            if let Some(package) = &self.explain {
+                                explanations.extend(self.explain(package, &state, &preferences));
+                            }
 
                             let resolution = state.into_resolution();
 
@@ -437,9 +514,24 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                             // direct or transitive, skip preferences, as we risk adding a preference from
                             // one fork (in which it's a transitive dependency) to another fork (in which
                             // it's direct).
-                            if matches!(
+                            //
+                            // Under the `single` fork strategy, the selected versions are preferred
+                            // in all other forks, to avoid diverging.
+                            if matches!(self.options.fork_strategy, ForkStrategy::Single) {
+                                for (package, version) in &resolution.nodes {
+                                    preferences.insert(
+                                        package.name.clone(),
+                                        package.index.clone(),
+                                        UniversalMarker::TRUE,
+                                        version.clone(),
+                                        PreferenceSource::Resolver,
+                                    );
+                                }
+                            } else if matches!(
                                 self.options.resolution_mode,
-                                ResolutionMode::Lowest | ResolutionMode::Highest
+                                ResolutionMode::Lowest
+                                    | ResolutionMode::Highest
+                                    | ResolutionMode::Security
                             ) {
                                 for (package, version) in &resolution.nodes {
                                     preferences.insert(
@@ -561,6 +653,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     let version = match version {
                         ResolverVersion::Unforked(version) => version,
                         ResolverVersion::Forked(forks) => {
+                            self.on_fork_complete(&state.env, start);
                             forked_states.extend(self.version_forks_to_fork_states(state, forks));
                             continue 'FORK;
                         }
@@ -591,6 +684,9 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 };
 
                 state.prefetcher.version_tried(next_package, &version);
+                if let (Some(profile), Some(name)) = (&self.profile, next_package.name_no_root()) {
+                    profile.on_version_tried(name, &version);
+                }
 
                 self.on_progress(next_package, &version);
 
@@ -668,10 +764,18 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                             state.env,
                             start.elapsed().as_secs_f32()
                         );
+                        self.on_fork_complete(&state.env, start);
+                        self.on_fork(
+                            ForkReason::Markers,
+                            state.pubgrub.package_store[state.next].name_no_root(),
+                            &diverging_packages,
+                            forks.len(),
+                        );
 
                         // Prioritize the forks.
                         match (self.options.fork_strategy, self.options.resolution_mode) {
-                            (ForkStrategy::Fewest, _) | (_, ResolutionMode::Lowest) => {
+                            (ForkStrategy::Fewest | ForkStrategy::Single, _)
+                            | (_, ResolutionMode::Lowest) => {
                                 // Prefer solving forks with lower Python bounds, since they're more
                                 // likely to produce solutions that work for forks with higher
                                 // Python bounds (whereas the inverse is not true).
@@ -741,13 +845,14 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             &self.conflicts,
             self.selector.resolution_strategy(),
             self.options.clone(),
+            explanations,
         )
     }
 
     /// Change the priority of often conflicting packages and backtrack.
     ///
     /// To be called after unit propagation.
-    fn reprioritize_conflicts(state: &mut ForkState) {
+    fn reprioritize_conflicts(&self, state: &mut ForkState) {
         for package in state.conflict_tracker.prioritize.drain(..) {
             let changed = state
                 .priorities
@@ -778,6 +883,12 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 let backtrack_level = state.pubgrub.backtrack_package(package);
                 if let Some(backtrack_level) = backtrack_level {
                     debug!("Backtracked {backtrack_level} decisions");
+                    if let (Some(profile), Some(name)) = (
+                        &self.profile,
+                        state.pubgrub.package_store[package].name_no_root(),
+                    ) {
+                        profile.on_backtrack(name, backtrack_level);
+                    }
                 } else {
                     debug!(
                         "Package {} is not decided, cannot backtrack",
@@ -1368,6 +1479,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                 .collect::<Vec<_>>()
                                 .join(", ")
                         );
+                        self.on_fork(ForkReason::RequiresPython, Some(name), &[], forks.len());
                         let forks = forks
                             .into_iter()
                             .map(|env| VersionFork {
@@ -1476,8 +1588,14 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 if dist.implied_markers().is_disjoint(marker)
                     && !find_environments(id, pubgrub).is_disjoint(marker)
                 {
-                    // Then we need to fork.
-                    let Some((left, right)) = fork_version_by_marker(env, marker) else {
+                    // Then we need to fork. Under the `single` fork strategy, reject the version
+                    // instead, to select a version that supports all required platforms.
+                    let fork = if matches!(self.options.fork_strategy, ForkStrategy::Single) {
+                        None
+                    } else {
+                        fork_version_by_marker(env, marker)
+                    };
+                    let Some((left, right)) = fork else {
                         return Ok(Some(ResolverVersion::Unavailable(
                             candidate.version().clone(),
                             UnavailableVersion::IncompatibleDist(IncompatibleDist::Wheel(
@@ -1497,6 +1615,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                             .collect::<Vec<_>>()
                             .join(", ")
                     );
+                    self.on_fork(ForkReason::RequiredEnvironment, Some(name), &[], 2);
                     let forks = vec![
                         VersionFork {
                             env: left,
@@ -1623,6 +1742,11 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             }
         }
 
+        // Under the `single` fork strategy, never select different versions across platforms.
+        if matches!(self.options.fork_strategy, ForkStrategy::Single) {
+            return Ok(None);
+        }
+
         // Otherwise, we need to fork.
         let Some((base_env, local_env)) = fork_version_by_marker(env, remainder) else {
             return Ok(None);
@@ -1638,6 +1762,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 .collect::<Vec<_>>()
                 .join(", ")
         );
+        self.on_fork(ForkReason::LocalVersion, Some(name), &[], 2);
         self.visit_candidate(candidate, dist, package, name, pins, request_sink)?;
         self.visit_candidate(
             &base_candidate,
@@ -2410,11 +2535,17 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         match request {
             // Fetch package metadata from the registry.
             Request::Package(package_name, index) => {
+                let span = self.profile.as_ref().map(|profile| {
+                    profile.span(SpanCategory::Versions, Some(&package_name), &package_name)
+                });
                 let package_versions = provider
                     .get_package_versions(&package_name, index.as_ref())
                     .boxed_local()
                     .await
                     .map_err(ResolveError::Client)?;
+                if let Some(span) = span {
+                    span.finish();
+                }
 
                 Ok(Some(Response::Package(
                     package_name,
@@ -2472,10 +2603,17 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                     }
                 }
 
+                let span = self
+                    .profile
+                    .as_ref()
+                    .map(|profile| profile.span(SpanCategory::Metadata, Some(dist.name()), &dist));
                 let metadata = provider
                     .get_or_build_wheel_metadata(&dist)
                     .boxed_local()
                     .await?;
+                if let Some(span) = span {
+                    span.finish();
+                }
 
                 if let MetadataResponse::Found(metadata) = &metadata {
                     if &metadata.metadata.name != dist.name() {
@@ -2654,10 +2792,16 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
 
                     let response = match dist {
                         ResolvedDist::Installable { dist, .. } => {
+                            let span = self.profile.as_ref().map(|profile| {
+                                profile.span(SpanCategory::Metadata, Some(dist.name()), &dist)
+                            });
                             let metadata = provider
                                 .get_or_build_wheel_metadata(&dist)
                                 .boxed_local()
                                 .await?;
+                            if let Some(span) = span {
+                                span.finish();
+                            }
 
                             Response::Dist {
                                 dist: (*dist).clone(),
@@ -2683,6 +2827,41 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         }
     }
 
+    /// Explain the version selected for a package in a solved fork.
+    fn explain(
+        &self,
+        name: &PackageName,
+        state: &ForkState,
+        preferences: &Preferences,
+    ) -> Option<VersionExplanation> {
+        // Packages from URLs don't have any other versions to choose from.
+        let versions_response = if state.fork_urls.get(name).is_some() {
+            None
+        } else if let Some(index) = state.fork_indexes.get(name) {
+            self.index
+                .explicit()
+                .get(&(name.clone(), index.url().clone()))
+        } else {
+            self.index.implicit().get(name)
+        };
+        let version_maps = match versions_response.as_deref() {
+            Some(VersionsResponse::Found(version_maps)) => version_maps.as_slice(),
+            _ => &[],
+        };
+
+        VersionExplanation::from_state(
+            name,
+            &state.pubgrub,
+            &state.env,
+            version_maps,
+            preferences,
+            !self.selector.use_highest_version(name, &state.env),
+            &state.python_requirement,
+            &self.workspace_members,
+            self.tags.as_ref(),
+        )
+    }
+
     fn convert_no_solution_err(
         &self,
         mut err: pubgrub::NoSolutionError<UvDependencyProvider>,
@@ -2823,6 +3002,26 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             reporter.on_complete();
         }
     }
+
+    fn on_fork(
+        &self,
+        reason: ForkReason,
+        package: Option<&PackageName>,
+        diverging: &[PackageName],
+        forks: usize,
+    ) {
+        if let Some(profile) = self.profile.as_ref() {
+            profile.on_fork(reason, package, diverging, forks);
+        }
+    }
+
+    /// Record the time spent solving the given fork, up to the point at which it was solved,
+    /// failed, or split into further forks.
+    fn on_fork_complete(&self, env: &ResolverEnvironment, start: Instant) {
+        if let Some(profile) = self.profile.as_ref() {
+            profile.on_solve(env, start);
+        }
+    }
 }
 
 /// State that is used during unit propagation in the resolver, one instance per fork.
@@ -2907,6 +3106,8 @@ pub(crate) struct ForkState {
     ///
     /// Tracked on the fork state to avoid counting each identical version between forks as new try.
     prefetcher: BatchPrefetcher,
+    /// The profile in which to record conflicts, if any.
+    profile: Option<Arc<ResolverProfile>>,
 }
 
 impl ForkState {
@@ -2915,6 +3116,7 @@ impl ForkState {
         env: ResolverEnvironment,
         python_requirement: PythonRequirement,
         prefetcher: BatchPrefetcher,
+        profile: Option<Arc<ResolverProfile>>,
     ) -> Self {
         Self {
             initial_id: None,
@@ -2930,6 +3132,7 @@ impl ForkState {
             python_requirement,
             conflict_tracker: ConflictTracker::default(),
             prefetcher,
+            profile,
         }
     }
 
@@ -3128,6 +3331,12 @@ impl ForkState {
                 }
             }
 
+            if let Some(profile) = &self.profile {
+                if let Some(name) = self.pubgrub.package_store[self.next].name_no_root() {
+                    profile.on_conflict(name);
+                }
+            }
+
             let affected_count = self.conflict_tracker.affected.entry(self.next).or_default();
             *affected_count += 1;
             if *affected_count == CONFLICT_THRESHOLD {
@@ -0,0 +1,890 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
+
+use uv_distribution_types::{BuildableSource, VersionOrUrlRef};
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_redacted::DisplaySafeUrl;
+
+use crate::resolver::reporter::Reporter;
+
+/// The name of the JSON report written by [`ResolverProfile::write`].
+pub const PROFILE_REPORT: &str = "profile.json";
+
+/// The name of the Chrome trace-event file written by [`ResolverProfile::write`].
+pub const PROFILE_TRACE: &str = "trace.json";
+
+#[derive(Debug, thiserror::Error)]
+pub enum ResolverProfileError {
+    #[error("Failed to write resolver profile to: `{}`", _0.user_display())]
+    Write(PathBuf, #[source] std::io::Error),
+}
+
+/// The kind of work performed in a timed [`ProfileSpan`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum SpanCategory {
+    /// Fetching the available versions of a package from an index.
+    Versions,
+    /// Fetching (or building) the metadata for a distribution.
+    Metadata,
+    /// Building a source distribution.
+    Build,
+    /// Downloading a distribution.
+    Download,
+    /// Checking out a Git repository.
+    Checkout,
+    /// Solving a single fork with PubGrub.
+    Solve,
+}
+
+/// The reason the resolver split into multiple forks.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum ForkReason {
+    /// The resolver started from multiple forks, e.g., those of an existing lockfile or those
+    /// implied by conflicting extras and groups.
+    Initial,
+    /// A package has dependencies with diverging markers.
+    Markers,
+    /// A candidate version requires a narrower Python version than the project.
+    RequiresPython,
+    /// A candidate version lacks wheels for one of the `required-environments`.
+    RequiredEnvironment,
+    /// A local version (e.g., `+cpu`) is only available on some platforms.
+    LocalVersion,
+}
+
+/// A profile of where time is spent during a resolution, e.g., fetching metadata, building
+/// source distributions, or backtracking in PubGrub.
+///
+/// The profile is shared between the solver thread, the fetcher, and the [`Reporter`], and can be
+/// written to disk with [`ResolverProfile::write`] once the resolution completes (or fails).
+#[derive(Debug)]
+pub struct ResolverProfile {
+    start: Instant,
+    data: Mutex<ProfileData>,
+}
+
+#[derive(Debug, Default)]
+struct ProfileData {
+    /// The completed spans, in order of completion.
+    spans: Vec<CompletedSpan>,
+    /// The per-package statistics.
+    packages: BTreeMap<PackageName, PackageProfile>,
+    /// Every fork created during resolution, in order of creation.
+    forks: Vec<ForkEvent>,
+    /// Every backtrack performed during resolution, in order.
+    backtracks: Vec<BacktrackEvent>,
+    /// The versions for which metadata was requested by the batch prefetcher.
+    prefetched: FxHashSet<(PackageName, Version)>,
+    /// The versions tried by the solver, across all forks.
+    tried: FxHashSet<(PackageName, Version)>,
+}
+
+#[derive(Debug)]
+struct CompletedSpan {
+    category: SpanCategory,
+    package: Option<PackageName>,
+    name: String,
+    start: Duration,
+    duration: Duration,
+}
+
+#[derive(Debug)]
+struct ForkEvent {
+    reason: ForkReason,
+    package: Option<PackageName>,
+    diverging: Vec<PackageName>,
+    forks: usize,
+    at: Duration,
+}
+
+#[derive(Debug)]
+struct BacktrackEvent {
+    package: PackageName,
+    decisions: u32,
+    at: Duration,
+}
+
+#[derive(Debug, Default)]
+struct PackageProfile {
+    versions: Timing,
+    metadata: Timing,
+    builds: Timing,
+    downloads: Timing,
+    versions_tried: usize,
+    conflicts: usize,
+    backtracks: usize,
+    decisions_backtracked: u64,
+    forks: usize,
+    prefetched: usize,
+    prefetch_hits: usize,
+}
+
+impl PackageProfile {
+    fn total(&self) -> Duration {
+        self.versions.duration + self.metadata.duration + self.builds.duration
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone)]
+struct Timing {
+    count: usize,
+    duration: Duration,
+}
+
+impl Timing {
+    fn add(&mut self, duration: Duration) {
+        self.count += 1;
+        self.duration += duration;
+    }
+}
+
+impl Default for ResolverProfile {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResolverProfile {
+    /// Start a new [`ResolverProfile`], measuring from now.
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            data: Mutex::new(ProfileData::default()),
+        }
+    }
+
+    /// Wrap a [`Reporter`] such that builds, downloads, and checkouts are timed in this profile.
+    pub fn reporter(self: &Arc<Self>, inner: Option<Arc<dyn Reporter>>) -> Arc<dyn Reporter> {
+        Arc::new(ProfileReporter {
+            inner,
+            profile: Arc::clone(self),
+            next_id: AtomicUsize::new(0),
+            pending: Mutex::new(FxHashMap::default()),
+        })
+    }
+
+    /// Start a timed span of the given category.
+    pub(crate) fn span(
+        self: &Arc<Self>,
+        category: SpanCategory,
+        package: Option<&PackageName>,
+        name: impl Display,
+    ) -> ProfileSpan {
+        ProfileSpan {
+            profile: Arc::clone(self),
+            category,
+            package: package.cloned(),
+            name: name.to_string(),
+            start: Instant::now(),
+        }
+    }
+
+    /// Record that the resolver split into the given number of forks while selecting a version
+    /// of `package`.
+    ///
+    /// For forks on diverging markers, `diverging` contains the dependencies of `package` with
+    /// diverging markers, which are credited with the fork; otherwise, `package` is credited.
+    pub(crate) fn on_fork(
+        &self,
+        reason: ForkReason,
+        package: Option<&PackageName>,
+        diverging: &[PackageName],
+        forks: usize,
+    ) {
+        let at = self.start.elapsed();
+        let mut data = self.data.lock().unwrap();
+        if diverging.is_empty() {
+            if let Some(package) = package {
+                data.packages.entry(package.clone()).or_default().forks += 1;
+            }
+        } else {
+            for package in diverging {
+                data.packages.entry(package.clone()).or_default().forks += 1;
+            }
+        }
+        data.forks.push(ForkEvent {
+            reason,
+            package: package.cloned(),
+            diverging: diverging.to_vec(),
+            forks,
+            at,
+        });
+    }
+
+    /// Record a conflict affecting the given package.
+    pub(crate) fn on_conflict(&self, package: &PackageName) {
+        let mut data = self.data.lock().unwrap();
+        data.packages.entry(package.clone()).or_default().conflicts += 1;
+    }
+
+    /// Record that the given package was deprioritized after repeated conflicts, backtracking
+    /// over the given number of decisions.
+    pub(crate) fn on_backtrack(&self, package: &PackageName, decisions: u32) {
+        let at = self.start.elapsed();
+        let mut data = self.data.lock().unwrap();
+        let profile = data.packages.entry(package.clone()).or_default();
+        profile.backtracks += 1;
+        profile.decisions_backtracked += u64::from(decisions);
+        data.backtracks.push(BacktrackEvent {
+            package: package.clone(),
+            decisions,
+            at,
+        });
+    }
+
+    /// Record that the batch prefetcher requested the metadata for the given version.
+    pub(crate) fn on_prefetch(&self, package: &PackageName, version: &Version) {
+        let mut data = self.data.lock().unwrap();
+        let key = (package.clone(), version.clone());
+        if data.tried.contains(&key) || !data.prefetched.insert(key) {
+            return;
+        }
+        data.packages.entry(package.clone()).or_default().prefetched += 1;
+    }
+
+    /// Record that the solver tried the given version.
+    pub(crate) fn on_version_tried(&self, package: &PackageName, version: &Version) {
+        let mut data = self.data.lock().unwrap();
+        let key = (package.clone(), version.clone());
+        let hit = data.prefetched.contains(&key);
+        if !data.tried.insert(key) {
+            return;
+        }
+        let profile = data.packages.entry(package.clone()).or_default();
+        profile.versions_tried += 1;
+        if hit {
+            profile.prefetch_hits += 1;
+        }
+    }
+
+    /// Record the time spent solving a fork in the given environment, which started at `start`.
+    pub(crate) fn on_solve(&self, env: impl Display, start: Instant) {
+        self.record(SpanCategory::Solve, None, env.to_string(), start);
+    }
+
+    fn record(
+        &self,
+        category: SpanCategory,
+        package: Option<PackageName>,
+        name: String,
+        start: Instant,
+    ) {
+        let duration = start.elapsed();
+        let start = start.saturating_duration_since(self.start);
+        let mut data = self.data.lock().unwrap();
+        if let Some(package) = &package {
+            let profile = data.packages.entry(package.clone()).or_default();
+            match category {
+                SpanCategory::Versions => profile.versions.add(duration),
+                SpanCategory::Metadata => profile.metadata.add(duration),
+                SpanCategory::Build => profile.builds.add(duration),
+                SpanCategory::Download => profile.downloads.add(duration),
+                SpanCategory::Checkout | SpanCategory::Solve => {}
+            }
+        }
+        data.spans.push(CompletedSpan {
+            category,
+            package,
+            name,
+            start,
+            duration,
+        });
+    }
+
+    /// Write the profile to the given directory, as a JSON report and a Chrome trace-event file.
+    ///
+    /// The trace can be opened in `chrome://tracing` or <https://ui.perfetto.dev>.
+    pub fn write(&self, directory: &Path) -> Result<(), ResolverProfileError> {
+        let duration = self.start.elapsed();
+        let data = self.data.lock().unwrap();
+
+        fs_err::create_dir_all(directory)
+            .map_err(|err| ResolverProfileError::Write(directory.to_path_buf(), err))?;
+
+        for (file, contents) in [
+            (
+                PROFILE_REPORT,
+                serde_json::to_string_pretty(&data.report(duration)),
+            ),
+            (PROFILE_TRACE, serde_json::to_string(&data.trace())),
+        ] {
+            let path = directory.join(file);
+            let mut contents =
+                contents.map_err(|err| ResolverProfileError::Write(path.clone(), err.into()))?;
+            contents.push('\n');
+            fs_err::write(&path, contents).map_err(|err| ResolverProfileError::Write(path, err))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A span of work that is being timed in a [`ResolverProfile`].
+///
+/// The span is only recorded once [`ProfileSpan::finish`] is called, such that work that never
+/// completes (e.g., a failed build) is omitted from the profile.
+#[must_use]
+#[derive(Debug)]
+pub(crate) struct ProfileSpan {
+    profile: Arc<ResolverProfile>,
+    category: SpanCategory,
+    package: Option<PackageName>,
+    name: String,
+    start: Instant,
+}
+
+impl ProfileSpan {
+    /// Record the span in its profile.
+    pub(crate) fn finish(self) {
+        self.profile
+            .record(self.category, self.package, self.name, self.start);
+    }
+}
+
+impl ProfileData {
+    fn report(&self, duration: Duration) -> ProfileReport<'_> {
+        let mut packages = self
+            .packages
+            .iter()
+            .map(|(name, profile)| PackageReport::new(name, profile))
+            .collect::<Vec<_>>();
+        packages.sort_by(|a, b| b.total.cmp(&a.total).then_with(|| a.name.cmp(b.name)));
+
+        let mut by_reason = BTreeMap::new();
+        for fork in &self.forks {
+            *by_reason.entry(fork.reason).or_default() += fork.forks;
+        }
+
+        let requested = self.prefetched.len();
+        let hits = self
+            .prefetched
+            .iter()
+            .filter(|key| self.tried.contains(*key))
+            .count();
+
+        ProfileReport {
+            duration_ms: millis(duration),
+            solve_ms: millis(
+                self.spans
+                    .iter()
+                    .filter(|span| span.category == SpanCategory::Solve)
+                    .map(|span| span.duration)
+                    .sum(),
+            ),
+            packages,
+            forks: ForkReport {
+                total: by_reason.values().sum(),
+                by_reason,
+                events: self
+                    .forks
+                    .iter()
+                    .map(|fork| ForkEventReport {
+                        reason: fork.reason,
+                        package: fork.package.as_ref(),
+                        diverging: &fork.diverging,
+                        forks: fork.forks,
+                    })
+                    .collect(),
+            },
+            prefetch: PrefetchReport {
+                requested,
+                hits,
+                hit_rate: if requested == 0 {
+                    None
+                } else {
+                    #[allow(clippy::cast_precision_loss)]
+                    Some(hits as f64 / requested as f64)
+                },
+            },
+        }
+    }
+
+    fn trace(&self) -> TraceFile {
+        let mut events = vec![TraceEvent::thread_name(0, "solver")];
+
+        // Solver spans are sequential, so they share a single thread. Any other spans may
+        // overlap, so assign them to the first lane that's free at the time they start.
+        let mut spans = self.spans.iter().collect::<Vec<_>>();
+        spans.sort_by_key(|span| span.start);
+        let mut lanes: Vec<Duration> = Vec::new();
+        for span in spans {
+            let tid = if span.category == SpanCategory::Solve {
+                0
+            } else {
+                let named = lanes.len();
+                let lane = assign_lane(&mut lanes, span.start, span.start + span.duration);
+                if lanes.len() > named {
+                    events.push(TraceEvent::thread_name(
+                        lane + 1,
+                        &format!("fetch {}", lane + 1),
+                    ));
+                }
+                lane + 1
+            };
+            events.push(TraceEvent {
+                name: span.name.clone(),
+                cat: Some(span.category),
+                ph: "X",
+                ts: micros(span.start),
+                dur: Some(micros(span.duration)),
+                pid: 1,
+                tid,
+                s: None,
+                args: span
+                    .package
+                    .as_ref()
+                    .map(|package| serde_json::json!({ "package": package })),
+            });
+        }
+
+        for fork in &self.forks {
+            events.push(TraceEvent {
+                name: match &fork.package {
+                    Some(package) => format!("fork on {package}"),
+                    None => "fork".to_string(),
+                },
+                cat: None,
+                ph: "i",
+                ts: micros(fork.at),
+                dur: None,
+                pid: 1,
+                tid: 0,
+                s: Some("t"),
+                args: Some(serde_json::json!({
+                    "reason": fork.reason,
+                    "diverging": fork.diverging,
+                    "forks": fork.forks,
+                })),
+            });
+        }
+
+        for backtrack in &self.backtracks {
+            events.push(TraceEvent {
+                name: format!("backtrack {}", backtrack.package),
+                cat: None,
+                ph: "i",
+                ts: micros(backtrack.at),
+                dur: None,
+                pid: 1,
+                tid: 0,
+                s: Some("t"),
+                args: Some(serde_json::json!({ "decisions": backtrack.decisions })),
+            });
+        }
+
+        TraceFile {
+            trace_events: events,
+        }
+    }
+}
+
+/// Assign a span to the first lane that is free at `start`, opening a new lane if necessary.
+fn assign_lane(lanes: &mut Vec<Duration>, start: Duration, end: Duration) -> usize {
+    if let Some(lane) = lanes.iter().position(|free| *free <= start) {
+        lanes[lane] = end;
+        lane
+    } else {
+        lanes.push(end);
+        lanes.len() - 1
+    }
+}
+
+/// Convert a [`Duration`] to milliseconds, rounded to the nearest microsecond.
+#[allow(clippy::cast_precision_loss)]
+fn millis(duration: Duration) -> f64 {
+    duration.as_micros() as f64 / 1000.0
+}
+
+/// Convert a [`Duration`] to microseconds, the unit used by the trace-event format.
+fn micros(duration: Duration) -> u64 {
+    u64::try_from(duration.as_micros()).unwrap_or(u64::MAX)
+}
+
+#[derive(Debug, Serialize)]
+struct ProfileReport<'a> {
+    duration_ms: f64,
+    solve_ms: f64,
+    packages: Vec<PackageReport<'a>>,
+    forks: ForkReport<'a>,
+    prefetch: PrefetchReport,
+}
+
+#[derive(Debug, Serialize)]
+struct PackageReport<'a> {
+    name: &'a PackageName,
+    #[serde(skip)]
+    total: Duration,
+    versions: TimingReport,
+    metadata: TimingReport,
+    builds: TimingReport,
+    downloads: TimingReport,
+    versions_tried: usize,
+    conflicts: usize,
+    backtracks: usize,
+    decisions_backtracked: u64,
+    forks: usize,
+    prefetch: PackagePrefetchReport,
+}
+
+impl<'a> PackageReport<'a> {
+    fn new(name: &'a PackageName, profile: &PackageProfile) -> Self {
+        Self {
+            name,
+            total: profile.total(),
+            versions: TimingReport::from(profile.versions),
+            metadata: TimingReport::from(profile.metadata),
+            builds: TimingReport::from(profile.builds),
+            downloads: TimingReport::from(profile.downloads),
+            versions_tried: profile.versions_tried,
+            conflicts: profile.conflicts,
+            backtracks: profile.backtracks,
+            decisions_backtracked: profile.decisions_backtracked,
+            forks: profile.forks,
+            prefetch: PackagePrefetchReport {
+                requested: profile.prefetched,
+                hits: profile.prefetch_hits,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct TimingReport {
+    count: usize,
+    duration_ms: f64,
+}
+
+impl From<Timing> for TimingReport {
+    fn from(timing: Timing) -> Self {
+        Self {
+            count: timing.count,
+            duration_ms: millis(timing.duration),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct PackagePrefetchReport {
+    requested: usize,
+    hits: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct ForkReport<'a> {
+    total: usize,
+    by_reason: BTreeMap<ForkReason, usize>,
+    events: Vec<ForkEventReport<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct ForkEventReport<'a> {
+    reason: ForkReason,
+    package: Option<&'a PackageName>,
+    #[serde(skip_serializing_if = "<[PackageName]>::is_empty")]
+    diverging: &'a [PackageName],
+    forks: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct PrefetchReport {
+    requested: usize,
+    hits: usize,
+    hit_rate: Option<f64>,
+}
+
+/// A file in the Chrome trace-event format.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TraceFile {
+    trace_events: Vec<TraceEvent>,
+}
+
+#[derive(Debug, Serialize)]
+struct TraceEvent {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cat: Option<SpanCategory>,
+    ph: &'static str,
+    ts: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dur: Option<u64>,
+    pid: u32,
+    tid: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    s: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    args: Option<serde_json::Value>,
+}
+
+impl TraceEvent {
+    /// A metadata event that names the given thread.
+    fn thread_name(tid: usize, name: &str) -> Self {
+        Self {
+            name: "thread_name".to_string(),
+            cat: None,
+            ph: "M",
+            ts: 0,
+            dur: None,
+            pid: 1,
+            tid,
+            s: None,
+            args: Some(serde_json::json!({ "name": name })),
+        }
+    }
+}
+
+/// A [`Reporter`] that times builds, downloads, and checkouts in a [`ResolverProfile`], before
+/// forwarding each event to the wrapped [`Reporter`], if any.
+struct ProfileReporter {
+    inner: Option<Arc<dyn Reporter>>,
+    profile: Arc<ResolverProfile>,
+    /// The ID to assign to the next event, if there's no wrapped [`Reporter`] to assign one.
+    next_id: AtomicUsize,
+    /// The spans that have started, but not yet completed.
+    pending: Mutex<FxHashMap<(SpanCategory, usize), ProfileSpan>>,
+}
+
+impl ProfileReporter {
+    fn start(&self, category: SpanCategory, id: usize, span: ProfileSpan) -> usize {
+        self.pending.lock().unwrap().insert((category, id), span);
+        id
+    }
+
+    fn complete(&self, category: SpanCategory, id: usize) {
+        if let Some(span) = self.pending.lock().unwrap().remove(&(category, id)) {
+            span.finish();
+        }
+    }
+
+    fn id(&self, inner: impl FnOnce(&dyn Reporter) -> usize) -> usize {
+        match self.inner.as_deref() {
+            Some(reporter) => inner(reporter),
+            None => self.next_id.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+}
+
+impl Reporter for ProfileReporter {
+    fn on_progress(&self, name: &PackageName, version: &VersionOrUrlRef) {
+        if let Some(reporter) = self.inner.as_deref() {
+            reporter.on_progress(name, version);
+        }
+    }
+
+    fn on_complete(&self) {
+        if let Some(reporter) = self.inner.as_deref() {
+            reporter.on_complete();
+        }
+    }
+
+    fn on_build_start(&self, source: &BuildableSource) -> usize {
+        let span = self
+            .profile
+            .span(SpanCategory::Build, source.name(), source);
+        let id = self.id(|reporter| reporter.on_build_start(source));
+        self.start(SpanCategory::Build, id, span)
+    }
+
+    fn on_build_complete(&self, source: &BuildableSource, id: usize) {
+        self.complete(SpanCategory::Build, id);
+        if let Some(reporter) = self.inner.as_deref() {
+            reporter.on_build_complete(source, id);
+        }
+    }
+
+    fn on_download_start(&self, name: &PackageName, size: Option<u64>) -> usize {
+        let span = self.profile.span(SpanCategory::Download, Some(name), name);
+        let id = self.id(|reporter| reporter.on_download_start(name, size));
+        self.start(SpanCategory::Download, id, span)
+    }
+
+    fn on_download_progress(&self, id: usize, bytes: u64) {
+        if let Some(reporter) = self.inner.as_deref() {
+            reporter.on_download_progress(id, bytes);
+        }
+    }
+
+    fn on_download_complete(&self, name: &PackageName, id: usize) {
+        self.complete(SpanCategory::Download, id);
+        if let Some(reporter) = self.inner.as_deref() {
+            reporter.on_download_complete(name, id);
+        }
+    }
+
+    fn on_checkout_start(&self, url: &DisplaySafeUrl, rev: &str) -> usize {
+        let span = self
+            .profile
+            .span(SpanCategory::Checkout, None, format!("{url}@{rev}"));
+        let id = self.id(|reporter| reporter.on_checkout_start(url, rev));
+        self.start(SpanCategory::Checkout, id, span)
+    }
+
+    fn on_checkout_complete(&self, url: &DisplaySafeUrl, rev: &str, id: usize) {
+        self.complete(SpanCategory::Checkout, id);
+        if let Some(reporter) = self.inner.as_deref() {
+            reporter.on_checkout_complete(url, rev, id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use uv_normalize::PackageName;
+    use uv_pep440::Version;
+
+    use super::{ForkReason, ResolverProfile, assign_lane};
+
+    #[test]
+    fn lanes() {
+        let ms = Duration::from_millis;
+        let mut lanes = Vec::new();
+        assert_eq!(assign_lane(&mut lanes, ms(0), ms(10)), 0);
+        assert_eq!(assign_lane(&mut lanes, ms(5), ms(8)), 1);
+        assert_eq!(assign_lane(&mut lanes, ms(8), ms(20)), 1);
+        assert_eq!(assign_lane(&mut lanes, ms(9), ms(12)), 2);
+        assert_eq!(assign_lane(&mut lanes, ms(10), ms(11)), 0);
+        assert_eq!(lanes.len(), 3);
+    }
+
+    #[test]
+    fn report() {
+        let anyio = PackageName::from_str("anyio").unwrap();
+        let idna = PackageName::from_str("idna").unwrap();
+        let v1 = Version::from_str("4.1.0").unwrap();
+        let v2 = Version::from_str("4.2.0").unwrap();
+        let v3 = Version::from_str("4.3.0").unwrap();
+
+        let profile = ResolverProfile::new();
+        profile.on_prefetch(&anyio, &v1);
+        profile.on_prefetch(&anyio, &v2);
+        profile.on_version_tried(&anyio, &v3);
+        profile.on_version_tried(&anyio, &v2);
+        profile.on_version_tried(&anyio, &v2);
+        profile.on_conflict(&anyio);
+        profile.on_backtrack(&anyio, 3);
+        profile.on_fork(
+            ForkReason::Markers,
+            Some(&anyio),
+            std::slice::from_ref(&idna),
+            2,
+        );
+        profile.on_fork(ForkReason::RequiresPython, Some(&anyio), &[], 2);
+        profile.on_fork(ForkReason::Markers, None, std::slice::from_ref(&idna), 3);
+
+        let data = profile.data.lock().unwrap();
+        let report = serde_json::to_string_pretty(&data.report(Duration::ZERO)).unwrap();
+        insta::assert_snapshot!(report, @r#"
+        {
+          "duration_ms": 0.0,
+          "solve_ms": 0.0,
+          "packages": [
+            {
+              "name": "anyio",
+              "versions": {
+                "count": 0,
+                "duration_ms": 0.0
+              },
+              "metadata": {
+                "count": 0,
+                "duration_ms": 0.0
+              },
+              "builds": {
+                "count": 0,
+                "duration_ms": 0.0
+              },
+              "downloads": {
+                "count": 0,
+                "duration_ms": 0.0
+              },
+              "versions_tried": 2,
+              "conflicts": 1,
+              "backtracks": 1,
+              "decisions_backtracked": 3,
+              "forks": 1,
+              "prefetch": {
+                "requested": 2,
+                "hits": 1
+              }
+            },
+            {
+              "name": "idna",
+              "versions": {
+                "count": 0,
+                "duration_ms": 0.0
+              },
+              "metadata": {
+                "count": 0,
+                "duration_ms": 0.0
+              },
+              "builds": {
+                "count": 0,
+                "duration_ms": 0.0
+              },
+              "downloads": {
+                "count": 0,
+                "duration_ms": 0.0
+              },
+              "versions_tried": 0,
+              "conflicts": 0,
+              "backtracks": 0,
+              "decisions_backtracked": 0,
+              "forks": 2,
+              "prefetch": {
+                "requested": 0,
+                "hits": 0
+              }
+            }
+          ],
+          "forks": {
+            "total": 7,
+            "by_reason": {
+              "markers": 5,
+              "requires-python": 2
+            },
+            "events": [
+              {
+                "reason": "markers",
+                "package": "anyio",
+                "diverging": [
+                  "idna"
+                ],
+                "forks": 2
+              },
+              {
+                "reason": "requires-python",
+                "package": "anyio",
+                "forks": 2
+              },
+              {
+                "reason": "markers",
+                "package": null,
+                "diverging": [
+                  "idna"
+                ],
+                "forks": 3
+              }
+            ]
+          },
+          "prefetch": {
+            "requested": 2,
+            "hits": 1,
+            "hit_rate": 0.5
+          }
+        }
+        "#);
+    }
+}
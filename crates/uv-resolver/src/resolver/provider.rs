@@ -1,5 +1,5 @@
 use std::future::Future;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use uv_client::MetadataFormat;
 use uv_configuration::BuildOptions;
 use uv_distribution::{ArchiveMetadata, DistributionDatabase, Reporter};
@@ -14,6 +14,7 @@ use uv_types::{BuildContext, HashStrategy};
 
 use crate::ExcludeNewer;
 use crate::flat_index::FlatIndex;
+use crate::resolver::trace::ResolverTrace;
 use crate::version_map::VersionMap;
 use crate::yanks::AllowedYanks;
 
@@ -316,3 +317,129 @@ impl<Context: BuildContext> ResolverProvider for DefaultResolverProvider<'_, Con
         }
     }
 }
+
+/// A [`ResolverProvider`] that records every package and metadata response from the wrapped
+/// provider into a [`ResolverTrace`].
+pub struct RecordingResolverProvider<Provider: ResolverProvider> {
+    inner: Provider,
+    trace: Arc<Mutex<ResolverTrace>>,
+}
+
+impl<Provider: ResolverProvider> RecordingResolverProvider<Provider> {
+    /// Wrap the given provider, recording its responses into the shared [`ResolverTrace`].
+    pub fn new(inner: Provider, trace: Arc<Mutex<ResolverTrace>>) -> Self {
+        Self { inner, trace }
+    }
+}
+
+impl<Provider: ResolverProvider> ResolverProvider for RecordingResolverProvider<Provider> {
+    async fn get_package_versions<'io>(
+        &'io self,
+        package_name: &'io PackageName,
+        index: Option<&'io IndexMetadata>,
+    ) -> PackageVersionsResult {
+        let result = self.inner.get_package_versions(package_name, index).await;
+        if let Ok(response) = &result {
+            self.trace
+                .lock()
+                .unwrap()
+                .record_versions(package_name, index, response);
+        }
+        result
+    }
+
+    async fn get_or_build_wheel_metadata<'io>(&'io self, dist: &'io Dist) -> WheelMetadataResult {
+        let result = self.inner.get_or_build_wheel_metadata(dist).await;
+        if let Ok(response) = &result {
+            self.trace.lock().unwrap().record_metadata(dist, response);
+        }
+        result
+    }
+
+    async fn get_installed_metadata<'io>(
+        &'io self,
+        dist: &'io InstalledDist,
+    ) -> WheelMetadataResult {
+        self.inner.get_installed_metadata(dist).await
+    }
+
+    fn with_reporter(self, reporter: Arc<dyn Reporter>) -> Self {
+        Self {
+            inner: self.inner.with_reporter(reporter),
+            ..self
+        }
+    }
+}
+
+/// A [`ResolverProvider`] that answers package and metadata requests from a [`ResolverTrace`],
+/// without network access.
+///
+/// Compatibility (e.g., with the target platform) is recomputed using the settings of the
+/// wrapped [`DefaultResolverProvider`], which is also used to read the metadata of local
+/// distributions, as those aren't recorded in the trace.
+pub struct ReplayResolverProvider<'a, Context: BuildContext> {
+    inner: DefaultResolverProvider<'a, Context>,
+    trace: ResolverTrace,
+}
+
+impl<'a, Context: BuildContext> ReplayResolverProvider<'a, Context> {
+    /// Replay the given [`ResolverTrace`], falling back to the given provider for local
+    /// distributions.
+    pub fn new(inner: DefaultResolverProvider<'a, Context>, trace: ResolverTrace) -> Self {
+        Self { inner, trace }
+    }
+}
+
+impl<Context: BuildContext> ResolverProvider for ReplayResolverProvider<'_, Context> {
+    async fn get_package_versions<'io>(
+        &'io self,
+        package_name: &'io PackageName,
+        index: Option<&'io IndexMetadata>,
+    ) -> PackageVersionsResult {
+        let Some(trace) = self.trace.versions(package_name, index) else {
+            return Err(uv_client::ErrorKind::Io(std::io::Error::other(format!(
+                "No response for `{package_name}` was recorded in the resolver trace"
+            )))
+            .into());
+        };
+        trace.to_response(
+            package_name,
+            self.inner.tags.as_ref(),
+            &self.inner.requires_python,
+            &self.inner.allowed_yanks,
+            &self.inner.hasher,
+            &self.inner.exclude_newer,
+            self.inner.build_options,
+        )
+    }
+
+    async fn get_or_build_wheel_metadata<'io>(&'io self, dist: &'io Dist) -> WheelMetadataResult {
+        if dist.is_local() {
+            return self.inner.get_or_build_wheel_metadata(dist).await;
+        }
+        let Some(trace) = self.trace.metadata(dist) else {
+            let err = uv_client::Error::from(uv_client::ErrorKind::Io(std::io::Error::other(
+                format!("No metadata for `{dist}` was recorded in the resolver trace"),
+            )));
+            return Ok(MetadataResponse::Error(
+                Box::new(RequestedDist::Installable(dist.clone())),
+                Arc::new(err.into()),
+            ));
+        };
+        Ok(trace.to_response())
+    }
+
+    async fn get_installed_metadata<'io>(
+        &'io self,
+        dist: &'io InstalledDist,
+    ) -> WheelMetadataResult {
+        self.inner.get_installed_metadata(dist).await
+    }
+
+    fn with_reporter(self, reporter: Arc<dyn Reporter>) -> Self {
+        Self {
+            inner: self.inner.with_reporter(reporter),
+            ..self
+        }
+    }
+}
@@ -0,0 +1,549 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use uv_client::{
+    FlatIndexEntry, OwnedArchive, SimpleDetailMetadata, SimpleDetailMetadatum, VersionFiles,
+};
+use uv_configuration::BuildOptions;
+use uv_distribution::ArchiveMetadata;
+use uv_distribution_filename::DistFilename;
+use uv_distribution_types::{
+    Dist, DistributionMetadata, File, IndexMetadata, IndexUrl, RequiresPython,
+};
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::{Version, VersionSpecifiers};
+use uv_platform_tags::Tags;
+use uv_pypi_types::ResolutionMetadata;
+use uv_types::HashStrategy;
+
+use crate::ExcludeNewer;
+use crate::flat_index::FlatDistributions;
+use crate::resolver::provider::{MetadataResponse, MetadataUnavailable, VersionsResponse};
+use crate::version_map::VersionMap;
+use crate::yanks::AllowedYanks;
+
+/// Whether to record the responses observed by the resolver, or to replay them from a trace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceMode {
+    /// Record every package and metadata response to the given trace file.
+    Record(PathBuf),
+    /// Answer every package and metadata request from the given trace file.
+    Replay(PathBuf),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ResolverTraceError {
+    #[error("Failed to read resolver trace at: `{}`", _0.user_display())]
+    Read(PathBuf, #[source] std::io::Error),
+    #[error("Failed to parse resolver trace at: `{}`", _0.user_display())]
+    Parse(PathBuf, #[source] serde_json::Error),
+    #[error("Failed to write resolver trace to: `{}`", _0.user_display())]
+    Write(PathBuf, #[source] std::io::Error),
+}
+
+/// The package and metadata responses observed by a [`ResolverProvider`] over the course of a
+/// resolution.
+///
+/// A trace is portable: local distributions (e.g., workspace members) are not recorded, and are
+/// instead read from disk when the trace is replayed.
+///
+/// [`ResolverProvider`]: crate::ResolverProvider
+#[derive(Debug, Default, Clone)]
+pub struct ResolverTrace {
+    /// The versions response for each package, keyed by the package name and the URL of the
+    /// index to which the request was pinned, if any.
+    packages: BTreeMap<(PackageName, Option<String>), VersionsTrace>,
+    /// The metadata response for each distribution, keyed by its version ID.
+    distributions: BTreeMap<String, MetadataTrace>,
+}
+
+impl ResolverTrace {
+    /// Read a [`ResolverTrace`] from the given path.
+    pub fn read(path: &Path) -> Result<Self, ResolverTraceError> {
+        let contents = fs_err::read_to_string(path)
+            .map_err(|err| ResolverTraceError::Read(path.to_path_buf(), err))?;
+        let wire = serde_json::from_str::<ResolverTraceWire>(&contents)
+            .map_err(|err| ResolverTraceError::Parse(path.to_path_buf(), err))?;
+        Ok(Self::from(wire))
+    }
+
+    /// Write the [`ResolverTrace`] to the given path.
+    pub fn write(&self, path: &Path) -> Result<(), ResolverTraceError> {
+        let mut contents = serde_json::to_string_pretty(&ResolverTraceWire::from(self.clone()))
+            .map_err(|err| ResolverTraceError::Write(path.to_path_buf(), err.into()))?;
+        contents.push('\n');
+        fs_err::write(path, contents)
+            .map_err(|err| ResolverTraceError::Write(path.to_path_buf(), err))
+    }
+
+    /// Record the versions response for a package.
+    pub(crate) fn record_versions(
+        &mut self,
+        package_name: &PackageName,
+        index: Option<&IndexMetadata>,
+        response: &VersionsResponse,
+    ) {
+        let response = match response {
+            VersionsResponse::Found(version_maps) => VersionsTrace::Found {
+                version_maps: version_maps.iter().map(VersionMapTrace::from).collect(),
+            },
+            VersionsResponse::NotFound => VersionsTrace::NotFound,
+            VersionsResponse::NoIndex => VersionsTrace::NoIndex,
+            VersionsResponse::Offline => VersionsTrace::Offline,
+        };
+        self.packages
+            .insert((package_name.clone(), index.map(index_key)), response);
+    }
+
+    /// Return the recorded versions response for a package, if any.
+    pub(crate) fn versions(
+        &self,
+        package_name: &PackageName,
+        index: Option<&IndexMetadata>,
+    ) -> Option<&VersionsTrace> {
+        self.packages
+            .get(&(package_name.clone(), index.map(index_key)))
+    }
+
+    /// Record the metadata response for a distribution.
+    ///
+    /// Responses for local distributions, along with errors that can't be reproduced from a
+    /// trace, are not recorded.
+    pub(crate) fn record_metadata(&mut self, dist: &Dist, response: &MetadataResponse) {
+        if dist.is_local() {
+            return;
+        }
+        let response = match response {
+            MetadataResponse::Found(metadata) => MetadataTrace::Found(Box::new(metadata.clone())),
+            MetadataResponse::Unavailable(MetadataUnavailable::Offline) => MetadataTrace::Offline,
+            MetadataResponse::Unavailable(MetadataUnavailable::RequiresPython(
+                requires_python,
+                python_version,
+            )) => MetadataTrace::RequiresPython {
+                requires_python: requires_python.clone(),
+                python_version: python_version.clone(),
+            },
+            MetadataResponse::Unavailable(_) | MetadataResponse::Error(..) => return,
+        };
+        self.distributions
+            .insert(dist.version_id().to_string(), response);
+    }
+
+    /// Return the recorded metadata response for a distribution, if any.
+    pub(crate) fn metadata(&self, dist: &Dist) -> Option<&MetadataTrace> {
+        self.distributions.get(&dist.version_id().to_string())
+    }
+}
+
+/// Return the key for an index in a [`ResolverTrace`], omitting any credentials.
+fn index_key(index: &IndexMetadata) -> String {
+    index.url.without_credentials().to_string()
+}
+
+/// A recorded response to a request for the versions of a package.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "response", rename_all = "kebab-case")]
+pub(crate) enum VersionsTrace {
+    Found { version_maps: Vec<VersionMapTrace> },
+    NotFound,
+    NoIndex,
+    Offline,
+}
+
+impl VersionsTrace {
+    /// Reconstruct the [`VersionsResponse`] from the trace, as of the given resolver settings.
+    pub(crate) fn to_response(
+        &self,
+        package_name: &PackageName,
+        tags: Option<&Tags>,
+        requires_python: &RequiresPython,
+        allowed_yanks: &AllowedYanks,
+        hasher: &HashStrategy,
+        exclude_newer: &ExcludeNewer,
+        build_options: &BuildOptions,
+    ) -> Result<VersionsResponse, uv_client::Error> {
+        match self {
+            Self::Found { version_maps } => Ok(VersionsResponse::Found(
+                version_maps
+                    .iter()
+                    .map(|version_map| {
+                        version_map.to_version_map(
+                            package_name,
+                            tags,
+                            requires_python,
+                            allowed_yanks,
+                            hasher,
+                            exclude_newer,
+                            build_options,
+                        )
+                    })
+                    .collect::<Result<_, _>>()?,
+            )),
+            Self::NotFound => Ok(VersionsResponse::NotFound),
+            Self::NoIndex => Ok(VersionsResponse::NoIndex),
+            Self::Offline => Ok(VersionsResponse::Offline),
+        }
+    }
+}
+
+/// The files (and any core metadata) that make up a [`VersionMap`].
+///
+/// Compatibility with the current platform, `requires-python`, and so on isn't recorded, but is
+/// recomputed on replay.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub(crate) enum VersionMapTrace {
+    /// A version map built from an index's simple metadata, possibly supplemented by a flat
+    /// index.
+    Simple {
+        index: IndexUrl,
+        versions: Vec<VersionTrace>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        flat: Vec<FlatFileTrace>,
+    },
+    /// A version map built entirely from a flat index.
+    Flat { files: Vec<FlatFileTrace> },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct VersionTrace {
+    version: Version,
+    files: Vec<File>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    metadata: Option<ResolutionMetadata>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct FlatFileTrace {
+    index: IndexUrl,
+    #[serde(flatten)]
+    file: File,
+}
+
+impl From<&VersionMap> for VersionMapTrace {
+    fn from(version_map: &VersionMap) -> Self {
+        let flat = version_map
+            .flat_dists()
+            .flat_map(uv_distribution_types::PrioritizedDist::indexed_files)
+            .map(|(file, index)| FlatFileTrace {
+                index: index.clone(),
+                file: file.clone(),
+            })
+            .collect();
+        match (version_map.index(), version_map.simple_metadata()) {
+            (Some(index), Some(simple_metadata)) => Self::Simple {
+                index: index.clone(),
+                versions: simple_metadata
+                    .into_iter()
+                    .map(|datum| VersionTrace {
+                        version: datum.version,
+                        files: datum.files.all().map(|(_, file)| file).collect(),
+                        metadata: datum.metadata,
+                    })
+                    .collect(),
+                flat,
+            },
+            _ => Self::Flat { files: flat },
+        }
+    }
+}
+
+impl VersionMapTrace {
+    /// Reconstruct the [`VersionMap`] from the trace.
+    fn to_version_map(
+        &self,
+        package_name: &PackageName,
+        tags: Option<&Tags>,
+        requires_python: &RequiresPython,
+        allowed_yanks: &AllowedYanks,
+        hasher: &HashStrategy,
+        exclude_newer: &ExcludeNewer,
+        build_options: &BuildOptions,
+    ) -> Result<VersionMap, uv_client::Error> {
+        let flat_entries = |files: &[FlatFileTrace]| {
+            files
+                .iter()
+                .filter_map(|FlatFileTrace { index, file }| {
+                    let filename = DistFilename::try_from_filename(&file.filename, package_name)?;
+                    Some(FlatIndexEntry {
+                        filename,
+                        file: file.clone(),
+                        index: index.clone(),
+                    })
+                })
+                .collect::<Vec<_>>()
+        };
+
+        match self {
+            Self::Simple {
+                index,
+                versions,
+                flat,
+            } => {
+                let simple_metadata = versions
+                    .iter()
+                    .map(|version| {
+                        let mut files = VersionFiles::default();
+                        for file in &version.files {
+                            if let Some(filename) =
+                                DistFilename::try_from_filename(&file.filename, package_name)
+                            {
+                                files.push(filename, file.clone());
+                            }
+                        }
+                        SimpleDetailMetadatum {
+                            version: version.version.clone(),
+                            files,
+                            metadata: version.metadata.clone(),
+                        }
+                    })
+                    .collect::<SimpleDetailMetadata>();
+                let flat_index = (!flat.is_empty()).then(|| {
+                    FlatDistributions::from_entries(flat_entries(flat), tags, hasher, build_options)
+                });
+                Ok(VersionMap::from_simple_metadata(
+                    OwnedArchive::from_unarchived(&simple_metadata)?,
+                    package_name,
+                    index,
+                    tags,
+                    requires_python,
+                    allowed_yanks,
+                    hasher,
+                    Some(exclude_newer),
+                    flat_index,
+                    build_options,
+                ))
+            }
+            Self::Flat { files } => Ok(VersionMap::from_flat_metadata(
+                flat_entries(files),
+                tags,
+                hasher,
+                build_options,
+            )),
+        }
+    }
+}
+
+/// A recorded response to a request for the metadata of a distribution.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "response", rename_all = "kebab-case")]
+pub(crate) enum MetadataTrace {
+    Found(Box<ArchiveMetadata>),
+    Offline,
+    #[serde(rename_all = "kebab-case")]
+    RequiresPython {
+        requires_python: VersionSpecifiers,
+        python_version: Version,
+    },
+}
+
+impl MetadataTrace {
+    /// Reconstruct the [`MetadataResponse`] from the trace.
+    pub(crate) fn to_response(&self) -> MetadataResponse {
+        match self {
+            Self::Found(metadata) => MetadataResponse::Found((**metadata).clone()),
+            Self::Offline => MetadataResponse::Unavailable(MetadataUnavailable::Offline),
+            Self::RequiresPython {
+                requires_python,
+                python_version,
+            } => MetadataResponse::Unavailable(MetadataUnavailable::RequiresPython(
+                requires_python.clone(),
+                python_version.clone(),
+            )),
+        }
+    }
+}
+
+/// The on-disk representation of a [`ResolverTrace`].
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct ResolverTraceWire {
+    #[serde(default)]
+    packages: Vec<PackageEntry>,
+    #[serde(default)]
+    distributions: Vec<DistributionEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct PackageEntry {
+    name: PackageName,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    index: Option<String>,
+    #[serde(flatten)]
+    response: VersionsTrace,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct DistributionEntry {
+    id: String,
+    #[serde(flatten)]
+    response: MetadataTrace,
+}
+
+impl From<ResolverTrace> for ResolverTraceWire {
+    fn from(trace: ResolverTrace) -> Self {
+        Self {
+            packages: trace
+                .packages
+                .into_iter()
+                .map(|((name, index), response)| PackageEntry {
+                    name,
+                    index,
+                    response,
+                })
+                .collect(),
+            distributions: trace
+                .distributions
+                .into_iter()
+                .map(|(id, response)| DistributionEntry { id, response })
+                .collect(),
+        }
+    }
+}
+
+impl From<ResolverTraceWire> for ResolverTrace {
+    fn from(wire: ResolverTraceWire) -> Self {
+        Self {
+            packages: wire
+                .packages
+                .into_iter()
+                .map(|entry| ((entry.name, entry.index), entry.response))
+                .collect(),
+            distributions: wire
+                .distributions
+                .into_iter()
+                .map(|entry| (entry.id, entry.response))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_client::{OwnedArchive, SimpleDetailMetadata, SimpleDetailMetadatum, VersionFiles};
+    use uv_configuration::BuildOptions;
+    use uv_distribution_filename::DistFilename;
+    use uv_distribution_types::{File, FileLocation, IndexUrl, RequiresPython, UrlString};
+    use uv_normalize::PackageName;
+    use uv_pep440::Version;
+    use uv_pypi_types::HashDigests;
+    use uv_types::HashStrategy;
+
+    use super::{ResolverTrace, ResolverTraceWire};
+    use crate::ExcludeNewer;
+    use crate::resolver::provider::VersionsResponse;
+    use crate::version_map::VersionMap;
+    use crate::yanks::AllowedYanks;
+
+    fn file(filename: &str) -> File {
+        File {
+            dist_info_metadata: true,
+            filename: filename.into(),
+            hashes: HashDigests::empty(),
+            requires_python: None,
+            size: Some(1024),
+            upload_time_utc_ms: Some(1_700_000_000_000),
+            url: FileLocation::AbsoluteUrl(UrlString::new(
+                format!("https://example.com/files/{filename}").into(),
+            )),
+            yanked: None,
+            zstd: None,
+        }
+    }
+
+    fn to_json(trace: &ResolverTrace) -> String {
+        serde_json::to_string_pretty(&ResolverTraceWire::from(trace.clone())).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let name = PackageName::from_str("anyio").unwrap();
+        let index = IndexUrl::from_str("https://example.com/simple").unwrap();
+        let requires_python = RequiresPython::greater_than_equal_version(&Version::new([3, 12]));
+
+        let simple_metadata = ["4.2.0", "4.3.0"]
+            .into_iter()
+            .map(|version| {
+                let mut files = VersionFiles::default();
+                for filename in [
+                    format!("anyio-{version}-py3-none-any.whl"),
+                    format!("anyio-{version}.tar.gz"),
+                ] {
+                    let dist_filename = DistFilename::try_from_filename(&filename, &name).unwrap();
+                    files.push(dist_filename, file(&filename));
+                }
+                SimpleDetailMetadatum {
+                    version: Version::from_str(version).unwrap(),
+                    files,
+                    metadata: None,
+                }
+            })
+            .collect::<SimpleDetailMetadata>();
+        let version_map = VersionMap::from_simple_metadata(
+            OwnedArchive::from_unarchived(&simple_metadata).unwrap(),
+            &name,
+            &index,
+            None,
+            &requires_python,
+            &AllowedYanks::default(),
+            &HashStrategy::None,
+            None,
+            None,
+            &BuildOptions::default(),
+        );
+
+        let mut trace = ResolverTrace::default();
+        trace.record_versions(&name, None, &VersionsResponse::Found(vec![version_map]));
+
+        // The trace should survive a round-trip through its on-disk representation.
+        let json = to_json(&trace);
+        let parsed = ResolverTrace::from(serde_json::from_str::<ResolverTraceWire>(&json).unwrap());
+        assert_eq!(to_json(&parsed), json);
+
+        // And the replayed response should contain the recorded versions.
+        let response = parsed
+            .versions(&name, None)
+            .unwrap()
+            .to_response(
+                &name,
+                None,
+                &requires_python,
+                &AllowedYanks::default(),
+                &HashStrategy::None,
+                &ExcludeNewer::default(),
+                &BuildOptions::default(),
+            )
+            .unwrap();
+        let VersionsResponse::Found(version_maps) = response else {
+            panic!("expected a `Found` response");
+        };
+        let [version_map] = version_maps.as_slice() else {
+            panic!("expected a single version map");
+        };
+        assert_eq!(version_map.index(), Some(&index));
+        assert_eq!(
+            version_map
+                .versions()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["4.2.0", "4.3.0"]
+        );
+        assert_eq!(
+            version_map
+                .get(&Version::from_str("4.3.0").unwrap())
+                .unwrap()
+                .files()
+                .count(),
+            2
+        );
+    }
+}
@@ -188,6 +188,29 @@ impl VersionMap {
         }
     }
 
+    /// Return the raw simple metadata from which this map was constructed, if any.
+    pub(crate) fn simple_metadata(&self) -> Option<SimpleDetailMetadata> {
+        match &self.inner {
+            VersionMapInner::Eager(_) => None,
+            VersionMapInner::Lazy(lazy) => Some(OwnedArchive::deserialize(&lazy.simple_metadata)),
+        }
+    }
+
+    /// Return an iterator over the distributions that were materialized eagerly, i.e., those
+    /// that originate from a flat index rather than simple metadata.
+    pub(crate) fn flat_dists(&self) -> impl Iterator<Item = &PrioritizedDist> {
+        match &self.inner {
+            VersionMapInner::Eager(eager) => either::Either::Left(eager.map.values()),
+            VersionMapInner::Lazy(lazy) => {
+                either::Either::Right(lazy.map.values().filter_map(|dist| match dist {
+                    LazyPrioritizedDist::OnlyFlat(flat)
+                    | LazyPrioritizedDist::Both { flat, .. } => Some(flat),
+                    LazyPrioritizedDist::OnlySimple(_) => None,
+                }))
+            }
+        }
+    }
+
     /// Return an iterator over the versions and distributions.
     ///
     /// Note that the value returned in this iterator is a [`VersionMapDist`],
@@ -3,6 +3,8 @@ use std::{collections::BTreeMap, num::NonZeroUsize};
 
 use url::Url;
 
+use uv_bin_install::BinarySource;
+use uv_cache::{CacheMaxAge, CacheMaxSize, RemoteBuildCacheLocation};
 use uv_configuration::{
     BuildIsolation, ExportFormat, IndexStrategy, KeyringProviderType, NoSources, ProxyUrl,
     Reinstall, RequiredVersion, TargetTriple, TrustedPublishing, Upgrade,
@@ -23,7 +25,7 @@ use uv_torch::TorchMode;
 use uv_workspace::pyproject::ExtraBuildDependencies;
 use uv_workspace::pyproject_mut::AddBoundsKind;
 
-use crate::{FilesystemOptions, Options, PipOptions};
+use crate::{AuditOptions, FilesystemOptions, Options, PipOptions};
 
 pub trait Combine {
     /// Combine two values, preferring the values in `self`.
@@ -72,6 +74,15 @@ impl Combine for Option<PipOptions> {
     }
 }
 
+impl Combine for Option<AuditOptions> {
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.combine(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
 macro_rules! impl_combine_or {
     ($name:ident) => {
         impl Combine for Option<$name> {
@@ -84,6 +95,8 @@ macro_rules! impl_combine_or {
 
 impl_combine_or!(AddBoundsKind);
 impl_combine_or!(AnnotationStyle);
+impl_combine_or!(CacheMaxAge);
+impl_combine_or!(CacheMaxSize);
 impl_combine_or!(ExcludeNewer);
 impl_combine_or!(ExcludeNewerValue);
 impl_combine_or!(ExportFormat);
@@ -104,6 +117,7 @@ impl_combine_or!(ProxyUrl);
 impl_combine_or!(PythonDownloads);
 impl_combine_or!(PythonPreference);
 impl_combine_or!(PythonVersion);
+impl_combine_or!(RemoteBuildCacheLocation);
 impl_combine_or!(RequiredVersion);
 impl_combine_or!(ResolutionMode);
 impl_combine_or!(SchemaConflicts);
@@ -144,6 +158,22 @@ impl<K: Ord, T> Combine for Option<BTreeMap<K, Vec<T>>> {
     }
 }
 
+impl Combine for Option<BTreeMap<String, BinarySource>> {
+    /// Combine two binary registries, preferring the definitions in `self` for binaries that are
+    /// defined in both.
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(mut a), Some(b)) => {
+                for (name, source) in b {
+                    a.entry(name).or_insert(source);
+                }
+                Some(a)
+            }
+            (a, b) => a.or(b),
+        }
+    }
+}
+
 impl Combine for Option<ExcludeNewerPackage> {
     /// Combine two [`ExcludeNewerPackage`] instances by merging them, with the values in `self` taking precedence.
     fn combine(self, other: Self) -> Self {
@@ -1,8 +1,10 @@
 use std::num::NonZeroUsize;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::time::Duration;
 
+use uv_cache::RemoteBuildCacheLocation;
 use uv_dirs::{system_config_file, user_config_dir};
 use uv_flags::EnvironmentFlags;
 use uv_fs::Simplified;
@@ -210,7 +212,10 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         publish: _,
         add: _,
         pip: _,
+        audit: _,
         cache_keys: _,
+        binaries: _,
+        binary_install_mirror: _,
         override_dependencies: _,
         exclude_dependencies: _,
         constraint_dependencies: _,
@@ -218,6 +223,7 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         environments,
         required_environments,
         conflicts,
+        license_policy,
         workspace,
         sources,
         dev_dependencies,
@@ -233,6 +239,12 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
     if conflicts.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "conflicts"));
     }
+    if license_policy.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "license-policy",
+        ));
+    }
     if workspace.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "workspace"));
     }
@@ -296,6 +308,10 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 offline,
                 no_cache,
                 cache_dir,
+                cache_max_size,
+                cache_max_age,
+                remote_build_cache,
+                remote_build_cache_publish,
                 preview,
                 python_preference,
                 python_downloads,
@@ -356,7 +372,10 @@ fn warn_uv_toml_masked_fields(options: &Options) {
             },
         add: AddOptions { add_bounds },
         pip,
+        audit,
         cache_keys,
+        binaries,
+        binary_install_mirror,
         override_dependencies,
         exclude_dependencies,
         constraint_dependencies,
@@ -364,6 +383,7 @@ fn warn_uv_toml_masked_fields(options: &Options) {
         environments: _,
         required_environments: _,
         conflicts: _,
+        license_policy: _,
         workspace: _,
         sources: _,
         dev_dependencies: _,
@@ -391,6 +411,18 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if cache_dir.is_some() {
         masked_fields.push("cache-dir");
     }
+    if cache_max_size.is_some() {
+        masked_fields.push("cache-max-size");
+    }
+    if cache_max_age.is_some() {
+        masked_fields.push("cache-max-age");
+    }
+    if remote_build_cache.is_some() {
+        masked_fields.push("remote-build-cache");
+    }
+    if remote_build_cache_publish.is_some() {
+        masked_fields.push("remote-build-cache-publish");
+    }
     if preview.is_some() {
         masked_fields.push("preview");
     }
@@ -538,9 +570,18 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if pip.is_some() {
         masked_fields.push("pip");
     }
+    if audit.is_some() {
+        masked_fields.push("audit");
+    }
     if cache_keys.is_some() {
         masked_fields.push("cache_keys");
     }
+    if binaries.is_some() {
+        masked_fields.push("binaries");
+    }
+    if binary_install_mirror.is_some() {
+        masked_fields.push("binary-install-mirror");
+    }
     if override_dependencies.is_some() {
         masked_fields.push("override-dependencies");
     }
@@ -623,6 +664,8 @@ pub struct EnvironmentOptions {
     pub install_mirrors: PythonInstallMirrors,
     pub log_context: Option<bool>,
     pub lfs: Option<bool>,
+    pub remote_build_cache: Option<RemoteBuildCacheLocation>,
+    pub remote_build_cache_publish: Option<bool>,
     pub http_timeout: Duration,
     pub http_retries: u32,
     pub upload_http_timeout: Duration,
@@ -688,6 +731,20 @@ impl EnvironmentOptions {
             },
             log_context: parse_boolish_environment_variable(EnvVars::UV_LOG_CONTEXT)?,
             lfs: parse_boolish_environment_variable(EnvVars::UV_GIT_LFS)?,
+            remote_build_cache: parse_string_environment_variable(EnvVars::UV_REMOTE_BUILD_CACHE)?
+                .map(|value| {
+                    RemoteBuildCacheLocation::from_str(&value).map_err(|err| {
+                        Error::InvalidEnvironmentVariable(InvalidEnvironmentVariable {
+                            name: EnvVars::UV_REMOTE_BUILD_CACHE.to_string(),
+                            value,
+                            err,
+                        })
+                    })
+                })
+                .transpose()?,
+            remote_build_cache_publish: parse_boolish_environment_variable(
+                EnvVars::UV_REMOTE_BUILD_CACHE_PUBLISH,
+            )?,
             upload_http_timeout: parse_integer_environment_variable(
                 EnvVars::UV_UPLOAD_HTTP_TIMEOUT,
             )?
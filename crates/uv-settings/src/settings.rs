@@ -1,7 +1,9 @@
-use std::{fmt::Debug, num::NonZeroUsize, path::Path, path::PathBuf};
+use std::{collections::BTreeMap, fmt::Debug, num::NonZeroUsize, path::Path, path::PathBuf};
 
 use serde::{Deserialize, Serialize};
 
+use uv_bin_install::BinarySource;
+use uv_cache::{CacheMaxAge, CacheMaxSize, RemoteBuildCacheLocation};
 use uv_cache_info::CacheKey;
 use uv_configuration::{
     BuildIsolation, IndexStrategy, KeyringProviderType, PackageNameSpecifier, ProxyUrl, Reinstall,
@@ -65,6 +67,9 @@ pub struct Options {
     #[option_group]
     pub pip: Option<PipOptions>,
 
+    #[option_group]
+    pub audit: Option<AuditOptions>,
+
     /// The keys to consider when caching builds for the project.
     ///
     /// Cache keys enable you to specify the files or directories that should trigger a rebuild when
@@ -109,6 +114,52 @@ pub struct Options {
     )]
     pub cache_keys: Option<Vec<CacheKey>>,
 
+    /// Definitions for the binary tools that uv can install and run (e.g., Ruff, for
+    /// `uv format` and `uv lint`).
+    ///
+    /// Each entry may override the built-in definition of a binary (e.g., to pin a different
+    /// version or download it from an internal server), or define an entirely new binary, in which
+    /// case `version`, `url`, and either `checksums` or `checksum-url` are required. Binaries that
+    /// aren't built into uv can be run with `uv bin`, e.g., `uv bin ty -- check`.
+    ///
+    /// The `url`, `executable`, and `checksum-url` fields are templates, in which `{name}`,
+    /// `{version}`, `{platform}` (e.g., `x86_64-unknown-linux-gnu`), and `{ext}` (e.g., `tar.gz`)
+    /// are replaced with the corresponding values. Archives can be read from the local filesystem
+    /// with `file://` URLs.
+    ///
+    /// If `checksums` or `checksum-url` is provided, the SHA-256 digest of the downloaded archive
+    /// is verified before the binary is installed. Binaries that aren't built into uv are never
+    /// installed without a checksum for the current platform.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            [binaries.ruff]
+            version = "0.12.5"
+            url = "https://example.com/ruff/{version}/ruff-{platform}.{ext}"
+            checksum-url = "https://example.com/ruff/{version}/ruff-{platform}.{ext}.sha256"
+        "#
+    )]
+    pub binaries: Option<BTreeMap<String, BinarySource>>,
+
+    /// Mirror URL for downloading managed binaries (e.g., Ruff).
+    ///
+    /// By default, binaries are downloaded from the URLs in their definitions (e.g., from
+    /// `https://github.com/astral-sh/ruff/releases/download/` for Ruff). When a mirror is set,
+    /// archives are instead fetched from `<mirror>/<name>/<version>/<filename>`, where `<filename>`
+    /// is the final path segment of the original URL; checksum files are resolved the same way.
+    ///
+    /// The mirror may be a URL or a path to a local directory, which allows binaries to be
+    /// installed in air-gapped environments.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            binary-install-mirror = "https://mirror.example.com/binaries"
+        "#
+    )]
+    pub binary_install_mirror: Option<String>,
+
     // NOTE(charlie): These fields are shared with `ToolUv` in
     // `crates/uv-workspace/src/pyproject.rs`. The documentation lives on that struct.
     // They're respected in both `pyproject.toml` and `uv.toml` files.
@@ -136,6 +187,9 @@ pub struct Options {
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub conflicts: Option<serde::de::IgnoredAny>,
 
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub license_policy: Option<serde::de::IgnoredAny>,
+
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub workspace: Option<serde::de::IgnoredAny>,
 
@@ -250,6 +304,60 @@ pub struct GlobalOptions {
         "#
     )]
     pub cache_dir: Option<PathBuf>,
+    /// The maximum total size of the unzipped wheels in the cache, e.g., `20GiB` or `500MB`.
+    ///
+    /// When exceeded, uv opportunistically evicts the least recently used wheels (along with any
+    /// cache entries that refer to them) until the cache is back within the limit. Eviction only
+    /// occurs when no other uv process is using the cache.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            cache-max-size = "20GiB"
+        "#
+    )]
+    pub cache_max_size: Option<CacheMaxSize>,
+    /// The maximum time since an unzipped wheel in the cache was last used, e.g., `30 days`.
+    ///
+    /// Accepts a "friendly" duration (e.g., `30 days`, `2 weeks`) or an ISO 8601 duration (e.g.,
+    /// `P30D`). Wheels that haven't been installed within this period are opportunistically
+    /// evicted from the cache, along with any cache entries that refer to them. Eviction only
+    /// occurs when no other uv process is using the cache.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            cache-max-age = "30 days"
+        "#
+    )]
+    pub cache_max_age: Option<CacheMaxAge>,
+    /// A remote cache of wheels built from source distributions, shared across machines.
+    ///
+    /// Accepts an HTTP(S) URL (e.g., `https://example.com/build-cache`) or a path to a directory
+    /// (e.g., a network share). Before building a source distribution from a registry or URL, uv
+    /// checks the remote cache for a wheel built from the same source distribution with the same
+    /// build settings and interpreter tags, falling back to a local build if none is found.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            remote-build-cache = "https://example.com/build-cache"
+        "#
+    )]
+    pub remote_build_cache: Option<RemoteBuildCacheLocation>,
+    /// Whether to upload wheels built from source distributions to the
+    /// [`remote-build-cache`](#remote-build-cache).
+    ///
+    /// For HTTP(S) caches, wheels are uploaded via `PUT` requests. Typically enabled in continuous
+    /// integration, such that developers can reuse the wheels built there.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            remote-build-cache-publish = true
+        "#
+    )]
+    pub remote_build_cache_publish: Option<bool>,
     /// Whether to enable experimental, preview features.
     #[option(
         default = "false",
@@ -741,6 +849,9 @@ pub struct ResolverInstallerSchema {
     /// Under `fewest`, uv will minimize the number of selected versions for each package,
     /// preferring older versions that are compatible with a wider range of supported Python
     /// versions or platforms.
+    ///
+    /// Under `single`, uv will select a single version of each package across all supported
+    /// Python versions and platforms, and fail if the requirements force a package to diverge.
     #[option(
         default = "\"requires-python\"",
         value_type = "str",
@@ -1480,6 +1591,9 @@ pub struct PipOptions {
     /// Under `fewest`, uv will minimize the number of selected versions for each package,
     /// preferring older versions that are compatible with a wider range of supported Python
     /// versions or platforms.
+    ///
+    /// Under `single`, uv will select a single version of each package across all supported
+    /// Python versions and platforms, and fail if the requirements force a package to diverge.
     #[option(
         default = "\"requires-python\"",
         value_type = "str",
@@ -2152,6 +2266,10 @@ pub struct OptionsWire {
     offline: Option<bool>,
     no_cache: Option<bool>,
     cache_dir: Option<PathBuf>,
+    cache_max_size: Option<CacheMaxSize>,
+    cache_max_age: Option<CacheMaxAge>,
+    remote_build_cache: Option<RemoteBuildCacheLocation>,
+    remote_build_cache_publish: Option<bool>,
     preview: Option<bool>,
     python_preference: Option<PythonPreference>,
     python_downloads: Option<PythonDownloads>,
@@ -2215,7 +2333,10 @@ pub struct OptionsWire {
     add_bounds: Option<AddBoundsKind>,
 
     pip: Option<PipOptions>,
+    audit: Option<AuditOptions>,
     cache_keys: Option<Vec<CacheKey>>,
+    binaries: Option<BTreeMap<String, BinarySource>>,
+    binary_install_mirror: Option<String>,
 
     // NOTE(charlie): These fields are shared with `ToolUv` in
     // `crates/uv-workspace/src/pyproject.rs`. The documentation lives on that struct.
@@ -2231,6 +2352,7 @@ pub struct OptionsWire {
     // `crates/uv-workspace/src/pyproject.rs`. The documentation lives on that struct.
     // They're only respected in `pyproject.toml` files, and should be rejected in `uv.toml` files.
     conflicts: Option<serde::de::IgnoredAny>,
+    license_policy: Option<serde::de::IgnoredAny>,
     workspace: Option<serde::de::IgnoredAny>,
     sources: Option<serde::de::IgnoredAny>,
     managed: Option<serde::de::IgnoredAny>,
@@ -2251,6 +2373,10 @@ impl From<OptionsWire> for Options {
             offline,
             no_cache,
             cache_dir,
+            cache_max_size,
+            cache_max_age,
+            remote_build_cache,
+            remote_build_cache_publish,
             preview,
             python_preference,
             python_downloads,
@@ -2295,7 +2421,10 @@ impl From<OptionsWire> for Options {
             no_binary_package,
             torch_backend,
             pip,
+            audit,
             cache_keys,
+            binaries,
+            binary_install_mirror,
             override_dependencies,
             exclude_dependencies,
             constraint_dependencies,
@@ -2303,6 +2432,7 @@ impl From<OptionsWire> for Options {
             environments,
             required_environments,
             conflicts,
+            license_policy,
             publish_url,
             trusted_publishing,
             check_url,
@@ -2327,6 +2457,10 @@ impl From<OptionsWire> for Options {
                 offline,
                 no_cache,
                 cache_dir,
+                cache_max_size,
+                cache_max_age,
+                remote_build_cache,
+                remote_build_cache_publish,
                 preview,
                 python_preference,
                 python_downloads,
@@ -2374,7 +2508,10 @@ impl From<OptionsWire> for Options {
                 torch_backend,
             },
             pip,
+            audit,
             cache_keys,
+            binaries,
+            binary_install_mirror,
             build_backend,
             override_dependencies,
             exclude_dependencies,
@@ -2388,6 +2525,7 @@ impl From<OptionsWire> for Options {
                 python_downloads_json_url,
             },
             conflicts,
+            license_policy,
             publish: PublishOptions {
                 publish_url,
                 trusted_publishing,
@@ -2458,6 +2596,36 @@ pub struct PublishOptions {
     pub check_url: Option<IndexUrl>,
 }
 
+/// Settings that are specific to the `uv audit` command.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, CombineOptions, OptionsMetadata)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AuditOptions {
+    /// Advisories to ignore when auditing the lockfile.
+    ///
+    /// Advisories can be referenced by their primary identifier (e.g., `PYSEC-2021-66` or
+    /// `GHSA-g3rq-g295-4j3m`) or by any of their aliases (e.g., `CVE-2020-28493`). Ignored
+    /// advisories are still included in the report, but will not cause `uv audit` to fail.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            ignore = ["PYSEC-2021-66", "CVE-2023-30608"]
+        "#
+    )]
+    pub ignore: Option<Vec<String>>,
+
+    /// Packages for which all advisories should be ignored when auditing the lockfile.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            ignore-package = ["jinja2"]
+        "#
+    )]
+    pub ignore_package: Option<Vec<PackageName>>,
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, CombineOptions, OptionsMetadata)]
 #[serde(rename_all = "kebab-case")]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -64,6 +64,16 @@ impl EnvVars {
     #[attr_added_in("0.8.15")]
     pub const UV_CREDENTIALS_DIR: &'static str = "UV_CREDENTIALS_DIR";
 
+    /// Equivalent to the `remote-build-cache` setting. The URL or path of a remote cache of
+    /// wheels built from source distributions, shared across machines.
+    #[attr_added_in("next version")]
+    pub const UV_REMOTE_BUILD_CACHE: &'static str = "UV_REMOTE_BUILD_CACHE";
+
+    /// Equivalent to the `remote-build-cache-publish` setting. If set to `true`, uv will upload
+    /// wheels built from source distributions to the remote build cache.
+    #[attr_added_in("next version")]
+    pub const UV_REMOTE_BUILD_CACHE_PUBLISH: &'static str = "UV_REMOTE_BUILD_CACHE_PUBLISH";
+
     /// Equivalent to the `--no-cache` command-line argument. If set, uv will not use the
     /// cache for any operations.
     #[attr_added_in("0.1.2")]
@@ -84,6 +94,16 @@ impl EnvVars {
     #[attr_added_in("0.5.9")]
     pub const UV_FORK_STRATEGY: &'static str = "UV_FORK_STRATEGY";
 
+    /// Equivalent to the `--database` argument in `uv audit`. The path to an advisory database in
+    /// the OSV format, either as a directory or a `.zip` archive.
+    #[attr_added_in("next version")]
+    pub const UV_AUDIT_DATABASE: &'static str = "UV_AUDIT_DATABASE";
+
+    /// Equivalent to the `--metadata-index` argument in `uv lock`. The path to a local index of
+    /// Simple API listings and package metadata, as produced by `uv index build-metadata`.
+    #[attr_added_in("next version")]
+    pub const UV_METADATA_INDEX: &'static str = "UV_METADATA_INDEX";
+
     /// Equivalent to the `--system` command-line argument. If set to `true`, uv will
     /// use the first Python interpreter found in the system `PATH`.
     ///
@@ -691,6 +691,21 @@ pub struct ToolUv {
     )]
     pub conflicts: Option<SchemaConflicts>,
 
+    /// The license policy to enforce on the project's dependencies.
+    ///
+    /// When set, `uv lock` and `uv sync` check the license of every locked package against the
+    /// policy, and fail if any package is distributed under a license that is denied or not
+    /// explicitly allowed.
+    ///
+    /// Licenses are read from the core metadata of the locked wheels (or, for local source
+    /// trees, from `pyproject.toml`), and are checked before any package is installed. Source
+    /// distributions are never built to read their license. The policy is not enforced with
+    /// `--frozen`.
+    ///
+    /// Only respected in the root of the workspace.
+    #[option_group]
+    pub license_policy: Option<LicensePolicy>,
+
     // Only exists on this type for schema and docs generation, the build backend settings are
     // never merged in a workspace and read separately by the backend code.
     /// Configuration for the uv build backend.
@@ -912,6 +927,53 @@ pub struct ToolUvWorkspace {
     pub exclude: Option<Vec<SerdePattern>>,
 }
 
+#[derive(Deserialize, OptionsMetadata, Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct LicensePolicy {
+    /// The licenses that dependencies are allowed to use, as SPDX license identifiers.
+    ///
+    /// If set, every locked package must declare a license, and its license expression must be
+    /// satisfiable using only the listed licenses. For example, a package licensed under
+    /// `MIT OR GPL-3.0-only` is accepted if `MIT` is allowed.
+    ///
+    /// If unset, any license that isn't explicitly denied is accepted.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            allow = ["MIT", "Apache-2.0", "BSD-3-Clause"]
+        "#
+    )]
+    pub allow: Option<Vec<String>>,
+    /// The licenses that dependencies are forbidden from using, as SPDX license identifiers.
+    ///
+    /// A package is rejected if its license expression can't be satisfied without using a denied
+    /// license. Denied licenses take precedence over allowed licenses.
+    ///
+    /// Note that SPDX treats the `-only` and `-or-later` variants of the GNU licenses as distinct
+    /// identifiers, so both must be listed to deny either.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            deny = ["AGPL-3.0-only", "AGPL-3.0-or-later", "GPL-3.0-only", "GPL-3.0-or-later"]
+        "#
+    )]
+    pub deny: Option<Vec<String>>,
+    /// Packages that are exempt from the license policy, e.g., because their licenses have been
+    /// reviewed manually.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            exceptions = ["certifi"]
+        "#
+    )]
+    pub exceptions: Option<Vec<PackageName>>,
+}
+
 /// (De)serialize globs as strings.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SerdePattern(Pattern);
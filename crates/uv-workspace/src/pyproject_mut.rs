@@ -7,15 +7,17 @@ use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use toml_edit::{
-    Array, ArrayOfTables, DocumentMut, Formatted, Item, RawString, Table, TomlError, Value,
+    Array, ArrayOfTables, DocumentMut, Formatted, InlineTable, Item, RawString, Table, TomlError,
+    Value,
 };
 
 use uv_cache_key::CanonicalUrl;
 use uv_distribution_types::Index;
 use uv_fs::PortablePath;
 use uv_normalize::{ExtraName, GroupName, PackageName};
-use uv_pep440::{Version, VersionParseError, VersionSpecifier, VersionSpecifiers};
+use uv_pep440::{Operator, Version, VersionParseError, VersionSpecifier, VersionSpecifiers};
 use uv_pep508::{MarkerTree, Requirement, VersionOrUrl};
+use uv_pypi_types::{ConflictKind, SchemaConflictSet};
 use uv_redacted::DisplaySafeUrl;
 
 use crate::pyproject::{DependencyType, Source};
@@ -43,6 +45,8 @@ pub enum Error {
     MalformedSources,
     #[error("Workspace in `pyproject.toml` is malformed")]
     MalformedWorkspace,
+    #[error("Conflicts in `pyproject.toml` are malformed")]
+    MalformedConflicts,
     #[error("Expected a dependency at index {0}")]
     MissingDependency(usize),
     #[error("Failed to parse `version` field of `pyproject.toml`")]
@@ -807,6 +811,106 @@ impl PyProjectTomlMut {
         Ok(())
     }
 
+    /// Raise the lower bound of an existing dependency to the given version, preserving the kind
+    /// of each bound (e.g., `>=1.2` becomes `>=1.4.0`, and `~=1.2` becomes `~=1.4`).
+    ///
+    /// Returns the updated requirement, or `None` if the dependency has no lower bound below the
+    /// given version.
+    pub fn raise_dependency_bound(
+        &mut self,
+        dependency_type: &DependencyType,
+        index: usize,
+        version: &Version,
+    ) -> Result<Option<Requirement>, Error> {
+        let group = match dependency_type {
+            DependencyType::Production => self.dependencies_array()?,
+            DependencyType::Dev => self.dev_dependencies_array()?,
+            DependencyType::Optional(extra) => self.optional_dependencies_array(extra)?,
+            DependencyType::Group(group) => self.dependency_groups_array(group)?,
+        };
+
+        let Some(req) = group.get(index) else {
+            return Err(Error::MissingDependency(index));
+        };
+
+        let mut req = req
+            .as_str()
+            .and_then(try_parse_requirement)
+            .ok_or(Error::MalformedDependencies)?;
+        let Some(VersionOrUrl::VersionSpecifier(specifiers)) = req.version_or_url.as_ref() else {
+            return Ok(None);
+        };
+        let Some(specifiers) = raise_lower_bounds(specifiers, version) else {
+            return Ok(None);
+        };
+        req.version_or_url = Some(VersionOrUrl::VersionSpecifier(specifiers));
+        group.replace(index, req.to_string());
+
+        Ok(Some(req))
+    }
+
+    /// Adds a set of conflicting extras or groups to `tool.uv.conflicts`.
+    pub fn add_conflict(&mut self, set: &SchemaConflictSet) -> Result<(), Error> {
+        // Get or create `tool.uv.conflicts`.
+        let conflicts = self
+            .doc
+            .entry("tool")
+            .or_insert(implicit())
+            .as_table_mut()
+            .ok_or(Error::MalformedConflicts)?
+            .entry("uv")
+            .or_insert(Item::Table(Table::new()))
+            .as_table_mut()
+            .ok_or(Error::MalformedConflicts)?
+            .entry("conflicts")
+            .or_insert(Item::Value(Value::Array(Array::new())))
+            .as_array_mut()
+            .ok_or(Error::MalformedConflicts)?;
+
+        // Match the indentation of any existing sets.
+        let indent = conflicts
+            .iter()
+            .next()
+            .and_then(|value| value.decor().prefix())
+            .and_then(|prefix| prefix.as_str())
+            .and_then(|prefix| prefix.rsplit_once('\n'))
+            .map(|(_, indent)| indent)
+            .filter(|indent| !indent.is_empty() && indent.trim().is_empty())
+            .unwrap_or("    ")
+            .to_string();
+
+        // Format each set on its own line, with one item per line.
+        let mut items = Array::new();
+        for item in set.iter() {
+            let mut table = InlineTable::new();
+            if let Some(package) = item.package() {
+                table.insert("package", Value::from(package.to_string()));
+            }
+            match item.kind() {
+                ConflictKind::Extra(extra) => {
+                    table.insert("extra", Value::from(extra.to_string()));
+                }
+                ConflictKind::Group(group) => {
+                    table.insert("group", Value::from(group.to_string()));
+                }
+                ConflictKind::Project => {}
+            }
+            let mut value = Value::InlineTable(table);
+            value.decor_mut().set_prefix(format!("\n{indent}{indent}"));
+            items.push_formatted(value);
+        }
+        items.set_trailing_comma(true);
+        items.set_trailing(format!("\n{indent}"));
+
+        let mut value = Value::Array(items);
+        value.decor_mut().set_prefix(format!("\n{indent}"));
+        conflicts.push_formatted(value);
+        conflicts.set_trailing_comma(true);
+        conflicts.set_trailing("\n");
+
+        Ok(())
+    }
+
     /// Get the TOML array for `project.dependencies`.
     fn dependencies_array(&mut self) -> Result<&mut Array, Error> {
         // Get or create `project.dependencies`.
@@ -1192,6 +1296,86 @@ impl PyProjectTomlMut {
         types
     }
 
+    /// Returns all dependencies in this `pyproject.toml`, along with their positions.
+    ///
+    /// This method searches `project.dependencies`, `project.optional-dependencies`,
+    /// `dependency-groups`, and `tool.uv.dev-dependencies`. Entries that can't be parsed as
+    /// requirements (e.g., `include-group` tables) are skipped.
+    pub fn dependencies(&self) -> Vec<(DependencyType, usize, Requirement)> {
+        fn parse(deps: &Array) -> impl Iterator<Item = (usize, Requirement)> + '_ {
+            deps.iter().enumerate().filter_map(|(i, dep)| {
+                dep.as_str()
+                    .and_then(try_parse_requirement)
+                    .map(|req| (i, req))
+            })
+        }
+
+        let mut dependencies = Vec::new();
+
+        if let Some(project) = self.doc.get("project").and_then(Item::as_table) {
+            // Read `project.dependencies`.
+            if let Some(deps) = project.get("dependencies").and_then(Item::as_array) {
+                for (i, req) in parse(deps) {
+                    dependencies.push((DependencyType::Production, i, req));
+                }
+            }
+
+            // Read `project.optional-dependencies`.
+            if let Some(extras) = project
+                .get("optional-dependencies")
+                .and_then(Item::as_table_like)
+            {
+                for (extra, deps) in extras.iter() {
+                    let Some(deps) = deps.as_array() else {
+                        continue;
+                    };
+                    let Ok(extra) = ExtraName::from_str(extra) else {
+                        continue;
+                    };
+                    for (i, req) in parse(deps) {
+                        dependencies.push((DependencyType::Optional(extra.clone()), i, req));
+                    }
+                }
+            }
+        }
+
+        // Read `dependency-groups`.
+        if let Some(groups) = self
+            .doc
+            .get("dependency-groups")
+            .and_then(Item::as_table_like)
+        {
+            for (group, deps) in groups.iter() {
+                let Some(deps) = deps.as_array() else {
+                    continue;
+                };
+                let Ok(group) = GroupName::from_str(group) else {
+                    continue;
+                };
+                for (i, req) in parse(deps) {
+                    dependencies.push((DependencyType::Group(group.clone()), i, req));
+                }
+            }
+        }
+
+        // Read `tool.uv.dev-dependencies`.
+        if let Some(deps) = self
+            .doc
+            .get("tool")
+            .and_then(Item::as_table)
+            .and_then(|tool| tool.get("uv"))
+            .and_then(Item::as_table)
+            .and_then(|uv| uv.get("dev-dependencies"))
+            .and_then(Item::as_array)
+        {
+            for (i, req) in parse(deps) {
+                dependencies.push((DependencyType::Dev, i, req));
+            }
+        }
+
+        dependencies
+    }
+
     pub fn version(&mut self) -> Result<Version, Error> {
         let version = self
             .doc
@@ -1615,6 +1799,60 @@ fn try_parse_requirement(req: &str) -> Option<Requirement> {
     Requirement::from_str(req).ok()
 }
 
+/// Raise the lower bounds in the given specifiers to the given version, preserving the kind of
+/// each bound.
+///
+/// Exact pins and upper bounds are left unchanged. Returns `None` if no bound was raised.
+fn raise_lower_bounds(
+    specifiers: &VersionSpecifiers,
+    version: &Version,
+) -> Option<VersionSpecifiers> {
+    // Local version identifiers aren't permitted in `>=` or `~=` constraints.
+    let version = version.clone().without_local();
+
+    let mut raised = false;
+    let specifiers = specifiers
+        .iter()
+        .map(|specifier| {
+            let replacement = match specifier.operator() {
+                Operator::GreaterThanEqual | Operator::GreaterThan
+                    if *specifier.version() < version =>
+                {
+                    Some(VersionSpecifier::greater_than_equal_version(
+                        version.clone(),
+                    ))
+                }
+                // Retain the number of release segments, e.g., `~=1.2` remains a two-segment
+                // bound, so that the implied upper bound is unchanged.
+                Operator::TildeEqual => {
+                    let bound = Version::new(
+                        version
+                            .release()
+                            .iter()
+                            .copied()
+                            .chain(iter::repeat(0))
+                            .take(specifier.version().release().len()),
+                    );
+                    if bound > *specifier.version() {
+                        VersionSpecifier::from_version(Operator::TildeEqual, bound).ok()
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            };
+            if let Some(replacement) = replacement {
+                raised = true;
+                replacement
+            } else {
+                specifier.clone()
+            }
+        })
+        .collect::<VersionSpecifiers>();
+
+    raised.then_some(specifiers)
+}
+
 /// Reformats a TOML array to multi line while trying to preserve all comments
 /// and move them around. This also formats the array to have a trailing comma.
 fn reformat_array_multiline(deps: &mut Array) {
@@ -1750,10 +1988,15 @@ fn split_specifiers(req: &str) -> (&str, &str) {
 
 #[cfg(test)]
 mod test {
-    use super::{AddBoundsKind, reformat_array_multiline, split_specifiers};
+    use super::{
+        AddBoundsKind, DependencyTarget, PyProjectTomlMut, reformat_array_multiline,
+        split_specifiers,
+    };
     use std::str::FromStr;
     use toml_edit::DocumentMut;
-    use uv_pep440::Version;
+    use uv_normalize::{ExtraName, GroupName, PackageName};
+    use uv_pep440::{Version, VersionSpecifiers};
+    use uv_pypi_types::{ConflictItem, ConflictSet};
 
     #[test]
     fn split() {
@@ -1927,4 +2170,119 @@ dependencies = [
             assert_eq!(actual, expected, "{version}");
         }
     }
+
+    #[test]
+    fn raise_lower_bounds() {
+        let tests = [
+            (">=1.2", "2.5.0", Some(">=2.5.0")),
+            (">=1.2, <3", "2.5.0", Some(">=2.5.0, <3")),
+            (">1.2", "2.5.0", Some(">=2.5.0")),
+            (">=2.5.0", "2.5.0", None),
+            ("~=1.2", "1.4.1", Some("~=1.4")),
+            ("~=1.2.0", "1.2.7", Some("~=1.2.7")),
+            ("~=1.4", "1.4.1", None),
+            (">=1.2", "2.5.0+local", Some(">=2.5.0")),
+            ("==1.2.3", "1.2.3", None),
+            ("<3", "2.5.0", None),
+            ("", "2.5.0", None),
+        ];
+
+        for (specifiers, version, expected) in tests {
+            let actual = super::raise_lower_bounds(
+                &VersionSpecifiers::from_str(specifiers).unwrap(),
+                &Version::from_str(version).unwrap(),
+            )
+            .map(|specifiers| specifiers.to_string());
+            assert_eq!(actual.as_deref(), expected, "{specifiers} ({version})");
+        }
+    }
+
+    #[test]
+    fn add_conflict() {
+        let project = PackageName::from_str("project").unwrap();
+        let member = PackageName::from_str("member").unwrap();
+        let mut toml = PyProjectTomlMut::from_toml(
+            r#"[project]
+name = "project"
+
+[tool.uv.sources]
+member = { workspace = true }
+"#,
+            DependencyTarget::PyProjectToml,
+        )
+        .unwrap();
+
+        let extras = ConflictSet::pair(
+            ConflictItem::from((project.clone(), ExtraName::from_str("extra1").unwrap())),
+            ConflictItem::from((project.clone(), ExtraName::from_str("extra2").unwrap())),
+        );
+        toml.add_conflict(&extras.to_schema(&project)).unwrap();
+
+        let groups = ConflictSet::pair(
+            ConflictItem::from((project.clone(), GroupName::from_str("group1").unwrap())),
+            ConflictItem::from((member, GroupName::from_str("group2").unwrap())),
+        );
+        toml.add_conflict(&groups.to_schema(&project)).unwrap();
+
+        assert_eq!(
+            toml.to_string(),
+            r#"[project]
+name = "project"
+
+[tool.uv]
+conflicts = [
+    [
+        { extra = "extra1" },
+        { extra = "extra2" },
+    ],
+    [
+        { package = "member", group = "group2" },
+        { group = "group1" },
+    ],
+]
+
+[tool.uv.sources]
+member = { workspace = true }
+"#
+        );
+    }
+
+    #[test]
+    fn add_conflict_existing() {
+        let project = PackageName::from_str("project").unwrap();
+        let mut toml = PyProjectTomlMut::from_toml(
+            r#"[tool.uv]
+conflicts = [
+  [
+    { extra = "extra1" },
+    { extra = "extra2" },
+  ],
+]
+"#,
+            DependencyTarget::PyProjectToml,
+        )
+        .unwrap();
+
+        let set = ConflictSet::pair(
+            ConflictItem::from((project.clone(), ExtraName::from_str("extra1").unwrap())),
+            ConflictItem::from((project.clone(), ExtraName::from_str("extra3").unwrap())),
+        );
+        toml.add_conflict(&set.to_schema(&project)).unwrap();
+
+        assert_eq!(
+            toml.to_string(),
+            r#"[tool.uv]
+conflicts = [
+  [
+    { extra = "extra1" },
+    { extra = "extra2" },
+  ],
+  [
+    { extra = "extra1" },
+    { extra = "extra3" },
+  ],
+]
+"#
+        );
+    }
 }
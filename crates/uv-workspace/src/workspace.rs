@@ -21,7 +21,8 @@ use uv_warnings::warn_user_once;
 
 use crate::dependency_groups::{DependencyGroupError, FlatDependencyGroup, FlatDependencyGroups};
 use crate::pyproject::{
-    Project, PyProjectToml, PyprojectTomlError, Source, Sources, ToolUvSources, ToolUvWorkspace,
+    LicensePolicy, Project, PyProjectToml, PyprojectTomlError, Source, Sources, ToolUvSources,
+    ToolUvWorkspace,
 };
 
 type WorkspaceMembers = Arc<BTreeMap<PackageName, WorkspaceMember>>;
@@ -561,6 +562,15 @@ impl Workspace {
             .and_then(|uv| uv.required_environments.as_ref())
     }
 
+    /// Returns the license policy for the workspace.
+    pub fn license_policy(&self) -> Option<&LicensePolicy> {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.license_policy.as_ref())
+    }
+
     /// Returns the set of conflicts for the workspace.
     pub fn conflicts(&self) -> Conflicts {
         let mut conflicting = Conflicts::empty();
@@ -2079,6 +2089,7 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "license-policy": null,
                       "build-backend": null
                     }
                   },
@@ -2180,6 +2191,7 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "license-policy": null,
                       "build-backend": null
                     }
                   },
@@ -2394,6 +2406,7 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "license-policy": null,
                       "build-backend": null
                     }
                   },
@@ -2504,6 +2517,7 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "license-policy": null,
                       "build-backend": null
                     }
                   },
@@ -2627,6 +2641,7 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "license-policy": null,
                       "build-backend": null
                     }
                   },
@@ -2724,6 +2739,7 @@ mod tests {
                       "environments": null,
                       "required-environments": null,
                       "conflicts": null,
+                      "license-policy": null,
                       "build-backend": null
                     }
                   },
@@ -0,0 +1,61 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use owo_colors::OwoColorize;
+
+use uv_cache::{LockedPackage, Removal};
+use uv_fs::Simplified;
+use uv_resolver::{Lock, VERSION};
+
+use crate::commands::human_readable_bytes;
+
+/// Read the packages pinned in the lockfile at the given path.
+pub(crate) fn locked_packages(lockfile: &Path) -> Result<Vec<LockedPackage>> {
+    let contents = fs_err::read_to_string(lockfile)?;
+    let lock = toml::from_str::<Lock>(&contents)
+        .with_context(|| format!("Failed to parse lockfile at: {}", lockfile.user_display()))?;
+    if lock.version() != VERSION {
+        bail!(
+            "Unsupported lockfile version at `{}`: expected {VERSION}, found {}",
+            lockfile.user_display(),
+            lock.version()
+        );
+    }
+
+    Ok(lock
+        .packages()
+        .iter()
+        .map(|package| LockedPackage {
+            name: package.name().clone(),
+            version: package.version().cloned(),
+        })
+        .collect())
+}
+
+/// Describe the number of files and directories in a [`Removal`], along with the total byte
+/// count, e.g., `3 files (1.2MiB)`.
+///
+/// Returns `None` if the [`Removal`] is empty.
+pub(crate) fn describe(removal: &Removal) -> Option<String> {
+    let mut description = match (removal.num_files, removal.num_dirs) {
+        (0, 0) => return None,
+        (0, 1) => "1 directory".to_string(),
+        (0, num_dirs) => format!("{num_dirs} directories"),
+        (1, _) => "1 file".to_string(),
+        (num_files, _) => format!("{num_files} files"),
+    };
+
+    // If any, add the total byte count.
+    if removal.total_bytes > 0 {
+        let bytes = if removal.total_bytes < 1024 {
+            format!("{}B", removal.total_bytes)
+        } else {
+            let (bytes, unit) = human_readable_bytes(removal.total_bytes);
+            format!("{bytes:.1}{unit}")
+        };
+        write!(description, " ({})", bytes.green()).ok()?;
+    }
+
+    Some(description)
+}
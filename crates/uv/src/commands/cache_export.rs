@@ -0,0 +1,50 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_fs::Simplified;
+use uv_preview::{Preview, PreviewFeatures};
+use uv_warnings::warn_user;
+
+use crate::commands::cache_common::locked_packages;
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+
+/// Export the cache entries required by a lockfile to a portable bundle.
+pub(crate) fn cache_export(
+    output: &Path,
+    lockfile: &Path,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeatures::CACHE_BUNDLE) {
+        warn_user!(
+            "`uv cache export` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::CACHE_BUNDLE
+        );
+    }
+
+    let packages = locked_packages(lockfile)?;
+
+    let summary = cache
+        .export_bundle(&packages, output)
+        .with_context(|| format!("Failed to export cache to: {}", output.user_display()))?;
+
+    let entries = match summary.files + summary.links {
+        1 => "1 cache entry".to_string(),
+        n => format!("{n} cache entries"),
+    };
+    let (bytes, unit) = human_readable_bytes(summary.bytes);
+    writeln!(
+        printer.stderr(),
+        "Exported {entries} ({}) to: {}",
+        format!("{bytes:.1}{unit}").green(),
+        output.user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,46 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_fs::Simplified;
+use uv_preview::{Preview, PreviewFeatures};
+use uv_warnings::warn_user;
+
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+
+/// Import the cache entries from a bundle created by `uv cache export`.
+pub(crate) fn cache_import(
+    bundle: &Path,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeatures::CACHE_BUNDLE) {
+        warn_user!(
+            "`uv cache import` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::CACHE_BUNDLE
+        );
+    }
+
+    let summary = cache
+        .import_bundle(bundle)
+        .with_context(|| format!("Failed to import cache from: {}", bundle.user_display()))?;
+
+    let entries = match summary.files + summary.links {
+        1 => "1 cache entry".to_string(),
+        n => format!("{n} cache entries"),
+    };
+    let (bytes, unit) = human_readable_bytes(summary.bytes);
+    writeln!(
+        printer.stderr(),
+        "Imported {entries} ({}) into: {}",
+        format!("{bytes:.1}{unit}").green(),
+        cache.root().user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
@@ -1,22 +1,37 @@
 use std::fmt::Write;
+use std::path::{Path, PathBuf};
 
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use owo_colors::OwoColorize;
 use tracing::debug;
 
-use uv_cache::{Cache, Removal};
+use uv_cache::{Cache, EvictionPolicy, Removal};
 use uv_fs::Simplified;
+use uv_preview::{Preview, PreviewFeatures};
+use uv_warnings::warn_user;
 
-use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::commands::ExitStatus;
+use crate::commands::cache_common::{describe, locked_packages};
 use crate::printer::Printer;
 
 /// Prune all unreachable objects from the cache.
 pub(crate) async fn cache_prune(
     ci: bool,
     force: bool,
+    keep_lock: &[String],
+    dry_run: bool,
+    eviction_policy: &EvictionPolicy,
     cache: Cache,
     printer: Printer,
+    preview: Preview,
 ) -> Result<ExitStatus> {
+    if !keep_lock.is_empty() && !preview.is_enabled(PreviewFeatures::CACHE_PRUNE_LOCK) {
+        warn_user!(
+            "`uv cache prune --keep-lock` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::CACHE_PRUNE_LOCK
+        );
+    }
+
     if !cache.root().exists() {
         writeln!(
             printer.stderr(),
@@ -26,6 +41,13 @@ pub(crate) async fn cache_prune(
         return Ok(ExitStatus::Success);
     }
 
+    // Read the lockfiles up-front, to avoid removing anything if any of them are invalid.
+    let mut packages = Vec::new();
+    for lockfile in find_lockfiles(keep_lock)? {
+        debug!("Retaining cache entries for: {}", lockfile.user_display());
+        packages.extend(locked_packages(&lockfile)?);
+    }
+
     let cache = match cache.with_exclusive_lock_no_wait() {
         Ok(cache) => cache,
         Err(cache) if force => {
@@ -41,6 +63,35 @@ pub(crate) async fn cache_prune(
         }
     };
 
+    if dry_run {
+        writeln!(
+            printer.stderr(),
+            "Checking cache at: {}",
+            cache.root().user_display().cyan()
+        )?;
+
+        let mut total = Removal::default();
+        for (bucket, removal) in cache
+            .prune_unlocked(&packages, true)
+            .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?
+        {
+            match describe(&removal) {
+                Some(description) => writeln!(
+                    printer.stderr(),
+                    "Would remove {description} from `{bucket}`"
+                )?,
+                None => writeln!(printer.stderr(), "No unused entries found in `{bucket}`")?,
+            }
+            total += removal;
+        }
+
+        if let Some(description) = describe(&total) {
+            writeln!(printer.stderr(), "Would remove {description} in total")?;
+        }
+
+        return Ok(ExitStatus::Success);
+    }
+
     writeln!(
         printer.stderr(),
         "Pruning cache at: {}",
@@ -58,37 +109,74 @@ pub(crate) async fn cache_prune(
         .prune(ci)
         .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
 
-    // Write a summary of the number of files and directories removed.
-    match (summary.num_files, summary.num_dirs) {
-        (0, 0) => {
-            write!(printer.stderr(), "No unused entries found")?;
-        }
-        (0, 1) => {
-            write!(printer.stderr(), "Removed 1 directory")?;
+    // Prune any entries that aren't required by the retained lockfiles.
+    if !keep_lock.is_empty() {
+        for (_, removal) in cache
+            .prune_unlocked(&packages, false)
+            .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?
+        {
+            summary += removal;
         }
-        (0, num_dirs_removed) => {
-            write!(printer.stderr(), "Removed {num_dirs_removed} directories")?;
+    }
+
+    // Evict any least recently used entries, if configured.
+    summary += cache.evict(eviction_policy).with_context(|| {
+        format!(
+            "Failed to evict cache entries at: {}",
+            cache.root().user_display()
+        )
+    })?;
+
+    // Write a summary of the number of files and directories removed.
+    match describe(&summary) {
+        Some(description) => writeln!(printer.stderr(), "Removed {description}")?,
+        None => writeln!(printer.stderr(), "No unused entries found")?,
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Resolve the lockfiles to retain from a set of paths or glob patterns.
+///
+/// Each path may refer to a lockfile, or to a directory containing a `uv.lock`.
+fn find_lockfiles(patterns: &[String]) -> Result<Vec<PathBuf>> {
+    let mut lockfiles = Vec::new();
+    for pattern in patterns {
+        // If the pattern is a literal path, require that it refers to a lockfile.
+        if !pattern.contains(['*', '?', '[']) {
+            let Some(lockfile) = lockfile_at(Path::new(pattern)) else {
+                bail!("No lockfile found at: `{pattern}`");
+            };
+            lockfiles.push(lockfile);
+            continue;
         }
-        (1, _) => {
-            write!(printer.stderr(), "Removed 1 file")?;
+
+        // Otherwise, expand the pattern, skipping any matches without a lockfile.
+        let mut matched = false;
+        for path in
+            glob::glob(pattern).with_context(|| format!("Invalid glob pattern: `{pattern}`"))?
+        {
+            let path = path?;
+            if let Some(lockfile) = lockfile_at(&path) {
+                lockfiles.push(lockfile);
+                matched = true;
+            }
         }
-        (num_files_removed, _) => {
-            write!(printer.stderr(), "Removed {num_files_removed} files")?;
+        if !matched {
+            bail!("No lockfiles matched: `{pattern}`");
         }
     }
+    Ok(lockfiles)
+}
 
-    // If any, write a summary of the total byte count removed.
-    if summary.total_bytes > 0 {
-        let bytes = if summary.total_bytes < 1024 {
-            format!("{}B", summary.total_bytes)
-        } else {
-            let (bytes, unit) = human_readable_bytes(summary.total_bytes);
-            format!("{bytes:.1}{unit}")
-        };
-        write!(printer.stderr(), " ({})", bytes.green())?;
+/// Return the lockfile at the given path, which may be a lockfile (e.g., `uv.lock` or
+/// `script.py.lock`) or a directory containing a `uv.lock`.
+fn lockfile_at(path: &Path) -> Option<PathBuf> {
+    if path.is_dir() {
+        let lockfile = path.join("uv.lock");
+        lockfile.is_file().then_some(lockfile)
+    } else {
+        (path.is_file() && path.extension().is_some_and(|ext| ext == "lock"))
+            .then(|| path.to_path_buf())
     }
-
-    writeln!(printer.stderr())?;
-
-    Ok(ExitStatus::Success)
 }
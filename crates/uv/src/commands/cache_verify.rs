@@ -0,0 +1,216 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::{ARCHIVE_VERSION, Cache, CacheBucket, CacheEntryVerifier, VerifiedArchives};
+use uv_client::{DataWithCachePolicy, OwnedArchive, SimpleDetailMetadata, SimpleIndexMetadata};
+use uv_distribution::{Archive, HttpArchivePointer, LocalArchivePointer};
+use uv_fs::Simplified;
+use uv_install_wheel::{RecordMismatch, verify_record};
+use uv_preview::{Preview, PreviewFeatures};
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::commands::cache_common::describe;
+use crate::printer::Printer;
+
+/// Verify the integrity of the cache, removing any corrupted entries.
+pub(crate) async fn cache_verify(
+    force: bool,
+    dry_run: bool,
+    cache: Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeatures::CACHE_VERIFY) {
+        warn_user!(
+            "`uv cache verify` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::CACHE_VERIFY
+        );
+    }
+
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let cache = match cache.with_exclusive_lock_no_wait() {
+        Ok(cache) => cache,
+        Err(cache) if force => {
+            debug!("Cache is currently in use, proceeding due to `--force`");
+            cache
+        }
+        Err(cache) => {
+            writeln!(
+                printer.stderr(),
+                "Cache is currently in-use, waiting for other uv processes to finish (use `--force` to override)"
+            )?;
+            cache.with_exclusive_lock().await?
+        }
+    };
+
+    writeln!(
+        printer.stderr(),
+        "Verifying cache at: {}",
+        cache.root().user_display().cyan()
+    )?;
+
+    let verification = cache
+        .verify(&EntryVerifier, dry_run)
+        .with_context(|| format!("Failed to verify cache at: {}", cache.root().user_display()))?;
+
+    for entry in &verification.broken {
+        let path = entry.path.strip_prefix(cache.root()).unwrap_or(&entry.path);
+        writeln!(
+            printer.stderr(),
+            "{} `{}` ({})",
+            if dry_run {
+                "Found broken entry:"
+            } else {
+                "Removed broken entry:"
+            },
+            path.user_display(),
+            entry.reason
+        )?;
+    }
+
+    let entries = match verification.checked {
+        1 => "1 cache entry".to_string(),
+        n => format!("{n} cache entries"),
+    };
+    match (verification.broken.len(), describe(&verification.removal)) {
+        (0, _) => writeln!(
+            printer.stderr(),
+            "Verified {entries}; no broken entries found"
+        )?,
+        (broken, description) => {
+            let broken = match broken {
+                1 => "1 broken entry".to_string(),
+                n => format!("{n} broken entries"),
+            };
+            let description = description.map(|description| format!(", {description}"));
+            writeln!(
+                printer.stderr(),
+                "Verified {entries}; {} {broken}{}",
+                if dry_run { "would remove" } else { "removed" },
+                description.unwrap_or_default()
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Verifies the cache entries written by uv: unzipped wheels, cached index responses, HTTP cache
+/// policies, and pointers to unzipped wheels.
+struct EntryVerifier;
+
+impl CacheEntryVerifier for EntryVerifier {
+    fn verify_file(
+        &self,
+        bucket: CacheBucket,
+        path: &Path,
+        archives: &VerifiedArchives,
+    ) -> Option<Result<(), String>> {
+        let extension = path.extension()?.to_str()?;
+        match (bucket, extension) {
+            // Simple API responses are stored as rkyv archives, alongside their cache policy.
+            (CacheBucket::Simple, "rkyv") => Some(verify_simple(path)),
+            // Wheel metadata and flat index responses are stored as MessagePack, alongside their
+            // cache policy.
+            (CacheBucket::Wheels | CacheBucket::FlatIndex, "msgpack") => {
+                Some(read_cache_policy(path).map(|_| ()))
+            }
+            // Pointers to unzipped wheels downloaded from a URL are stored alongside their cache
+            // policy.
+            (CacheBucket::Wheels, "http") => Some(read_cache_policy(path).and_then(|_| {
+                let pointer = HttpArchivePointer::read_from(path)
+                    .map_err(|err| format!("invalid archive pointer: {err}"))?;
+                verify_pointer(pointer.map(HttpArchivePointer::into_archive), archives)
+            })),
+            // Pointers to unzipped wheels from a local path.
+            (CacheBucket::Wheels, "rev") => Some(
+                LocalArchivePointer::read_from(path)
+                    .map_err(|err| format!("invalid archive pointer: {err}"))
+                    .and_then(|pointer| {
+                        verify_pointer(pointer.map(LocalArchivePointer::into_archive), archives)
+                    }),
+            ),
+            // Pointers to source distribution revisions are stored alongside their cache policy.
+            (CacheBucket::SourceDistributions, "http") => Some(read_cache_policy(path).map(|_| ())),
+            _ => None,
+        }
+    }
+
+    fn verify_archive(&self, path: &Path) -> Result<(), String> {
+        let mut dist_infos = fs_err::read_dir(path)
+            .map_err(|err| err.to_string())?
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "dist-info") && path.is_dir())
+            .peekable();
+        if dist_infos.peek().is_none() {
+            return Err("missing `.dist-info` directory".to_string());
+        }
+
+        for dist_info in dist_infos {
+            let verification = verify_record(&dist_info).map_err(|err| match err {
+                uv_install_wheel::Error::MissingRecord(_) => "missing `RECORD` file".to_string(),
+                err => err.to_string(),
+            })?;
+            if let Some(mismatch) = verification.mismatches.first() {
+                return Err(match mismatch {
+                    RecordMismatch::Missing(path) => {
+                        format!("`{}` is missing", path.user_display())
+                    }
+                    RecordMismatch::Modified(path) => {
+                        format!("`{}` does not match its recorded hash", path.user_display())
+                    }
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Verify that an archive pointer refers to an unzipped wheel that exists in the cache and passed
+/// verification.
+///
+/// Pointers to archives from previous cache versions are ignored, as uv never reads them.
+fn verify_pointer(archive: Option<Archive>, archives: &VerifiedArchives) -> Result<(), String> {
+    match archive {
+        Some(archive) if archive.version == ARCHIVE_VERSION && !archives.contains(&archive.id) => {
+            Err(format!("archive `{}` does not exist", archive.id))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Read the data and HTTP cache policy from a cache entry.
+fn read_cache_policy(path: &Path) -> Result<DataWithCachePolicy, String> {
+    let file = fs_err::File::open(path).map_err(|err| err.to_string())?;
+    DataWithCachePolicy::from_reader(file)
+        .map_err(|err| format!("invalid HTTP cache policy: {err}"))
+}
+
+/// Verify that a cached Simple API response deserializes.
+fn verify_simple(path: &Path) -> Result<(), String> {
+    let data = read_cache_policy(path)?.data;
+    let result = if path
+        .file_name()
+        .is_some_and(|file_name| file_name == "index.html.rkyv")
+    {
+        OwnedArchive::<SimpleIndexMetadata>::new(data).map(|_| ())
+    } else {
+        OwnedArchive::<SimpleDetailMetadata>::new(data).map(|_| ())
+    };
+    result.map_err(|err| format!("invalid index response: {err}"))
+}
@@ -324,6 +324,29 @@ pub(crate) fn no_solution_hint(err: Box<uv_resolver::NoSolutionError>, help: Str
     anstream::eprint!("{report:?}");
 }
 
+/// Render a [`uv_resolver::LicenseViolations`] report.
+pub(crate) fn license_policy(violations: uv_resolver::LicenseViolations) {
+    #[derive(Debug, miette::Diagnostic, thiserror::Error)]
+    #[error("The locked dependencies violate the license policy:")]
+    #[diagnostic()]
+    struct Error {
+        /// The underlying violations.
+        #[source]
+        violations: uv_resolver::LicenseViolations,
+
+        /// The help message to display.
+        #[help]
+        help: String,
+    }
+
+    let help = format!(
+        "If the license of a package has been reviewed, add it to `{}` to exempt it from the policy",
+        "tool.uv.license-policy.exceptions".green()
+    );
+    let report = miette::Report::new(Error { violations, help });
+    anstream::eprint!("{report:?}");
+}
+
 /// Render a [`uv_resolver::NoSolutionError`] with a help message.
 pub(crate) fn native_tls_hint(err: uv_client::Error) {
     #[derive(Debug, miette::Diagnostic)]
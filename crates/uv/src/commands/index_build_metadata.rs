@@ -0,0 +1,75 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_client::MetadataIndex;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_preview::{Preview, PreviewFeatures};
+use uv_warnings::warn_user;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Build a local metadata index from the Simple API listings and wheel metadata in the cache.
+pub(crate) fn index_build_metadata(
+    output: &Path,
+    packages: &[PackageName],
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeatures::METADATA_INDEX) {
+        warn_user!(
+            "`uv index build-metadata` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::METADATA_INDEX
+        );
+    }
+
+    if !cache.root().exists() {
+        writeln!(
+            printer.stderr(),
+            "No cache found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let metadata_index = MetadataIndex::new(output);
+    let summary = metadata_index
+        .build_from_cache(cache, packages)
+        .with_context(|| {
+            format!(
+                "Failed to build metadata index at: {}",
+                output.user_display()
+            )
+        })?;
+
+    if summary.packages == 0 {
+        writeln!(
+            printer.stderr(),
+            "No cached package listings found at: {}",
+            cache.root().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let packages = match summary.packages {
+        1 => "1 package".to_string(),
+        n => format!("{n} packages"),
+    };
+    let metadata = match summary.metadata {
+        1 => "1 metadata file".to_string(),
+        n => format!("{n} metadata files"),
+    };
+    writeln!(
+        printer.stderr(),
+        "Wrote {packages} with {metadata} to: {}",
+        output.user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
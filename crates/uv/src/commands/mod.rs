@@ -17,9 +17,13 @@ pub(crate) use auth::token::token as auth_token;
 pub(crate) use build_frontend::build_frontend;
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
+pub(crate) use cache_export::cache_export;
+pub(crate) use cache_import::cache_import;
 pub(crate) use cache_prune::cache_prune;
 pub(crate) use cache_size::cache_size;
+pub(crate) use cache_verify::cache_verify;
 pub(crate) use help::help;
+pub(crate) use index_build_metadata::index_build_metadata;
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
 pub(crate) use pip::freeze::pip_freeze;
@@ -30,14 +34,21 @@ pub(crate) use pip::sync::pip_sync;
 pub(crate) use pip::tree::pip_tree;
 pub(crate) use pip::uninstall::pip_uninstall;
 pub(crate) use project::add::add;
+pub(crate) use project::audit::audit;
+pub(crate) use project::bin::bin;
 pub(crate) use project::export::export;
 pub(crate) use project::format::format;
+pub(crate) use project::imports::import_requirements;
 pub(crate) use project::init::{InitKind, InitProjectKind, init};
+pub(crate) use project::lint::lint;
 pub(crate) use project::lock::lock;
+pub(crate) use project::lock_diff::{LockDiffBase, lock_diff};
+pub(crate) use project::lock_merge::lock_merge;
 pub(crate) use project::remove::remove;
 pub(crate) use project::run::{RunCommand, run};
 pub(crate) use project::sync::sync;
 pub(crate) use project::tree::tree;
+pub(crate) use project::upgrade::upgrade;
 pub(crate) use project::version::{project_version, self_version};
 pub(crate) use publish::publish;
 pub(crate) use python::dir::dir as python_dir;
@@ -78,11 +89,16 @@ mod auth;
 pub(crate) mod build_backend;
 mod build_frontend;
 mod cache_clean;
+mod cache_common;
 mod cache_dir;
+mod cache_export;
+mod cache_import;
 mod cache_prune;
 mod cache_size;
+mod cache_verify;
 mod diagnostics;
 mod help;
+mod index_build_metadata;
 pub(crate) mod pip;
 mod project;
 mod publish;
@@ -3,11 +3,14 @@ use std::time::Instant;
 
 use anyhow::Result;
 use owo_colors::OwoColorize;
+use serde::Serialize;
 
 use uv_cache::Cache;
+use uv_cli::PipCheckFormat;
 use uv_configuration::TargetTriple;
 use uv_distribution_types::{Diagnostic, InstalledDist};
 use uv_installer::{SitePackages, SitePackagesDiagnostic};
+use uv_normalize::PackageName;
 use uv_preview::Preview;
 use uv_python::{
     EnvironmentPreference, PythonEnvironment, PythonPreference, PythonRequest, PythonVersion,
@@ -24,6 +27,8 @@ pub(crate) fn pip_check(
     system: bool,
     python_version: Option<&PythonVersion>,
     python_platform: Option<&TargetTriple>,
+    verify_record: bool,
+    output_format: PipCheckFormat,
     cache: &Cache,
     printer: Printer,
     preview: Preview,
@@ -61,11 +66,40 @@ pub(crate) fn pip_check(
     let markers = resolution_markers(python_version, python_platform, environment.interpreter());
     let tags = resolution_tags(python_version, python_platform, environment.interpreter())?;
 
-    // Run the diagnostics.
-    let diagnostics: Vec<SitePackagesDiagnostic> = site_packages
+    // Run the diagnostics, then (if requested) verify the installed files against their `RECORD`
+    // files.
+    let mut diagnostics: Vec<SitePackagesDiagnostic> = site_packages
         .diagnostics(&markers, &tags)?
         .into_iter()
         .collect();
+    if verify_record {
+        diagnostics.extend(site_packages.verify_records());
+    }
+
+    if let PipCheckFormat::Json = output_format {
+        let report = CheckReport {
+            checked: packages.len(),
+            diagnostics: diagnostics
+                .iter()
+                .map(|diagnostic| CheckDiagnostic {
+                    kind: diagnostic.kind(),
+                    package: diagnostic.package(),
+                    message: diagnostic.message(),
+                })
+                .collect(),
+        };
+        writeln!(
+            printer.stdout(),
+            "{}",
+            serde_json::to_string_pretty(&report)?
+        )?;
+
+        return if diagnostics.is_empty() {
+            Ok(ExitStatus::Success)
+        } else {
+            Ok(ExitStatus::Failure)
+        };
+    }
 
     if diagnostics.is_empty() {
         writeln!(
@@ -98,3 +132,18 @@ pub(crate) fn pip_check(
         Ok(ExitStatus::Failure)
     }
 }
+
+/// The JSON report for `uv pip check`.
+#[derive(Debug, Serialize)]
+struct CheckReport<'a> {
+    /// The number of installed packages that were checked.
+    checked: usize,
+    diagnostics: Vec<CheckDiagnostic<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct CheckDiagnostic<'a> {
+    kind: &'static str,
+    package: &'a PackageName,
+    message: String,
+}
@@ -54,6 +54,7 @@ use uv_workspace::WorkspaceCache;
 use uv_workspace::pyproject::ExtraBuildDependencies;
 
 use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::operations::ResolveDiagnostics;
 use crate::commands::pip::{operations, resolution_markers, resolution_tags};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::{ExitStatus, OutputWriter, diagnostics};
@@ -591,6 +592,7 @@ pub(crate) async fn pip_compile(
         &build_dispatch,
         concurrency,
         options,
+        ResolveDiagnostics::default(),
         Box::new(DefaultResolveLogger),
         printer,
     )
@@ -2,8 +2,8 @@
 
 use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::fmt::Write;
-use std::path::PathBuf;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
 use anyhow::{Context, anyhow};
 use itertools::Itertools;
@@ -40,7 +40,8 @@ use uv_requirements::{
 };
 use uv_resolver::{
     DependencyMode, Exclusions, FlatIndex, InMemoryIndex, Manifest, Options, Preference,
-    Preferences, PythonRequirement, Resolver, ResolverEnvironment, ResolverOutput,
+    Preferences, PythonRequirement, RecordingResolverProvider, ReplayResolverProvider, Resolver,
+    ResolverEnvironment, ResolverOutput, ResolverProfile, ResolverTrace, TraceMode,
 };
 use uv_tool::InstalledTools;
 use uv_types::{BuildContext, HashStrategy, InFlight, InstalledPackagesProvider};
@@ -102,6 +103,24 @@ pub(crate) async fn read_constraints(
     )
 }
 
+/// Options to record, replay, explain, or profile a resolution.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct ResolveDiagnostics<'a> {
+    /// Record the resolver's responses to, or replay them from, a trace file.
+    pub(crate) trace: Option<&'a TraceMode>,
+    /// Explain the version selected for the given package in each resolution fork.
+    pub(crate) explain: Option<&'a PackageName>,
+    /// Write a profile of the resolution to the given directory.
+    pub(crate) profile: Option<&'a Path>,
+}
+
+impl ResolveDiagnostics<'_> {
+    /// Returns `true` if any diagnostics are requested.
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.trace.is_some() || self.explain.is_some() || self.profile.is_some()
+    }
+}
+
 /// Resolve a set of requirements, similar to running `pip compile`.
 pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     requirements: Vec<UnresolvedRequirementSpecification>,
@@ -129,10 +148,16 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     build_dispatch: &BuildDispatch<'_>,
     concurrency: Concurrency,
     options: Options,
+    diagnostics: ResolveDiagnostics<'_>,
     logger: Box<dyn ResolveLogger>,
     printer: Printer,
 ) -> Result<ResolverOutput, Error> {
     let start = std::time::Instant::now();
+    let ResolveDiagnostics {
+        trace,
+        explain,
+        profile,
+    } = diagnostics;
 
     // Resolve the requirements from the provided sources.
     let requirements = {
@@ -343,7 +368,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
             }
         };
 
-        let resolver = Resolver::new(
+        let mut resolver = Resolver::new(
             manifest,
             options,
             &python_requirement,
@@ -360,7 +385,58 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
         )?
         .with_reporter(Arc::new(reporter));
 
-        resolver.resolve().await?
+        if let Some(explain) = explain {
+            resolver = resolver.with_explain(explain.clone());
+        }
+
+        let resolver_profile = profile.map(|_| Arc::new(ResolverProfile::new()));
+        if let Some(resolver_profile) = &resolver_profile {
+            resolver = resolver.with_profile(Arc::clone(resolver_profile));
+        }
+
+        let result = match trace {
+            None => resolver.resolve().await,
+            Some(TraceMode::Record(path)) => {
+                let trace = Arc::new(Mutex::new(ResolverTrace::default()));
+                let result = resolver
+                    .map_provider(|provider| {
+                        RecordingResolverProvider::new(provider, Arc::clone(&trace))
+                    })
+                    .resolve()
+                    .await;
+
+                // Write the trace even if the resolution failed, so that the failure can be
+                // reproduced.
+                trace
+                    .lock()
+                    .unwrap()
+                    .write(path)
+                    .map_err(anyhow::Error::from)?;
+                result
+            }
+            Some(TraceMode::Replay(path)) => {
+                let trace = ResolverTrace::read(path).map_err(anyhow::Error::from)?;
+                resolver
+                    .map_provider(|provider| ReplayResolverProvider::new(provider, trace))
+                    .resolve()
+                    .await
+            }
+        };
+
+        // Write the profile even if the resolution failed, since slow failures are worth
+        // profiling too.
+        if let (Some(profile), Some(resolver_profile)) = (profile, resolver_profile) {
+            resolver_profile
+                .write(profile)
+                .map_err(anyhow::Error::from)?;
+            writeln!(
+                printer.stderr(),
+                "Wrote resolver profile to `{}`",
+                profile.user_display().cyan()
+            )?;
+        }
+
+        result?
     };
 
     logger.on_complete(resolution.len(), start, printer)?;
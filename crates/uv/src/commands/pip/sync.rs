@@ -42,7 +42,9 @@ use uv_workspace::pyproject::ExtraBuildDependencies;
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
 use crate::commands::pip::operations::Modifications;
-use crate::commands::pip::operations::{report_interpreter, report_target_environment};
+use crate::commands::pip::operations::{
+    ResolveDiagnostics, report_interpreter, report_target_environment,
+};
 use crate::commands::pip::{operations, resolution_markers, resolution_tags};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::{ExitStatus, diagnostics};
@@ -497,6 +499,7 @@ pub(crate) async fn pip_sync(
             &build_dispatch,
             concurrency,
             options,
+            ResolveDiagnostics::default(),
             Box::new(DefaultResolveLogger),
             printer,
         )
@@ -0,0 +1,322 @@
+use std::fmt::Write as _;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, anyhow};
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
+
+use uv_audit::{AdvisoryDatabase, AuditIgnore, AuditReport, AuditedPackage, Finding};
+use uv_cli::AuditFormat;
+use uv_configuration::{DependencyGroups, ExtrasSpecification, InstallOptions};
+use uv_fs::Simplified;
+use uv_normalize::{DefaultExtras, PackageName};
+use uv_pep440::Version;
+use uv_pep508::MarkerTree;
+use uv_preview::{Preview, PreviewFeatures};
+use uv_resolver::reachable_packages;
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
+
+use crate::commands::project::default_dependency_groups;
+use crate::commands::project::install_target::InstallTarget;
+use crate::commands::project::lock_target::LockTarget;
+use crate::commands::{ExitStatus, OutputWriter};
+use crate::printer::Printer;
+
+/// Audit the project's lockfile for known vulnerabilities.
+pub(crate) async fn audit(
+    project_dir: &Path,
+    database: PathBuf,
+    ignore: Vec<String>,
+    ignore_package: Vec<PackageName>,
+    environments: Vec<MarkerTree>,
+    output_format: AuditFormat,
+    output_file: Option<PathBuf>,
+    quiet: bool,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeatures::AUDIT) {
+        warn_user!(
+            "`uv audit` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::AUDIT
+        );
+    }
+
+    // Identify the project.
+    let workspace_cache = WorkspaceCache::default();
+    let project =
+        VirtualProject::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+            .await?;
+
+    // Read the existing lockfile; auditing never modifies it.
+    let lock_target = LockTarget::Workspace(project.workspace());
+    let Some(lock) = lock_target.read().await? else {
+        return Err(anyhow!(
+            "Unable to find lockfile at `uv.lock`. To create a lockfile, run `{}`.",
+            "uv lock".green()
+        ));
+    };
+
+    // Read the advisory database.
+    let database = AdvisoryDatabase::from_path(&database)?;
+
+    // Audit every package that could be installed from the lockfile, across all workspace members,
+    // extras, and dependency groups.
+    let default_groups = default_dependency_groups(project.pyproject_toml())?;
+    let groups = DependencyGroups::from_args(
+        false,
+        false,
+        false,
+        Vec::new(),
+        Vec::new(),
+        false,
+        Vec::new(),
+        true,
+    )
+    .with_defaults(default_groups);
+    let extras = ExtrasSpecification::from_all_extras().with_defaults(DefaultExtras::default());
+    let install_options = InstallOptions::default();
+    let target = match &project {
+        VirtualProject::Project(project) => InstallTarget::Workspace {
+            workspace: project.workspace(),
+            lock: &lock,
+        },
+        VirtualProject::NonProject(workspace) => InstallTarget::NonProjectWorkspace {
+            workspace,
+            lock: &lock,
+        },
+    };
+
+    // Determine the environments that the project targets. A package is considered reachable if it
+    // can be installed on any of them.
+    let environments = if environments.is_empty() {
+        lock.simplified_required_environments()
+    } else {
+        environments
+            .into_iter()
+            .map(|marker| lock.simplify_environment(marker))
+            .collect()
+    };
+
+    // Locate each package in the lockfile, to attribute findings to a line.
+    let lines = if matches!(output_format, AuditFormat::Sarif) {
+        let contents = fs_err::tokio::read_to_string(lock_target.lock_path()).await?;
+        package_lines(&contents)
+    } else {
+        FxHashMap::default()
+    };
+
+    let packages = reachable_packages(&target, &extras, &groups, &install_options)?
+        .into_iter()
+        .filter(|reachable| {
+            !project
+                .workspace()
+                .packages()
+                .contains_key(reachable.package.name())
+        })
+        .filter_map(|reachable| {
+            let version = reachable.package.version()?.clone();
+            let name = reachable.package.name().clone();
+            let line = lines.get(&(name.clone(), version.clone())).copied();
+            let reachable_on_target = environments.is_empty()
+                || environments
+                    .iter()
+                    .any(|environment| !environment.is_disjoint(reachable.marker));
+            Some(AuditedPackage {
+                marker: reachable.marker.contents().map(|marker| marker.to_string()),
+                reachable: reachable_on_target,
+                name,
+                version,
+                line,
+            })
+        });
+
+    let ignore = AuditIgnore::new(ignore, ignore_package);
+    let report = uv_audit::audit(&database, packages, &ignore);
+
+    let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file.as_deref());
+    match output_format {
+        AuditFormat::Text => write!(writer, "{}", format_report(&report))?,
+        AuditFormat::Json => writeln!(writer, "{}", report.to_json()?)?,
+        AuditFormat::Sarif => writeln!(writer, "{}", report.to_sarif("uv.lock")?)?,
+    }
+    writer.commit().await.with_context(|| {
+        format!(
+            "Failed to write audit report to: `{}`",
+            output_file
+                .as_deref()
+                .unwrap_or(Path::new("-"))
+                .user_display()
+        )
+    })?;
+
+    if report.has_vulnerabilities() {
+        Ok(ExitStatus::Failure)
+    } else {
+        if matches!(output_format, AuditFormat::Json | AuditFormat::Sarif) {
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!(
+                    "Audited {} {}",
+                    report.packages,
+                    if report.packages == 1 {
+                        "package"
+                    } else {
+                        "packages"
+                    }
+                )
+                .dimmed()
+            )?;
+        }
+        Ok(ExitStatus::Success)
+    }
+}
+
+/// Render a human-readable audit report.
+fn format_report(report: &AuditReport) -> String {
+    let mut output = String::new();
+
+    let reachable = report.reachable().collect::<Vec<_>>();
+    let unreachable = report.unreachable().collect::<Vec<_>>();
+
+    let s = if report.packages == 1 { "" } else { "s" };
+    if reachable.is_empty() {
+        let _ = writeln!(
+            output,
+            "No known vulnerabilities found in {} package{s}",
+            report.packages.bold()
+        );
+    } else {
+        let _ = writeln!(
+            output,
+            "{} in {} package{s}:",
+            pluralize_vulnerabilities(reachable.len()).red().bold(),
+            report.packages.bold()
+        );
+        format_findings(&mut output, &reachable);
+    }
+
+    if !unreachable.is_empty() {
+        let _ = writeln!(output);
+        let _ = writeln!(
+            output,
+            "{} in packages that are not installed on the targeted environments:",
+            pluralize_vulnerabilities(unreachable.len()).yellow().bold(),
+        );
+        format_findings(&mut output, &unreachable);
+    }
+
+    if !report.ignored.is_empty() {
+        let _ = writeln!(output);
+        let _ = writeln!(
+            output,
+            "{}",
+            format!(
+                "Ignored {} known {}",
+                report.ignored.len(),
+                if report.ignored.len() == 1 {
+                    "vulnerability"
+                } else {
+                    "vulnerabilities"
+                }
+            )
+            .dimmed()
+        );
+    }
+
+    output
+}
+
+/// Render a list of findings, grouped by package.
+fn format_findings(output: &mut String, findings: &[&Finding]) {
+    let mut previous: Option<(&PackageName, &Version)> = None;
+    for finding in findings {
+        if previous != Some((&finding.name, &finding.version)) {
+            let _ = writeln!(output);
+            if let Some(marker) = &finding.marker {
+                let _ = writeln!(
+                    output,
+                    "{} {} {}",
+                    finding.name.bold(),
+                    finding.version,
+                    format!("; {marker}").dimmed()
+                );
+            } else {
+                let _ = writeln!(output, "{} {}", finding.name.bold(), finding.version);
+            }
+            previous = Some((&finding.name, &finding.version));
+        }
+
+        let advisory = &finding.advisory;
+        let mut header = advisory.id.cyan().to_string();
+        if !advisory.aliases.is_empty() {
+            let _ = write!(header, " ({})", advisory.aliases.join(", "));
+        }
+        if let Some(severity) = &advisory.severity {
+            let _ = write!(header, " [{}]", severity.to_lowercase());
+        }
+        if let Some(summary) = &advisory.summary {
+            let _ = write!(header, ": {summary}");
+        }
+        let _ = writeln!(output, "  - {header}");
+
+        if advisory.fixed_versions.is_empty() {
+            let _ = writeln!(output, "    {}", "No fixed version available".dimmed());
+        } else {
+            let _ = writeln!(
+                output,
+                "    Fixed in: {}",
+                advisory
+                    .fixed_versions
+                    .iter()
+                    .map(|version| version.green().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+        if let Some(url) = &advisory.url {
+            let _ = writeln!(output, "    {}", url.dimmed());
+        }
+    }
+}
+
+/// Format a count of vulnerabilities (e.g., "Found 1 known vulnerability").
+fn pluralize_vulnerabilities(count: usize) -> String {
+    if count == 1 {
+        "Found 1 known vulnerability".to_string()
+    } else {
+        format!("Found {count} known vulnerabilities")
+    }
+}
+
+/// Map each `[[package]]` entry in the lockfile to its (one-based) line number.
+fn package_lines(contents: &str) -> FxHashMap<(PackageName, Version), usize> {
+    let mut lines = FxHashMap::default();
+    let mut iter = contents.lines().enumerate().peekable();
+    while let Some((index, line)) = iter.next() {
+        if line != "[[package]]" {
+            continue;
+        }
+        let Some(name) = iter
+            .next()
+            .and_then(|(_, line)| line.strip_prefix("name = \""))
+            .and_then(|line| line.strip_suffix('"'))
+            .and_then(|name| name.parse::<PackageName>().ok())
+        else {
+            continue;
+        };
+        let Some(version) = iter
+            .peek()
+            .and_then(|(_, line)| line.strip_prefix("version = \""))
+            .and_then(|line| line.strip_suffix('"'))
+            .and_then(|version| version.parse::<Version>().ok())
+        else {
+            continue;
+        };
+        lines.insert((name, version), index + 1);
+    }
+    lines
+}
@@ -0,0 +1,121 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use tokio::process::Command;
+
+use uv_bin_install::{BinaryRegistry, BinarySource, bin_install};
+use uv_cache::Cache;
+use uv_client::BaseClientBuilder;
+use uv_pep440::Version;
+use uv_preview::{Preview, PreviewFeatures};
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache, WorkspaceError};
+
+use crate::child::run_to_completion;
+use crate::commands::ExitStatus;
+use crate::commands::reporters::BinaryDownloadReporter;
+use crate::printer::Printer;
+
+/// Run a binary from the binary registry.
+pub(crate) async fn bin(
+    project_dir: &Path,
+    name: &str,
+    extra_args: Vec<String>,
+    version: Option<String>,
+    binaries: &BTreeMap<String, BinarySource>,
+    binary_install_mirror: Option<&str>,
+    client_builder: BaseClientBuilder<'_>,
+    cache: Cache,
+    printer: Printer,
+    preview: Preview,
+    no_project: bool,
+) -> Result<ExitStatus> {
+    // Check if the bin feature is in preview
+    if !preview.is_enabled(PreviewFeatures::BIN) {
+        warn_user!(
+            "`uv bin` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::BIN
+        );
+    }
+
+    let target_dir = binary_target_dir(project_dir, no_project).await?;
+
+    // Get the path to the binary, downloading it if necessary
+    let path = install_binary(
+        name,
+        version.as_deref(),
+        binaries,
+        binary_install_mirror,
+        client_builder,
+        &cache,
+        printer,
+    )
+    .await?;
+
+    let mut command = Command::new(&path);
+    command.current_dir(target_dir);
+
+    // Add any additional arguments passed after `--`
+    command.args(extra_args.iter());
+
+    let handle = command
+        .spawn()
+        .with_context(|| format!("Failed to spawn `{name}`"))?;
+    run_to_completion(handle).await
+}
+
+/// Determine the directory in which to run a binary.
+pub(super) async fn binary_target_dir(project_dir: &Path, no_project: bool) -> Result<PathBuf> {
+    // If `no_project` is provided, we use the provided directory
+    if no_project {
+        return Ok(project_dir.to_owned());
+    }
+
+    // Otherwise, we discover the project and use the project root.
+    let workspace_cache = WorkspaceCache::default();
+    match VirtualProject::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+        .await
+    {
+        // If we found a project, we use the project root
+        Ok(proj) => Ok(proj.root().to_owned()),
+        // If there is a problem finding a project, we just use the provided directory,
+        // e.g., for unmanaged projects
+        Err(
+            WorkspaceError::MissingPyprojectToml
+            | WorkspaceError::MissingProject(_)
+            | WorkspaceError::NonWorkspace(_),
+        ) => Ok(project_dir.to_owned()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Get the path to the binary with the given name, downloading it if necessary.
+///
+/// If no version is requested, the version from the binary definition is used.
+pub(super) async fn install_binary(
+    name: &str,
+    version: Option<&str>,
+    binaries: &BTreeMap<String, BinarySource>,
+    binary_install_mirror: Option<&str>,
+    client_builder: BaseClientBuilder<'_>,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<PathBuf> {
+    // Parse version if provided
+    let version = version.map(Version::from_str).transpose()?;
+
+    let retry_policy = client_builder.retry_policy();
+    // Python downloads are performing their own retries to catch stream errors, disable the
+    // default retries to avoid the middleware from performing uncontrolled retries.
+    let client = client_builder.retries(0).build();
+
+    let reporter = BinaryDownloadReporter::single(printer);
+    let registry = BinaryRegistry::from_sources(binaries, binary_install_mirror)?;
+    let binary = registry.get(name)?;
+    let version = version.as_ref().unwrap_or(binary.default_version());
+    bin_install(binary, version, &client, &retry_policy, cache, &reporter)
+        .await
+        .with_context(|| format!("Failed to install {name} {version}"))
+}
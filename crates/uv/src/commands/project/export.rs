@@ -17,7 +17,9 @@ use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_preview::Preview;
 use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
 use uv_requirements::is_pylock_toml;
-use uv_resolver::{PylockToml, RequirementsTxtExport, cyclonedx_json};
+use uv_resolver::cyclonedx_json::CycloneDxVersion;
+use uv_resolver::spdx_json::SpdxVersion;
+use uv_resolver::{PackageLicenses, PylockToml, RequirementsTxtExport, cyclonedx_json, spdx_json};
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
 use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace, WorkspaceCache};
@@ -141,8 +143,15 @@ pub(crate) async fn export(
     let groups = groups.with_defaults(default_groups);
     let extras = extras.with_defaults(default_extras);
 
-    // Find an interpreter for the project, unless `--frozen` is set.
-    let interpreter = if frozen.is_some() {
+    // Read the licenses of the locked packages for the SBOM formats that include them. (The
+    // default formats never do.)
+    let licenses = matches!(
+        format,
+        Some(ExportFormat::CycloneDX1_6 | ExportFormat::Spdx2_3 | ExportFormat::Spdx3_0)
+    );
+
+    // Find an interpreter for the project, unless `--frozen` is set and no licenses are read.
+    let interpreter = if frozen.is_some() && !licenses {
         None
     } else {
         Some(match &target {
@@ -214,6 +223,7 @@ pub(crate) async fn export(
             printer,
             preview,
         )
+        .with_licenses(interpreter.as_ref().filter(|_| licenses))
         .execute((&target).into()),
     )
     .await
@@ -331,8 +341,8 @@ pub(crate) async fn export(
         }
     });
 
-    // Skip conflict detection for CycloneDX exports, as SBOMs are meant to document all dependencies including conflicts.
-    if !matches!(format, ExportFormat::CycloneDX1_5) {
+    // Skip conflict detection for SBOM exports, as SBOMs are meant to document all dependencies including conflicts.
+    if !format.is_sbom() {
         detect_conflicts(&target, &extras, &groups)?;
     }
 
@@ -404,12 +414,51 @@ pub(crate) async fn export(
                 &groups,
                 include_annotations,
                 &install_options,
+                &PackageLicenses::default(),
+                CycloneDxVersion::V1_5,
                 preview,
                 all_packages,
             )?;
 
             export.output_as_json_v1_5(&mut writer)?;
         }
+        ExportFormat::CycloneDX1_6 => {
+            let licenses = PackageLicenses::from_index(&lock, state.index());
+            let export = cyclonedx_json::from_lock(
+                &target,
+                &prune,
+                &extras,
+                &groups,
+                include_annotations,
+                &install_options,
+                &licenses,
+                CycloneDxVersion::V1_6,
+                preview,
+                all_packages,
+            )?;
+
+            cyclonedx_json::output_as_json_v1_6(export, &mut writer)?;
+        }
+        ExportFormat::Spdx2_3 | ExportFormat::Spdx3_0 => {
+            let licenses = PackageLicenses::from_index(&lock, state.index());
+            let export = spdx_json::from_lock(
+                &target,
+                &prune,
+                &extras,
+                &groups,
+                &install_options,
+                &licenses,
+                if matches!(format, ExportFormat::Spdx2_3) {
+                    SpdxVersion::V2_3
+                } else {
+                    SpdxVersion::V3_0
+                },
+                preview,
+                all_packages,
+            )?;
+
+            writeln!(writer, "{}", export.to_json()?)?;
+        }
     }
 
     writer.commit().await?;
@@ -1,20 +1,18 @@
-use std::path::Path;
-use std::str::FromStr;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use tokio::process::Command;
 
-use uv_bin_install::{Binary, bin_install};
+use uv_bin_install::BinarySource;
 use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
-use uv_pep440::Version;
 use uv_preview::{Preview, PreviewFeatures};
 use uv_warnings::warn_user;
-use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache, WorkspaceError};
 
 use crate::child::run_to_completion;
 use crate::commands::ExitStatus;
-use crate::commands::reporters::BinaryDownloadReporter;
+use crate::commands::project::bin::{binary_target_dir, install_binary};
 use crate::printer::Printer;
 
 /// Run the formatter.
@@ -24,6 +22,8 @@ pub(crate) async fn format(
     diff: bool,
     extra_args: Vec<String>,
     version: Option<String>,
+    binaries: &BTreeMap<String, BinarySource>,
+    binary_install_mirror: Option<&str>,
     client_builder: BaseClientBuilder<'_>,
     cache: Cache,
     printer: Printer,
@@ -38,50 +38,18 @@ pub(crate) async fn format(
         );
     }
 
-    let workspace_cache = WorkspaceCache::default();
-    // If `no_project` is provided, we use the provided directory
-    // Otherwise, we discover the project and use the project root.
-    let target_dir = if no_project {
-        project_dir.to_owned()
-    } else {
-        match VirtualProject::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
-            .await
-        {
-            // If we found a project, we use the project root
-            Ok(proj) => proj.root().to_owned(),
-            // If there is a problem finding a project, we just use the provided directory,
-            // e.g., for unmanaged projects
-            Err(
-                WorkspaceError::MissingPyprojectToml
-                | WorkspaceError::MissingProject(_)
-                | WorkspaceError::NonWorkspace(_),
-            ) => project_dir.to_owned(),
-            Err(err) => return Err(err.into()),
-        }
-    };
-
-    // Parse version if provided
-    let version = version.as_deref().map(Version::from_str).transpose()?;
-
-    let retry_policy = client_builder.retry_policy();
-    // Python downloads are performing their own retries to catch stream errors, disable the
-    // default retries to avoid the middleware from performing uncontrolled retries.
-    let client = client_builder.retries(0).build();
+    let target_dir = binary_target_dir(project_dir, no_project).await?;
 
     // Get the path to Ruff, downloading it if necessary
-    let reporter = BinaryDownloadReporter::single(printer);
-    let default_version = Binary::Ruff.default_version();
-    let version = version.as_ref().unwrap_or(&default_version);
-    let ruff_path = bin_install(
-        Binary::Ruff,
-        version,
-        &client,
-        &retry_policy,
+    let ruff_path = install_ruff(
+        version.as_deref(),
+        binaries,
+        binary_install_mirror,
+        client_builder,
         &cache,
-        &reporter,
+        printer,
     )
-    .await
-    .with_context(|| format!("Failed to install ruff {version}"))?;
+    .await?;
 
     let mut command = Command::new(&ruff_path);
     command.current_dir(target_dir);
@@ -100,3 +68,26 @@ pub(crate) async fn format(
     let handle = command.spawn().context("Failed to spawn `ruff format`")?;
     run_to_completion(handle).await
 }
+
+/// Get the path to Ruff, downloading it if necessary.
+///
+/// If no version is requested, the version from the `ruff` binary definition is used.
+pub(super) async fn install_ruff(
+    version: Option<&str>,
+    binaries: &BTreeMap<String, BinarySource>,
+    binary_install_mirror: Option<&str>,
+    client_builder: BaseClientBuilder<'_>,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<PathBuf> {
+    install_binary(
+        "ruff",
+        version,
+        binaries,
+        binary_install_mirror,
+        client_builder,
+        cache,
+        printer,
+    )
+    .await
+}
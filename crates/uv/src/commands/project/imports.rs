@@ -0,0 +1,509 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use serde::Deserialize;
+use tracing::debug;
+use walkdir::WalkDir;
+
+use uv_build_frontend::{MODULE_MAPPING, is_stdlib_module};
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep508::VerbatimUrl;
+use uv_pypi_types::DependencyGroupSpecifier;
+use uv_requirements::RequirementsSource;
+use uv_scripts::Pep723Script;
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache, WorkspaceError};
+
+use crate::commands::ScriptPath;
+use crate::printer::Printer;
+
+/// Directories that are never scanned for imports.
+const IGNORED_DIRECTORIES: &[&str] = &[
+    "__pycache__",
+    "build",
+    "dist",
+    "node_modules",
+    "site-packages",
+    "venv",
+];
+
+/// Infer the requirements to add for `uv add --from-imports`, reporting the inferred packages.
+pub(crate) async fn import_requirements(
+    project_dir: &Path,
+    script: Option<&ScriptPath>,
+    printer: Printer,
+) -> Result<Vec<RequirementsSource>> {
+    let script = script.map(|script| match script {
+        ScriptPath::Script(script) => script.path.as_path(),
+        ScriptPath::Path(path) => path.as_path(),
+    });
+    let InferredImports { packages, unmapped } = infer_imports(project_dir, script).await?;
+
+    if packages.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "No undeclared third-party imports found".bold()
+        )?;
+    } else {
+        let s = if packages.len() == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "{} {} from imports: {}",
+            "Inferred".bold(),
+            format!("{} package{s}", packages.len()).bold(),
+            packages.iter().join(", ")
+        )?;
+    }
+
+    if !unmapped.is_empty() {
+        warn_user!(
+            "Skipping imports that could not be mapped to a package: {}. If the packages of the same name are correct, add them with `uv add {}`",
+            unmapped
+                .iter()
+                .map(|module| format!("`{module}`"))
+                .join(", "),
+            unmapped.iter().join(" ")
+        );
+    }
+
+    packages
+        .iter()
+        .map(|package| RequirementsSource::from_package_argument(package.as_ref()))
+        .collect()
+}
+
+/// The third-party imports of a project or script.
+#[derive(Debug, Default)]
+struct InferredImports {
+    /// The packages that provide the imported modules, per the `pipreqs` module mapping.
+    packages: Vec<PackageName>,
+    /// The imported modules that aren't in the `pipreqs` module mapping.
+    ///
+    /// These are not added by default, as the package of the same name may not be the one that
+    /// provides the module (or may be an unrelated, malicious package).
+    unmapped: Vec<String>,
+}
+
+/// Infer the third-party packages imported by a project or script.
+///
+/// Scans the Python sources (including Jupyter notebooks) of the project in `project_dir`, or the
+/// given script, for top-level imports. Standard library and first-party modules are ignored, along
+/// with any packages that are already declared as dependencies. The remaining modules are mapped to
+/// package names via the `pipreqs` module mapping; modules that aren't in the mapping are returned
+/// separately.
+async fn infer_imports(project_dir: &Path, script: Option<&Path>) -> Result<InferredImports> {
+    let mut modules = BTreeSet::new();
+    let mut first_party = BTreeSet::new();
+    let mut declared = BTreeSet::new();
+
+    if let Some(script) = script {
+        let contents = fs_err::tokio::read_to_string(script).await?;
+        modules.extend(imported_modules(&contents));
+
+        // Treat any modules alongside the script as first-party.
+        let parent = script
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        first_party.extend(local_modules(parent));
+
+        if let Some(script) = Pep723Script::read(script).await? {
+            declared.extend(
+                script
+                    .metadata
+                    .dependencies
+                    .iter()
+                    .flatten()
+                    .map(|requirement| requirement.name.clone()),
+            );
+        }
+    } else {
+        let workspace_cache = WorkspaceCache::default();
+        let root = match VirtualProject::discover(
+            project_dir,
+            &DiscoveryOptions::default(),
+            &workspace_cache,
+        )
+        .await
+        {
+            Ok(project) => {
+                // Treat every workspace member as first-party, and skip anything that's already
+                // declared by the project.
+                for (name, member) in project.workspace().packages() {
+                    first_party.insert(name.as_dist_info_name().to_string());
+                    first_party.extend(local_modules(member.root()));
+                    first_party.extend(local_modules(&member.root().join("src")));
+                }
+                if let Some(project) = &project.pyproject_toml().project {
+                    declared.extend(
+                        project
+                            .dependencies
+                            .iter()
+                            .flatten()
+                            .chain(
+                                project
+                                    .optional_dependencies
+                                    .iter()
+                                    .flatten()
+                                    .flat_map(|(_, dependencies)| dependencies.iter()),
+                            )
+                            .filter_map(|requirement| requirement_name(requirement)),
+                    );
+                }
+                if let Some(groups) = &project.pyproject_toml().dependency_groups {
+                    declared.extend(
+                        groups
+                            .iter()
+                            .flat_map(|(_, specifiers)| specifiers)
+                            .filter_map(|specifier| match specifier {
+                                DependencyGroupSpecifier::Requirement(requirement) => {
+                                    requirement_name(requirement)
+                                }
+                                _ => None,
+                            }),
+                    );
+                }
+                project.root().to_path_buf()
+            }
+            Err(
+                WorkspaceError::MissingPyprojectToml
+                | WorkspaceError::MissingProject(_)
+                | WorkspaceError::NonWorkspace(_),
+            ) => project_dir.to_path_buf(),
+            Err(err) => return Err(err.into()),
+        };
+
+        first_party.extend(local_modules(&root));
+        first_party.extend(local_modules(&root.join("src")));
+
+        for path in python_sources(&root) {
+            let contents = fs_err::tokio::read_to_string(&path).await?;
+            let imports = if path.extension().is_some_and(|ext| ext == "ipynb") {
+                notebook_source(&contents)
+                    .map(|source| imported_modules(&source))
+                    .with_context(|| {
+                        format!("Failed to parse notebook: `{}`", path.user_display())
+                    })?
+            } else {
+                imported_modules(&contents)
+            };
+            modules.extend(imports);
+        }
+    }
+
+    let mut packages = BTreeSet::new();
+    let mut unmapped = BTreeSet::new();
+    for module in modules {
+        if is_stdlib_module(&module) || first_party.contains(&module) {
+            continue;
+        }
+        let (package, mapped) = if let Some(package) = MODULE_MAPPING.lookup(&module) {
+            (package.clone(), true)
+        } else if let Ok(package) = PackageName::from_str(&module) {
+            (package, false)
+        } else {
+            debug!("Skipping import of `{module}`, which is not a valid package name");
+            continue;
+        };
+        if declared.contains(&package) {
+            debug!("Skipping import of `{module}`, which is already declared as `{package}`");
+            continue;
+        }
+        if mapped {
+            packages.insert(package);
+        } else {
+            unmapped.insert(module);
+        }
+    }
+
+    Ok(InferredImports {
+        packages: packages.into_iter().collect(),
+        unmapped: unmapped.into_iter().collect(),
+    })
+}
+
+/// Extract the package name from a PEP 508 requirement string.
+fn requirement_name(requirement: &str) -> Option<PackageName> {
+    uv_pep508::Requirement::<VerbatimUrl>::from_str(requirement)
+        .ok()
+        .map(|requirement| requirement.name)
+}
+
+/// Returns the names of the importable modules and packages directly within a directory.
+fn local_modules(dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs_err::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.is_dir() {
+                path.file_name()?.to_str().map(ToString::to_string)
+            } else if path
+                .extension()
+                .is_some_and(|ext| ext == "py" || ext == "pyi")
+            {
+                path.file_stem()?.to_str().map(ToString::to_string)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Returns the paths to the Python sources and notebooks within a directory, skipping hidden
+/// directories and virtual environments.
+fn python_sources(root: &Path) -> impl Iterator<Item = std::path::PathBuf> {
+    WalkDir::new(root)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| {
+            if entry.depth() == 0 || !entry.file_type().is_dir() {
+                return true;
+            }
+            let name = entry.file_name().to_string_lossy();
+            !(name.starts_with('.')
+                || IGNORED_DIRECTORIES.contains(&name.as_ref())
+                || entry.path().join("pyvenv.cfg").is_file())
+        })
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .map(walkdir::DirEntry::into_path)
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|ext| ext == "py" || ext == "pyw" || ext == "ipynb")
+        })
+}
+
+#[derive(Deserialize)]
+struct Notebook {
+    #[serde(default)]
+    cells: Vec<NotebookCell>,
+}
+
+#[derive(Deserialize)]
+struct NotebookCell {
+    cell_type: String,
+    #[serde(default)]
+    source: NotebookSource,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NotebookSource {
+    Lines(Vec<String>),
+    Text(String),
+}
+
+impl Default for NotebookSource {
+    fn default() -> Self {
+        Self::Lines(Vec::new())
+    }
+}
+
+/// Extract the Python source code from the code cells of a Jupyter notebook, omitting `IPython`
+/// magics and shell escapes.
+fn notebook_source(contents: &str) -> Result<String> {
+    let notebook = serde_json::from_str::<Notebook>(contents)?;
+    let mut source = String::new();
+    for cell in notebook.cells {
+        if cell.cell_type != "code" {
+            continue;
+        }
+        let text = match cell.source {
+            NotebookSource::Lines(lines) => lines.concat(),
+            NotebookSource::Text(text) => text,
+        };
+        for line in text.lines() {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with('%') || trimmed.starts_with('!') {
+                continue;
+            }
+            source.push_str(line);
+            source.push('\n');
+        }
+        source.push('\n');
+    }
+    Ok(source)
+}
+
+/// Returns the top-level modules imported by the given Python source.
+///
+/// Relative imports are ignored. Imports are detected anywhere in the source (e.g., within
+/// functions or `try` blocks), but not within strings or comments.
+fn imported_modules(source: &str) -> BTreeSet<String> {
+    let mut modules = BTreeSet::new();
+    for line in logical_lines(source) {
+        for statement in line.split(';') {
+            let statement = statement.trim();
+            if let Some(rest) = statement.strip_prefix("import ") {
+                for alias in rest.split(',') {
+                    if let Some(module) = alias.split_whitespace().next() {
+                        insert_module(&mut modules, module);
+                    }
+                }
+            } else if let Some(rest) = statement.strip_prefix("from ") {
+                let rest = rest.trim_start();
+                let Some(module) = rest.split_whitespace().next() else {
+                    continue;
+                };
+                if rest[module.len()..].trim_start().starts_with("import") {
+                    insert_module(&mut modules, module);
+                }
+            }
+        }
+    }
+    modules
+}
+
+/// Insert the top-level module of a (possibly dotted) module path, skipping relative imports.
+fn insert_module(modules: &mut BTreeSet<String>, module: &str) {
+    if module.starts_with('.') {
+        return;
+    }
+    let Some(top_level) = module.split('.').next() else {
+        return;
+    };
+    if !top_level.is_empty()
+        && top_level.chars().all(|c| c.is_alphanumeric() || c == '_')
+        && !top_level.starts_with(|c: char| c.is_ascii_digit())
+    {
+        modules.insert(top_level.to_string());
+    }
+}
+
+/// Split Python source into logical lines, joining explicit (`\`) and implicit (bracketed) line
+/// continuations, and removing comments and the contents of string literals.
+fn logical_lines(source: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut depth = 0usize;
+    let mut chars = source.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '#' => {
+                // Skip the comment, leaving the newline to terminate the line.
+                while chars.next_if(|&c| c != '\n').is_some() {}
+            }
+            '\\' if chars.peek() == Some(&'\n') => {
+                chars.next();
+                line.push(' ');
+            }
+            '\'' | '"' => {
+                let quote = c;
+                let triple = if chars.peek() == Some(&quote) {
+                    chars.next();
+                    if chars.peek() == Some(&quote) {
+                        chars.next();
+                        true
+                    } else {
+                        // An empty string.
+                        line.push_str("\"\"");
+                        continue;
+                    }
+                } else {
+                    false
+                };
+                // Skip to the end of the string.
+                let mut closing = 0;
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => {
+                            chars.next();
+                            closing = 0;
+                        }
+                        '\n' if !triple => break,
+                        c if c == quote => {
+                            closing += 1;
+                            if !triple || closing == 3 {
+                                break;
+                            }
+                        }
+                        _ => closing = 0,
+                    }
+                }
+                line.push_str("\"\"");
+            }
+            '(' | '[' | '{' => {
+                depth += 1;
+                line.push(c);
+            }
+            ')' | ']' | '}' => {
+                depth = depth.saturating_sub(1);
+                line.push(c);
+            }
+            '\n' if depth > 0 => line.push(' '),
+            '\n' => lines.push(std::mem::take(&mut line)),
+            c => line.push(c),
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{imported_modules, notebook_source};
+
+    #[test]
+    fn imports() {
+        let source = r#"
+import os, sys
+import numpy as np
+import google.cloud.storage
+from pandas import DataFrame
+from . import sibling
+from .module import thing
+from yaml import (
+    safe_load,
+    safe_dump,
+)
+import requests; import attrs
+from \
+    rich import print
+
+def main():
+    import httpx
+
+"""
+import not_an_import
+"""
+text = "import also_not_an_import"
+# import commented_out
+"#;
+        assert_eq!(
+            imported_modules(source).into_iter().collect::<Vec<_>>(),
+            [
+                "attrs", "google", "httpx", "numpy", "os", "pandas", "requests", "rich", "sys",
+                "yaml"
+            ]
+        );
+    }
+
+    #[test]
+    fn notebook() {
+        let notebook = r#"{
+            "cells": [
+                {"cell_type": "markdown", "source": ["import markdown_only"]},
+                {"cell_type": "code", "source": ["%matplotlib inline\n", "!pip install foo\n", "import matplotlib.pyplot as plt\n"]},
+                {"cell_type": "code", "source": "from sklearn import datasets"}
+            ]
+        }"#;
+        let source = notebook_source(notebook).unwrap();
+        assert_eq!(
+            imported_modules(&source).into_iter().collect::<Vec<_>>(),
+            ["matplotlib", "sklearn"]
+        );
+    }
+}
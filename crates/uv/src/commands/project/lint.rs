@@ -0,0 +1,71 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use tokio::process::Command;
+
+use uv_bin_install::BinarySource;
+use uv_cache::Cache;
+use uv_client::BaseClientBuilder;
+use uv_preview::{Preview, PreviewFeatures};
+use uv_warnings::warn_user;
+
+use crate::child::run_to_completion;
+use crate::commands::ExitStatus;
+use crate::commands::project::bin::binary_target_dir;
+use crate::commands::project::format::install_ruff;
+use crate::printer::Printer;
+
+/// Run the linter.
+pub(crate) async fn lint(
+    project_dir: &Path,
+    fix: bool,
+    diff: bool,
+    extra_args: Vec<String>,
+    version: Option<String>,
+    binaries: &BTreeMap<String, BinarySource>,
+    binary_install_mirror: Option<&str>,
+    client_builder: BaseClientBuilder<'_>,
+    cache: Cache,
+    printer: Printer,
+    preview: Preview,
+    no_project: bool,
+) -> Result<ExitStatus> {
+    // Check if the lint feature is in preview
+    if !preview.is_enabled(PreviewFeatures::LINT) {
+        warn_user!(
+            "`uv lint` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::LINT
+        );
+    }
+
+    let target_dir = binary_target_dir(project_dir, no_project).await?;
+
+    // Get the path to Ruff, downloading it if necessary
+    let ruff_path = install_ruff(
+        version.as_deref(),
+        binaries,
+        binary_install_mirror,
+        client_builder,
+        &cache,
+        printer,
+    )
+    .await?;
+
+    let mut command = Command::new(&ruff_path);
+    command.current_dir(target_dir);
+    command.arg("check");
+
+    if fix {
+        command.arg("--fix");
+    }
+    if diff {
+        command.arg("--diff");
+    }
+
+    // Add any additional arguments passed after `--`
+    command.args(extra_args.iter());
+
+    let handle = command.spawn().context("Failed to spawn `ruff check`")?;
+    run_to_completion(handle).await
+}
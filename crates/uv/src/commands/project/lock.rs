@@ -2,15 +2,16 @@
 
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use owo_colors::OwoColorize;
-use rustc_hash::{FxBuildHasher, FxHashMap};
+use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
 use tracing::debug;
 
 use uv_cache::{Cache, Refresh};
-use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
+use uv_cli::LockPlatform;
+use uv_client::{BaseClientBuilder, FlatIndexClient, MetadataIndex, RegistryClientBuilder};
 use uv_configuration::{
     Concurrency, Constraints, DependencyGroupsWithDefaults, DryRun, ExtrasSpecification, Reinstall,
     Upgrade,
@@ -21,30 +22,37 @@ use uv_distribution_types::{
     DependencyMetadata, HashGeneration, Index, IndexLocations, NameRequirementSpecification,
     Requirement, RequiresPython, UnresolvedRequirementSpecification,
 };
+use uv_fs::Simplified;
 use uv_git::ResolvedRepositoryReference;
 use uv_git_types::GitOid;
 use uv_normalize::{GroupName, PackageName};
 use uv_pep440::Version;
 use uv_preview::{Preview, PreviewFeatures};
-use uv_pypi_types::{ConflictKind, Conflicts, SupportedEnvironments};
+use uv_pypi_types::{
+    ConflictItem, ConflictKind, ConflictSet, Conflicts, SchemaConflictSet, SupportedEnvironments,
+};
 use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
 use uv_requirements::ExtrasResolver;
 use uv_requirements::upgrade::{LockedRequirements, read_lock_requirements};
 use uv_resolver::{
-    FlatIndex, InMemoryIndex, Lock, Options, OptionsBuilder, Package, PythonRequirement,
-    ResolverEnvironment, ResolverManifest, SatisfiesResult, UniversalMarker,
+    FlatIndex, InMemoryIndex, LicensePolicyError, Lock, Options, OptionsBuilder, Package,
+    PythonRequirement, ResolverEnvironment, ResolverManifest, SatisfiesResult, TraceMode,
+    UniversalMarker,
 };
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
 use uv_types::{BuildContext, BuildIsolation, EmptyInstalledPackages, HashStrategy};
 use uv_warnings::{warn_user, warn_user_once};
+use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
 use uv_workspace::{DiscoveryOptions, Editability, Workspace, WorkspaceCache, WorkspaceMember};
 
 use crate::commands::pip::loggers::{DefaultResolveLogger, ResolveLogger, SummaryResolveLogger};
+use crate::commands::pip::operations::ResolveDiagnostics;
+use crate::commands::project::lock_platforms::report_platforms;
 use crate::commands::project::lock_target::LockTarget;
 use crate::commands::project::{
     MissingLockfileSource, ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState,
-    init_script_python_requirement, script_extra_build_requires,
+    check_license_policy, init_script_python_requirement, script_extra_build_requires,
 };
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
 use crate::commands::{ExitStatus, ScriptPath, diagnostics, pip};
@@ -85,6 +93,12 @@ pub(crate) async fn lock(
     frozen: Option<FrozenSource>,
     dry_run: DryRun,
     refresh: Refresh,
+    trace: Option<TraceMode>,
+    explain: Option<PackageName>,
+    profile: Option<PathBuf>,
+    metadata_index: Option<PathBuf>,
+    platforms: Vec<LockPlatform>,
+    auto_conflicts: bool,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     settings: ResolverSettings,
@@ -98,6 +112,20 @@ pub(crate) async fn lock(
     printer: Printer,
     preview: Preview,
 ) -> anyhow::Result<ExitStatus> {
+    if metadata_index.is_some() && !preview.is_enabled(PreviewFeatures::METADATA_INDEX) {
+        warn_user!(
+            "`--metadata-index` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::METADATA_INDEX
+        );
+    }
+    let metadata_index = metadata_index.map(MetadataIndex::new);
+    if !platforms.is_empty() && !preview.is_enabled(PreviewFeatures::LOCK_PLATFORMS) {
+        warn_user!(
+            "`--platforms` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::LOCK_PLATFORMS
+        );
+    }
+
     // If necessary, initialize the PEP 723 script.
     let script = match script {
         Some(ScriptPath::Path(path)) => {
@@ -186,42 +214,136 @@ pub(crate) async fn lock(
         }
     };
 
+    // If the workspace defines a license policy, read the licenses of the locked packages to
+    // enforce it. With `--check-exists`, the project isn't locked, so the policy isn't checked.
+    let license_policy = match (target, mode) {
+        (LockTarget::Workspace(workspace), _) if workspace.license_policy().is_none() => None,
+        (LockTarget::Workspace(_), LockMode::Frozen(_)) => {
+            warn_user!(
+                "The license policy is not checked with `--check-exists`; use `uv sync --frozen` to check it against the existing lockfile"
+            );
+            None
+        }
+        (
+            LockTarget::Workspace(_),
+            LockMode::Write(interpreter)
+            | LockMode::DryRun(interpreter)
+            | LockMode::Locked(interpreter, _),
+        ) => Some(interpreter),
+        (LockTarget::Script(_), _) => None,
+    };
+
     // Initialize any shared state.
     let state = UniversalState::default();
 
     // Perform the lock operation.
-    match Box::pin(
-        LockOperation::new(
-            mode,
-            &settings,
-            &client_builder,
-            &state,
-            Box::new(DefaultResolveLogger),
-            concurrency,
-            cache,
-            &workspace_cache,
-            printer,
-            preview,
+    let mut target = target;
+    let mut declared = false;
+    let mut rediscovered;
+    let (result, conflicts) = loop {
+        let result = Box::pin(
+            LockOperation::new(
+                mode,
+                &settings,
+                &client_builder,
+                &state,
+                Box::new(DefaultResolveLogger),
+                concurrency,
+                cache,
+                &workspace_cache,
+                printer,
+                preview,
+            )
+            .with_refresh(&refresh)
+            .with_trace(trace.as_ref())
+            .with_explain(explain.as_ref())
+            .with_profile(profile.as_deref())
+            .with_metadata_index(metadata_index.as_ref())
+            .with_license_policy(license_policy)
+            .execute(target),
         )
-        .with_refresh(&refresh)
-        .execute(target),
-    )
-    .await
-    {
+        .await;
+
+        // If the resolution failed because extras or dependency groups are incompatible, determine
+        // the conflicts that would need to be declared for the resolution to succeed.
+        let conflicts = match (&result, target, mode) {
+            (
+                Err(ProjectError::Operation(err)),
+                LockTarget::Workspace(workspace),
+                LockMode::Write(interpreter)
+                | LockMode::DryRun(interpreter)
+                | LockMode::Locked(interpreter, _),
+            ) => {
+                find_missing_conflicts(
+                    err,
+                    workspace,
+                    interpreter,
+                    &settings,
+                    &client_builder,
+                    concurrency,
+                    cache,
+                    preview,
+                )
+                .await
+            }
+            _ => None,
+        };
+
+        // If requested, declare the conflicts and lock the project again.
+        if let (Some(conflicts), LockTarget::Workspace(workspace)) = (&conflicts, target) {
+            if auto_conflicts && !declared {
+                for (path, sets) in conflict_declarations(workspace, conflicts) {
+                    let mut pyproject = PyProjectTomlMut::from_toml(
+                        &fs_err::read_to_string(&path)?,
+                        DependencyTarget::PyProjectToml,
+                    )?;
+                    for set in sets {
+                        pyproject.add_conflict(&set)?;
+                    }
+                    fs_err::write(&path, pyproject.to_string())?;
+                    writeln!(
+                        printer.stderr(),
+                        "Declared conflicts in `{}`",
+                        path.user_display().cyan()
+                    )?;
+                }
+
+                declared = true;
+                rediscovered = Workspace::discover(
+                    project_dir,
+                    &DiscoveryOptions::default(),
+                    &WorkspaceCache::default(),
+                )
+                .await?;
+                target = LockTarget::Workspace(&rediscovered);
+                continue;
+            }
+        }
+
+        break (result, conflicts);
+    };
+
+    match result {
         Ok(lock) => {
             if dry_run.enabled() {
                 // In `--dry-run` mode, show all changes.
                 if let LockResult::Changed(previous, lock) = &lock {
-                    let mut changed = false;
-                    for event in LockEvent::detect_changes(previous.as_ref(), lock, dry_run) {
-                        changed = true;
+                    let events = LockEvent::detect_changes(previous.as_ref(), lock, dry_run)
+                        .collect::<Vec<_>>();
+                    for event in &events {
                         writeln!(printer.stderr(), "{event}")?;
                     }
 
                     // If we didn't report any version changes, but the lockfile changed, report back.
-                    if !changed {
+                    if events.is_empty() {
                         writeln!(printer.stderr(), "{}", "Lockfile changes detected".bold())?;
                     }
+
+                    if let Some(previous) = previous {
+                        if settings.resolution.is_minimal() {
+                            report_blast_radius(previous, &events, dry_run, printer)?;
+                        }
+                    }
                 } else {
                     writeln!(
                         printer.stderr(),
@@ -231,20 +353,51 @@ pub(crate) async fn lock(
                 }
             } else {
                 if let LockResult::Changed(Some(previous), lock) = &lock {
-                    for event in LockEvent::detect_changes(Some(previous), lock, dry_run) {
+                    let events = LockEvent::detect_changes(Some(previous), lock, dry_run)
+                        .collect::<Vec<_>>();
+                    for event in &events {
                         writeln!(printer.stderr(), "{event}")?;
                     }
+
+                    if settings.resolution.is_minimal() {
+                        report_blast_radius(previous, &events, dry_run, printer)?;
+                    }
                 }
             }
 
+            // Report whether the lockfile can be installed on each of the requested platforms.
+            if !platforms.is_empty()
+                && !report_platforms(
+                    target,
+                    lock.lock(),
+                    &platforms,
+                    &settings.build_options,
+                    printer,
+                )?
+            {
+                return Ok(ExitStatus::Failure);
+            }
+
             Ok(ExitStatus::Success)
         }
         Err(err @ ProjectError::LockMismatch(..)) => {
             writeln!(printer.stderr(), "{}", err.to_string().bold())?;
             Ok(ExitStatus::Failure)
         }
+        Err(ProjectError::LicensePolicy(LicensePolicyError::Violations(violations))) => {
+            diagnostics::license_policy(violations);
+            Ok(ExitStatus::Failure)
+        }
         Err(ProjectError::Operation(err)) => {
-            diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
+            let diagnostic =
+                diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls());
+            let diagnostic = match (conflicts, target) {
+                (Some(conflicts), LockTarget::Workspace(workspace)) => {
+                    diagnostic.with_hint(conflicts_hint(workspace, &conflicts)?)
+                }
+                _ => diagnostic,
+            };
+            diagnostic
                 .report(err)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
         }
@@ -252,6 +405,265 @@ pub(crate) async fn lock(
     }
 }
 
+/// The maximum number of resolutions to attempt when searching for missing conflicts.
+const MAX_CONFLICT_PROBES: usize = 8;
+
+/// If a resolution failed because two or more extras or dependency groups of workspace members
+/// are incompatible with one another, determine the conflicts that would need to be declared
+/// (i.e., in `tool.uv.conflicts`) for the resolution to succeed.
+///
+/// Each candidate set of conflicts is verified by re-resolving the workspace with the sets
+/// declared. If the resolution still fails for another reason, no conflicts are returned.
+async fn find_missing_conflicts(
+    err: &pip::operations::Error,
+    workspace: &Workspace,
+    interpreter: &Interpreter,
+    settings: &ResolverSettings,
+    client_builder: &BaseClientBuilder<'_>,
+    concurrency: Concurrency,
+    cache: &Cache,
+    preview: Preview,
+) -> Option<Conflicts> {
+    let mut set = conflict_set(err, workspace, &Conflicts::empty())?;
+    let mut conflicts = Conflicts::empty();
+    let state = UniversalState::default();
+    let workspace_cache = WorkspaceCache::default();
+
+    for _ in 0..MAX_CONFLICT_PROBES {
+        conflicts.push(set);
+
+        let result = Box::pin(
+            LockOperation::new(
+                LockMode::DryRun(interpreter),
+                settings,
+                client_builder,
+                &state,
+                Box::new(SummaryResolveLogger),
+                concurrency,
+                cache,
+                &workspace_cache,
+                Printer::Silent,
+                preview,
+            )
+            .with_conflicts(conflicts.clone())
+            .execute(LockTarget::Workspace(workspace)),
+        )
+        .await;
+
+        match result {
+            Ok(_) => return Some(merge_conflicts(&conflicts)),
+            Err(ProjectError::Operation(err)) => {
+                set = conflict_set(&err, workspace, &conflicts)?;
+            }
+            Err(err) => {
+                debug!("Failed to resolve with additional conflicts: {err}");
+                return None;
+            }
+        }
+    }
+
+    None
+}
+
+/// Merge sets of conflicts in which every pair of items already conflicts, e.g., `{a, b}`,
+/// `{b, c}`, and `{a, c}` into `{a, b, c}`.
+fn merge_conflicts(conflicts: &Conflicts) -> Conflicts {
+    let conflicting = |item1: &ConflictItem, item2: &ConflictItem| {
+        conflicts
+            .iter()
+            .any(|set| set.contains_item(item1) && set.contains_item(item2))
+    };
+    let candidates = conflicts
+        .iter()
+        .flat_map(ConflictSet::iter)
+        .collect::<BTreeSet<_>>();
+
+    let mut merged = Vec::<BTreeSet<&ConflictItem>>::new();
+    for set in conflicts.iter() {
+        if merged
+            .iter()
+            .any(|existing| set.iter().all(|item| existing.contains(item)))
+        {
+            continue;
+        }
+        let mut items = set.iter().collect::<BTreeSet<_>>();
+        for candidate in &candidates {
+            if items.iter().all(|item| conflicting(item, candidate)) {
+                items.insert(candidate);
+            }
+        }
+        merged.push(items);
+    }
+
+    // Sort the sets, such that the result doesn't depend on the order in which the conflicts were
+    // discovered.
+    merged.sort();
+
+    let mut result = Conflicts::empty();
+    for items in merged {
+        if let Ok(set) = ConflictSet::try_from(items.into_iter().cloned().collect::<Vec<_>>()) {
+            result.push(set);
+        }
+    }
+    result
+}
+
+/// Extract the set of conflicting extras and dependency groups from a resolution failure, if there
+/// are at least two.
+///
+/// Returns `None` if the set is already declared (in the workspace, or in the given additional
+/// conflicts), as declaring it again wouldn't resolve the failure.
+fn conflict_set(
+    err: &pip::operations::Error,
+    workspace: &Workspace,
+    additional: &Conflicts,
+) -> Option<ConflictSet> {
+    let pip::operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(err)) = err else {
+        return None;
+    };
+    let items = err.conflict_items();
+    if items.len() < 2 {
+        return None;
+    }
+    let set = ConflictSet::try_from(items.into_iter().collect::<Vec<_>>()).ok()?;
+    if workspace
+        .conflicts()
+        .iter()
+        .chain(additional.iter())
+        .any(|existing| *existing == set)
+    {
+        return None;
+    }
+    Some(set)
+}
+
+/// Group the given conflicts by the `pyproject.toml` in which they should be declared, i.e., that
+/// of the workspace member that owns the first item in each set.
+fn conflict_declarations(
+    workspace: &Workspace,
+    conflicts: &Conflicts,
+) -> BTreeMap<PathBuf, Vec<SchemaConflictSet>> {
+    let mut declarations = BTreeMap::<PathBuf, Vec<SchemaConflictSet>>::new();
+    for set in conflicts.iter() {
+        let Some(package) = set.iter().next().map(ConflictItem::package) else {
+            continue;
+        };
+        let Some(member) = workspace.packages().get(package) else {
+            continue;
+        };
+        declarations
+            .entry(member.root().join("pyproject.toml"))
+            .or_default()
+            .push(set.to_schema(package));
+    }
+    declarations
+}
+
+/// Render a hint suggesting the `tool.uv.conflicts` entries to declare.
+fn conflicts_hint(workspace: &Workspace, conflicts: &Conflicts) -> anyhow::Result<String> {
+    let items = conflicts
+        .iter()
+        .flat_map(ConflictSet::iter)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|item| match item.kind() {
+            ConflictKind::Project => format!("`{}`", item.package()),
+            ConflictKind::Extra(extra) => format!("`{}[{}]`", item.package(), extra),
+            ConflictKind::Group(group) => format!("`{}:{}`", item.package(), group),
+        })
+        .collect::<Vec<_>>();
+    let items = match items.as_slice() {
+        [init @ .., last] if init.len() > 1 => format!("{}, and {last}", init.join(", ")),
+        _ => items.join(" and "),
+    };
+
+    let mut hint = format!(
+        "The project can be locked if {items} are resolved separately. To do so, declare them as conflicting"
+    );
+    for (index, (path, sets)) in conflict_declarations(workspace, conflicts)
+        .into_iter()
+        .enumerate()
+    {
+        let mut toml = PyProjectTomlMut::from_toml("", DependencyTarget::PyProjectToml)?;
+        for set in sets {
+            toml.add_conflict(&set)?;
+        }
+        write!(
+            hint,
+            "{} `{}`:\n\n{}",
+            if index == 0 { " in" } else { "\n\nAnd in" },
+            path.user_display().cyan(),
+            toml.to_string().trim_end()
+        )?;
+    }
+    write!(
+        hint,
+        "\n\nOr, use `{}` to declare them automatically.",
+        "uv lock --auto-conflicts".green()
+    )?;
+    Ok(hint)
+}
+
+/// Report the package versions added, removed, or updated relative to the existing lockfile.
+pub(super) fn report_changes(
+    previous: Option<&Lock>,
+    lock: &Lock,
+    dry_run: DryRun,
+    printer: Printer,
+) -> Result<(), std::fmt::Error> {
+    for event in LockEvent::detect_changes(previous, lock, dry_run) {
+        writeln!(printer.stderr(), "{event}")?;
+    }
+    Ok(())
+}
+
+/// Report the number of packages changed by a minimal-change resolution, relative to the existing
+/// lockfile.
+fn report_blast_radius(
+    previous: &Lock,
+    events: &[LockEvent],
+    dry_run: DryRun,
+    printer: Printer,
+) -> Result<(), std::fmt::Error> {
+    let total = previous
+        .packages()
+        .iter()
+        .map(Package::name)
+        .collect::<FxHashSet<_>>()
+        .len();
+
+    let (mut updated, mut added, mut removed) = (0, 0, 0);
+    for event in events {
+        match event {
+            LockEvent::Update(..) => updated += 1,
+            LockEvent::Add(..) => added += 1,
+            LockEvent::Remove(..) => removed += 1,
+        }
+    }
+    let counts = [(updated, "updated"), (added, "added"), (removed, "removed")]
+        .into_iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, label)| format!("{count} {label}"))
+        .collect::<Vec<_>>();
+
+    let message = format!(
+        "{} {} of {total} locked package{}{}",
+        if dry_run.enabled() {
+            "Would change"
+        } else {
+            "Changed"
+        },
+        events.len(),
+        if total == 1 { "" } else { "s" },
+        if counts.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", counts.join(", "))
+        }
+    );
+    writeln!(printer.stderr(), "{}", message.bold())
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(super) enum LockMode<'env> {
     /// Write the lockfile to disk.
@@ -264,11 +676,38 @@ pub(super) enum LockMode<'env> {
     Frozen(MissingLockfileSource),
 }
 
+/// Options for a [`LockOperation`], beyond the target and its settings.
+#[derive(Default)]
+struct LockOptions<'env> {
+    /// Additional conflicts to declare, beyond those in the target.
+    conflicts: Conflicts,
+    /// A lockfile to merge with the existing lockfile.
+    merge: Option<&'env Lock>,
+    /// A local metadata index from which to read Simple API listings and wheel metadata.
+    metadata_index: Option<&'env MetadataIndex>,
+    /// The interpreter for which to read the license of every locked package into the in-memory
+    /// index, if any.
+    licenses: Option<&'env Interpreter>,
+    /// Whether to enforce the workspace's license policy before the lockfile is written.
+    license_policy: bool,
+    /// Options to record, replay, explain, or profile the resolution.
+    diagnostics: ResolveDiagnostics<'env>,
+}
+
+impl LockOptions<'_> {
+    /// Returns `true` if the project must be re-resolved, even if the existing lockfile
+    /// satisfies its requirements.
+    fn requires_resolution(&self) -> bool {
+        self.merge.is_some() || self.diagnostics.is_enabled()
+    }
+}
+
 /// A lock operation.
 pub(super) struct LockOperation<'env> {
     mode: LockMode<'env>,
     constraints: Vec<NameRequirementSpecification>,
     refresh: Option<&'env Refresh>,
+    options: LockOptions<'env>,
     settings: &'env ResolverSettings,
     client_builder: &'env BaseClientBuilder<'env>,
     state: &'env UniversalState,
@@ -298,6 +737,7 @@ impl<'env> LockOperation<'env> {
             mode,
             constraints: vec![],
             refresh: None,
+            options: LockOptions::default(),
             settings,
             client_builder,
             state,
@@ -320,6 +760,13 @@ impl<'env> LockOperation<'env> {
         self
     }
 
+    /// Declare additional conflicts for the [`LockOperation`], beyond those in the target.
+    #[must_use]
+    pub(super) fn with_conflicts(mut self, conflicts: Conflicts) -> Self {
+        self.options.conflicts = conflicts;
+        self
+    }
+
     /// Set the refresh strategy for the [`LockOperation`].
     #[must_use]
     pub(super) fn with_refresh(mut self, refresh: &'env Refresh) -> Self {
@@ -327,8 +774,97 @@ impl<'env> LockOperation<'env> {
         self
     }
 
+    /// Record the resolver's responses to, or replay them from, a trace file.
+    #[must_use]
+    pub(super) fn with_trace(mut self, trace: Option<&'env TraceMode>) -> Self {
+        self.options.diagnostics.trace = trace;
+        self
+    }
+
+    /// Explain the version selected for the given package in each resolution fork.
+    #[must_use]
+    pub(super) fn with_explain(mut self, explain: Option<&'env PackageName>) -> Self {
+        self.options.diagnostics.explain = explain;
+        self
+    }
+
+    /// Write a profile of the resolution to the given directory.
+    #[must_use]
+    pub(super) fn with_profile(mut self, profile: Option<&'env Path>) -> Self {
+        self.options.diagnostics.profile = profile;
+        self
+    }
+
+    /// Read Simple API listings and wheel metadata from the given local metadata index.
+    #[must_use]
+    pub(super) fn with_metadata_index(
+        mut self,
+        metadata_index: Option<&'env MetadataIndex>,
+    ) -> Self {
+        self.options.metadata_index = metadata_index;
+        self
+    }
+
+    /// Read the license of every locked package into the in-memory index, e.g., to enforce a
+    /// license policy.
+    ///
+    /// The given interpreter determines which distribution of each package the license is read
+    /// from. Licenses are read regardless of the [`LockMode`], including for a frozen lockfile.
+    #[must_use]
+    pub(super) fn with_licenses(mut self, licenses: Option<&'env Interpreter>) -> Self {
+        self.options.licenses = licenses;
+        self
+    }
+
+    /// Enforce the workspace's license policy, if any, reading the license of every locked
+    /// package for the given interpreter.
+    ///
+    /// The policy is enforced before the lockfile is written, such that a violation leaves the
+    /// existing lockfile untouched.
+    #[must_use]
+    pub(super) fn with_license_policy(mut self, interpreter: Option<&'env Interpreter>) -> Self {
+        self.options.licenses = interpreter;
+        self.options.license_policy = interpreter.is_some();
+        self
+    }
+
+    /// Merge two lockfiles by re-resolving the project with the locked versions from both as
+    /// preferences.
+    ///
+    /// The merged lockfile is not written to disk, regardless of the [`LockMode`].
+    pub(super) async fn merge(
+        self,
+        target: LockTarget<'_>,
+        interpreter: &Interpreter,
+        ours: Lock,
+        theirs: &Lock,
+    ) -> Result<LockResult, ProjectError> {
+        Box::pin(do_lock(
+            target,
+            interpreter,
+            Some(ours),
+            self.constraints,
+            LockOptions {
+                merge: Some(theirs),
+                ..self.options
+            },
+            self.refresh,
+            self.settings,
+            self.client_builder,
+            self.state,
+            self.logger,
+            self.concurrency,
+            self.cache,
+            self.workspace_cache,
+            self.printer,
+            self.preview,
+        ))
+        .await
+    }
+
     /// Perform a [`LockOperation`].
     pub(super) async fn execute(self, target: LockTarget<'_>) -> Result<LockResult, ProjectError> {
+        let license_policy = self.options.license_policy;
         match self.mode {
             LockMode::Frozen(source) => {
                 // Read the existing lockfile, but don't attempt to lock the project.
@@ -347,6 +883,25 @@ impl<'env> LockOperation<'env> {
                             })?;
                     }
                 }
+                // If requested, read the license of every locked package.
+                if let Some(interpreter) = self.options.licenses {
+                    read_licenses(
+                        target,
+                        &existing,
+                        interpreter,
+                        self.settings,
+                        self.client_builder,
+                        self.state,
+                        self.concurrency,
+                        self.cache,
+                        self.workspace_cache,
+                        self.preview,
+                    )
+                    .await?;
+                }
+                if license_policy {
+                    enforce_license_policy(target, &existing, self.state)?;
+                }
                 Ok(LockResult::Unchanged(existing))
             }
             LockMode::Locked(interpreter, lock_source) => {
@@ -362,6 +917,7 @@ impl<'env> LockOperation<'env> {
                     interpreter,
                     Some(existing),
                     self.constraints,
+                    self.options,
                     self.refresh,
                     self.settings,
                     self.client_builder,
@@ -384,6 +940,10 @@ impl<'env> LockOperation<'env> {
                     ));
                 }
 
+                if license_policy {
+                    enforce_license_policy(target, result.lock(), self.state)?;
+                }
+
                 Ok(result)
             }
             LockMode::Write(interpreter) | LockMode::DryRun(interpreter) => {
@@ -406,6 +966,7 @@ impl<'env> LockOperation<'env> {
                     interpreter,
                     existing,
                     self.constraints,
+                    self.options,
                     self.refresh,
                     self.settings,
                     self.client_builder,
@@ -419,6 +980,10 @@ impl<'env> LockOperation<'env> {
                 ))
                 .await?;
 
+                if license_policy {
+                    enforce_license_policy(target, result.lock(), self.state)?;
+                }
+
                 // If the lockfile changed, write it to disk.
                 if !matches!(self.mode, LockMode::DryRun(_)) {
                     if let LockResult::Changed(_, lock) = &result {
@@ -432,12 +997,26 @@ impl<'env> LockOperation<'env> {
     }
 }
 
+/// Enforce the license policy of the target's workspace, if any, against the given lockfile.
+#[allow(clippy::result_large_err)]
+fn enforce_license_policy(
+    target: LockTarget<'_>,
+    lock: &Lock,
+    state: &UniversalState,
+) -> Result<(), ProjectError> {
+    match target {
+        LockTarget::Workspace(workspace) => check_license_policy(workspace, lock, state.index()),
+        LockTarget::Script(_) => Ok(()),
+    }
+}
+
 /// Lock the project requirements into a lockfile.
 async fn do_lock(
     target: LockTarget<'_>,
     interpreter: &Interpreter,
     existing_lock: Option<Lock>,
     external: Vec<NameRequirementSpecification>,
+    lock_options: LockOptions<'_>,
     refresh: Option<&Refresh>,
     settings: &ResolverSettings,
     client_builder: &BaseClientBuilder<'_>,
@@ -451,6 +1030,16 @@ async fn do_lock(
 ) -> Result<LockResult, ProjectError> {
     let start = std::time::Instant::now();
 
+    let reresolve = lock_options.requires_resolution();
+    let LockOptions {
+        conflicts: mut additional_conflicts,
+        merge: merge_lock,
+        metadata_index,
+        licenses,
+        license_policy: _,
+        diagnostics,
+    } = lock_options;
+
     // Extract the project settings.
     let ResolverSettings {
         index_locations,
@@ -534,6 +1123,7 @@ async fn do_lock(
 
     // Collect the conflicts.
     let mut conflicts = target.conflicts();
+    conflicts.append(&mut additional_conflicts);
     if let LockTarget::Workspace(workspace) = target {
         if let Some(groups) = &workspace.pyproject_toml().dependency_groups {
             if let Some(project) = &workspace.pyproject_toml().project {
@@ -691,6 +1281,7 @@ async fn do_lock(
     let client = RegistryClientBuilder::new(client_builder, cache.clone())
         .index_locations(index_locations.clone())
         .index_strategy(*index_strategy)
+        .metadata_index(metadata_index.cloned())
         .markers(interpreter.markers())
         .platform(interpreter.platform())
         .build();
@@ -805,6 +1396,7 @@ async fn do_lock(
             index_locations,
             upgrade,
             refresh,
+            reresolve,
             &options,
             &hasher,
             state.index(),
@@ -828,13 +1420,13 @@ async fn do_lock(
         None
     };
 
-    match existing_lock {
+    let result = match existing_lock {
         // Resolution from the lockfile succeeded.
         Some(ValidatedLock::Satisfies(lock)) => {
             // Print the success message after completing resolution.
             logger.on_complete(lock.len(), start, printer)?;
 
-            Ok(LockResult::Unchanged(lock))
+            LockResult::Unchanged(lock)
         }
 
         // The lockfile did not contain enough information to obtain a resolution, fallback
@@ -849,11 +1441,39 @@ async fn do_lock(
             });
 
             // If an existing lockfile exists, build up a set of preferences.
-            let LockedRequirements { preferences, git } = versions_lock
-                .map(|lock| read_lock_requirements(lock, target.install_path(), upgrade))
+            //
+            // In a minimal-change resolution, retain every locked version, even for upgraded
+            // packages; the upgrade constraints determine which packages have to move.
+            let minimal = options.resolution_mode.is_minimal();
+            let upgrade_preferences = if minimal { &Upgrade::None } else { upgrade };
+            let LockedRequirements {
+                mut preferences,
+                mut git,
+            } = versions_lock
+                .map(|lock| {
+                    read_lock_requirements(lock, target.install_path(), upgrade_preferences)
+                })
                 .transpose()?
                 .unwrap_or_default();
 
+            // When merging lockfiles, prefer the versions from the other lockfile too. If the
+            // lockfiles pin different versions of a package, the resolution strategy determines
+            // which is preferred; if they pin different commits of a Git dependency, the commit
+            // from the existing lockfile is retained.
+            if let Some(merge_lock) = merge_lock.filter(|_| versions_lock.is_some()) {
+                let merged =
+                    read_lock_requirements(merge_lock, target.install_path(), upgrade_preferences)?;
+                preferences.extend(merged.preferences);
+                for reference in merged.git {
+                    if !git
+                        .iter()
+                        .any(|existing| existing.reference == reference.reference)
+                    {
+                        git.push(reference);
+                    }
+                }
+            }
+
             // Populate the Git resolver.
             for ResolvedRepositoryReference { reference, sha } in git {
                 debug!("Inserting Git reference into resolver: `{reference:?}` at `{sha}`");
@@ -892,21 +1512,25 @@ async fn do_lock(
 
             // Resolve the requirements.
             let resolution = pip::operations::resolve(
-                ExtrasResolver::new(&hasher, state.index(), database)
-                    .with_reporter(Arc::new(ResolverReporter::from(printer)))
-                    .resolve(target.members_requirements())
-                    .await
-                    .map_err(|err| ProjectError::Operation(err.into()))?
-                    .into_iter()
-                    .chain(target.group_requirements())
-                    .chain(requirements.iter().cloned())
-                    .chain(
-                        dependency_groups
-                            .values()
-                            .flat_map(|requirements| requirements.iter().cloned()),
-                    )
-                    .map(UnresolvedRequirementSpecification::from)
-                    .collect(),
+                ExtrasResolver::new(
+                    &hasher,
+                    state.index(),
+                    DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads),
+                )
+                .with_reporter(Arc::new(ResolverReporter::from(printer)))
+                .resolve(target.members_requirements())
+                .await
+                .map_err(|err| ProjectError::Operation(err.into()))?
+                .into_iter()
+                .chain(target.group_requirements())
+                .chain(requirements.iter().cloned())
+                .chain(
+                    dependency_groups
+                        .values()
+                        .flat_map(|requirements| requirements.iter().cloned()),
+                )
+                .map(UnresolvedRequirementSpecification::from)
+                .collect(),
                 constraints
                     .iter()
                     .cloned()
@@ -941,6 +1565,7 @@ async fn do_lock(
                 &build_dispatch,
                 concurrency,
                 options,
+                diagnostics,
                 Box::new(SummaryResolveLogger),
                 printer,
             )
@@ -952,6 +1577,16 @@ async fn do_lock(
             // Notify the user of any resolution diagnostics.
             pip::operations::diagnose_resolution(resolution.diagnostics(), printer)?;
 
+            // Explain the selected versions of the requested package, if any.
+            if let Some(explain) = diagnostics.explain {
+                if resolution.explanations().is_empty() {
+                    warn_user!("`{explain}` is not included in the resolution");
+                }
+                for explanation in resolution.explanations() {
+                    writeln!(printer.stdout(), "{explanation}")?;
+                }
+            }
+
             let manifest = ResolverManifest::new(
                 members,
                 requirements,
@@ -965,7 +1600,19 @@ async fn do_lock(
             .relative_to(target.install_path())?;
 
             let previous = existing_lock.map(ValidatedLock::into_lock);
-            let lock = Lock::from_resolution(&resolution, target.install_path())?
+
+            // A minimal-change resolution is applied on top of the existing lockfile, so retain
+            // its resolution mode, such that subsequent operations continue to respect it.
+            let mut lock = Lock::from_resolution(&resolution, target.install_path())?;
+            if minimal {
+                lock = lock.with_resolution_mode(
+                    previous
+                        .as_ref()
+                        .map(Lock::resolution_mode)
+                        .unwrap_or_default(),
+                );
+            }
+            let lock = lock
                 .with_manifest(manifest)
                 .with_conflicts(conflicts)
                 .with_supported_environments(
@@ -982,12 +1629,142 @@ async fn do_lock(
                 );
 
             if previous.as_ref().is_some_and(|previous| *previous == lock) {
-                Ok(LockResult::Unchanged(lock))
+                LockResult::Unchanged(lock)
             } else {
-                Ok(LockResult::Changed(previous, lock))
+                LockResult::Changed(previous, lock)
+            }
+        }
+    };
+
+    // If requested, read the license of every locked package.
+    if let Some(interpreter) = licenses {
+        result
+            .lock()
+            .read_licenses(
+                target.install_path(),
+                interpreter.tags()?,
+                interpreter.markers(),
+                state.index(),
+                &database,
+            )
+            .await?;
+    }
+
+    Ok(result)
+}
+
+/// Read the license of every package in an existing lockfile into the in-memory index, without
+/// locking the project.
+async fn read_licenses(
+    target: LockTarget<'_>,
+    lock: &Lock,
+    interpreter: &Interpreter,
+    settings: &ResolverSettings,
+    client_builder: &BaseClientBuilder<'_>,
+    state: &UniversalState,
+    concurrency: Concurrency,
+    cache: &Cache,
+    workspace_cache: &WorkspaceCache,
+    preview: Preview,
+) -> Result<(), ProjectError> {
+    let ResolverSettings {
+        index_locations,
+        index_strategy,
+        keyring_provider,
+        dependency_metadata,
+        config_setting,
+        config_settings_package,
+        build_isolation,
+        extra_build_dependencies,
+        extra_build_variables,
+        exclude_newer,
+        link_mode,
+        build_options,
+        sources,
+        ..
+    } = settings;
+
+    // Initialize the client.
+    let client_builder = client_builder.clone().keyring(*keyring_provider);
+
+    for index in target.indexes() {
+        if let Some(credentials) = index.credentials() {
+            if let Some(root_url) = index.root_url() {
+                client_builder.store_credentials(&root_url, credentials.clone());
             }
+            client_builder.store_credentials(index.raw_url(), credentials);
         }
     }
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::new(client_builder, cache.clone())
+        .index_locations(index_locations.clone())
+        .index_strategy(*index_strategy)
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build();
+
+    // Determine whether to enable build isolation.
+    let environment;
+    let build_isolation = match build_isolation {
+        uv_configuration::BuildIsolation::Isolate => BuildIsolation::Isolated,
+        uv_configuration::BuildIsolation::Shared => {
+            environment = PythonEnvironment::from_interpreter(interpreter.clone());
+            BuildIsolation::Shared(&environment)
+        }
+        uv_configuration::BuildIsolation::SharedPackage(packages) => {
+            environment = PythonEnvironment::from_interpreter(interpreter.clone());
+            BuildIsolation::SharedPackage(&environment, packages)
+        }
+    };
+
+    // Source distributions are never built to read their license, so the build settings are only
+    // needed to construct the build dispatch.
+    let flat_index = FlatIndex::default();
+    let build_constraints = Constraints::default();
+    let build_hasher = HashStrategy::default();
+    let extra_build_requires =
+        LoweredExtraBuildDependencies::from_non_lowered(extra_build_dependencies.clone())
+            .into_inner();
+
+    // Create a build dispatch.
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        cache,
+        &build_constraints,
+        interpreter,
+        index_locations,
+        &flat_index,
+        dependency_metadata,
+        state.fork().into_inner(),
+        *index_strategy,
+        config_setting,
+        config_settings_package,
+        build_isolation,
+        &extra_build_requires,
+        extra_build_variables,
+        *link_mode,
+        build_options,
+        &build_hasher,
+        exclude_newer.clone(),
+        sources.clone(),
+        workspace_cache.clone(),
+        concurrency,
+        preview,
+    );
+
+    let database = DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads);
+
+    lock.read_licenses(
+        target.install_path(),
+        interpreter.tags()?,
+        interpreter.markers(),
+        state.index(),
+        &database,
+    )
+    .await?;
+
+    Ok(())
 }
 
 #[derive(Debug)]
@@ -1027,6 +1804,7 @@ impl ValidatedLock {
         index_locations: &IndexLocations,
         upgrade: &Upgrade,
         refresh: Option<&Refresh>,
+        reresolve: bool,
         options: &Options,
         hasher: &HashStrategy,
         index: &InMemoryIndex,
@@ -1037,7 +1815,9 @@ impl ValidatedLock {
         // first (i.e., every check that returns `Self::Unusable`, followed by every check that
         // returns `Self::Versions`, followed by every check that returns `Self::Preferable`, and
         // finally `Self::Satisfies`).
-        if lock.resolution_mode() != options.resolution_mode {
+        if lock.resolution_mode() != options.resolution_mode
+            && !options.resolution_mode.is_minimal()
+        {
             let _ = writeln!(
                 printer.stderr(),
                 "Ignoring existing lockfile due to change in resolution mode: `{}` vs. `{}`",
@@ -1069,11 +1849,15 @@ impl ValidatedLock {
 
         match upgrade {
             Upgrade::None => {}
-            Upgrade::All => {
+            Upgrade::All if !options.resolution_mode.is_minimal() => {
                 // If the user specified `--upgrade`, then we can't use the existing lockfile.
                 debug!("Ignoring existing lockfile due to `--upgrade`");
                 return Ok(Self::Unusable(lock));
             }
+            Upgrade::All => {
+                // In a minimal-change resolution, `--upgrade` is handled like `--upgrade-package`,
+                // retaining the existing versions wherever possible.
+            }
             Upgrade::Packages(_) => {
                 // This is handled below, after some checks regarding fork
                 // markers. In particular, we'd like to return `Preferable`
@@ -1194,12 +1978,28 @@ impl ValidatedLock {
             return Ok(Self::Preferable(lock));
         }
 
+        // If the user specified `--upgrade` in a minimal-change resolution, then we can prefer all
+        // of the existing versions.
+        if let Upgrade::All = upgrade {
+            debug!("Resolving despite existing lockfile due to `--upgrade`");
+            return Ok(Self::Preferable(lock));
+        }
+
         // If the user specified `--refresh`, then we have to re-resolve.
         if matches!(refresh, Some(Refresh::All(..) | Refresh::Packages(..))) {
             debug!("Resolving despite existing lockfile due to `--refresh`");
             return Ok(Self::Preferable(lock));
         }
 
+        // If the user specified `--record`, `--replay`, `--explain`, or `--profile`, or we're
+        // merging lockfiles, then we have to re-resolve.
+        if reresolve {
+            debug!(
+                "Resolving despite existing lockfile due to `--record`, `--replay`, `--explain`, `--profile`, or a merge"
+            );
+            return Ok(Self::Preferable(lock));
+        }
+
         // If the user provided at least one index URL (from the command line, or from a configuration
         // file), don't use the existing lockfile if it references any registries that are no longer
         // included in the current configuration.
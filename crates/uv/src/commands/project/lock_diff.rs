@@ -0,0 +1,199 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result, anyhow, bail};
+use owo_colors::OwoColorize;
+
+use uv_cli::LockFormat;
+use uv_fs::Simplified;
+use uv_git::GIT;
+use uv_pep440::Version;
+use uv_resolver::{Lock, LockDiff, PackageChange, VERSION};
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
+
+use crate::commands::ExitStatus;
+use crate::commands::project::lock_target::LockTarget;
+use crate::printer::Printer;
+
+/// The lockfile to compare the project's lockfile against.
+#[derive(Debug, Clone)]
+pub(crate) enum LockDiffBase {
+    /// The `uv.lock` at a Git revision.
+    Revision(String),
+    /// A lockfile on disk, relative to the workspace root.
+    File(PathBuf),
+}
+
+/// Compare the project's lockfile against the lockfile at a Git revision or path.
+pub(crate) async fn lock_diff(
+    project_dir: &Path,
+    base: LockDiffBase,
+    output_format: LockFormat,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let workspace_cache = WorkspaceCache::default();
+    let workspace =
+        Workspace::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache).await?;
+
+    // Read the lockfile in the working tree.
+    let target = LockTarget::Workspace(&workspace);
+    let Some(lock) = target.read().await? else {
+        return Err(anyhow!(
+            "Unable to find lockfile at `uv.lock`. To create a lockfile, run `{}`.",
+            "uv lock".green()
+        ));
+    };
+
+    // Read the lockfile to compare against.
+    let base_lock = read_base_lock(&target.lock_path(), &base)?;
+
+    let diff = LockDiff::between(&base_lock, &lock);
+
+    match output_format {
+        LockFormat::Text => {
+            if diff.is_empty() {
+                writeln!(
+                    printer.stdout(),
+                    "{}",
+                    "No lockfile changes detected".bold()
+                )?;
+            }
+            if let Some(change) = &diff.exclude_newer {
+                writeln!(
+                    printer.stdout(),
+                    "{}: {change}",
+                    "Changed options".yellow().bold()
+                )?;
+            }
+            for change in &diff.changes {
+                write_change(printer, change)?;
+            }
+        }
+        LockFormat::Json => {
+            writeln!(printer.stdout(), "{}", serde_json::to_string_pretty(&diff)?)?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Read the lockfile to compare against, from either a path (relative to the workspace root) or a
+/// Git revision.
+fn read_base_lock(lock_path: &Path, base: &LockDiffBase) -> Result<Lock> {
+    let Some(directory) = lock_path.parent() else {
+        bail!(
+            "Failed to determine the parent directory of `{}`",
+            lock_path.user_display()
+        );
+    };
+    let (encoded, source) = match base {
+        LockDiffBase::File(path) => {
+            let path = directory.join(path);
+            let encoded = fs_err::read_to_string(&path)?;
+            (encoded, format!("`{}`", path.user_display()))
+        }
+        LockDiffBase::Revision(revision) => {
+            let Ok(git) = GIT.as_ref() else {
+                bail!(
+                    "`git` was not found in PATH, but is required to read `uv.lock` at Git revision `{revision}`"
+                );
+            };
+            let output = Command::new(git)
+                .arg("show")
+                .arg(format!("{revision}:./uv.lock"))
+                .current_dir(directory)
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .output()?;
+            if !output.status.success() {
+                bail!(
+                    "Failed to read `uv.lock` at Git revision `{revision}`: {}",
+                    String::from_utf8_lossy(&output.stderr).trim()
+                );
+            }
+            let encoded = String::from_utf8(output.stdout)?;
+            (encoded, format!("Git revision `{revision}`"))
+        }
+    };
+
+    let lock = toml::from_str::<Lock>(&encoded)
+        .with_context(|| format!("Failed to parse the lockfile at {source}"))?;
+    if lock.version() != VERSION {
+        bail!(
+            "The lockfile at {source} uses an unsupported schema version (v{}, but only v{VERSION} is supported)",
+            lock.version()
+        );
+    }
+    Ok(lock)
+}
+
+/// Write a single change in the human-readable format.
+fn write_change(printer: Printer, change: &PackageChange) -> Result<()> {
+    let version = |version: Option<&Version>| {
+        version.map_or_else(|| "(dynamic)".to_string(), |version| format!("v{version}"))
+    };
+    let marker = change
+        .marker()
+        .map(|marker| format!(" ; {marker}"))
+        .unwrap_or_default();
+
+    match change {
+        PackageChange::Added {
+            name, version: v, ..
+        } => writeln!(
+            printer.stdout(),
+            "{} {name} {}{marker}",
+            "Added".green().bold(),
+            version(v.as_ref())
+        )?,
+        PackageChange::Removed {
+            name, version: v, ..
+        } => writeln!(
+            printer.stdout(),
+            "{} {name} {}{marker}",
+            "Removed".red().bold(),
+            version(v.as_ref())
+        )?,
+        PackageChange::Upgraded { name, from, to, .. } => writeln!(
+            printer.stdout(),
+            "{} {name} v{from} -> v{to}{marker}",
+            "Upgraded".green().bold()
+        )?,
+        PackageChange::Downgraded { name, from, to, .. } => writeln!(
+            printer.stdout(),
+            "{} {name} v{from} -> v{to}{marker}",
+            "Downgraded".yellow().bold()
+        )?,
+        PackageChange::SourceChanged {
+            name,
+            version: v,
+            from,
+            to,
+            ..
+        } => writeln!(
+            printer.stdout(),
+            "{} {name} {}{marker}: {from} -> {to}",
+            "Changed source".yellow().bold(),
+            version(v.as_ref())
+        )?,
+        PackageChange::HashesChanged {
+            name,
+            version: v,
+            files,
+            ..
+        } => {
+            writeln!(
+                printer.stdout(),
+                "{} {name} {}{marker}",
+                "Changed hashes".yellow().bold(),
+                version(v.as_ref())
+            )?;
+            for file in files {
+                writeln!(printer.stdout(), "  {file}")?;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,364 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::Path;
+use std::process::Command;
+use std::sync::Arc;
+
+use anyhow::{Result, anyhow};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::BaseClientBuilder;
+use uv_configuration::{Concurrency, DependencyGroupsWithDefaults};
+use uv_distribution::{ArchiveMetadata, Metadata};
+use uv_distribution_types::{Requirement, VersionId};
+use uv_fs::Simplified;
+use uv_git::GIT;
+use uv_normalize::{ExtraName, GroupName, PackageName};
+use uv_preview::Preview;
+use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
+use uv_redacted::DisplaySafeUrl;
+use uv_resolver::{Lock, MetadataResponse, VERSION};
+use uv_settings::PythonInstallMirrors;
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache, WorkspaceMember};
+
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::project::lock::{LockMode, LockOperation};
+use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::{ProjectError, ProjectInterpreter, UniversalState};
+use crate::commands::{ExitStatus, diagnostics};
+use crate::printer::Printer;
+use crate::settings::ResolverSettings;
+
+/// A requirement recorded in a lockfile, along with the workspace member (or `None`, for the
+/// workspace itself) and dependency group that declared it.
+type RecordedRequirement = (Option<PackageName>, Option<GroupName>, Requirement);
+
+/// Merge two versions of the project's lockfile, e.g., as a Git merge driver.
+///
+/// The merged lockfile is written to `ours`.
+pub(crate) async fn lock_merge(
+    project_dir: &Path,
+    base: &Path,
+    ours: &Path,
+    theirs: &Path,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverSettings,
+    client_builder: BaseClientBuilder<'_>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    concurrency: Concurrency,
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    // Parse each version of the lockfile.
+    let (base_lock, ours_lock, theirs_lock) =
+        match (read_lock(base), read_lock(ours), read_lock(theirs)) {
+            (Ok(base_lock), Ok(ours_lock), Ok(theirs_lock)) => (base_lock, ours_lock, theirs_lock),
+            (Err(err), _, _) | (_, Err(err), _) | (_, _, Err(err)) => {
+                warn_user!("{err}");
+                return conflict(base, ours, theirs);
+            }
+        };
+
+    // If both sides made the same changes, there's nothing to merge.
+    if ours_lock == theirs_lock {
+        debug!("Both lockfiles are equivalent; skipping resolution");
+        return Ok(ExitStatus::Success);
+    }
+
+    let workspace_cache = WorkspaceCache::default();
+    let workspace = match Workspace::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        &workspace_cache,
+    )
+    .await
+    {
+        Ok(workspace) => workspace,
+        Err(err) => {
+            warn_user!("Failed to discover the project: {err}");
+            return conflict(base, ours, theirs);
+        }
+    };
+
+    let interpreter = match ProjectInterpreter::discover(
+        &workspace,
+        project_dir,
+        // Don't enable any groups' requires-python for interpreter discovery
+        &DependencyGroupsWithDefaults::none(),
+        python.as_deref().map(PythonRequest::parse),
+        &client_builder,
+        python_preference,
+        python_downloads,
+        &install_mirrors,
+        false,
+        no_config,
+        Some(false),
+        cache,
+        printer,
+        preview,
+    )
+    .await
+    {
+        Ok(interpreter) => interpreter.into_interpreter(),
+        Err(err) => {
+            warn_user!("Failed to find a Python interpreter for the project: {err}");
+            return conflict(base, ours, theirs);
+        }
+    };
+
+    // Determine the requirements that the merged lockfile should reflect.
+    let expected = merge_sets(
+        &recorded_requirements(&base_lock),
+        &recorded_requirements(&ours_lock),
+        &recorded_requirements(&theirs_lock),
+    );
+
+    // Git invokes the merge driver before the rest of the tree has been merged, so each member's
+    // `pyproject.toml` may only reflect one side of the merge. Instead, seed the index with the
+    // metadata for each member, using the merged requirements.
+    let state = UniversalState::default();
+    for member in workspace.packages().values() {
+        let Some(metadata) = member_metadata(
+            member,
+            &expected,
+            [&base_lock, &ours_lock, &theirs_lock],
+            workspace.install_path(),
+        ) else {
+            continue;
+        };
+        let url = DisplaySafeUrl::from_file_path(member.root()).map_err(|()| {
+            anyhow!(
+                "Failed to convert workspace member root to a URL: {}",
+                member.root().user_display()
+            )
+        })?;
+        state.index().distributions().done(
+            VersionId::from_url(&url),
+            Arc::new(MetadataResponse::Found(ArchiveMetadata::from(metadata))),
+        );
+    }
+
+    // Re-resolve the project, preferring the versions from both lockfiles.
+    let result = LockOperation::new(
+        LockMode::DryRun(&interpreter),
+        &settings,
+        &client_builder,
+        &state,
+        Box::new(DefaultResolveLogger),
+        concurrency,
+        cache,
+        &workspace_cache,
+        printer,
+        preview,
+    )
+    .merge(
+        LockTarget::Workspace(&workspace),
+        &interpreter,
+        ours_lock,
+        &theirs_lock,
+    )
+    .await;
+
+    let lock = match result {
+        Ok(result) => result.into_lock(),
+        Err(ProjectError::Operation(err)) => {
+            if let Some(err) =
+                diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
+                    .report(err)
+            {
+                warn_user!("Failed to resolve the merged lockfile: {err}");
+            }
+            return conflict(base, ours, theirs);
+        }
+        Err(err) => {
+            warn_user!("Failed to resolve the merged lockfile: {err}");
+            return conflict(base, ours, theirs);
+        }
+    };
+
+    // If the project's requirements don't reflect the changes from both sides (e.g., because a
+    // member was added, or the workspace root's dependency groups changed), the resolution can't
+    // be used.
+    if recorded_requirements(&lock) != expected {
+        warn_user!(
+            "The project requirements don't include the requirement changes from both lockfiles; merge `pyproject.toml`, then run `{}`",
+            "uv lock".green()
+        );
+        return conflict(base, ours, theirs);
+    }
+
+    fs_err::write(ours, lock.to_toml()?)?;
+
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!("Merged lockfile into `{}`", ours.user_display()).bold()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Read a version of the lockfile.
+fn read_lock(path: &Path) -> Result<Lock> {
+    let encoded = fs_err::read_to_string(path)?;
+    let lock = toml::from_str::<Lock>(&encoded).map_err(|err| {
+        anyhow::anyhow!(
+            "Failed to parse the lockfile at `{}`: {err}",
+            path.user_display()
+        )
+    })?;
+    if lock.version() != VERSION {
+        anyhow::bail!(
+            "The lockfile at `{}` uses an unsupported schema version (v{}, but only v{VERSION} is supported)",
+            path.user_display(),
+            lock.version()
+        );
+    }
+    Ok(lock)
+}
+
+/// Collect the requirements recorded in a lockfile, for the workspace and each of its members.
+fn recorded_requirements(lock: &Lock) -> BTreeSet<RecordedRequirement> {
+    let mut requirements = BTreeSet::new();
+    for requirement in lock.requirements() {
+        requirements.insert((None, None, requirement.clone()));
+    }
+    for (group, group_requirements) in lock.dependency_groups() {
+        for requirement in group_requirements {
+            requirements.insert((None, Some(group.clone()), requirement.clone()));
+        }
+    }
+    for package in lock.packages() {
+        for requirement in package.requires_dist() {
+            requirements.insert((Some(package.name().clone()), None, requirement.clone()));
+        }
+        for (group, group_requirements) in package.dependency_groups() {
+            for requirement in group_requirements {
+                requirements.insert((
+                    Some(package.name().clone()),
+                    Some(group.clone()),
+                    requirement.clone(),
+                ));
+            }
+        }
+    }
+    requirements
+}
+
+/// Merge three versions of a set: retain the items that neither side removed, along with those
+/// that either side added.
+fn merge_sets<T: Ord + Clone>(
+    base: &BTreeSet<T>,
+    ours: &BTreeSet<T>,
+    theirs: &BTreeSet<T>,
+) -> BTreeSet<T> {
+    base.iter()
+        .filter(|item| ours.contains(*item) && theirs.contains(*item))
+        .chain(ours.difference(base))
+        .chain(theirs.difference(base))
+        .cloned()
+        .collect()
+}
+
+/// Build the metadata for a workspace member from the merged requirements.
+///
+/// Returns `None` if the member's version is dynamic, or if the member is absent from each of the
+/// lockfiles, in which case its metadata is read from the working tree.
+fn member_metadata(
+    member: &WorkspaceMember,
+    requirements: &BTreeSet<RecordedRequirement>,
+    locks: [&Lock; 3],
+    root: &Path,
+) -> Option<Metadata> {
+    let project = member.project();
+    let version = project.version.clone()?;
+
+    let [base, ours, theirs] = locks.map(|lock| {
+        lock.packages()
+            .iter()
+            .find(|package| package.name() == &project.name)
+            .map(|package| {
+                package
+                    .provides_extras()
+                    .iter()
+                    .cloned()
+                    .collect::<BTreeSet<ExtraName>>()
+            })
+    });
+    if base.is_none() && ours.is_none() && theirs.is_none() {
+        return None;
+    }
+    let provides_extra = merge_sets(
+        &base.unwrap_or_default(),
+        &ours.unwrap_or_default(),
+        &theirs.unwrap_or_default(),
+    );
+
+    let mut requires_dist = Vec::new();
+    let mut dependency_groups: BTreeMap<GroupName, Vec<Requirement>> = BTreeMap::new();
+    for (package, group, requirement) in requirements {
+        if package.as_ref() != Some(&project.name) {
+            continue;
+        }
+        let requirement = requirement.clone().to_absolute(root);
+        match group {
+            Some(group) => dependency_groups
+                .entry(group.clone())
+                .or_default()
+                .push(requirement),
+            None => requires_dist.push(requirement),
+        }
+    }
+
+    Some(Metadata {
+        name: project.name.clone(),
+        version,
+        requires_dist: requires_dist.into_boxed_slice(),
+        requires_python: project.requires_python.clone(),
+        provides_extra: provides_extra.into_iter().collect(),
+        dependency_groups: dependency_groups
+            .into_iter()
+            .map(|(group, requirements)| (group, requirements.into_boxed_slice()))
+            .collect(),
+        dynamic: false,
+    })
+}
+
+/// Merge the lockfiles line-by-line, writing conflict markers to `ours` wherever they differ.
+///
+/// Returns [`ExitStatus::Success`] if the lockfiles merged cleanly.
+fn conflict(base: &Path, ours: &Path, theirs: &Path) -> Result<ExitStatus> {
+    // `git merge-file` exits with the number of conflicts (capped at 127), or a negative value
+    // (i.e., a status above 127) on error.
+    if let Ok(git) = GIT.as_ref() {
+        let status = Command::new(git)
+            .arg("merge-file")
+            .args(["-L", "ours", "-L", "base", "-L", "theirs"])
+            .arg(ours)
+            .arg(base)
+            .arg(theirs)
+            .status()?;
+        match status.code() {
+            Some(0) => return Ok(ExitStatus::Success),
+            Some(1..=127) => return Ok(ExitStatus::Failure),
+            _ => {}
+        }
+    }
+
+    // Otherwise, mark the entire lockfile as conflicting.
+    let ours_contents = fs_err::read_to_string(ours)?;
+    let theirs_contents = fs_err::read_to_string(theirs)?;
+    fs_err::write(
+        ours,
+        format!("<<<<<<< ours\n{ours_contents}=======\n{theirs_contents}>>>>>>> theirs\n"),
+    )?;
+
+    Ok(ExitStatus::Failure)
+}
@@ -0,0 +1,269 @@
+use std::fmt::Write;
+use std::ops::Bound;
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+use owo_colors::OwoColorize;
+
+use uv_cli::LockPlatform;
+use uv_configuration::{BuildOptions, DependencyGroups, ExtrasSpecification, InstallOptions};
+use uv_normalize::{DefaultExtras, DefaultGroups};
+use uv_pep508::{MarkerEnvironment, MarkerEnvironmentBuilder, MarkerTree};
+use uv_platform_tags::Tags;
+use uv_python::{ImplementationName, PythonVersion};
+use uv_resolver::{Lock, Package, PackageAvailability, reachable_packages};
+
+use crate::commands::project::install_target::InstallTarget;
+use crate::commands::project::lock_target::LockTarget;
+use crate::printer::Printer;
+
+/// The PyPy version to assume when validating against PyPy targets.
+const PYPY_VERSION: (u8, u8) = (7, 3);
+
+/// Report whether the lockfile can be installed on each of the given platforms.
+///
+/// Every package that could be installed from the lockfile (across all workspace members, extras,
+/// and dependency groups) is evaluated against each platform's markers and tags. Returns `false`
+/// if any platform falls outside the project's supported environments, or if any package would be
+/// unavailable on any platform.
+pub(crate) fn report_platforms(
+    target: LockTarget<'_>,
+    lock: &Lock,
+    platforms: &[LockPlatform],
+    build_options: &BuildOptions,
+    printer: Printer,
+) -> Result<bool> {
+    let install_target = match target {
+        LockTarget::Workspace(workspace) if workspace.is_non_project() => {
+            InstallTarget::NonProjectWorkspace { workspace, lock }
+        }
+        LockTarget::Workspace(workspace) => InstallTarget::Workspace { workspace, lock },
+        LockTarget::Script(script) => InstallTarget::Script { script, lock },
+    };
+    let extras = ExtrasSpecification::from_all_extras().with_defaults(DefaultExtras::default());
+    let groups = DependencyGroups::from_args(
+        false,
+        false,
+        false,
+        Vec::new(),
+        Vec::new(),
+        false,
+        Vec::new(),
+        true,
+    )
+    .with_defaults(DefaultGroups::default());
+    let install_options = InstallOptions::default();
+
+    // Workspace members are always built from source, so they're omitted from the report.
+    let packages = reachable_packages(&install_target, &extras, &groups, &install_options)?
+        .into_iter()
+        .filter(|reachable| !target.packages().contains_key(reachable.package.name()))
+        .collect::<Vec<_>>();
+
+    let mut installable = true;
+    for platform in platforms {
+        let environment = PlatformEnvironment::from_platform(platform, lock)?;
+
+        write!(
+            printer.stderr(),
+            "{} ({}):",
+            platform.to_string().cyan(),
+            environment.description
+        )?;
+
+        // If the Python version is outside the project's supported range, nothing can be
+        // installed.
+        if !lock
+            .requires_python()
+            .contains(&environment.markers.python_full_version().version)
+        {
+            writeln!(
+                printer.stderr(),
+                " incompatible with the project's Python requirement: `{}`",
+                lock.requires_python()
+            )?;
+            installable = false;
+            continue;
+        }
+
+        // If the project restricts its supported environments, the lockfile only covers platforms
+        // within them (or within the environments for which wheels are required).
+        if !is_covered(lock, &environment.markers) {
+            let environments = lock
+                .simplified_supported_environments()
+                .into_iter()
+                .filter_map(MarkerTree::try_to_string)
+                .map(|marker| format!("`{marker}`"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(
+                printer.stderr(),
+                " not covered by the project's supported environments: {environments}"
+            )?;
+            installable = false;
+            continue;
+        }
+
+        let mut total = 0;
+        let mut source = Vec::new();
+        let mut unavailable = Vec::new();
+        for reachable in &packages {
+            if !reachable
+                .marker
+                .without_extras()
+                .evaluate(&environment.markers, &[])
+            {
+                continue;
+            }
+            total += 1;
+            match reachable
+                .package
+                .availability(&environment.tags, build_options)
+            {
+                PackageAvailability::Wheel => {}
+                PackageAvailability::Source => source.push(reachable.package),
+                PackageAvailability::Unavailable => unavailable.push(reachable.package),
+            }
+        }
+
+        let s = if total == 1 { "" } else { "s" };
+        if source.is_empty() && unavailable.is_empty() {
+            writeln!(
+                printer.stderr(),
+                " {total} package{s}, all with compatible wheels"
+            )?;
+            continue;
+        }
+
+        let mut summary = format!(" {total} package{s}");
+        if !source.is_empty() {
+            write!(summary, ", {} built from source", source.len())?;
+        }
+        if !unavailable.is_empty() {
+            write!(
+                summary,
+                ", {}",
+                format!("{} unavailable", unavailable.len()).red()
+            )?;
+            installable = false;
+        }
+        writeln!(printer.stderr(), "{summary}")?;
+
+        if !source.is_empty() {
+            writeln!(printer.stderr(), "  Built from source:")?;
+            for package in source {
+                writeln!(printer.stderr(), "    - {}", format_package(package))?;
+            }
+        }
+        if !unavailable.is_empty() {
+            writeln!(printer.stderr(), "  Unavailable:")?;
+            for package in unavailable {
+                writeln!(printer.stderr(), "    - {}", format_package(package).red())?;
+            }
+        }
+    }
+
+    Ok(installable)
+}
+
+/// Returns `true` if the lockfile covers the given marker environment, i.e., if the environment
+/// falls within the project's supported or required environments.
+fn is_covered(lock: &Lock, markers: &MarkerEnvironment) -> bool {
+    lock.supported_environments().is_empty()
+        || lock
+            .supported_environments()
+            .iter()
+            .chain(lock.required_environments())
+            .any(|marker| marker.evaluate(markers, &[]))
+}
+
+/// The marker environment and tags for a [`LockPlatform`].
+struct PlatformEnvironment {
+    /// A human-readable description of the Python interpreter, e.g., `CPython 3.12`.
+    description: String,
+    markers: MarkerEnvironment,
+    tags: Tags,
+}
+
+impl PlatformEnvironment {
+    fn from_platform(platform: &LockPlatform, lock: &Lock) -> Result<Self> {
+        // If the Python version is omitted, use the minimum version supported by the project.
+        let python_version = if let Some(python_version) = &platform.python_version {
+            python_version.clone()
+        } else {
+            let version = match lock.requires_python().range().lower().as_ref() {
+                Bound::Included(version) | Bound::Excluded(version) => version,
+                Bound::Unbounded => {
+                    return Err(anyhow!(
+                        "Unable to determine a Python version for `{platform}`, since the project does not declare a `requires-python` lower bound; specify one with `{platform}@<version>`"
+                    ));
+                }
+            };
+            PythonVersion::from_str(&version.only_release().to_string())
+                .map_err(|err| anyhow!(err))?
+        };
+
+        let implementation = platform.implementation.unwrap_or_default();
+        let python_tuple = (python_version.major(), python_version.minor());
+        let (implementation_name, platform_python_implementation, implementation_version) =
+            match implementation {
+                ImplementationName::PyPy => (
+                    "pypy",
+                    "PyPy",
+                    format!("{}.{}.0", PYPY_VERSION.0, PYPY_VERSION.1),
+                ),
+                _ => (
+                    "cpython",
+                    "CPython",
+                    python_version.python_full_version().to_string(),
+                ),
+            };
+        let implementation_tuple = match implementation {
+            ImplementationName::PyPy => PYPY_VERSION,
+            _ => python_tuple,
+        };
+
+        let triple = platform.triple;
+        let markers = MarkerEnvironment::try_from(MarkerEnvironmentBuilder {
+            implementation_name,
+            implementation_version: &implementation_version,
+            os_name: triple.os_name(),
+            platform_machine: triple.platform_machine(),
+            platform_python_implementation,
+            platform_release: triple.platform_release(),
+            platform_system: triple.platform_system(),
+            platform_version: triple.platform_version(),
+            python_full_version: &python_version.python_full_version().to_string(),
+            python_version: &python_version.python_version().to_string(),
+            sys_platform: triple.sys_platform(),
+        })?;
+
+        let tags = Tags::from_env(
+            &triple.platform(),
+            python_tuple,
+            implementation_name,
+            implementation_tuple,
+            triple.manylinux_compatible(),
+            false,
+            true,
+        )?;
+
+        Ok(Self {
+            description: format!(
+                "{} {}",
+                implementation.pretty(),
+                python_version.python_version()
+            ),
+            markers,
+            tags,
+        })
+    }
+}
+
+/// Format a locked package for display, e.g., `pyyaml v6.0.1`.
+fn format_package(package: &Package) -> String {
+    match package.version() {
+        Some(version) => format!("{} v{version}", package.name()),
+        None => package.name().to_string(),
+    }
+}
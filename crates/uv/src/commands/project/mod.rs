@@ -37,8 +37,8 @@ use uv_python::{
 use uv_requirements::upgrade::{LockedRequirements, read_lock_requirements};
 use uv_requirements::{NamedRequirementsResolver, RequirementsSpecification};
 use uv_resolver::{
-    FlatIndex, Installable, Lock, OptionsBuilder, Preference, PythonRequirement,
-    ResolverEnvironment, ResolverOutput,
+    FlatIndex, InMemoryIndex, Installable, Lock, OptionsBuilder, PackageLicenses, Preference,
+    PythonRequirement, ResolverEnvironment, ResolverOutput,
 };
 use uv_scripts::Pep723ItemRef;
 use uv_settings::PythonInstallMirrors;
@@ -52,7 +52,7 @@ use uv_workspace::pyproject::{ExtraBuildDependency, PyProjectToml};
 use uv_workspace::{RequiresPythonSources, Workspace, WorkspaceCache};
 
 use crate::commands::pip::loggers::{InstallLogger, ResolveLogger};
-use crate::commands::pip::operations::{Changelog, Modifications};
+use crate::commands::pip::operations::{Changelog, Modifications, ResolveDiagnostics};
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
 use crate::commands::{capitalize, conjunction, pip};
@@ -63,17 +63,25 @@ use crate::settings::{
 };
 
 pub(crate) mod add;
+pub(crate) mod audit;
+pub(crate) mod bin;
 pub(crate) mod environment;
 pub(crate) mod export;
 pub(crate) mod format;
+pub(crate) mod imports;
 pub(crate) mod init;
 mod install_target;
+pub(crate) mod lint;
 pub(crate) mod lock;
+pub(crate) mod lock_diff;
+pub(crate) mod lock_merge;
+mod lock_platforms;
 mod lock_target;
 pub(crate) mod remove;
 pub(crate) mod run;
 pub(crate) mod sync;
 pub(crate) mod tree;
+pub(crate) mod upgrade;
 pub(crate) mod version;
 
 /// The source of a missing lockfile error.
@@ -295,6 +303,9 @@ pub(crate) enum ProjectError {
     #[error(transparent)]
     Lock(#[from] uv_resolver::LockError),
 
+    #[error(transparent)]
+    LicensePolicy(#[from] uv_resolver::LicensePolicyError),
+
     #[error(transparent)]
     Operation(#[from] pip::operations::Error),
 
@@ -2131,6 +2142,7 @@ pub(crate) async fn resolve_environment(
         &resolve_dispatch,
         concurrency,
         options,
+        ResolveDiagnostics::default(),
         logger,
         printer,
     )
@@ -2526,6 +2538,7 @@ pub(crate) async fn update_environment(
         &build_dispatch,
         concurrency,
         options,
+        ResolveDiagnostics::default(),
         resolve,
         printer,
     )
@@ -2695,6 +2708,36 @@ pub(crate) fn detect_conflicts(
     Ok(())
 }
 
+/// Enforce the workspace's license policy, if any, against the locked packages.
+///
+/// The licenses are read from the [`InMemoryIndex`], as populated by a lock operation that was
+/// asked to read them.
+#[allow(clippy::result_large_err)]
+pub(crate) fn check_license_policy(
+    workspace: &Workspace,
+    lock: &Lock,
+    index: &InMemoryIndex,
+) -> Result<(), ProjectError> {
+    let Some(policy) = workspace.license_policy() else {
+        return Ok(());
+    };
+    let licenses = PackageLicenses::from_index(lock, index);
+    let unread = lock.check_license_policy(policy, &licenses)?;
+    if !unread.is_empty() {
+        warn_user!(
+            "The license policy was not checked for packages that are only available as a source distribution: {}",
+            unread
+                .iter()
+                .map(|package| match package.version() {
+                    Some(version) => format!("{}=={version}", package.name()),
+                    None => package.name().to_string(),
+                })
+                .join(", ")
+        );
+    }
+    Ok(())
+}
+
 /// Determine the [`RequirementsSpecification`] for a script.
 #[allow(clippy::result_large_err)]
 pub(crate) fn script_specification(
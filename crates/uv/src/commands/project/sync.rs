@@ -28,7 +28,9 @@ use uv_pep508::{MarkerTree, VersionOrUrl};
 use uv_preview::{Preview, PreviewFeatures};
 use uv_pypi_types::{ParsedArchiveUrl, ParsedGitUrl, ParsedUrl};
 use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
-use uv_resolver::{FlatIndex, ForkStrategy, Installable, Lock, PrereleaseMode, ResolutionMode};
+use uv_resolver::{
+    FlatIndex, ForkStrategy, Installable, LicensePolicyError, Lock, PrereleaseMode, ResolutionMode,
+};
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
 use uv_types::{BuildIsolation, HashStrategy};
@@ -345,6 +347,13 @@ pub(crate) async fn sync(
         SyncTarget::Script(script) => LockTarget::from(script),
     };
 
+    // If the workspace defines a license policy, enforce it before installing the locked
+    // packages, even if the lockfile is frozen.
+    let license_policy = match &target {
+        SyncTarget::Project(project) => project.workspace().license_policy().is_some(),
+        SyncTarget::Script(_) => false,
+    };
+
     let outcome = match Box::pin(
         LockOperation::new(
             mode,
@@ -358,6 +367,7 @@ pub(crate) async fn sync(
             printer,
             preview,
         )
+        .with_license_policy(license_policy.then(|| environment.interpreter()))
         .execute(lock_target),
     )
     .await
@@ -368,6 +378,10 @@ pub(crate) async fn sync(
                 .report(err)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
         }
+        Err(ProjectError::LicensePolicy(LicensePolicyError::Violations(violations))) => {
+            diagnostics::license_policy(violations);
+            return Ok(ExitStatus::Failure);
+        }
         Err(ProjectError::LockMismatch(prev, cur, lock_source)) => {
             if dry_run.enabled() {
                 // The lockfile is mismatched, but we're in dry-run mode. We should proceed with the
@@ -1,11 +1,12 @@
 use std::path::Path;
 
-use anstream::print;
+use anstream::{print, println};
 use anyhow::{Error, Result};
 use futures::StreamExt;
 use tokio::sync::Semaphore;
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
+use uv_cli::TreeFormat;
 use uv_client::{BaseClientBuilder, RegistryClientBuilder};
 use uv_configuration::{Concurrency, DependencyGroups, TargetTriple};
 use uv_distribution_types::IndexCapabilities;
@@ -41,6 +42,7 @@ pub(crate) async fn tree(
     lock_check: LockCheck,
     frozen: Option<FrozenSource>,
     universal: bool,
+    format: TreeFormat,
     depth: u8,
     prune: Vec<PackageName>,
     package: Vec<PackageName>,
@@ -287,7 +289,12 @@ pub(crate) async fn tree(
         show_sizes,
     );
 
-    print!("{tree}");
+    match format {
+        TreeFormat::Text => print!("{tree}"),
+        TreeFormat::Json => println!("{}", tree.to_json()?),
+        TreeFormat::Dot => print!("{}", tree.to_dot()),
+        TreeFormat::Mermaid => print!("{}", tree.to_mermaid()),
+    }
 
     Ok(ExitStatus::Success)
 }
@@ -0,0 +1,399 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_cli::UpgradeLimit;
+use uv_client::BaseClientBuilder;
+use uv_configuration::{Concurrency, DependencyGroupsWithDefaults, Upgrade};
+use uv_distribution_types::{Requirement, RequirementSource};
+use uv_fs::Simplified;
+use uv_normalize::{DEV_DEPENDENCIES, PackageName};
+use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
+use uv_pep508::{MarkerTree, VersionOrUrl};
+use uv_preview::Preview;
+use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
+use uv_resolver::Lock;
+use uv_workspace::pyproject::DependencyType;
+use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
+use uv_workspace::{DiscoveryOptions, VirtualProject, Workspace, WorkspaceCache};
+
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::project::lock::{LockMode, LockOperation, LockResult, report_changes};
+use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::{ProjectError, ProjectInterpreter, UniversalState};
+use crate::commands::{ExitStatus, diagnostics};
+use crate::printer::Printer;
+use crate::settings::{ResolverSettings, UpgradeSettings};
+
+/// Raise the lower bounds of the project's requirements to the latest compatible versions.
+pub(crate) async fn upgrade(
+    project_dir: &Path,
+    args: UpgradeSettings,
+    client_builder: BaseClientBuilder<'_>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    concurrency: Concurrency,
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    let UpgradeSettings {
+        packages,
+        limit,
+        dependency_types,
+        all_packages,
+        package,
+        dry_run,
+        python,
+        install_mirrors,
+        refresh,
+        settings,
+    } = args;
+
+    let workspace_cache = WorkspaceCache::default();
+    let project =
+        VirtualProject::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+            .await?;
+    let workspace = project.workspace();
+
+    // Determine the `pyproject.toml` files to upgrade.
+    let roots = if all_packages {
+        workspace
+            .packages()
+            .values()
+            .map(|member| member.root().clone())
+            .chain(
+                workspace
+                    .is_non_project()
+                    .then(|| workspace.install_path().clone()),
+            )
+            .collect::<Vec<_>>()
+    } else if let Some(name) = package.as_ref() {
+        let member = workspace
+            .packages()
+            .get(name)
+            .with_context(|| format!("Package `{name}` not found in workspace"))?;
+        vec![member.root().clone()]
+    } else {
+        vec![project.root().to_path_buf()]
+    };
+
+    let mut targets = roots
+        .into_iter()
+        .map(UpgradeTarget::read)
+        .collect::<Result<Vec<_>>>()?;
+
+    // Collect the requirements to upgrade, i.e., those in the selected sections that have a
+    // version specifier.
+    let candidates = targets
+        .iter()
+        .enumerate()
+        .flat_map(|(target, upgrade_target)| {
+            upgrade_target.toml.dependencies().into_iter().map(
+                move |(dependency_type, index, requirement)| {
+                    (target, dependency_type, index, requirement)
+                },
+            )
+        })
+        .filter(|(_, dependency_type, ..)| is_selected(dependency_type, &dependency_types))
+        .filter(|(.., requirement)| packages.is_empty() || packages.contains(&requirement.name))
+        .filter(|(.., requirement)| {
+            matches!(
+                requirement.version_or_url,
+                Some(VersionOrUrl::VersionSpecifier(_))
+            )
+        })
+        .collect::<Vec<_>>();
+
+    for name in &packages {
+        if !candidates
+            .iter()
+            .any(|(.., requirement)| requirement.name == *name)
+        {
+            anyhow::bail!(
+                "No requirement on `{name}` with a version specifier was found in the selected dependencies"
+            );
+        }
+    }
+
+    let interpreter = ProjectInterpreter::discover(
+        workspace,
+        project_dir,
+        // Don't enable any groups' requires-python for interpreter discovery
+        &DependencyGroupsWithDefaults::none(),
+        python.as_deref().map(PythonRequest::parse),
+        &client_builder,
+        python_preference,
+        python_downloads,
+        &install_mirrors,
+        false,
+        no_config,
+        Some(false),
+        cache,
+        printer,
+        preview,
+    )
+    .await?
+    .into_interpreter();
+
+    // Limit each upgrade relative to the highest locked version of the package.
+    let target = LockTarget::Workspace(workspace);
+    let existing = target.read().await?;
+    let locked = existing
+        .as_ref()
+        .map(|lock| locked_versions(lock, workspace))
+        .unwrap_or_default();
+    let upgrade = Upgrade::Packages(
+        candidates
+            .iter()
+            .map(|(.., requirement)| {
+                let constraint = locked
+                    .get(&requirement.name)
+                    .and_then(|(_, highest)| upgrade_limit(&requirement.name, highest, limit));
+                (requirement.name.clone(), constraint.into_iter().collect())
+            })
+            .collect(),
+    );
+    let settings = ResolverSettings {
+        upgrade: upgrade.combine(settings.upgrade.clone()),
+        ..settings
+    };
+
+    // Lock the project, allowing upgrades for the selected requirements. The lockfile is only
+    // written once the requirements have been updated to match.
+    let state = UniversalState::default();
+    let result = Box::pin(
+        LockOperation::new(
+            LockMode::DryRun(&interpreter),
+            &settings,
+            &client_builder,
+            &state,
+            Box::new(DefaultResolveLogger),
+            concurrency,
+            cache,
+            &workspace_cache,
+            printer,
+            preview,
+        )
+        .with_refresh(&refresh)
+        .execute(target),
+    )
+    .await;
+
+    let result = match result {
+        Ok(result) => result,
+        Err(ProjectError::Operation(err)) => {
+            return diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+        }
+        Err(err) => return Err(err.into()),
+    };
+    if let LockResult::Changed(previous, lock) = &result {
+        report_changes(previous.as_ref(), lock, dry_run, printer)?;
+    }
+    let lock = result.lock();
+
+    // Raise the lower bound of each requirement to the lowest locked version of the package.
+    let locked = locked_versions(lock, workspace);
+    let mut modified = false;
+    for (target, dependency_type, index, requirement) in candidates {
+        let Some((lowest, _)) = locked.get(&requirement.name) else {
+            debug!("Skipping `{requirement}`, which is not locked from a registry");
+            continue;
+        };
+        let upgrade_target = &mut targets[target];
+        let Some(raised) =
+            upgrade_target
+                .toml
+                .raise_dependency_bound(&dependency_type, index, lowest)?
+        else {
+            continue;
+        };
+        modified = true;
+        upgrade_target.modified = true;
+
+        let path = upgrade_target.root.join("pyproject.toml");
+        writeln!(
+            printer.stderr(),
+            "{} `{requirement}` -> `{raised}` in `{}` ({})",
+            if dry_run.enabled() { "Bump" } else { "Bumped" }
+                .green()
+                .bold(),
+            section(&dependency_type),
+            path.user_display().cyan(),
+        )?;
+    }
+
+    if !modified {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            "All requirement bounds are up-to-date".bold()
+        )?;
+    }
+
+    if dry_run.enabled() {
+        return Ok(ExitStatus::Success);
+    }
+
+    // If the requirements are unchanged, write the upgraded lockfile as-is.
+    if !modified {
+        if let LockResult::Changed(_, lock) = &result {
+            target.commit(lock).await?;
+        }
+        return Ok(ExitStatus::Success);
+    }
+
+    // Save the modified `pyproject.toml` files.
+    for target in targets.iter().filter(|target| target.modified) {
+        fs_err::write(target.root.join("pyproject.toml"), target.toml.to_string())?;
+    }
+
+    // Re-lock the project to record the updated requirements. Since each lower bound was raised
+    // to a version in the upgraded lockfile, the same upgrades yield the same versions.
+    let workspace_cache = WorkspaceCache::default();
+    let workspace = Workspace::discover(
+        workspace.install_path(),
+        &DiscoveryOptions::default(),
+        &workspace_cache,
+    )
+    .await?;
+    let state = UniversalState::default();
+    let result = Box::pin(
+        LockOperation::new(
+            LockMode::Write(&interpreter),
+            &settings,
+            &client_builder,
+            &state,
+            Box::new(DefaultResolveLogger),
+            concurrency,
+            cache,
+            &workspace_cache,
+            printer,
+            preview,
+        )
+        .execute(LockTarget::Workspace(&workspace)),
+    )
+    .await;
+
+    match result {
+        Ok(_) => Ok(ExitStatus::Success),
+        Err(ProjectError::Operation(err)) => {
+            diagnostics::OperationDiagnostic::native_tls(client_builder.is_native_tls())
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+        }
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// A `pyproject.toml` whose requirements are being upgraded.
+struct UpgradeTarget {
+    root: PathBuf,
+    toml: PyProjectTomlMut,
+    modified: bool,
+}
+
+impl UpgradeTarget {
+    /// Read the `pyproject.toml` in the given directory.
+    fn read(root: PathBuf) -> Result<Self> {
+        let content = fs_err::read_to_string(root.join("pyproject.toml"))?;
+        let toml = PyProjectTomlMut::from_toml(&content, DependencyTarget::PyProjectToml)?;
+        Ok(Self {
+            root,
+            toml,
+            modified: false,
+        })
+    }
+}
+
+/// Returns `true` if requirements of the given type should be upgraded.
+///
+/// If no types were selected, all requirements are upgraded. The `dev` dependency group and
+/// `tool.uv.dev-dependencies` are selected together.
+fn is_selected(dependency_type: &DependencyType, selected: &[DependencyType]) -> bool {
+    if selected.is_empty() || selected.contains(dependency_type) {
+        return true;
+    }
+    match dependency_type {
+        DependencyType::Dev => selected.contains(&DependencyType::Group(DEV_DEPENDENCIES.clone())),
+        DependencyType::Group(group) => {
+            group == &*DEV_DEPENDENCIES && selected.contains(&DependencyType::Dev)
+        }
+        _ => false,
+    }
+}
+
+/// Returns the lowest and highest locked version of each package that was locked from a registry.
+fn locked_versions(
+    lock: &Lock,
+    workspace: &Workspace,
+) -> FxHashMap<PackageName, (Version, Version)> {
+    let mut versions = FxHashMap::<PackageName, (Version, Version)>::default();
+    for package in lock.packages() {
+        if !matches!(package.index(workspace.install_path()), Ok(Some(_))) {
+            continue;
+        }
+        let Some(version) = package.version() else {
+            continue;
+        };
+        versions
+            .entry(package.name().clone())
+            .and_modify(|(lowest, highest)| {
+                if version < lowest {
+                    *lowest = version.clone();
+                }
+                if version > highest {
+                    *highest = version.clone();
+                }
+            })
+            .or_insert_with(|| (version.clone(), version.clone()));
+    }
+    versions
+}
+
+/// Returns a constraint that limits upgrades of the package to the same major or minor version
+/// as the given version, if any.
+fn upgrade_limit(
+    name: &PackageName,
+    version: &Version,
+    limit: UpgradeLimit,
+) -> Option<Requirement> {
+    let release = version.release();
+    let bound = match limit {
+        UpgradeLimit::Major => return None,
+        UpgradeLimit::Minor => Version::new([release[0] + 1]),
+        UpgradeLimit::Patch => {
+            Version::new([release[0], release.get(1).copied().unwrap_or_default() + 1])
+        }
+    };
+    Some(Requirement {
+        name: name.clone(),
+        extras: Box::new([]),
+        groups: Box::new([]),
+        marker: MarkerTree::TRUE,
+        source: RequirementSource::Registry {
+            specifier: VersionSpecifiers::from(VersionSpecifier::less_than_version(bound)),
+            index: None,
+            conflict: None,
+        },
+        origin: None,
+    })
+}
+
+/// Returns the TOML path of the table that contains requirements of the given type.
+fn section(dependency_type: &DependencyType) -> String {
+    match dependency_type {
+        DependencyType::Production => "project.dependencies".to_string(),
+        DependencyType::Dev => "tool.uv.dev-dependencies".to_string(),
+        DependencyType::Optional(extra) => format!("project.optional-dependencies.{extra}"),
+        DependencyType::Group(group) => format!("dependency-groups.{group}"),
+    }
+}
@@ -29,11 +29,11 @@ use uv_cache_info::Timestamp;
 use uv_cli::SelfUpdateArgs;
 use uv_cli::{
     AuthCommand, AuthHelperCommand, AuthNamespace, BuildBackendCommand, CacheCommand,
-    CacheNamespace, Cli, Commands, PipCommand, PipNamespace, ProjectCommand, PythonCommand,
-    PythonNamespace, SelfCommand, SelfNamespace, ToolCommand, ToolNamespace, TopLevelArgs,
-    WorkspaceCommand, WorkspaceNamespace, compat::CompatArgs,
+    CacheNamespace, Cli, Commands, IndexCommand, IndexNamespace, PipCommand, PipNamespace,
+    ProjectCommand, PythonCommand, PythonNamespace, SelfCommand, SelfNamespace, ToolCommand,
+    ToolNamespace, TopLevelArgs, WorkspaceCommand, WorkspaceNamespace, compat::CompatArgs,
 };
-use uv_client::BaseClientBuilder;
+use uv_client::{BaseClientBuilder, Connectivity};
 use uv_configuration::min_stack_size;
 use uv_flags::EnvironmentFlags;
 use uv_fs::{CWD, Simplified};
@@ -45,6 +45,7 @@ use uv_pypi_types::{ParsedDirectoryUrl, ParsedUrl};
 use uv_python::PythonRequest;
 use uv_requirements::{GroupsSpecification, RequirementsSource};
 use uv_requirements_txt::RequirementsTxtRequirement;
+use uv_resolver::TraceMode;
 use uv_scripts::{Pep723Error, Pep723Item, Pep723Metadata, Pep723Script};
 use uv_settings::{Combine, EnvironmentOptions, FilesystemOptions, Options};
 use uv_static::EnvVars;
@@ -350,7 +351,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     }
 
     // Resolve the cache settings.
-    let cache_settings = CacheSettings::resolve(*cli.top_level.cache_args, filesystem.as_ref());
+    let cache_settings =
+        CacheSettings::resolve(*cli.top_level.cache_args, filesystem.as_ref(), &environment);
 
     // Set the global flags.
     uv_flags::init(EnvironmentFlags::from(&environment))
@@ -476,7 +478,26 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     if cache_settings.no_cache {
         debug!("Disabling the uv cache due to `--no-cache`");
     }
-    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?;
+    if cache_settings.remote_build_cache.is_some()
+        && !globals
+            .preview
+            .is_enabled(PreviewFeatures::REMOTE_BUILD_CACHE)
+    {
+        warn_user!(
+            "The remote build cache is experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::REMOTE_BUILD_CACHE
+        );
+    }
+    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?
+        .with_remote_build_cache(cache_settings.remote_build_cache)
+        .with_eviction_policy(cache_settings.eviction_policy);
+
+    // Once the command completes, evict any least recently used cache entries, if configured. The
+    // `uv cache` commands manage the cache directly (e.g., `uv cache prune` applies the policy
+    // unconditionally).
+    let eviction = (!matches!(*cli.command, Commands::Cache(_))
+        && cache.eviction_policy().is_some())
+    .then(|| cache.clone());
 
     // Configure the global network settings.
     let client_builder = BaseClientBuilder::new(
@@ -491,7 +512,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     .https_proxy(globals.network_settings.https_proxy.clone())
     .no_proxy(globals.network_settings.no_proxy.clone());
 
-    match *cli.command {
+    let status = match *cli.command {
         Commands::Auth(AuthNamespace {
             command: AuthCommand::Login(args),
         }) => {
@@ -1093,6 +1114,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.system,
                 args.settings.python_version.as_ref(),
                 args.settings.python_platform.as_ref(),
+                args.verify_record,
+                args.output_format,
                 &cache,
                 printer,
                 globals.preview,
@@ -1114,7 +1137,23 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             command: CacheCommand::Prune(args),
         }) => {
             show_settings!(args);
-            commands::cache_prune(args.ci, args.force, cache, printer).await
+            commands::cache_prune(
+                args.ci,
+                args.force,
+                &args.keep_lock,
+                args.dry_run,
+                &cache_settings.eviction_policy,
+                cache,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Verify(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_verify(args.force, args.dry_run, cache, printer, globals.preview).await
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Dir,
@@ -1122,6 +1161,38 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Size(args),
         }) => commands::cache_size(&cache, args.human, printer, globals.preview),
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Export(args),
+        }) => {
+            show_settings!(args);
+            let cache = cache.init().await?;
+            commands::cache_export(
+                &args.output,
+                &args.lockfile,
+                &cache,
+                printer,
+                globals.preview,
+            )
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Import(args),
+        }) => {
+            show_settings!(args);
+            let cache = cache.init().await?;
+            commands::cache_import(&args.bundle, &cache, printer, globals.preview)
+        }
+        Commands::Index(IndexNamespace {
+            command: IndexCommand::BuildMetadata(args),
+        }) => {
+            show_settings!(args);
+            commands::index_build_metadata(
+                &args.output,
+                &args.package,
+                &cache,
+                printer,
+                globals.preview,
+            )
+        }
         Commands::Build(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::BuildSettings::resolve(args, filesystem, environment);
@@ -1922,7 +1993,23 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         })
         .await
         .expect("tokio threadpool exited unexpectedly"),
+    };
+
+    if let Some((cache, policy)) = eviction
+        .as_ref()
+        .and_then(|cache| Some((cache, cache.eviction_policy()?)))
+    {
+        match cache.evict_opportunistically(policy) {
+            Ok(summary) if summary.num_dirs > 0 || summary.num_files > 0 => debug!(
+                "Evicted {} files and {} directories from the cache",
+                summary.num_files, summary.num_dirs
+            ),
+            Ok(_) => {}
+            Err(err) => warn_user!("Failed to evict cache entries: {err}"),
+        }
     }
+
+    status
 }
 
 /// Run a [`ProjectCommand`].
@@ -2144,6 +2231,11 @@ async fn run_project(
             let args = settings::LockSettings::resolve(args, filesystem, environment);
             show_settings!(args);
 
+            // Compare the lockfile against a base lockfile, without re-locking.
+            if let Some(base) = args.diff {
+                return commands::lock_diff(project_dir, base, args.output_format, printer).await;
+            }
+
             // Check for conflicts between offline and refresh.
             globals
                 .network_settings
@@ -2169,12 +2261,47 @@ async fn run_project(
                 .map(ScriptPath::Script)
                 .or(args.script.map(ScriptPath::Path));
 
+            // Merge two lockfiles, e.g., as a Git merge driver.
+            if let Some(merge) = args.merge {
+                return Box::pin(commands::lock_merge(
+                    project_dir,
+                    &merge.base,
+                    &merge.ours,
+                    &merge.theirs,
+                    args.python,
+                    args.install_mirrors,
+                    args.settings,
+                    client_builder.subcommand(vec!["lock".to_owned(), "merge".to_owned()]),
+                    globals.python_preference,
+                    globals.python_downloads,
+                    globals.concurrency,
+                    no_config,
+                    &cache,
+                    printer,
+                    globals.preview,
+                ))
+                .await;
+            }
+
+            // When replaying a trace, resolve without network access.
+            let client_builder = if matches!(args.trace, Some(TraceMode::Replay(_))) {
+                client_builder.connectivity(Connectivity::Offline)
+            } else {
+                client_builder
+            };
+
             Box::pin(commands::lock(
                 project_dir,
                 args.lock_check,
                 args.frozen,
                 args.dry_run,
                 args.refresh,
+                args.trace,
+                args.explain,
+                args.profile,
+                args.metadata_index,
+                args.platforms,
+                args.auto_conflicts,
                 args.python,
                 args.install_mirrors,
                 args.settings,
@@ -2208,7 +2335,7 @@ async fn run_project(
                 .map(ScriptPath::Script)
                 .or(args.script.map(ScriptPath::Path));
 
-            let requirements = args
+            let mut requirements = args
                 .packages
                 .iter()
                 .map(String::as_str)
@@ -2220,6 +2347,16 @@ async fn run_project(
                 )
                 .collect::<Result<Vec<_>>>()?;
 
+            // Infer any additional requirements from the imports in the project or script.
+            if args.from_imports {
+                requirements.extend(
+                    commands::import_requirements(project_dir, script.as_ref(), printer).await?,
+                );
+                if requirements.is_empty() {
+                    return Ok(ExitStatus::Success);
+                }
+            }
+
             // Special-case: any local source trees specified on the command-line are automatically
             // reinstalled.
             for requirement in &requirements {
@@ -2373,6 +2510,37 @@ async fn run_project(
             ))
             .await
         }
+        ProjectCommand::Upgrade(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::UpgradeSettings::resolve(args, filesystem, environment);
+            show_settings!(args);
+
+            // Check for conflicts between offline and refresh.
+            globals
+                .network_settings
+                .check_refresh_conflict(&args.refresh);
+
+            // Initialize the cache.
+            let cache = cache.init().await?.with_refresh(
+                args.refresh
+                    .clone()
+                    .combine(Refresh::from(args.settings.upgrade.clone())),
+            );
+
+            Box::pin(commands::upgrade(
+                project_dir,
+                args,
+                client_builder.subcommand(vec!["upgrade".to_owned()]),
+                globals.python_preference,
+                globals.python_downloads,
+                globals.concurrency,
+                no_config,
+                &cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
         ProjectCommand::Version(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::VersionSettings::resolve(args, filesystem, environment);
@@ -2439,6 +2607,7 @@ async fn run_project(
                 args.lock_check,
                 args.frozen,
                 args.universal,
+                args.format,
                 args.depth,
                 args.prune,
                 args.package,
@@ -2463,6 +2632,25 @@ async fn run_project(
             ))
             .await
         }
+        ProjectCommand::Audit(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::AuditSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            Box::pin(commands::audit(
+                project_dir,
+                args.database,
+                args.ignore,
+                args.ignore_package,
+                args.environments,
+                args.output_format,
+                args.output_file,
+                globals.quiet > 0,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
         ProjectCommand::Export(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::ExportSettings::resolve(args, filesystem, environment);
@@ -2525,6 +2713,8 @@ async fn run_project(
                 args.diff,
                 args.extra_args,
                 args.version,
+                &args.binaries,
+                args.binary_install_mirror.as_deref(),
                 client_builder.subcommand(vec!["format".to_owned()]),
                 cache,
                 printer,
@@ -2533,6 +2723,53 @@ async fn run_project(
             ))
             .await
         }
+        ProjectCommand::Lint(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::LintSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init().await?;
+
+            Box::pin(commands::lint(
+                project_dir,
+                args.fix,
+                args.diff,
+                args.extra_args,
+                args.version,
+                &args.binaries,
+                args.binary_install_mirror.as_deref(),
+                client_builder.subcommand(vec!["lint".to_owned()]),
+                cache,
+                printer,
+                globals.preview,
+                args.no_project,
+            ))
+            .await
+        }
+        ProjectCommand::Bin(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::BinSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init().await?;
+
+            Box::pin(commands::bin(
+                project_dir,
+                &args.name,
+                args.extra_args,
+                args.version,
+                &args.binaries,
+                args.binary_install_mirror.as_deref(),
+                client_builder.subcommand(vec!["bin".to_owned()]),
+                cache,
+                printer,
+                globals.preview,
+                args.no_project,
+            ))
+            .await
+        }
     }
 }
 
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::env::VarError;
 use std::num::NonZeroUsize;
 use std::path::PathBuf;
@@ -7,20 +8,22 @@ use std::time::Duration;
 
 use crate::commands::{PythonUpgrade, PythonUpgradeSource};
 use uv_auth::Service;
-use uv_cache::{CacheArgs, Refresh};
+use uv_bin_install::BinarySource;
+use uv_cache::{CacheArgs, EvictionPolicy, Refresh, RemoteBuildCache};
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
     AddArgs, AuthLoginArgs, AuthLogoutArgs, AuthTokenArgs, ColorChoice, ExternalCommand,
-    GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe, PipCheckArgs, PipCompileArgs, PipFreezeArgs,
+    GlobalArgs, InitArgs, LintArgs, ListFormat, LockArgs, LockCommand, LockFormat, LockMergeArgs,
+    LockPlatform, Maybe, PipCheckArgs, PipCheckFormat, PipCompileArgs, PipFreezeArgs,
     PipInstallArgs, PipListArgs, PipShowArgs, PipSyncArgs, PipTreeArgs, PipUninstallArgs,
     PythonFindArgs, PythonInstallArgs, PythonListArgs, PythonListFormat, PythonPinArgs,
     PythonUninstallArgs, PythonUpgradeArgs, RemoveArgs, RunArgs, SyncArgs, SyncFormat, ToolDirArgs,
-    ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs, VersionArgs,
-    VersionBumpSpec, VersionFormat,
+    ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, TreeFormat,
+    UpgradeArgs, UpgradeLimit, VenvArgs, VersionArgs, VersionBumpSpec, VersionFormat,
 };
 use uv_cli::{
-    AuthorFrom, BuildArgs, ExportArgs, FormatArgs, PublishArgs, PythonDirArgs,
-    ResolverInstallerArgs, ToolUpgradeArgs,
+    AuditArgs, AuditFormat, AuthorFrom, BinArgs, BuildArgs, ExportArgs, FormatArgs, PublishArgs,
+    PythonDirArgs, ResolverInstallerArgs, ToolUpgradeArgs,
     options::{
         Flag, FlagSource, check_conflicts, flag, resolve_flag, resolver_installer_options,
         resolver_options,
@@ -47,7 +50,7 @@ use uv_python::{Prefix, PythonDownloads, PythonPreference, PythonVersion, Target
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
     AnnotationStyle, DependencyMode, ExcludeNewer, ExcludeNewerPackage, ForkStrategy,
-    PrereleaseMode, ResolutionMode,
+    PrereleaseMode, ResolutionMode, TraceMode,
 };
 use uv_settings::{
     Combine, EnvironmentOptions, FilesystemOptions, Options, PipOptions, PublishOptions,
@@ -59,6 +62,7 @@ use uv_warnings::warn_user_once;
 use uv_workspace::pyproject::{DependencyType, ExtraBuildDependencies};
 use uv_workspace::pyproject_mut::AddBoundsKind;
 
+use crate::commands::LockDiffBase;
 use crate::commands::ToolRunCommand;
 use crate::commands::{InitKind, InitProjectKind, pip::operations::Modifications};
 
@@ -345,11 +349,17 @@ impl NetworkSettings {
 pub(crate) struct CacheSettings {
     pub(crate) no_cache: bool,
     pub(crate) cache_dir: Option<PathBuf>,
+    pub(crate) eviction_policy: EvictionPolicy,
+    pub(crate) remote_build_cache: Option<RemoteBuildCache>,
 }
 
 impl CacheSettings {
-    /// Resolve the [`CacheSettings`] from the CLI and filesystem configuration.
-    pub(crate) fn resolve(args: CacheArgs, workspace: Option<&FilesystemOptions>) -> Self {
+    /// Resolve the [`CacheSettings`] from the CLI, filesystem, and environment configuration.
+    pub(crate) fn resolve(
+        args: CacheArgs,
+        workspace: Option<&FilesystemOptions>,
+        environment: &EnvironmentOptions,
+    ) -> Self {
         Self {
             no_cache: args.no_cache
                 || workspace
@@ -358,6 +368,26 @@ impl CacheSettings {
             cache_dir: args
                 .cache_dir
                 .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_dir.clone())),
+            eviction_policy: EvictionPolicy {
+                max_size: workspace.and_then(|workspace| workspace.globals.cache_max_size),
+                max_age: workspace.and_then(|workspace| workspace.globals.cache_max_age),
+            },
+            remote_build_cache: environment
+                .remote_build_cache
+                .clone()
+                .or_else(|| {
+                    workspace.and_then(|workspace| workspace.globals.remote_build_cache.clone())
+                })
+                .map(|location| {
+                    let publish = environment
+                        .remote_build_cache_publish
+                        .or_else(|| {
+                            workspace
+                                .and_then(|workspace| workspace.globals.remote_build_cache_publish)
+                        })
+                        .unwrap_or(false);
+                    RemoteBuildCache::new(location, publish)
+                }),
         }
     }
 }
@@ -1721,6 +1751,15 @@ pub(crate) struct LockSettings {
     pub(crate) lock_check: LockCheck,
     pub(crate) frozen: Option<FrozenSource>,
     pub(crate) dry_run: DryRun,
+    pub(crate) diff: Option<LockDiffBase>,
+    pub(crate) output_format: LockFormat,
+    pub(crate) merge: Option<LockMergeSettings>,
+    pub(crate) trace: Option<TraceMode>,
+    pub(crate) explain: Option<PackageName>,
+    pub(crate) profile: Option<PathBuf>,
+    pub(crate) metadata_index: Option<PathBuf>,
+    pub(crate) platforms: Vec<LockPlatform>,
+    pub(crate) auto_conflicts: bool,
     pub(crate) script: Option<PathBuf>,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
@@ -1737,10 +1776,21 @@ impl LockSettings {
         environment: EnvironmentOptions,
     ) -> Self {
         let LockArgs {
+            command,
             check,
             locked,
             check_exists,
             dry_run,
+            diff,
+            diff_file,
+            output_format,
+            record,
+            replay,
+            explain,
+            profile,
+            metadata_index,
+            platforms,
+            auto_conflicts,
             script,
             resolver,
             build,
@@ -1770,6 +1820,23 @@ impl LockSettings {
             lock_check,
             frozen: resolve_frozen(frozen),
             dry_run: DryRun::from_args(dry_run),
+            diff: diff
+                .map(LockDiffBase::Revision)
+                .or(diff_file.map(LockDiffBase::File)),
+            output_format,
+            merge: command.map(|command| match command {
+                LockCommand::Merge(LockMergeArgs { base, ours, theirs }) => {
+                    LockMergeSettings { base, ours, theirs }
+                }
+            }),
+            trace: record
+                .map(TraceMode::Record)
+                .or(replay.map(TraceMode::Replay)),
+            explain,
+            profile,
+            metadata_index,
+            platforms,
+            auto_conflicts,
             script,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
@@ -1781,6 +1848,14 @@ impl LockSettings {
     }
 }
 
+/// The lockfiles to merge in a `lock merge` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct LockMergeSettings {
+    pub(crate) base: PathBuf,
+    pub(crate) ours: PathBuf,
+    pub(crate) theirs: PathBuf,
+}
+
 /// The resolved settings to use for a `add` invocation.
 #[allow(clippy::struct_excessive_bools, dead_code)]
 #[derive(Debug, Clone)]
@@ -1792,6 +1867,7 @@ pub(crate) struct AddSettings {
     pub(crate) packages: Vec<String>,
     pub(crate) requirements: Vec<PathBuf>,
     pub(crate) constraints: Vec<PathBuf>,
+    pub(crate) from_imports: bool,
     pub(crate) marker: Option<MarkerTree>,
     pub(crate) dependency_type: DependencyType,
     pub(crate) editable: Option<bool>,
@@ -1832,6 +1908,7 @@ impl AddSettings {
             packages,
             requirements,
             constraints,
+            from_imports,
             marker,
             dev,
             optional,
@@ -1972,6 +2049,7 @@ impl AddSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            from_imports,
             marker,
             dependency_type,
             raw,
@@ -2007,6 +2085,74 @@ impl AddSettings {
     }
 }
 
+/// The resolved settings to use for an `upgrade` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct UpgradeSettings {
+    pub(crate) packages: Vec<PackageName>,
+    pub(crate) limit: UpgradeLimit,
+    pub(crate) dependency_types: Vec<DependencyType>,
+    pub(crate) all_packages: bool,
+    pub(crate) package: Option<PackageName>,
+    pub(crate) dry_run: DryRun,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverSettings,
+}
+
+impl UpgradeSettings {
+    /// Resolve the [`UpgradeSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(
+        args: UpgradeArgs,
+        filesystem: Option<FilesystemOptions>,
+        environment: EnvironmentOptions,
+    ) -> Self {
+        let UpgradeArgs {
+            packages,
+            limit,
+            dev,
+            optional,
+            group,
+            all_packages,
+            package,
+            dry_run,
+            resolver,
+            build,
+            refresh,
+            python,
+        } = args;
+
+        let filesystem_install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        // If no sections are selected, all sections are upgraded.
+        let dependency_types = optional
+            .into_iter()
+            .map(DependencyType::Optional)
+            .chain(group.into_iter().map(DependencyType::Group))
+            .chain(dev.then_some(DependencyType::Dev))
+            .collect();
+
+        Self {
+            packages,
+            limit,
+            dependency_types,
+            all_packages,
+            package,
+            dry_run: DryRun::from_args(dry_run),
+            python: python.and_then(Maybe::into_option),
+            refresh: Refresh::from(refresh),
+            settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+            install_mirrors: environment
+                .install_mirrors
+                .combine(filesystem_install_mirrors),
+        }
+    }
+}
+
 /// The resolved settings to use for a `remove` invocation.
 #[allow(clippy::struct_excessive_bools, dead_code)]
 #[derive(Debug, Clone)]
@@ -2200,6 +2346,7 @@ pub(crate) struct TreeSettings {
     pub(crate) lock_check: LockCheck,
     pub(crate) frozen: Option<FrozenSource>,
     pub(crate) universal: bool,
+    pub(crate) format: TreeFormat,
     pub(crate) depth: u8,
     pub(crate) prune: Vec<PackageName>,
     pub(crate) package: Vec<PackageName>,
@@ -2226,6 +2373,7 @@ impl TreeSettings {
         let TreeArgs {
             tree,
             universal,
+            format,
             dev,
             only_dev,
             no_dev,
@@ -2273,6 +2421,7 @@ impl TreeSettings {
             lock_check: resolve_lock_check(locked),
             frozen: resolve_frozen(frozen),
             universal,
+            format,
             depth: tree.depth,
             prune: tree.prune,
             package: tree.package,
@@ -2435,6 +2584,46 @@ impl ExportSettings {
     }
 }
 
+/// The resolved settings to use for an `audit` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct AuditSettings {
+    pub(crate) database: PathBuf,
+    pub(crate) ignore: Vec<String>,
+    pub(crate) ignore_package: Vec<PackageName>,
+    pub(crate) environments: Vec<MarkerTree>,
+    pub(crate) output_format: AuditFormat,
+    pub(crate) output_file: Option<PathBuf>,
+}
+
+impl AuditSettings {
+    /// Resolve the [`AuditSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: AuditArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let AuditArgs {
+            database,
+            ignore,
+            ignore_package,
+            environment,
+            output_format,
+            output_file,
+        } = args;
+
+        let audit = filesystem.and_then(|fs| fs.into_options().audit);
+
+        Self {
+            database,
+            ignore: Some(ignore)
+                .combine(audit.as_ref().and_then(|audit| audit.ignore.clone()))
+                .unwrap_or_default(),
+            ignore_package: Some(ignore_package)
+                .combine(audit.and_then(|audit| audit.ignore_package))
+                .unwrap_or_default(),
+            environments: environment,
+            output_format,
+            output_file,
+        }
+    }
+}
+
 /// The resolved settings to use for a `format` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct FormatSettings {
@@ -2443,11 +2632,13 @@ pub(crate) struct FormatSettings {
     pub(crate) extra_args: Vec<String>,
     pub(crate) version: Option<String>,
     pub(crate) no_project: bool,
+    pub(crate) binaries: BTreeMap<String, BinarySource>,
+    pub(crate) binary_install_mirror: Option<String>,
 }
 
 impl FormatSettings {
     /// Resolve the [`FormatSettings`] from the CLI and filesystem configuration.
-    pub(crate) fn resolve(args: FormatArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+    pub(crate) fn resolve(args: FormatArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let FormatArgs {
             check,
             diff,
@@ -2456,12 +2647,102 @@ impl FormatSettings {
             no_project,
         } = args;
 
+        let (binaries, binary_install_mirror) = filesystem
+            .map(|fs| {
+                let options = fs.into_options();
+                (options.binaries, options.binary_install_mirror)
+            })
+            .unwrap_or_default();
+
         Self {
             check,
             diff,
             extra_args,
             version,
             no_project,
+            binaries: binaries.unwrap_or_default(),
+            binary_install_mirror,
+        }
+    }
+}
+
+/// The resolved settings to use for a `lint` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct LintSettings {
+    pub(crate) fix: bool,
+    pub(crate) diff: bool,
+    pub(crate) extra_args: Vec<String>,
+    pub(crate) version: Option<String>,
+    pub(crate) no_project: bool,
+    pub(crate) binaries: BTreeMap<String, BinarySource>,
+    pub(crate) binary_install_mirror: Option<String>,
+}
+
+impl LintSettings {
+    /// Resolve the [`LintSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: LintArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let LintArgs {
+            fix,
+            diff,
+            extra_args,
+            version,
+            no_project,
+        } = args;
+
+        let (binaries, binary_install_mirror) = filesystem
+            .map(|fs| {
+                let options = fs.into_options();
+                (options.binaries, options.binary_install_mirror)
+            })
+            .unwrap_or_default();
+
+        Self {
+            fix,
+            diff,
+            extra_args,
+            version,
+            no_project,
+            binaries: binaries.unwrap_or_default(),
+            binary_install_mirror,
+        }
+    }
+}
+
+/// The resolved settings to use for a `bin` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct BinSettings {
+    pub(crate) name: String,
+    pub(crate) extra_args: Vec<String>,
+    pub(crate) version: Option<String>,
+    pub(crate) no_project: bool,
+    pub(crate) binaries: BTreeMap<String, BinarySource>,
+    pub(crate) binary_install_mirror: Option<String>,
+}
+
+impl BinSettings {
+    /// Resolve the [`BinSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: BinArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let BinArgs {
+            name,
+            version,
+            extra_args,
+            no_project,
+        } = args;
+
+        let (binaries, binary_install_mirror) = filesystem
+            .map(|fs| {
+                let options = fs.into_options();
+                (options.binaries, options.binary_install_mirror)
+            })
+            .unwrap_or_default();
+
+        Self {
+            name,
+            extra_args,
+            version,
+            no_project,
+            binaries: binaries.unwrap_or_default(),
+            binary_install_mirror,
         }
     }
 }
@@ -3227,6 +3508,8 @@ impl PipTreeSettings {
 /// The resolved settings to use for a `pip check` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct PipCheckSettings {
+    pub(crate) verify_record: bool,
+    pub(crate) output_format: PipCheckFormat,
     pub(crate) settings: PipSettings,
 }
 
@@ -3243,9 +3526,13 @@ impl PipCheckSettings {
             no_system,
             python_version,
             python_platform,
+            verify_record,
+            output_format,
         } = args;
 
         Self {
+            verify_record,
+            output_format,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -0,0 +1,358 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Write a project with a lockfile that contains a vulnerable version of `jinja2` on all
+/// platforms, and a vulnerable version of `pywin32` on Windows only, alongside an advisory database
+/// covering both.
+fn write_project(context: &TestContext) -> Result<()> {
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["jinja2==2.11.2", "pywin32==300 ; sys_platform == 'win32'"]
+    "#})?;
+
+    context.temp_dir.child("uv.lock").write_str(indoc! {r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "jinja2"
+        version = "2.11.2"
+        source = { registry = "https://pypi.org/simple" }
+        dependencies = [
+            { name = "markupsafe" },
+        ]
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/30/9e/f663a2aa66a09d838042ae1a2c5659828bb9b41ea3a6efa20a20fd92b121/Jinja2-2.11.2-py2.py3-none-any.whl", hash = "sha256:f0a4641d3cf955324a89c04f3d94663aa4d638abe8f733ecd3582848e1c37035", size = 125776 },
+        ]
+
+        [[package]]
+        name = "markupsafe"
+        version = "2.1.5"
+        source = { registry = "https://pypi.org/simple" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/0c/40/2e73e7d532d030b1e41180807a80d564eda53babaf04d65e15c1cf897e40/MarkupSafe-2.1.5-py3-none-any.whl", hash = "sha256:0000000000000000000000000000000000000000000000000000000000000000", size = 1 },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "jinja2" },
+            { name = "pywin32", marker = "sys_platform == 'win32'" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "jinja2", specifier = "==2.11.2" },
+            { name = "pywin32", marker = "sys_platform == 'win32'", specifier = "==300" },
+        ]
+
+        [[package]]
+        name = "pywin32"
+        version = "300"
+        source = { registry = "https://pypi.org/simple" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/00/00/pywin32-300-cp312-cp312-win_amd64.whl", hash = "sha256:0000000000000000000000000000000000000000000000000000000000000000", size = 1 },
+        ]
+    "#})?;
+
+    let advisories = context.temp_dir.child("advisories");
+    advisories
+        .child("PYSEC-2021-66.json")
+        .write_str(indoc! {r#"
+        {
+            "id": "PYSEC-2021-66",
+            "aliases": ["CVE-2020-28493"],
+            "summary": "ReDoS in Jinja2",
+            "affected": [{
+                "package": {"ecosystem": "PyPI", "name": "jinja2"},
+                "ranges": [{"type": "ECOSYSTEM", "events": [{"introduced": "0"}, {"fixed": "2.11.3"}]}]
+            }],
+            "references": [{"type": "ADVISORY", "url": "https://nvd.nist.gov/vuln/detail/CVE-2020-28493"}]
+        }
+    "#})?;
+    advisories
+        .child("PYSEC-2021-90.json")
+        .write_str(indoc! {r#"
+        {
+            "id": "PYSEC-2021-90",
+            "summary": "Integer overflow in pywin32",
+            "affected": [{
+                "package": {"ecosystem": "PyPI", "name": "pywin32"},
+                "ranges": [{"type": "ECOSYSTEM", "events": [{"introduced": "0"}, {"fixed": "301"}]}]
+            }]
+        }
+    "#})?;
+
+    Ok(())
+}
+
+#[test]
+fn audit() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    write_project(&context)?;
+
+    uv_snapshot!(context.filters(), context.audit().arg("--database").arg("advisories"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    Found 2 known vulnerabilities in 3 packages:
+
+    jinja2 2.11.2
+      - PYSEC-2021-66 (CVE-2020-28493): ReDoS in Jinja2
+        Fixed in: 2.11.3
+        https://nvd.nist.gov/vuln/detail/CVE-2020-28493
+
+    pywin32 300 ; sys_platform == 'win32'
+      - PYSEC-2021-90: Integer overflow in pywin32
+        Fixed in: 301
+
+    ----- stderr -----
+    warning: `uv audit` is experimental and may change without warning. Pass `--preview-features audit` to disable this warning.
+    ");
+
+    Ok(())
+}
+
+/// Vulnerabilities in packages that are only installed on non-targeted environments are reported
+/// separately, and don't fail the audit.
+#[test]
+fn audit_environment() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    write_project(&context)?;
+
+    uv_snapshot!(context.filters(), context.audit()
+        .arg("--database").arg("advisories")
+        .arg("--ignore").arg("CVE-2020-28493")
+        .arg("--environment").arg("sys_platform == 'linux'"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    No known vulnerabilities found in 3 packages
+
+    Found 1 known vulnerability in packages that are not installed on the targeted environments:
+
+    pywin32 300 ; sys_platform == 'win32'
+      - PYSEC-2021-90: Integer overflow in pywin32
+        Fixed in: 301
+
+    Ignored 1 known vulnerability
+
+    ----- stderr -----
+    warning: `uv audit` is experimental and may change without warning. Pass `--preview-features audit` to disable this warning.
+    ");
+
+    Ok(())
+}
+
+/// Advisories can be ignored via `[tool.uv.audit]`.
+#[test]
+fn audit_ignore_settings() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    write_project(&context)?;
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["jinja2==2.11.2", "pywin32==300 ; sys_platform == 'win32'"]
+
+        [tool.uv.audit]
+        ignore = ["PYSEC-2021-66"]
+        ignore-package = ["pywin32"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.audit().arg("--database").arg("advisories"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    No known vulnerabilities found in 3 packages
+
+    Ignored 2 known vulnerabilities
+
+    ----- stderr -----
+    warning: `uv audit` is experimental and may change without warning. Pass `--preview-features audit` to disable this warning.
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn audit_json() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    write_project(&context)?;
+
+    uv_snapshot!(context.filters(), context.audit()
+        .arg("--database").arg("advisories")
+        .arg("--output-format").arg("json"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    {
+      "packages": 3,
+      "findings": [
+        {
+          "name": "jinja2",
+          "version": "2.11.2",
+          "reachable": true,
+          "advisory": {
+            "id": "PYSEC-2021-66",
+            "aliases": [
+              "CVE-2020-28493"
+            ],
+            "summary": "ReDoS in Jinja2",
+            "fixed-versions": [
+              "2.11.3"
+            ],
+            "url": "https://nvd.nist.gov/vuln/detail/CVE-2020-28493"
+          }
+        },
+        {
+          "name": "pywin32",
+          "version": "300",
+          "marker": "sys_platform == 'win32'",
+          "reachable": true,
+          "advisory": {
+            "id": "PYSEC-2021-90",
+            "aliases": [],
+            "summary": "Integer overflow in pywin32",
+            "fixed-versions": [
+              "301"
+            ]
+          }
+        }
+      ],
+      "ignored": []
+    }
+
+    ----- stderr -----
+    warning: `uv audit` is experimental and may change without warning. Pass `--preview-features audit` to disable this warning.
+    "#);
+
+    Ok(())
+}
+
+#[test]
+fn audit_sarif() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    write_project(&context)?;
+
+    uv_snapshot!(context.filters(), context.audit()
+        .arg("--database").arg("advisories")
+        .arg("--output-format").arg("sarif"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    {
+      "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+      "version": "2.1.0",
+      "runs": [
+        {
+          "tool": {
+            "driver": {
+              "name": "uv",
+              "informationUri": "https://docs.astral.sh/uv/",
+              "version": "0.9.26",
+              "rules": [
+                {
+                  "id": "PYSEC-2021-66",
+                  "shortDescription": {
+                    "text": "ReDoS in Jinja2"
+                  },
+                  "helpUri": "https://nvd.nist.gov/vuln/detail/CVE-2020-28493"
+                },
+                {
+                  "id": "PYSEC-2021-90",
+                  "shortDescription": {
+                    "text": "Integer overflow in pywin32"
+                  }
+                }
+              ]
+            }
+          },
+          "results": [
+            {
+              "ruleId": "PYSEC-2021-66",
+              "ruleIndex": 0,
+              "level": "error",
+              "message": {
+                "text": "jinja2 2.11.2 is affected by PYSEC-2021-66: ReDoS in Jinja2 (fixed in: 2.11.3)"
+              },
+              "locations": [
+                {
+                  "physicalLocation": {
+                    "artifactLocation": {
+                      "uri": "uv.lock"
+                    },
+                    "region": {
+                      "startLine": 5
+                    }
+                  }
+                }
+              ]
+            },
+            {
+              "ruleId": "PYSEC-2021-90",
+              "ruleIndex": 1,
+              "level": "error",
+              "message": {
+                "text": "pywin32 300 is affected by PYSEC-2021-90: Integer overflow in pywin32 (fixed in: 301) [installed when: sys_platform == 'win32']"
+              },
+              "locations": [
+                {
+                  "physicalLocation": {
+                    "artifactLocation": {
+                      "uri": "uv.lock"
+                    },
+                    "region": {
+                      "startLine": 39
+                    }
+                  }
+                }
+              ]
+            }
+          ]
+        }
+      ]
+    }
+
+    ----- stderr -----
+    warning: `uv audit` is experimental and may change without warning. Pass `--preview-features audit` to disable this warning.
+    "#);
+
+    Ok(())
+}
+
+#[test]
+fn audit_missing_lockfile() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    write_project(&context)?;
+    fs_err::remove_file(context.temp_dir.child("uv.lock"))?;
+
+    uv_snapshot!(context.filters(), context.audit().arg("--database").arg("advisories"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv audit` is experimental and may change without warning. Pass `--preview-features audit` to disable this warning.
+    error: Unable to find lockfile at `uv.lock`. To create a lockfile, run `uv lock`.
+    ");
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+#![cfg(unix)]
+
+use std::io::Cursor;
+
+use anyhow::Result;
+use assert_fs::prelude::*;
+use flate2::write::GzEncoder;
+use indoc::formatdoc;
+use sha2::{Digest, Sha256};
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Write a `tar.gz` archive containing a fake `ty` executable that echoes its arguments, along with
+/// a `.sha256` checksum file.
+fn write_fake_ty_archive(context: &TestContext) -> Result<url::Url> {
+    let archive = context.temp_dir.child("ty-0.0.1.tar.gz");
+    let script = "#!/bin/sh\necho \"fake ty: $*\"\n";
+    {
+        let file = fs_err::File::create(archive.path())?;
+        let enc = GzEncoder::new(file, flate2::Compression::default());
+        let mut tar = tar::Builder::new(enc);
+        let mut header = tar::Header::new_gnu();
+        header.set_size(script.len() as u64);
+        header.set_mode(0o755);
+        header.set_cksum();
+        tar.append_data(&mut header, "ty-0.0.1/bin/ty", Cursor::new(script))?;
+        tar.into_inner()?.finish()?;
+    }
+    let digest = Sha256::digest(fs_err::read(archive.path())?);
+    context
+        .temp_dir
+        .child("ty-0.0.1.tar.gz.sha256")
+        .write_str(&format!("{digest:x}  ty-0.0.1.tar.gz\n"))?;
+    Ok(url::Url::from_file_path(archive.path()).unwrap())
+}
+
+/// Install and run a binary that isn't built into uv.
+#[test]
+fn bin_custom() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    let url = write_fake_ty_archive(&context)?;
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&formatdoc! {r#"
+            [project]
+            name = "project"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = []
+
+            [tool.uv.binaries.ty]
+            version = "0.0.1"
+            url = "{url}"
+            executable = "{{name}}-{{version}}/bin/{{name}}"
+            checksum-url = "{url}.sha256"
+        "#,
+        })?;
+
+    uv_snapshot!(context.filters(), context.bin().arg("ty").arg("--").arg("check").arg("src"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    fake ty: check src
+
+    ----- stderr -----
+    warning: `uv bin` is experimental and may change without warning. Pass `--preview-features bin` to disable this warning.
+    ");
+
+    // The binary is resolved from the cache on subsequent runs, including without a project.
+    fs_err::remove_file(context.temp_dir.child("ty-0.0.1.tar.gz"))?;
+    uv_snapshot!(context.filters(), context.bin().arg("ty").arg("--no-project").arg("--version").arg("0.0.1").arg("--preview-features").arg("bin").arg("--").arg("--help"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    fake ty: --help
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// Binaries that aren't built into uv are never installed without a checksum.
+#[test]
+fn bin_custom_missing_checksum() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    let url = write_fake_ty_archive(&context)?;
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&formatdoc! {r#"
+            [project]
+            name = "project"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = []
+
+            [tool.uv.binaries.ty]
+            version = "0.0.1"
+            url = "{url}"
+            executable = "{{name}}-{{version}}/bin/{{name}}"
+            checksums = {{ riscv64-unknown-none = "0123abcd" }}
+        "#,
+        })?;
+
+    let filters: Vec<_> = [(r"on `[^`]+`", "on `[PLATFORM]`")]
+        .into_iter()
+        .chain(context.filters())
+        .collect();
+
+    uv_snapshot!(filters, context.bin().arg("ty").arg("--preview-features").arg("bin"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to install ty 0.0.1
+      Caused by: No checksum is defined for binary `ty` on `[PLATFORM]`; add one to `checksums` or provide a `checksum-url`
+    ");
+
+    Ok(())
+}
+
+/// Binaries must be defined before they can be run.
+#[test]
+fn bin_unknown() {
+    let context = TestContext::new_with_versions(&[]);
+
+    uv_snapshot!(context.filters(), context.bin().arg("ty").arg("--no-project").arg("--preview-features").arg("bin"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No binary named `ty` is defined
+    ");
+}
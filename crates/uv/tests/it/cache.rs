@@ -1,12 +1,17 @@
-#[cfg(unix)]
 use anyhow::Result;
-#[cfg(unix)]
+use assert_cmd::prelude::*;
 use assert_fs::prelude::*;
+use predicates::prelude::*;
 #[cfg(unix)]
 use std::process::Command;
 
+use uv_static::EnvVars;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
 #[cfg(unix)]
-use crate::common::{TestContext, get_bin, uv_snapshot};
+use crate::common::get_bin;
+use crate::common::{TestContext, uv_snapshot};
 
 /// When the cache directory cannot be created (e.g., due to permissions), we should show a
 /// chained error message that indicates we failed to initialize the cache.
@@ -68,3 +73,150 @@ fn cache_init_failure() -> Result<()> {
 
     Ok(())
 }
+
+/// Wheels built from source distributions should be published to, and fetched from, a remote
+/// build cache.
+#[test]
+fn remote_build_cache() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+    let remote = context.temp_dir.child("remote");
+
+    // Build the source distribution, publishing the wheel to the remote build cache.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("source-distribution==0.0.1")
+        .arg("--preview-features")
+        .arg("remote-build-cache")
+        .env(EnvVars::UV_REMOTE_BUILD_CACHE, remote.path())
+        .env(EnvVars::UV_REMOTE_BUILD_CACHE_PUBLISH, "1"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + source-distribution==0.0.1
+    ");
+
+    // The remote build cache should contain the wheel, along with a pointer to it.
+    let entries = fs_err::read_dir(remote.child("source-distribution").path())?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(entries.len(), 2);
+    assert!(entries.iter().any(|entry| entry.is_dir()));
+    assert!(
+        entries
+            .iter()
+            .any(|entry| entry.extension().is_some_and(|ext| ext == "json"))
+    );
+
+    // After clearing the local cache, the wheel should be fetched from the remote build cache
+    // rather than rebuilt.
+    context.clean().assert().success();
+    context.venv().arg("--clear").assert().success();
+    context
+        .pip_install()
+        .arg("source-distribution==0.0.1")
+        .arg("--preview-features")
+        .arg("remote-build-cache")
+        .arg("--verbose")
+        .env(EnvVars::UV_REMOTE_BUILD_CACHE, remote.path())
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Fetched `source-distribution==0.0.1` from remote build cache",
+        ))
+        .stderr(predicate::str::contains("Building: source-distribution==0.0.1").not());
+
+    Ok(())
+}
+
+/// Wheels built from source distributions should be published to, and fetched from, a remote
+/// build cache served over HTTP.
+#[tokio::test]
+async fn remote_build_cache_http() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    // Start with an empty remote build cache that accepts uploads.
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .respond_with(ResponseTemplate::new(201))
+        .mount(&server)
+        .await;
+
+    // On a miss, the source distribution should be built locally and published.
+    context
+        .pip_install()
+        .arg("source-distribution==0.0.1")
+        .arg("--preview-features")
+        .arg("remote-build-cache")
+        .arg("--verbose")
+        .env(EnvVars::UV_REMOTE_BUILD_CACHE, server.uri())
+        .env(EnvVars::UV_REMOTE_BUILD_CACHE_PUBLISH, "1")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "No entry for `source-distribution==0.0.1` in remote build cache",
+        ))
+        .stderr(predicate::str::contains(
+            "Published `source-distribution==0.0.1` to remote build cache",
+        ));
+
+    // The wheel should be uploaded before the pointer to it.
+    let uploads = server
+        .received_requests()
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|request| request.method.as_str() == "PUT")
+        .collect::<Vec<_>>();
+    assert_eq!(uploads.len(), 2);
+    let (wheel, pointer) = (&uploads[0], &uploads[1]);
+    assert_eq!(wheel.url.path().rsplit('.').next(), Some("whl"));
+    assert_eq!(pointer.url.path().rsplit('.').next(), Some("json"));
+    assert!(!wheel.body.is_empty());
+
+    // Serve the uploaded entry from the remote build cache.
+    server.reset().await;
+    for upload in [wheel, pointer] {
+        Mock::given(method("GET"))
+            .and(path(upload.url.path()))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(upload.body.clone()))
+            .mount(&server)
+            .await;
+    }
+
+    // After clearing the local cache, the wheel should be fetched from the remote build cache
+    // rather than rebuilt.
+    context.clean().assert().success();
+    context.venv().arg("--clear").assert().success();
+    context
+        .pip_install()
+        .arg("source-distribution==0.0.1")
+        .arg("--preview-features")
+        .arg("remote-build-cache")
+        .arg("--verbose")
+        .env(EnvVars::UV_REMOTE_BUILD_CACHE, server.uri())
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Fetched `source-distribution==0.0.1` from remote build cache",
+        ))
+        .stderr(predicate::str::contains("Building: source-distribution==0.0.1").not());
+
+    // Only the pointer and the wheel should have been requested.
+    let downloads = server.received_requests().await.unwrap();
+    assert_eq!(downloads.len(), 2);
+    assert!(
+        downloads
+            .iter()
+            .all(|request| request.method.as_str() == "GET")
+    );
+
+    Ok(())
+}
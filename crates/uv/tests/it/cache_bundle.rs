@@ -0,0 +1,99 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Export the cache entries for a lockfile, then import them into an empty cache and sync
+/// offline.
+#[test]
+fn cache_export_import() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0", "source-distribution==0.0.1"]
+
+        [tool.uv]
+        package = false
+        "#,
+    )?;
+
+    // Populate the cache.
+    context.sync().assert().success();
+
+    let filters: Vec<_> =
+        std::iter::once((r"Exported \d+ cache entries", "Exported [N] cache entries"))
+            .chain(std::iter::once((
+                r"Imported \d+ cache entries",
+                "Imported [N] cache entries",
+            )))
+            .chain(context.filters())
+            .collect();
+
+    uv_snapshot!(&filters, context.cache_export().arg("cache.tar.gz").arg("--preview-features").arg("cache-bundle"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Exported [N] cache entries ([SIZE]) to: cache.tar.gz
+    ");
+
+    // Clear the cache and the environment.
+    context.clean().assert().success();
+    context.venv().arg("--clear").assert().success();
+
+    uv_snapshot!(&filters, context.cache_import().arg("cache.tar.gz").arg("--preview-features").arg("cache-bundle"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Imported [N] cache entries ([SIZE]) into: [CACHE_DIR]/
+    ");
+
+    // Syncing should succeed without network access.
+    uv_snapshot!(&filters, context.sync().arg("--offline"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + source-distribution==0.0.1
+    ");
+
+    Ok(())
+}
+
+/// Importing a bundle without a manifest should fail.
+#[test]
+fn cache_import_missing_manifest() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let bundle = context.temp_dir.child("cache.tar.gz");
+    bundle.write_binary(&[
+        0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x03, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ])?;
+
+    uv_snapshot!(context.filters(), context.cache_import().arg("cache.tar.gz").arg("--preview-features").arg("cache-bundle"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to import cache from: cache.tar.gz
+      Caused by: Expected the bundle to start with a `manifest.json`
+    ");
+
+    Ok(())
+}
@@ -445,3 +445,252 @@ fn prune_stale_revision() -> Result<()> {
 
     Ok(())
 }
+
+/// `cache prune --keep-lock` should remove any entries that aren't required by the lockfile.
+#[test]
+fn prune_keep_lock() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [tool.uv]
+        package = false
+    "# })?;
+
+    // Populate the cache with the locked package, along with an unrelated package.
+    context.sync().assert().success();
+    context
+        .pip_install()
+        .arg("typing-extensions==4.10.0")
+        .assert()
+        .success();
+
+    let filters: Vec<_> = [(r"\d+ files", "[N] files")]
+        .into_iter()
+        .chain(context.filters())
+        .collect();
+
+    // A dry run should report the reclaimable size per bucket, without removing anything.
+    uv_snapshot!(&filters, context.prune().arg("--keep-lock").arg("uv.lock").arg("--dry-run").arg("--preview-features").arg("cache-prune-lock"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checking cache at: [CACHE_DIR]/
+    Would remove [N] files ([SIZE]) from `wheels-v5`
+    No unused entries found in `sdists-v9`
+    Would remove [N] files ([SIZE]) from `archive-v0`
+    Would remove [N] files ([SIZE]) in total
+    ");
+
+    uv_snapshot!(&filters, context.prune().arg("--keep-lock").arg(".").arg("--preview-features").arg("cache-prune-lock"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Removed [N] files ([SIZE])
+    ");
+
+    // The locked package should still be available offline.
+    context.venv().arg("--clear").assert().success();
+    uv_snapshot!(&filters, context.sync().arg("--offline"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // But the unrelated package should not.
+    uv_snapshot!(&filters, context.pip_install().arg("typing-extensions==4.10.0").arg("--offline"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because typing-extensions==4.10.0 needs to be downloaded from a registry and you require typing-extensions==4.10.0, we can conclude that your requirements are unsatisfiable.
+
+          hint: Packages were unavailable because the network was disabled. When the network is disabled, registry packages may only be read from the cache.
+    ");
+
+    Ok(())
+}
+
+/// `cache prune` should evict the least recently used unzipped wheels, per `cache-max-age`.
+#[test]
+fn prune_evict() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    // Populate the cache with two packages.
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("typing-extensions==4.10.0")
+        .assert()
+        .success();
+
+    // Mark every unzipped wheel as last used two days ago.
+    let two_days_ago = filetime::FileTime::from_unix_time(
+        filetime::FileTime::now().unix_seconds() - 2 * 24 * 60 * 60,
+        0,
+    );
+    for entry in fs_err::read_dir(context.cache_dir.child("archive-v0").path())? {
+        filetime::set_file_mtime(entry?.path(), two_days_ago)?;
+    }
+
+    // Reinstalling a package should record a use of its unzipped wheel.
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--reinstall")
+        .assert()
+        .success();
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(r#"cache-max-age = "1 day""#)?;
+
+    let filters: Vec<_> = [(r"\d+ files", "[N] files")]
+        .into_iter()
+        .chain(context.filters())
+        .collect();
+
+    uv_snapshot!(&filters, context.prune().arg("--config-file").arg("uv.toml"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Removed [N] files ([SIZE])
+    ");
+
+    // The recently used package should still be available offline.
+    context.venv().arg("--clear").assert().success();
+    uv_snapshot!(&filters, context.pip_install().arg("iniconfig==2.0.0").arg("--offline"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // But the evicted package should not.
+    uv_snapshot!(&filters, context.pip_install().arg("typing-extensions==4.10.0").arg("--offline"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to download `typing-extensions==4.10.0`
+      ╰─▶ Network connectivity is disabled, but the requested data wasn't found in the cache for: `https://files.pythonhosted.org/packages/f9/de/dc04a3ea60b22624b51c703a84bbe0184abcd1d0b9bc8074b5d6b7ab90bb/typing_extensions-4.10.0-py3-none-any.whl`
+    ");
+
+    Ok(())
+}
+
+/// Evicting unzipped wheels should not break environments that were installed from them.
+#[test]
+fn prune_evict_retained_environment() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--link-mode")
+        .arg("copy")
+        .assert()
+        .success();
+
+    // Mark every unzipped wheel as last used two days ago, as if the environment had been used
+    // without reinstalling.
+    let two_days_ago = filetime::FileTime::from_unix_time(
+        filetime::FileTime::now().unix_seconds() - 2 * 24 * 60 * 60,
+        0,
+    );
+    for entry in fs_err::read_dir(context.cache_dir.child("archive-v0").path())? {
+        filetime::set_file_mtime(entry?.path(), two_days_ago)?;
+    }
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(r#"cache-max-age = "1 day""#)?;
+
+    context
+        .prune()
+        .arg("--config-file")
+        .arg("uv.toml")
+        .assert()
+        .success();
+
+    // The unzipped wheel should be evicted...
+    assert_eq!(
+        fs_err::read_dir(context.cache_dir.child("archive-v0").path())?.count(),
+        0
+    );
+
+    // ...but the environment should keep working.
+    context.assert_command("import iniconfig").success();
+    context.assert_installed("iniconfig", "2.0.0");
+
+    Ok(())
+}
+
+/// Symlink-based installation should warn when combined with a cache eviction policy.
+#[test]
+fn evict_symlink_warning() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(r#"cache-max-age = "30 days""#)?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--link-mode")
+        .arg("symlink")
+        .arg("--config-file")
+        .arg("uv.toml"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    warning: Symlink-based installation is not recommended with `cache-max-size` or `cache-max-age`. Environments will be rendered unusable if the linked cache entries are evicted; use `--link-mode copy` or `--link-mode hardlink` instead.
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    Ok(())
+}
+
+/// `cache prune --keep-lock` should fail if no lockfile is found.
+#[test]
+fn prune_keep_lock_missing() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.prune().arg("--keep-lock").arg("projects/*").arg("--preview-features").arg("cache-prune-lock"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No lockfiles matched: `projects/*`
+    ");
+}
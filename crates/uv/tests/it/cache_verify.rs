@@ -0,0 +1,109 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Corrupted cache entries should be reported and removed individually, retaining the rest of the
+/// cache.
+#[test]
+fn cache_verify() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([
+            // The number of entries varies by operating system, so we filter it out.
+            (r"Verified \d+ cache entries", "Verified [N] cache entries"),
+            (r"\d+ files \(", "[N] files ("),
+            // The archive does not have a stable key, so we filter it out.
+            (r"`archive-v0[\\/][^`]+`", "`archive-v0/[ENTRY]`"),
+            (
+                r"archive `[^`]+` does not exist",
+                "archive `[ENTRY]` does not exist",
+            ),
+            (r"[\\/]pypi[\\/]iniconfig", "/pypi/iniconfig"),
+        ])
+        .collect();
+
+    // An intact cache should pass verification.
+    uv_snapshot!(&filters, context.cache_verify().arg("--preview-features").arg("cache-verify"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Verified [N] cache entries; no broken entries found
+    ");
+
+    // Modify a file in the unzipped wheel, and corrupt the cached Simple API response.
+    let archive = fs_err::read_dir(context.cache_dir.child("archive-v0").path())?
+        .next()
+        .unwrap()?
+        .path();
+    fs_err::write(archive.join("iniconfig").join("__init__.py"), "")?;
+    context
+        .cache_dir
+        .child("simple-v18")
+        .child("pypi")
+        .child("iniconfig.rkyv")
+        .write_str("garbage\n")?;
+
+    uv_snapshot!(&filters, context.cache_verify().arg("--dry-run").arg("--preview-features").arg("cache-verify"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Found broken entry: `archive-v0/[ENTRY]` (`iniconfig/__init__.py` does not match its recorded hash)
+    Found broken entry: `simple-v18/pypi/iniconfig.rkyv` (invalid HTTP cache policy: Reading from cache archive failed: invalid cache entry: data-with-cache-policy has cache policy length of 749118580988207463, but total buffer size is 8)
+    Found broken entry: `wheels-v5/pypi/iniconfig/2.0.0-py3-none-any.http` (archive `[ENTRY]` does not exist)
+    Found broken entry: `wheels-v5/pypi/iniconfig/2.0.0-py3-none-any` (link target does not exist)
+    Verified [N] cache entries; would remove 4 broken entries, [N] files ([SIZE])
+    ");
+
+    // Removing the archive should also remove the pointer and link to it.
+    uv_snapshot!(&filters, context.cache_verify().arg("--preview-features").arg("cache-verify"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Removed broken entry: `archive-v0/[ENTRY]` (`iniconfig/__init__.py` does not match its recorded hash)
+    Removed broken entry: `simple-v18/pypi/iniconfig.rkyv` (invalid HTTP cache policy: Reading from cache archive failed: invalid cache entry: data-with-cache-policy has cache policy length of 749118580988207463, but total buffer size is 8)
+    Removed broken entry: `wheels-v5/pypi/iniconfig/2.0.0-py3-none-any.http` (archive `[ENTRY]` does not exist)
+    Removed broken entry: `wheels-v5/pypi/iniconfig/2.0.0-py3-none-any` (link target does not exist)
+    Verified [N] cache entries; removed 4 broken entries, [N] files ([SIZE])
+    ");
+
+    // Reinstalling should succeed, re-fetching the removed entries.
+    context.venv().arg("--clear").assert().success();
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    uv_snapshot!(&filters, context.cache_verify().arg("--preview-features").arg("cache-verify"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verifying cache at: [CACHE_DIR]/
+    Verified [N] cache entries; no broken entries found
+    ");
+
+    Ok(())
+}
@@ -4332,6 +4332,198 @@ fn add_frozen() -> Result<()> {
     Ok(())
 }
 
+/// Add the third-party packages imported by the project's sources and notebooks.
+#[test]
+fn add_from_imports() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Remove the virtual environment.
+    fs_err::remove_dir_all(&context.venv)?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["requests"]
+    "#})?;
+
+    // Standard library, first-party, and declared imports should be ignored, `yaml` should be
+    // mapped to `PyYAML`, and `numpy` (which isn't in the module mapping) should be skipped.
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .write_str(indoc! {r#"
+            import os
+            import requests
+            import yaml
+            from project import utils
+            from . import helpers
+
+            def main():
+                """
+                import not_an_import
+                """
+                import numpy as np
+        "#})?;
+    context
+        .temp_dir
+        .child("analysis.ipynb")
+        .write_str(indoc! {r#"
+            {
+              "cells": [
+                {"cell_type": "markdown", "metadata": {}, "source": ["import not_an_import"]},
+                {"cell_type": "code", "metadata": {}, "source": ["%matplotlib inline\n", "import numpy\n", "from bs4 import BeautifulSoup"]}
+              ],
+              "metadata": {},
+              "nbformat": 4,
+              "nbformat_minor": 5
+            }
+        "#})?;
+
+    uv_snapshot!(context.filters(), context.add().arg("--from-imports").arg("--frozen"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Inferred 2 packages from imports: beautifulsoup4, pyyaml
+    warning: Skipping imports that could not be mapped to a package: `numpy`. If the packages of the same name are correct, add them with `uv add numpy`
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    ");
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "beautifulsoup4",
+            "pyyaml",
+            "requests",
+        ]
+        "#
+        );
+    });
+
+    Ok(())
+}
+
+/// Imports that aren't in the module mapping should be skipped, rather than added under the module
+/// name, which may belong to an unrelated package.
+#[test]
+fn add_from_imports_unmapped() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["rich"]
+    "#})?;
+
+    context.temp_dir.child("main.py").write_str(indoc! {r"
+        import rich
+        import reqeusts
+        from numpy import array
+    "})?;
+
+    uv_snapshot!(context.filters(), context.add().arg("--from-imports"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No undeclared third-party imports found
+    warning: Skipping imports that could not be mapped to a package: `numpy`, `reqeusts`. If the packages of the same name are correct, add them with `uv add numpy reqeusts`
+    ");
+
+    // The project should be unchanged.
+    assert_snapshot!(context.read("pyproject.toml"), @r#"
+    [project]
+    name = "project"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = ["rich"]
+    "#);
+
+    Ok(())
+}
+
+/// If every import is already declared, there's nothing to add.
+#[test]
+fn add_from_imports_declared() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["pyyaml>=6"]
+
+        [dependency-groups]
+        dev = ["pytest"]
+    "#})?;
+
+    context.temp_dir.child("main.py").write_str(indoc! {r"
+        import json
+        import yaml
+    "})?;
+    context
+        .temp_dir
+        .child("tests")
+        .child("test_main.py")
+        .write_str(indoc! {r"
+            import pytest
+            from main import load
+        "})?;
+
+    uv_snapshot!(context.filters(), context.add().arg("--from-imports"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No undeclared third-party imports found
+    ");
+
+    // The same applies to scripts, with dependencies declared in the inline metadata.
+    context.temp_dir.child("script.py").write_str(indoc! {r#"
+        # /// script
+        # requires-python = ">=3.12"
+        # dependencies = ["rich"]
+        # ///
+
+        import sys
+        from rich.pretty import pprint
+        import main
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add().arg("--from-imports").arg("--script").arg("script.py"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No undeclared third-party imports found
+    ");
+
+    Ok(())
+}
+
 /// Add a requirement without updating the environment.
 #[test]
 fn add_no_sync() -> Result<()> {
@@ -4490,7 +4490,7 @@ fn pep_751_infer_output_format() -> Result<()> {
 
     ----- stderr -----
     Resolved 4 packages in [TIME]
-    error: `pyproject.toml` is not a supported output format for `uv export` (supported formats: requirements.txt, pylock.toml, cyclonedx1.5)
+    error: `pyproject.toml` is not a supported output format for `uv export` (supported formats: requirements.txt, pylock.toml, cyclonedx1.5, cyclonedx1.6, spdx2.3, spdx3.0)
     ");
 
     Ok(())
@@ -8413,3 +8413,927 @@ fn cyclonedx_export_alternative_registry() -> Result<()> {
 
     Ok(())
 }
+
+/// Write a project with a handwritten lockfile for the SBOM export tests.
+fn write_sbom_project(context: &TestContext) -> Result<()> {
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["jinja2==2.11.2"]
+
+        [dependency-groups]
+        dev = ["iniconfig==2.0.0"]
+    "#})?;
+
+    context.temp_dir.child("uv.lock").write_str(indoc! {r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
+        ]
+
+        [[package]]
+        name = "jinja2"
+        version = "2.11.2"
+        source = { registry = "https://pypi.org/simple" }
+        dependencies = [
+            { name = "markupsafe" },
+        ]
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/30/9e/f663a2aa66a09d838042ae1a2c5659828bb9b41ea3a6efa20a20fd92b121/Jinja2-2.11.2-py2.py3-none-any.whl", hash = "sha256:f0a4641d3cf955324a89c04f3d94663aa4d638abe8f733ecd3582848e1c37035", size = 125776 },
+        ]
+
+        [[package]]
+        name = "markupsafe"
+        version = "2.1.5"
+        source = { registry = "https://pypi.org/simple" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/53/bd/583bf3e4c8d6a321938c13f49d44024dbe5ed63e0a7ba127e454a66da974/MarkupSafe-2.1.5-cp312-cp312-macosx_10_9_universal2.whl", hash = "sha256:8dec4936e9c3100156f8a2dc89c4b88d5c435175ff03413b443469c7c8c5f4d1", size = 18215 },
+            { url = "https://files.pythonhosted.org/packages/7c/52/2b1b570f6b8b803cef5ac28fdf78c0da318916c7d2fe9402a84d591b394c/MarkupSafe-2.1.5-cp312-cp312-manylinux_2_17_x86_64.manylinux2014_x86_64.whl", hash = "sha256:8e254ae696c88d98da6555f5ace2279cf7cd5b3f52be2b5cf97feafe883b58d2", size = 26406 },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "jinja2" },
+        ]
+
+        [package.dev-dependencies]
+        dev = [
+            { name = "iniconfig" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "jinja2", specifier = "==2.11.2" }]
+
+        [package.metadata.requires-dev]
+        dev = [{ name = "iniconfig", specifier = "==2.0.0" }]
+    "#})?;
+
+    Ok(())
+}
+
+/// Read the declared licenses of the locked packages from their wheels, even with `--frozen`.
+#[test]
+fn spdx2_3_export() -> Result<()> {
+    let context = TestContext::new("3.12").with_spdx_filters();
+    write_sbom_project(&context)?;
+
+    uv_snapshot!(context.filters(), context.export().arg("--frozen").arg("--format").arg("spdx2.3"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "spdxVersion": "SPDX-2.3",
+      "dataLicense": "CC0-1.0",
+      "SPDXID": "SPDXRef-DOCUMENT",
+      "name": "project",
+      "documentNamespace": "https://spdx.org/spdxdocs/project-[UUID]",
+      "creationInfo": {
+        "created": "[TIMESTAMP]",
+        "creators": [
+          "Organization: Astral Software Inc.",
+          "Tool: uv-[VERSION]"
+        ]
+      },
+      "packages": [
+        {
+          "name": "iniconfig",
+          "SPDXID": "SPDXRef-Package-iniconfig-2.0.0",
+          "versionInfo": "2.0.0",
+          "downloadLocation": "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz",
+          "filesAnalyzed": false,
+          "checksums": [
+            {
+              "algorithm": "SHA256",
+              "checksumValue": "2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3"
+            }
+          ],
+          "licenseConcluded": "NOASSERTION",
+          "licenseDeclared": "MIT",
+          "copyrightText": "NOASSERTION",
+          "externalRefs": [
+            {
+              "referenceCategory": "PACKAGE-MANAGER",
+              "referenceType": "purl",
+              "referenceLocator": "pkg:pypi/iniconfig@2.0.0"
+            }
+          ],
+          "primaryPackagePurpose": "LIBRARY"
+        },
+        {
+          "name": "jinja2",
+          "SPDXID": "SPDXRef-Package-jinja2-2.11.2",
+          "versionInfo": "2.11.2",
+          "downloadLocation": "https://files.pythonhosted.org/packages/30/9e/f663a2aa66a09d838042ae1a2c5659828bb9b41ea3a6efa20a20fd92b121/Jinja2-2.11.2-py2.py3-none-any.whl",
+          "filesAnalyzed": false,
+          "checksums": [
+            {
+              "algorithm": "SHA256",
+              "checksumValue": "f0a4641d3cf955324a89c04f3d94663aa4d638abe8f733ecd3582848e1c37035"
+            }
+          ],
+          "licenseConcluded": "NOASSERTION",
+          "licenseDeclared": "BSD-3-Clause",
+          "copyrightText": "NOASSERTION",
+          "externalRefs": [
+            {
+              "referenceCategory": "PACKAGE-MANAGER",
+              "referenceType": "purl",
+              "referenceLocator": "pkg:pypi/jinja2@2.11.2"
+            }
+          ],
+          "primaryPackagePurpose": "LIBRARY"
+        },
+        {
+          "name": "markupsafe",
+          "SPDXID": "SPDXRef-Package-markupsafe-2.1.5",
+          "versionInfo": "2.1.5",
+          "downloadLocation": "https://pypi.org/simple",
+          "filesAnalyzed": false,
+          "licenseConcluded": "NOASSERTION",
+          "licenseDeclared": "BSD-3-Clause",
+          "copyrightText": "NOASSERTION",
+          "externalRefs": [
+            {
+              "referenceCategory": "PACKAGE-MANAGER",
+              "referenceType": "purl",
+              "referenceLocator": "pkg:pypi/markupsafe@2.1.5"
+            }
+          ],
+          "primaryPackagePurpose": "LIBRARY"
+        },
+        {
+          "name": "project",
+          "SPDXID": "SPDXRef-Package-project-0.1.0",
+          "versionInfo": "0.1.0",
+          "downloadLocation": "NOASSERTION",
+          "filesAnalyzed": false,
+          "licenseConcluded": "NOASSERTION",
+          "licenseDeclared": "NOASSERTION",
+          "copyrightText": "NOASSERTION",
+          "primaryPackagePurpose": "LIBRARY"
+        }
+      ],
+      "relationships": [
+        {
+          "spdxElementId": "SPDXRef-DOCUMENT",
+          "relationshipType": "DESCRIBES",
+          "relatedSpdxElement": "SPDXRef-Package-project-0.1.0"
+        },
+        {
+          "spdxElementId": "SPDXRef-Package-jinja2-2.11.2",
+          "relationshipType": "DEPENDS_ON",
+          "relatedSpdxElement": "SPDXRef-Package-markupsafe-2.1.5"
+        },
+        {
+          "spdxElementId": "SPDXRef-Package-project-0.1.0",
+          "relationshipType": "DEPENDS_ON",
+          "relatedSpdxElement": "SPDXRef-Package-jinja2-2.11.2"
+        },
+        {
+          "spdxElementId": "SPDXRef-Package-iniconfig-2.0.0",
+          "relationshipType": "DEV_DEPENDENCY_OF",
+          "relatedSpdxElement": "SPDXRef-Package-project-0.1.0"
+        }
+      ]
+    }
+
+    ----- stderr -----
+    warning: `uv export --format=spdx2.3` is experimental and may change without warning. Pass `--preview-features sbom-export` to disable this warning.
+    "#);
+
+    Ok(())
+}
+
+/// Read the declared licenses of local packages from their `pyproject.toml`, reporting invalid
+/// SPDX expressions as `NOASSERTION`.
+#[test]
+fn spdx2_3_export_licenses() -> Result<()> {
+    let context = TestContext::new("3.12").with_spdx_filters();
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["mit", "classified", "invalid"]
+
+        [tool.uv.sources]
+        mit = { path = "mit" }
+        classified = { path = "classified" }
+        invalid = { path = "invalid" }
+    "#})?;
+
+    for (name, license) in [
+        ("mit", r#"license = "MIT""#),
+        (
+            "classified",
+            r#"classifiers = ["License :: OSI Approved :: Apache Software License"]"#,
+        ),
+        ("invalid", r#"license = "Proprietary, all rights reserved""#),
+    ] {
+        context
+            .temp_dir
+            .child(name)
+            .child("pyproject.toml")
+            .write_str(&formatdoc! {r#"
+            [project]
+            name = "{name}"
+            version = "1.0.0"
+            {license}
+
+            [build-system]
+            requires = ["uv_build>=0.7,<10000"]
+            build-backend = "uv_build"
+        "#})?;
+    }
+
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("spdx2.3"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "spdxVersion": "SPDX-2.3",
+      "dataLicense": "CC0-1.0",
+      "SPDXID": "SPDXRef-DOCUMENT",
+      "name": "project",
+      "documentNamespace": "https://spdx.org/spdxdocs/project-[UUID]",
+      "creationInfo": {
+        "created": "[TIMESTAMP]",
+        "creators": [
+          "Organization: Astral Software Inc.",
+          "Tool: uv-[VERSION]"
+        ]
+      },
+      "packages": [
+        {
+          "name": "classified",
+          "SPDXID": "SPDXRef-Package-classified-1.0.0",
+          "versionInfo": "1.0.0",
+          "downloadLocation": "NOASSERTION",
+          "filesAnalyzed": false,
+          "licenseConcluded": "NOASSERTION",
+          "licenseDeclared": "Apache-2.0",
+          "copyrightText": "NOASSERTION",
+          "primaryPackagePurpose": "LIBRARY"
+        },
+        {
+          "name": "invalid",
+          "SPDXID": "SPDXRef-Package-invalid-1.0.0",
+          "versionInfo": "1.0.0",
+          "downloadLocation": "NOASSERTION",
+          "filesAnalyzed": false,
+          "licenseConcluded": "NOASSERTION",
+          "licenseDeclared": "NOASSERTION",
+          "copyrightText": "NOASSERTION",
+          "primaryPackagePurpose": "LIBRARY"
+        },
+        {
+          "name": "mit",
+          "SPDXID": "SPDXRef-Package-mit-1.0.0",
+          "versionInfo": "1.0.0",
+          "downloadLocation": "NOASSERTION",
+          "filesAnalyzed": false,
+          "licenseConcluded": "NOASSERTION",
+          "licenseDeclared": "MIT",
+          "copyrightText": "NOASSERTION",
+          "primaryPackagePurpose": "LIBRARY"
+        },
+        {
+          "name": "project",
+          "SPDXID": "SPDXRef-Package-project-0.1.0",
+          "versionInfo": "0.1.0",
+          "downloadLocation": "NOASSERTION",
+          "filesAnalyzed": false,
+          "licenseConcluded": "NOASSERTION",
+          "licenseDeclared": "NOASSERTION",
+          "copyrightText": "NOASSERTION",
+          "primaryPackagePurpose": "LIBRARY"
+        }
+      ],
+      "relationships": [
+        {
+          "spdxElementId": "SPDXRef-DOCUMENT",
+          "relationshipType": "DESCRIBES",
+          "relatedSpdxElement": "SPDXRef-Package-project-0.1.0"
+        },
+        {
+          "spdxElementId": "SPDXRef-Package-project-0.1.0",
+          "relationshipType": "DEPENDS_ON",
+          "relatedSpdxElement": "SPDXRef-Package-classified-1.0.0"
+        },
+        {
+          "spdxElementId": "SPDXRef-Package-project-0.1.0",
+          "relationshipType": "DEPENDS_ON",
+          "relatedSpdxElement": "SPDXRef-Package-invalid-1.0.0"
+        },
+        {
+          "spdxElementId": "SPDXRef-Package-project-0.1.0",
+          "relationshipType": "DEPENDS_ON",
+          "relatedSpdxElement": "SPDXRef-Package-mit-1.0.0"
+        }
+      ]
+    }
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    warning: `uv export --format=spdx2.3` is experimental and may change without warning. Pass `--preview-features sbom-export` to disable this warning.
+    "#);
+
+    Ok(())
+}
+
+#[test]
+fn spdx3_0_export() -> Result<()> {
+    let context = TestContext::new("3.12").with_spdx_filters();
+    write_sbom_project(&context)?;
+
+    uv_snapshot!(context.filters(), context.export().arg("--frozen").arg("--format").arg("spdx3.0"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "@context": "https://spdx.org/rdf/3.0.1/spdx-context.jsonld",
+      "@graph": [
+        {
+          "type": "CreationInfo",
+          "@id": "_:creationinfo",
+          "specVersion": "3.0.1",
+          "created": "[TIMESTAMP]",
+          "createdBy": [
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Organization-Astral"
+          ],
+          "createdUsing": [
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Tool-uv"
+          ]
+        },
+        {
+          "type": "Organization",
+          "spdxId": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Organization-Astral",
+          "creationInfo": "_:creationinfo",
+          "name": "Astral Software Inc."
+        },
+        {
+          "type": "Tool",
+          "spdxId": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Tool-uv",
+          "creationInfo": "_:creationinfo",
+          "name": "uv [VERSION]"
+        },
+        {
+          "type": "simplelicensing_LicenseExpression",
+          "spdxId": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-License-CC0-1.0",
+          "creationInfo": "_:creationinfo",
+          "simplelicensing_licenseExpression": "CC0-1.0"
+        },
+        {
+          "type": "software_Package",
+          "spdxId": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-iniconfig-2.0.0",
+          "creationInfo": "_:creationinfo",
+          "name": "iniconfig",
+          "software_packageVersion": "2.0.0",
+          "software_downloadLocation": "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz",
+          "software_packageUrl": "pkg:pypi/iniconfig@2.0.0",
+          "verifiedUsing": [
+            {
+              "type": "Hash",
+              "algorithm": "sha256",
+              "hashValue": "2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3"
+            }
+          ],
+          "software_primaryPurpose": "library"
+        },
+        {
+          "type": "simplelicensing_LicenseExpression",
+          "spdxId": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-iniconfig-2.0.0-License",
+          "creationInfo": "_:creationinfo",
+          "simplelicensing_licenseExpression": "MIT"
+        },
+        {
+          "type": "Relationship",
+          "spdxId": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Relationship-1",
+          "creationInfo": "_:creationinfo",
+          "from": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-iniconfig-2.0.0",
+          "relationshipType": "hasDeclaredLicense",
+          "to": [
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-iniconfig-2.0.0-License"
+          ]
+        },
+        {
+          "type": "software_Package",
+          "spdxId": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-jinja2-2.11.2",
+          "creationInfo": "_:creationinfo",
+          "name": "jinja2",
+          "software_packageVersion": "2.11.2",
+          "software_downloadLocation": "https://files.pythonhosted.org/packages/30/9e/f663a2aa66a09d838042ae1a2c5659828bb9b41ea3a6efa20a20fd92b121/Jinja2-2.11.2-py2.py3-none-any.whl",
+          "software_packageUrl": "pkg:pypi/jinja2@2.11.2",
+          "verifiedUsing": [
+            {
+              "type": "Hash",
+              "algorithm": "sha256",
+              "hashValue": "f0a4641d3cf955324a89c04f3d94663aa4d638abe8f733ecd3582848e1c37035"
+            }
+          ],
+          "software_primaryPurpose": "library"
+        },
+        {
+          "type": "simplelicensing_LicenseExpression",
+          "spdxId": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-jinja2-2.11.2-License",
+          "creationInfo": "_:creationinfo",
+          "simplelicensing_licenseExpression": "BSD-3-Clause"
+        },
+        {
+          "type": "Relationship",
+          "spdxId": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Relationship-2",
+          "creationInfo": "_:creationinfo",
+          "from": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-jinja2-2.11.2",
+          "relationshipType": "hasDeclaredLicense",
+          "to": [
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-jinja2-2.11.2-License"
+          ]
+        },
+        {
+          "type": "software_Package",
+          "spdxId": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-markupsafe-2.1.5",
+          "creationInfo": "_:creationinfo",
+          "name": "markupsafe",
+          "software_packageVersion": "2.1.5",
+          "software_downloadLocation": "https://pypi.org/simple",
+          "software_packageUrl": "pkg:pypi/markupsafe@2.1.5",
+          "software_primaryPurpose": "library"
+        },
+        {
+          "type": "simplelicensing_LicenseExpression",
+          "spdxId": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-markupsafe-2.1.5-License",
+          "creationInfo": "_:creationinfo",
+          "simplelicensing_licenseExpression": "BSD-3-Clause"
+        },
+        {
+          "type": "Relationship",
+          "spdxId": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Relationship-3",
+          "creationInfo": "_:creationinfo",
+          "from": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-markupsafe-2.1.5",
+          "relationshipType": "hasDeclaredLicense",
+          "to": [
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-markupsafe-2.1.5-License"
+          ]
+        },
+        {
+          "type": "software_Package",
+          "spdxId": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-project-0.1.0",
+          "creationInfo": "_:creationinfo",
+          "name": "project",
+          "software_packageVersion": "0.1.0",
+          "software_primaryPurpose": "library"
+        },
+        {
+          "type": "Relationship",
+          "spdxId": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Relationship-4",
+          "creationInfo": "_:creationinfo",
+          "from": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-jinja2-2.11.2",
+          "relationshipType": "dependsOn",
+          "to": [
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-markupsafe-2.1.5"
+          ]
+        },
+        {
+          "type": "Relationship",
+          "spdxId": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Relationship-5",
+          "creationInfo": "_:creationinfo",
+          "from": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-project-0.1.0",
+          "relationshipType": "dependsOn",
+          "to": [
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-jinja2-2.11.2"
+          ]
+        },
+        {
+          "type": "LifecycleScopedRelationship",
+          "spdxId": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Relationship-6",
+          "creationInfo": "_:creationinfo",
+          "from": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-project-0.1.0",
+          "relationshipType": "dependsOn",
+          "to": [
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-iniconfig-2.0.0"
+          ],
+          "scope": "development"
+        },
+        {
+          "type": "software_Sbom",
+          "spdxId": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Sbom",
+          "creationInfo": "_:creationinfo",
+          "software_sbomType": [
+            "build"
+          ],
+          "rootElement": [
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-project-0.1.0"
+          ],
+          "element": [
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-iniconfig-2.0.0",
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-iniconfig-2.0.0-License",
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Relationship-1",
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-jinja2-2.11.2",
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-jinja2-2.11.2-License",
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Relationship-2",
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-markupsafe-2.1.5",
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-markupsafe-2.1.5-License",
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Relationship-3",
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Package-project-0.1.0",
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Relationship-4",
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Relationship-5",
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Relationship-6"
+          ]
+        },
+        {
+          "type": "SpdxDocument",
+          "spdxId": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-DOCUMENT",
+          "creationInfo": "_:creationinfo",
+          "name": "project",
+          "dataLicense": "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-License-CC0-1.0",
+          "rootElement": [
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Sbom"
+          ],
+          "element": [
+            "https://spdx.org/spdxdocs/project-[UUID]#SPDXRef-Sbom"
+          ]
+        }
+      ]
+    }
+
+    ----- stderr -----
+    warning: `uv export --format=spdx3.0` is experimental and may change without warning. Pass `--preview-features sbom-export` to disable this warning.
+    "#);
+
+    Ok(())
+}
+
+/// Include the declared license of each locked package as a component license, even with
+/// `--frozen`.
+#[test]
+fn cyclonedx1_6_export() -> Result<()> {
+    let context = TestContext::new("3.12").with_cyclonedx_filters();
+    write_sbom_project(&context)?;
+
+    uv_snapshot!(context.filters(), context.export().arg("--frozen").arg("--format").arg("cyclonedx1.6"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "bomFormat": "CycloneDX",
+      "specVersion": "1.6",
+      "version": 1,
+      "serialNumber": "[SERIAL_NUMBER]",
+      "metadata": {
+        "timestamp": "[TIMESTAMP]",
+        "tools": [
+          {
+            "vendor": "Astral Software Inc.",
+            "name": "uv",
+            "version": "[VERSION]"
+          }
+        ],
+        "component": {
+          "type": "library",
+          "bom-ref": "project-1@0.1.0",
+          "name": "project",
+          "version": "0.1.0"
+        }
+      },
+      "components": [
+        {
+          "type": "library",
+          "bom-ref": "iniconfig-2@2.0.0",
+          "name": "iniconfig",
+          "version": "2.0.0",
+          "licenses": [
+            {
+              "expression": "MIT",
+              "acknowledgement": "declared"
+            }
+          ],
+          "purl": "pkg:pypi/iniconfig@2.0.0",
+          "components": [
+            {
+              "type": "file",
+              "bom-ref": "iniconfig-2.0.0.tar.gz-3",
+              "name": "iniconfig-2.0.0.tar.gz",
+              "version": "2.0.0",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3"
+                }
+              ],
+              "purl": "pkg:pypi/iniconfig@2.0.0?file_name=iniconfig-2.0.0.tar.gz",
+              "externalReferences": [
+                {
+                  "type": "distribution",
+                  "url": "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz"
+                }
+              ],
+              "properties": [
+                {
+                  "name": "uv:artifact:type",
+                  "value": "sdist"
+                }
+              ],
+              "evidence": {
+                "identity": [
+                  {
+                    "field": "hash",
+                    "confidence": 1.0,
+                    "methods": [
+                      {
+                        "technique": "manifest-analysis",
+                        "confidence": 1.0,
+                        "value": "uv.lock"
+                      }
+                    ]
+                  }
+                ]
+              }
+            },
+            {
+              "type": "file",
+              "bom-ref": "iniconfig-2.0.0-py3-none-any.whl-4",
+              "name": "iniconfig-2.0.0-py3-none-any.whl",
+              "version": "2.0.0",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374"
+                }
+              ],
+              "purl": "pkg:pypi/iniconfig@2.0.0?file_name=iniconfig-2.0.0-py3-none-any.whl",
+              "externalReferences": [
+                {
+                  "type": "distribution",
+                  "url": "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl"
+                }
+              ],
+              "properties": [
+                {
+                  "name": "uv:artifact:type",
+                  "value": "wheel"
+                }
+              ],
+              "evidence": {
+                "identity": [
+                  {
+                    "field": "hash",
+                    "confidence": 1.0,
+                    "methods": [
+                      {
+                        "technique": "manifest-analysis",
+                        "confidence": 1.0,
+                        "value": "uv.lock"
+                      }
+                    ]
+                  }
+                ]
+              }
+            }
+          ],
+          "evidence": {
+            "identity": [
+              {
+                "field": "purl",
+                "confidence": 1.0,
+                "methods": [
+                  {
+                    "technique": "manifest-analysis",
+                    "confidence": 1.0,
+                    "value": "uv.lock"
+                  }
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "type": "library",
+          "bom-ref": "jinja2-5@2.11.2",
+          "name": "jinja2",
+          "version": "2.11.2",
+          "licenses": [
+            {
+              "expression": "BSD-3-Clause",
+              "acknowledgement": "declared"
+            }
+          ],
+          "purl": "pkg:pypi/jinja2@2.11.2",
+          "components": [
+            {
+              "type": "file",
+              "bom-ref": "Jinja2-2.11.2-py2.py3-none-any.whl-6",
+              "name": "Jinja2-2.11.2-py2.py3-none-any.whl",
+              "version": "2.11.2",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "f0a4641d3cf955324a89c04f3d94663aa4d638abe8f733ecd3582848e1c37035"
+                }
+              ],
+              "purl": "pkg:pypi/jinja2@2.11.2?file_name=Jinja2-2.11.2-py2.py3-none-any.whl",
+              "externalReferences": [
+                {
+                  "type": "distribution",
+                  "url": "https://files.pythonhosted.org/packages/30/9e/f663a2aa66a09d838042ae1a2c5659828bb9b41ea3a6efa20a20fd92b121/Jinja2-2.11.2-py2.py3-none-any.whl"
+                }
+              ],
+              "properties": [
+                {
+                  "name": "uv:artifact:type",
+                  "value": "wheel"
+                }
+              ],
+              "evidence": {
+                "identity": [
+                  {
+                    "field": "hash",
+                    "confidence": 1.0,
+                    "methods": [
+                      {
+                        "technique": "manifest-analysis",
+                        "confidence": 1.0,
+                        "value": "uv.lock"
+                      }
+                    ]
+                  }
+                ]
+              }
+            }
+          ],
+          "evidence": {
+            "identity": [
+              {
+                "field": "purl",
+                "confidence": 1.0,
+                "methods": [
+                  {
+                    "technique": "manifest-analysis",
+                    "confidence": 1.0,
+                    "value": "uv.lock"
+                  }
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "type": "library",
+          "bom-ref": "markupsafe-7@2.1.5",
+          "name": "markupsafe",
+          "version": "2.1.5",
+          "licenses": [
+            {
+              "expression": "BSD-3-Clause",
+              "acknowledgement": "declared"
+            }
+          ],
+          "purl": "pkg:pypi/markupsafe@2.1.5",
+          "components": [
+            {
+              "type": "file",
+              "bom-ref": "MarkupSafe-2.1.5-cp312-cp312-macosx_10_9_universal2.whl-8",
+              "name": "MarkupSafe-2.1.5-cp312-cp312-macosx_10_9_universal2.whl",
+              "version": "2.1.5",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "8dec4936e9c3100156f8a2dc89c4b88d5c435175ff03413b443469c7c8c5f4d1"
+                }
+              ],
+              "purl": "pkg:pypi/markupsafe@2.1.5?file_name=MarkupSafe-2.1.5-cp312-cp312-macosx_10_9_universal2.whl",
+              "externalReferences": [
+                {
+                  "type": "distribution",
+                  "url": "https://files.pythonhosted.org/packages/53/bd/583bf3e4c8d6a321938c13f49d44024dbe5ed63e0a7ba127e454a66da974/MarkupSafe-2.1.5-cp312-cp312-macosx_10_9_universal2.whl"
+                }
+              ],
+              "properties": [
+                {
+                  "name": "uv:artifact:type",
+                  "value": "wheel"
+                }
+              ],
+              "evidence": {
+                "identity": [
+                  {
+                    "field": "hash",
+                    "confidence": 1.0,
+                    "methods": [
+                      {
+                        "technique": "manifest-analysis",
+                        "confidence": 1.0,
+                        "value": "uv.lock"
+                      }
+                    ]
+                  }
+                ]
+              }
+            },
+            {
+              "type": "file",
+              "bom-ref": "MarkupSafe-2.1.5-cp312-cp312-manylinux_2_17_x86_64.manylinux2014_x86_64.whl-9",
+              "name": "MarkupSafe-2.1.5-cp312-cp312-manylinux_2_17_x86_64.manylinux2014_x86_64.whl",
+              "version": "2.1.5",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "8e254ae696c88d98da6555f5ace2279cf7cd5b3f52be2b5cf97feafe883b58d2"
+                }
+              ],
+              "purl": "pkg:pypi/markupsafe@2.1.5?file_name=MarkupSafe-2.1.5-cp312-cp312-manylinux_2_17_x86_64.manylinux2014_x86_64.whl",
+              "externalReferences": [
+                {
+                  "type": "distribution",
+                  "url": "https://files.pythonhosted.org/packages/7c/52/2b1b570f6b8b803cef5ac28fdf78c0da318916c7d2fe9402a84d591b394c/MarkupSafe-2.1.5-cp312-cp312-manylinux_2_17_x86_64.manylinux2014_x86_64.whl"
+                }
+              ],
+              "properties": [
+                {
+                  "name": "uv:artifact:type",
+                  "value": "wheel"
+                }
+              ],
+              "evidence": {
+                "identity": [
+                  {
+                    "field": "hash",
+                    "confidence": 1.0,
+                    "methods": [
+                      {
+                        "technique": "manifest-analysis",
+                        "confidence": 1.0,
+                        "value": "uv.lock"
+                      }
+                    ]
+                  }
+                ]
+              }
+            }
+          ],
+          "evidence": {
+            "identity": [
+              {
+                "field": "purl",
+                "confidence": 1.0,
+                "methods": [
+                  {
+                    "technique": "manifest-analysis",
+                    "confidence": 1.0,
+                    "value": "uv.lock"
+                  }
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "dependencies": [
+        {
+          "ref": "iniconfig-2@2.0.0",
+          "dependsOn": []
+        },
+        {
+          "ref": "jinja2-5@2.11.2",
+          "dependsOn": [
+            "markupsafe-7@2.1.5"
+          ]
+        },
+        {
+          "ref": "markupsafe-7@2.1.5",
+          "dependsOn": []
+        },
+        {
+          "ref": "project-1@0.1.0",
+          "dependsOn": [
+            "iniconfig-2@2.0.0",
+            "jinja2-5@2.11.2"
+          ]
+        }
+      ]
+    }
+    ----- stderr -----
+    warning: `uv export --format=cyclonedx1.6` is experimental and may change without warning. Pass `--preview-features sbom-export` to disable this warning.
+    "#);
+
+    Ok(())
+}
@@ -1,8 +1,10 @@
 use anyhow::Result;
 use assert_fs::prelude::*;
-use indoc::indoc;
+use indoc::{formatdoc, indoc};
 use insta::assert_snapshot;
 
+#[cfg(unix)]
+use crate::common::write_fake_ruff_archive;
 use crate::common::{TestContext, uv_snapshot};
 
 #[test]
@@ -489,3 +491,111 @@ fn format_version_option() -> Result<()> {
 
     Ok(())
 }
+
+/// Install Ruff from a local mirror, using a custom binary definition.
+#[test]
+#[cfg(unix)]
+fn format_binary_local_mirror() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    // Populate a local mirror, laid out as `<mirror>/<name>/<version>/<filename>`.
+    let mirror = context.temp_dir.child("mirror");
+    let release = mirror.child("ruff").child("0.0.1");
+    release.create_dir_all()?;
+    let digest = write_fake_ruff_archive(release.child("ruff-0.0.1.tar.gz").path())?;
+    release
+        .child("ruff-0.0.1.tar.gz.sha256")
+        .write_str(&format!("{digest}  ruff-0.0.1.tar.gz\n"))?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        binary-install-mirror = "{mirror}"
+
+        [tool.uv.binaries.ruff]
+        version = "0.0.1"
+        url = "https://example.com/ruff-{{version}}.tar.gz"
+        executable = "ruff-{{version}}/ruff"
+        checksum-url = "https://example.com/ruff-{{version}}.tar.gz.sha256"
+    "#,
+        mirror = mirror.path().display(),
+    })?;
+
+    uv_snapshot!(context.filters(), context.format().arg("--offline"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    fake ruff: format
+
+    ----- stderr -----
+    warning: `uv format` is experimental and may change without warning. Pass `--preview-features format` to disable this warning.
+    ");
+
+    Ok(())
+}
+
+/// Reject an archive that doesn't match its expected checksum.
+#[test]
+#[cfg(unix)]
+fn format_binary_hash_mismatch() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    let archive = context.temp_dir.child("ruff-0.0.1.tar.gz");
+    write_fake_ruff_archive(archive.path())?;
+    context
+        .temp_dir
+        .child("ruff-0.0.1.tar.gz.sha256")
+        .write_str(&"0".repeat(64))?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.binaries.ruff]
+        version = "0.0.1"
+        url = "{url}"
+        executable = "ruff-{{version}}/ruff"
+        checksum-url = "{url}.sha256"
+    "#,
+        url = url::Url::from_file_path(archive.path()).unwrap(),
+    })?;
+
+    // The digest of the archive depends on the compression backend.
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(
+            r"Computed:\n  sha256:[0-9a-f]{64}",
+            "Computed:\n  sha256:[DIGEST]",
+        )])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.format(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv format` is experimental and may change without warning. Pass `--preview-features format` to disable this warning.
+    error: Failed to install ruff 0.0.1
+      Caused by: Hash mismatch for `file://[TEMP_DIR]/ruff-0.0.1.tar.gz`
+
+    Expected:
+      sha256:0000000000000000000000000000000000000000000000000000000000000000
+
+    Computed:
+      sha256:[DIGEST]
+    ");
+
+    Ok(())
+}
@@ -21,18 +21,23 @@ fn help() {
       init                       Create a new project
       add                        Add dependencies to the project
       remove                     Remove dependencies from the project
+      upgrade                    Raise the lower bounds of the project's requirements
       version                    Read or update the project's version
       sync                       Update the project's environment
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
+      audit                      Audit the project's lockfile for known vulnerabilities
       format                     Format Python code in the project
+      lint                       Lint Python code in the project
+      bin                        Run a binary tool defined in the `binaries` setting
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      index                      Manage package indexes
       cache                      Manage uv's cache
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
@@ -102,18 +107,23 @@ fn help_flag() {
       init     Create a new project
       add      Add dependencies to the project
       remove   Remove dependencies from the project
+      upgrade  Raise the lower bounds of the project's requirements
       version  Read or update the project's version
       sync     Update the project's environment
       lock     Update the project's lockfile
       export   Export the project's lockfile to an alternate format
       tree     Display the project's dependency tree
+      audit    Audit the project's lockfile for known vulnerabilities
       format   Format Python code in the project
+      lint     Lint Python code in the project
+      bin      Run a binary tool defined in the `binaries` setting
       tool     Run and install commands provided by Python packages
       python   Manage Python versions and installations
       pip      Manage Python packages with a pip-compatible interface
       venv     Create a virtual environment
       build    Build Python packages into source distributions and wheels
       publish  Upload distributions to an index
+      index    Manage package indexes
       cache    Manage uv's cache
       self     Manage the uv executable
       help     Display documentation for a command
@@ -181,18 +191,23 @@ fn help_short_flag() {
       init     Create a new project
       add      Add dependencies to the project
       remove   Remove dependencies from the project
+      upgrade  Raise the lower bounds of the project's requirements
       version  Read or update the project's version
       sync     Update the project's environment
       lock     Update the project's lockfile
       export   Export the project's lockfile to an alternate format
       tree     Display the project's dependency tree
+      audit    Audit the project's lockfile for known vulnerabilities
       format   Format Python code in the project
+      lint     Lint Python code in the project
+      bin      Run a binary tool defined in the `binaries` setting
       tool     Run and install commands provided by Python packages
       python   Manage Python versions and installations
       pip      Manage Python packages with a pip-compatible interface
       venv     Create a virtual environment
       build    Build Python packages into source distributions and wheels
       publish  Upload distributions to an index
+      index    Manage package indexes
       cache    Manage uv's cache
       self     Manage the uv executable
       help     Display documentation for a command
@@ -902,18 +917,23 @@ fn help_unknown_subcommand() {
         init
         add
         remove
+        upgrade
         version
         sync
         lock
         export
         tree
+        audit
         format
+        lint
+        bin
         tool
         python
         pip
         venv
         build
         publish
+        index
         cache
         self
         generate-shell-completion
@@ -931,18 +951,23 @@ fn help_unknown_subcommand() {
         init
         add
         remove
+        upgrade
         version
         sync
         lock
         export
         tree
+        audit
         format
+        lint
+        bin
         tool
         python
         pip
         venv
         build
         publish
+        index
         cache
         self
         generate-shell-completion
@@ -989,18 +1014,23 @@ fn help_with_global_option() {
       init                       Create a new project
       add                        Add dependencies to the project
       remove                     Remove dependencies from the project
+      upgrade                    Raise the lower bounds of the project's requirements
       version                    Read or update the project's version
       sync                       Update the project's environment
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
+      audit                      Audit the project's lockfile for known vulnerabilities
       format                     Format Python code in the project
+      lint                       Lint Python code in the project
+      bin                        Run a binary tool defined in the `binaries` setting
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      index                      Manage package indexes
       cache                      Manage uv's cache
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
@@ -1111,18 +1141,23 @@ fn help_with_no_pager() {
       init                       Create a new project
       add                        Add dependencies to the project
       remove                     Remove dependencies from the project
+      upgrade                    Raise the lower bounds of the project's requirements
       version                    Read or update the project's version
       sync                       Update the project's environment
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
+      audit                      Audit the project's lockfile for known vulnerabilities
       format                     Format Python code in the project
+      lint                       Lint Python code in the project
+      bin                        Run a binary tool defined in the `binaries` setting
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      index                      Manage package indexes
       cache                      Manage uv's cache
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
@@ -0,0 +1,98 @@
+#![cfg(unix)]
+
+use anyhow::Result;
+use assert_fs::prelude::*;
+use indoc::formatdoc;
+
+use crate::common::{TestContext, uv_snapshot, write_fake_ruff_archive};
+
+/// Write a `pyproject.toml` that installs a fake Ruff from a local archive.
+fn write_pyproject_toml(context: &TestContext) -> Result<()> {
+    let archive = context.temp_dir.child("ruff-0.0.1.tar.gz");
+    write_fake_ruff_archive(archive.path())?;
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&formatdoc! {r#"
+            [project]
+            name = "project"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = []
+
+            [tool.uv.binaries.ruff]
+            version = "0.0.1"
+            url = "{url}"
+            executable = "ruff-{{version}}/ruff"
+        "#,
+            url = url::Url::from_file_path(archive.path()).unwrap(),
+        })?;
+
+    Ok(())
+}
+
+#[test]
+fn lint_project() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    write_pyproject_toml(&context)?;
+
+    uv_snapshot!(context.filters(), context.lint(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    fake ruff: check
+
+    ----- stderr -----
+    warning: `uv lint` is experimental and may change without warning. Pass `--preview-features lint` to disable this warning.
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn lint_fix() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    write_pyproject_toml(&context)?;
+
+    uv_snapshot!(context.filters(), context.lint().arg("--fix"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    fake ruff: check --fix
+
+    ----- stderr -----
+    warning: `uv lint` is experimental and may change without warning. Pass `--preview-features lint` to disable this warning.
+    ");
+
+    // `--diff` is forwarded alongside any additional arguments.
+    uv_snapshot!(context.filters(), context.lint().arg("--diff").arg("--").arg("--select").arg("I"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    fake ruff: check --diff --select I
+
+    ----- stderr -----
+    warning: `uv lint` is experimental and may change without warning. Pass `--preview-features lint` to disable this warning.
+    ");
+
+    Ok(())
+}
+
+/// Lint without a project, using the `--version` flag to select the Ruff version.
+#[test]
+fn lint_no_project() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    write_pyproject_toml(&context)?;
+
+    uv_snapshot!(context.filters(), context.lint().arg("--no-project").arg("--version").arg("0.0.1").arg("--preview-features").arg("lint"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    fake ruff: check
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
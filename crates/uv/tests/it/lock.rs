@@ -4075,7 +4075,7 @@ fn lock_conflicting_mixed() -> Result<()> {
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @"
+    uv_snapshot!(context.filters(), context.lock(), @r#"
     success: false
     exit_code: 1
     ----- stdout -----
@@ -4084,7 +4084,18 @@ fn lock_conflicting_mixed() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because project:project1 depends on sortedcontainers==2.3.0 and project[project2] depends on sortedcontainers==2.4.0, we can conclude that project:project1 and project[project2] are incompatible.
           And because your project requires project[project2] and project:project1, we can conclude that your project's requirements are unsatisfiable.
-    ");
+      help: The project can be locked if `project[project2]` and `project:project1` are resolved separately. To do so, declare them as conflicting in `pyproject.toml`:
+
+            [tool.uv]
+            conflicts = [
+                [
+                    { extra = "project2" },
+                    { group = "project1" },
+                ],
+            ]
+
+            Or, use `uv lock --auto-conflicts` to declare them automatically.
+    "#);
 
     // And now with the same extra/group configuration, we tell uv
     // about the conflicting groups, which forces it to resolve each in
@@ -7148,6 +7159,129 @@ fn lock_requires_python_fewest_versions() -> Result<()> {
     Ok(())
 }
 
+/// Under `fork-strategy = "single"`, a package that diverges across environments is an error, and
+/// the resolver selects a single version rather than forking on `requires-python`.
+#[test]
+fn lock_fork_strategy_single() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "iniconfig==1.1.1 ; sys_platform == 'win32'",
+            "iniconfig==2.0.0 ; sys_platform != 'win32'",
+        ]
+
+        [tool.uv]
+        fork-strategy = "single"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The `single` fork strategy requires a single version of each package, but the requirements diverge across environments:
+      - `iniconfig`:
+        - `1.1.1` for `sys_platform == 'win32'`
+        - `2.0.0` for `sys_platform != 'win32'`
+    ");
+
+    // Without the divergent pins, the resolution succeeds.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio"]
+
+        [tool.uv]
+        fork-strategy = "single"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    // Where the default strategy would fork on `requires-python` (`iniconfig==2.0.0` requires
+    // Python 3.7 or later), a single version is selected for the entire range.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.6"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        fork-strategy = "single"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.6"
+
+        [options]
+        fork-strategy = "single"
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "iniconfig"
+        version = "1.1.1"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/23/a2/97899f6bd0e873fed3a7e67ae8d3a08b21799430fb4da15cfedf10d6e2c2/iniconfig-1.1.1.tar.gz", hash = "sha256:bc3af051d7d14b2ee5ef9969666def0cd1a000e121eaea580d4a313df4b37f32", size = 8104, upload-time = "2020-10-14T10:20:18.572Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/9b/dd/b3c12c6d707058fa947864b67f0c4e0c39ef8610988d7baea9578f3c48f3/iniconfig-1.1.1-py2.py3-none-any.whl", hash = "sha256:011e24c64b7f47f6ebd835bb12a743f2fbe9a26d4cecaa7f53bc4f35ee9da8b3", size = 4990, upload-time = "2020-10-16T17:37:23.05Z" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "iniconfig" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "iniconfig" }]
+        "#
+        );
+    });
+
+    Ok(())
+}
+
 /// Ensure that `python_version >= '3.10' or python_version < '3.10'` is correctly collapsed to
 /// the full version range. This is _not_ the case under standard PEP 440 semantics, but Python
 /// requirements are evaluated using release-only semantics.
@@ -33329,3 +33463,1393 @@ fn lock_check_multiple_default_indexes_explicit_assignment_dependency_group() ->
 
     Ok(())
 }
+
+/// Write a project alongside two versions of its lockfile: `base.lock`, and the `uv.lock` in the
+/// working tree.
+fn write_lock_diff_project(context: &TestContext) -> Result<()> {
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio", "numpy"]
+    "#})?;
+
+    context.temp_dir.child("base.lock").write_str(indoc! {r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+        resolution-markers = [
+            "sys_platform == 'win32'",
+            "sys_platform != 'win32'",
+        ]
+
+        [[package]]
+        name = "anyio"
+        version = "4.2.0"
+        source = { registry = "https://pypi.org/simple" }
+        dependencies = [
+            { name = "idna" },
+            { name = "sniffio" },
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/anyio-4.2.0.tar.gz", hash = "sha256:1111111111111111111111111111111111111111111111111111111111111111", size = 1 }
+
+        [[package]]
+        name = "idna"
+        version = "3.6"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/idna-3.6.tar.gz", hash = "sha256:2222222222222222222222222222222222222222222222222222222222222222", size = 1 }
+
+        [[package]]
+        name = "numpy"
+        version = "1.26.4"
+        source = { registry = "https://pypi.org/simple" }
+        resolution-markers = [
+            "sys_platform == 'win32'",
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/numpy-1.26.4.tar.gz", hash = "sha256:3333333333333333333333333333333333333333333333333333333333333333", size = 1 }
+
+        [[package]]
+        name = "numpy"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        resolution-markers = [
+            "sys_platform != 'win32'",
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/numpy-2.0.0.tar.gz", hash = "sha256:4444444444444444444444444444444444444444444444444444444444444444", size = 1 }
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "anyio" },
+            { name = "numpy", version = "1.26.4", source = { registry = "https://pypi.org/simple" }, marker = "sys_platform == 'win32'" },
+            { name = "numpy", version = "2.0.0", source = { registry = "https://pypi.org/simple" }, marker = "sys_platform != 'win32'" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "anyio" },
+            { name = "numpy" },
+        ]
+
+        [[package]]
+        name = "sniffio"
+        version = "1.3.1"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/sniffio-1.3.1.tar.gz", hash = "sha256:5555555555555555555555555555555555555555555555555555555555555555", size = 1 }
+    "#})?;
+
+    context.temp_dir.child("uv.lock").write_str(indoc! {r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+        resolution-markers = [
+            "sys_platform == 'win32'",
+            "sys_platform != 'win32'",
+        ]
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "anyio"
+        version = "4.3.0"
+        source = { registry = "https://pypi.org/simple" }
+        dependencies = [
+            { name = "idna" },
+            { name = "typing-extensions" },
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/anyio-4.3.0.tar.gz", hash = "sha256:6666666666666666666666666666666666666666666666666666666666666666", size = 1 }
+
+        [[package]]
+        name = "idna"
+        version = "3.6"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/idna-3.6.tar.gz", hash = "sha256:7777777777777777777777777777777777777777777777777777777777777777", size = 1 }
+
+        [[package]]
+        name = "numpy"
+        version = "1.26.3"
+        source = { registry = "https://pypi.org/simple" }
+        resolution-markers = [
+            "sys_platform == 'win32'",
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/numpy-1.26.3.tar.gz", hash = "sha256:8888888888888888888888888888888888888888888888888888888888888888", size = 1 }
+
+        [[package]]
+        name = "numpy"
+        version = "2.1.0"
+        source = { registry = "https://pypi.org/simple" }
+        resolution-markers = [
+            "sys_platform != 'win32'",
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/numpy-2.1.0.tar.gz", hash = "sha256:9999999999999999999999999999999999999999999999999999999999999999", size = 1 }
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "anyio" },
+            { name = "numpy", version = "1.26.3", source = { registry = "https://pypi.org/simple" }, marker = "sys_platform == 'win32'" },
+            { name = "numpy", version = "2.1.0", source = { registry = "https://pypi.org/simple" }, marker = "sys_platform != 'win32'" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "anyio" },
+            { name = "numpy" },
+        ]
+
+        [[package]]
+        name = "typing-extensions"
+        version = "4.10.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/typing_extensions-4.10.0.tar.gz", hash = "sha256:0000000000000000000000000000000000000000000000000000000000000000", size = 1 }
+    "#})?;
+
+    Ok(())
+}
+
+/// Compare the lockfile against a lockfile on disk.
+#[test]
+fn lock_diff_path() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    write_lock_diff_project(&context)?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--diff-file").arg("base.lock"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Changed options: addition of global exclude newer 2024-03-25T00:00:00Z
+    Upgraded anyio v4.2.0 -> v4.3.0
+    Changed hashes idna v3.6
+      idna-3.6.tar.gz: sha256:2222222222222222222222222222222222222222222222222222222222222222 -> sha256:7777777777777777777777777777777777777777777777777777777777777777
+    Downgraded numpy v1.26.4 -> v1.26.3 ; sys_platform == 'win32'
+    Upgraded numpy v2.0.0 -> v2.1.0 ; sys_platform != 'win32'
+    Removed sniffio v1.3.1
+    Added typing-extensions v4.10.0
+
+    ----- stderr -----
+    ");
+
+    // The path is resolved relative to the workspace root, rather than the working directory.
+    let child = context.temp_dir.child("child");
+    child.create_dir_all()?;
+    uv_snapshot!(context.filters(), context.lock().arg("--diff-file").arg("base.lock").current_dir(&child), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Changed options: addition of global exclude newer 2024-03-25T00:00:00Z
+    Upgraded anyio v4.2.0 -> v4.3.0
+    Changed hashes idna v3.6
+      idna-3.6.tar.gz: sha256:2222222222222222222222222222222222222222222222222222222222222222 -> sha256:7777777777777777777777777777777777777777777777777777777777777777
+    Downgraded numpy v1.26.4 -> v1.26.3 ; sys_platform == 'win32'
+    Upgraded numpy v2.0.0 -> v2.1.0 ; sys_platform != 'win32'
+    Removed sniffio v1.3.1
+    Added typing-extensions v4.10.0
+
+    ----- stderr -----
+    ");
+
+    // The lockfile is unchanged.
+    uv_snapshot!(context.filters(), context.lock().arg("--diff-file").arg("uv.lock"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    No lockfile changes detected
+
+    ----- stderr -----
+    ");
+
+    // A file that isn't a lockfile is rejected, rather than treated as a Git revision.
+    context.temp_dir.child("main").write_str("not a lockfile")?;
+    uv_snapshot!(context.filters(), context.lock().arg("--diff-file").arg("main"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse the lockfile at `main`
+      Caused by: TOML parse error at line 1, column 5
+      |
+    1 | not a lockfile
+      |     ^
+    key with no value, expected `=`
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn lock_diff_json() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    write_lock_diff_project(&context)?;
+
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--diff-file").arg("base.lock")
+        .arg("--output-format").arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "exclude-newer": "addition of global exclude newer 2024-03-25T00:00:00Z",
+      "changes": [
+        {
+          "kind": "upgraded",
+          "name": "anyio",
+          "from": "4.2.0",
+          "to": "4.3.0"
+        },
+        {
+          "kind": "hashes-changed",
+          "name": "idna",
+          "version": "3.6",
+          "files": [
+            {
+              "filename": "idna-3.6.tar.gz",
+              "from": "sha256:2222222222222222222222222222222222222222222222222222222222222222",
+              "to": "sha256:7777777777777777777777777777777777777777777777777777777777777777"
+            }
+          ]
+        },
+        {
+          "kind": "downgraded",
+          "name": "numpy",
+          "from": "1.26.4",
+          "to": "1.26.3",
+          "marker": "sys_platform == 'win32'"
+        },
+        {
+          "kind": "upgraded",
+          "name": "numpy",
+          "from": "2.0.0",
+          "to": "2.1.0",
+          "marker": "sys_platform != 'win32'"
+        },
+        {
+          "kind": "removed",
+          "name": "sniffio",
+          "version": "1.3.1"
+        },
+        {
+          "kind": "added",
+          "name": "typing-extensions",
+          "version": "4.10.0"
+        }
+      ]
+    }
+
+    ----- stderr -----
+    "#);
+
+    Ok(())
+}
+
+/// Compare the lockfile against the lockfile at a Git revision, defaulting to `HEAD`.
+#[test]
+fn lock_diff_git() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    write_lock_diff_project(&context)?;
+
+    // Commit the base lockfile.
+    fs_err::copy(
+        context.temp_dir.child("base.lock"),
+        context.temp_dir.child("uv.lock.new"),
+    )?;
+    fs_err::rename(
+        context.temp_dir.child("uv.lock"),
+        context.temp_dir.child("uv.lock.updated"),
+    )?;
+    fs_err::rename(
+        context.temp_dir.child("uv.lock.new"),
+        context.temp_dir.child("uv.lock"),
+    )?;
+    for args in [
+        vec!["init", "--quiet"],
+        vec!["add", "uv.lock", "pyproject.toml"],
+        vec![
+            "-c",
+            "user.name=uv",
+            "-c",
+            "user.email=uv@example.com",
+            "commit",
+            "--quiet",
+            "-m",
+            "Initial commit",
+        ],
+    ] {
+        std::process::Command::new("git")
+            .args(args)
+            .current_dir(&context.temp_dir)
+            .assert()
+            .success();
+    }
+    fs_err::rename(
+        context.temp_dir.child("uv.lock.updated"),
+        context.temp_dir.child("uv.lock"),
+    )?;
+
+    // A file with the same name as the revision shouldn't be mistaken for the base lockfile.
+    context.temp_dir.child("HEAD").write_str("not a lockfile")?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--diff"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Changed options: addition of global exclude newer 2024-03-25T00:00:00Z
+    Upgraded anyio v4.2.0 -> v4.3.0
+    Changed hashes idna v3.6
+      idna-3.6.tar.gz: sha256:2222222222222222222222222222222222222222222222222222222222222222 -> sha256:7777777777777777777777777777777777777777777777777777777777777777
+    Downgraded numpy v1.26.4 -> v1.26.3 ; sys_platform == 'win32'
+    Upgraded numpy v2.0.0 -> v2.1.0 ; sys_platform != 'win32'
+    Removed sniffio v1.3.1
+    Added typing-extensions v4.10.0
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.lock().arg("--diff").arg("missing"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to read `uv.lock` at Git revision `missing`: fatal: invalid object name 'missing'.
+    ");
+
+    Ok(())
+}
+
+/// Write a project with the given `[tool.uv.license-policy]` table, along with local dependencies
+/// that declare their licenses in `pyproject.toml`.
+fn write_license_policy_project(context: &TestContext, policy: &str) -> Result<()> {
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&formatdoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["library"]
+
+        [dependency-groups]
+        dev = ["unlicensed"]
+
+        [tool.uv.sources]
+        library = {{ path = "library" }}
+        unlicensed = {{ path = "unlicensed" }}
+
+        [tool.uv.license-policy]
+        {policy}
+    "#})?;
+
+    context
+        .temp_dir
+        .child("library")
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "library"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        license = "BSD-3-Clause"
+        dependencies = ["copyleft"]
+
+        [tool.uv.sources]
+        copyleft = { path = "../copyleft" }
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+
+    context
+        .temp_dir
+        .child("copyleft")
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "copyleft"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        license = "GPL-3.0-only OR AGPL-3.0-only"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+
+    context
+        .temp_dir
+        .child("unlicensed")
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "unlicensed"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        classifiers = ["Operating System :: OS Independent"]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+
+    Ok(())
+}
+
+/// Reject locked packages whose licenses violate the license policy, reporting the chain of
+/// dependents that pulled in each package.
+#[test]
+fn lock_license_policy() -> Result<()> {
+    let context = TestContext::new("3.12");
+    write_license_policy_project(
+        &context,
+        indoc! {r#"
+        allow = ["MIT", "BSD-3-Clause"]
+        deny = ["GPL-3.0-only", "AGPL-3.0-only"]
+    "#},
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+      × The locked dependencies violate the license policy:
+      ╰─▶ Because copyleft==0.1.0 is licensed under GPL-3.0-only OR AGPL-3.0-only, which is denied by the license policy, and library==0.1.0 depends on copyleft, we can conclude that library==0.1.0 cannot be used.
+          And because project depends on library, we can conclude that project's dependencies violate the license policy.
+
+          Because unlicensed==0.1.0 has no recognized license, which is not allowed by the license policy, and project:dev depends on unlicensed, we can conclude that project:dev's dependencies violate the license policy.
+      help: If the license of a package has been reviewed, add it to `tool.uv.license-policy.exceptions` to exempt it from the policy
+    ");
+
+    // The policy is enforced before the lockfile is written.
+    assert!(!context.temp_dir.child("uv.lock").exists());
+
+    // Without an allowlist, packages without a recognized license are accepted.
+    write_license_policy_project(
+        &context,
+        indoc! {r#"
+        deny = ["GPL-3.0-only"]
+    "#},
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    // A frozen lockfile is used as-is, so the policy isn't checked.
+    uv_snapshot!(context.filters(), context.lock().arg("--frozen"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: The license policy is not checked with `--check-exists`; use `uv sync --frozen` to check it against the existing lockfile
+    ");
+
+    // Packages that aren't explicitly allowed are rejected too.
+    write_license_policy_project(
+        &context,
+        indoc! {r#"
+        allow = ["BSD-3-Clause"]
+    "#},
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+      × The locked dependencies violate the license policy:
+      ╰─▶ Because copyleft==0.1.0 is licensed under GPL-3.0-only OR AGPL-3.0-only, which is not allowed by the license policy, and library==0.1.0 depends on copyleft, we can conclude that library==0.1.0 cannot be used.
+          And because project depends on library, we can conclude that project's dependencies violate the license policy.
+
+          Because unlicensed==0.1.0 has no recognized license, which is not allowed by the license policy, and project:dev depends on unlicensed, we can conclude that project:dev's dependencies violate the license policy.
+      help: If the license of a package has been reviewed, add it to `tool.uv.license-policy.exceptions` to exempt it from the policy
+    ");
+
+    // The existing lockfile is left untouched.
+    assert_eq!(context.read("uv.lock"), lock);
+
+    Ok(())
+}
+
+/// Packages listed as exceptions are exempt from the license policy.
+#[test]
+fn lock_license_policy_exceptions() -> Result<()> {
+    let context = TestContext::new("3.12");
+    write_license_policy_project(
+        &context,
+        indoc! {r#"
+        allow = ["MIT", "BSD-3-Clause"]
+        deny = ["GPL-3.0-only", "AGPL-3.0-only"]
+        exceptions = ["copyleft", "unlicensed"]
+    "#},
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Packages that are only available as a source distribution are never built to read their
+/// license, so they're skipped with a warning rather than rejected as unlicensed.
+#[test]
+fn lock_license_policy_source_distribution() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-29T00:00:00Z");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["source-distribution==0.0.1"]
+
+        [tool.uv.license-policy]
+        allow = ["MIT"]
+    "#})?;
+
+    // With an allowlist, the unread license is rejected.
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+      × The locked dependencies violate the license policy:
+      ╰─▶ Because source-distribution==0.0.1 has an unknown license (it's only available as a source distribution), which is not allowed by the license policy, and project depends on source-distribution, we can conclude that project's dependencies violate the license policy.
+    ");
+
+    // Without an allowlist, the package is skipped with a warning.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["source-distribution==0.0.1"]
+
+        [tool.uv.license-policy]
+        deny = ["GPL-3.0-only"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    warning: The license policy was not checked for packages that are only available as a source distribution: source-distribution==0.0.1
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn lock_license_policy_invalid() -> Result<()> {
+    let context = TestContext::new("3.12");
+    write_license_policy_project(
+        &context,
+        indoc! {r#"
+        deny = ["GPL-3"]
+    "#},
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    error: Invalid license identifier `GPL-3` in `tool.uv.license-policy.deny`: unknown term
+    ");
+
+    Ok(())
+}
+
+/// Record the resolver's responses with `--record`, and reproduce the lockfile offline with
+/// `--replay`.
+#[test]
+fn lock_record_replay() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--record").arg("trace.json"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(context.temp_dir.child("trace.json").is_file());
+
+    // Replay the resolution without a lockfile, cache, or network access.
+    fs_err::remove_file(context.temp_dir.join("uv.lock"))?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--replay").arg("trace.json").arg("--no-cache"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    assert_eq!(context.read("uv.lock"), lock);
+
+    // Requests that weren't recorded can't be answered from the trace.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--replay").arg("trace.json").arg("--no-cache"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No response for `iniconfig` was recorded in the resolver trace
+    ");
+
+    Ok(())
+}
+
+/// Explain why a package was resolved to an older version with `--explain`.
+#[test]
+fn lock_explain() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio<4"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--explain").arg("anyio"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    anyio==3.7.1 was selected over newer versions (4.0.0, 4.1.0, 4.2.0, 4.3.0) because:
+      - your project depends on anyio<4
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    // With an existing lockfile, the locked version is preferred over newer versions.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--explain").arg("anyio"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    anyio==3.7.1 was selected over newer versions (4.0.0, 4.1.0, 4.2.0, 4.3.0) because:
+      - anyio>=4.4.0 were published after the exclude newer time
+      - anyio==3.7.1 is preferred by the existing lockfile (use `--upgrade-package anyio` to ignore it)
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    // Versions published after `exclude-newer` are summarized.
+    uv_snapshot!(context.filters(), context.lock().arg("--explain").arg("anyio").arg("--upgrade"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    anyio==4.3.0 was selected over newer versions because:
+      - anyio>=4.4.0 were published after the exclude newer time
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Updated anyio v3.7.1 -> v4.3.0
+    ");
+
+    // Packages that aren't part of the resolution can't be explained.
+    uv_snapshot!(context.filters(), context.lock().arg("--explain").arg("flask"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    warning: `flask` is not included in the resolution
+    ");
+
+    Ok(())
+}
+
+/// Write a profile of the resolution with `--profile`.
+#[test]
+fn lock_profile() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "iniconfig==1.1.1 ; sys_platform == 'win32'", "iniconfig==2.0.0 ; sys_platform != 'win32'"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--profile").arg("profile"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Wrote resolver profile to `profile`
+    Resolved 5 packages in [TIME]
+    ");
+
+    let profile: serde_json::Value = serde_json::from_str(&context.read("profile/profile.json"))?;
+    let anyio = profile["packages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|package| package["name"] == "anyio")
+        .unwrap();
+    assert_eq!(anyio["versions"]["count"], 1);
+    assert_eq!(anyio["versions_tried"], 1);
+    assert_eq!(profile["forks"]["by_reason"]["markers"], 2);
+    assert_eq!(
+        profile["forks"]["events"][0]["diverging"],
+        serde_json::json!(["iniconfig"])
+    );
+
+    let trace: serde_json::Value = serde_json::from_str(&context.read("profile/trace.json"))?;
+    assert!(
+        trace["traceEvents"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|event| event["cat"] == "metadata" && event["name"] == "anyio==3.7.0")
+    );
+
+    // The project is re-resolved even if the lockfile is up-to-date.
+    uv_snapshot!(context.filters(), context.lock().arg("--profile").arg("profile"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Wrote resolver profile to `profile`
+    Resolved 5 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Lock offline against a metadata index built from the cache.
+#[test]
+fn lock_metadata_index() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    uv_snapshot!(context.filters(), context
+        .command()
+        .arg("index")
+        .arg("build-metadata")
+        .arg("metadata-index")
+        .arg("--preview-features")
+        .arg("metadata-index"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Wrote 1 package with 1 metadata file to: metadata-index
+    ");
+
+    // Re-lock from scratch, without network access or a populated cache.
+    fs_err::remove_file(context.temp_dir.join("uv.lock"))?;
+
+    uv_snapshot!(context.filters(), context
+        .lock()
+        .arg("--offline")
+        .arg("--no-cache")
+        .arg("--metadata-index")
+        .arg("metadata-index")
+        .arg("--preview-features")
+        .arg("metadata-index"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // The lockfile should be unchanged.
+    assert_eq!(context.read("uv.lock"), lock);
+
+    Ok(())
+}
+
+/// Report whether the lockfile can be installed on each of a set of target platforms.
+#[test]
+fn lock_platforms() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok==1.0.0", "abi3-package", "maturin==2.0.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context
+        .lock()
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links"))
+        .arg("--platforms")
+        .arg("x86_64-manylinux_2_28,x86_64-unknown-linux-musl,aarch64-apple-darwin")
+        .arg("--preview-features")
+        .arg("lock-platforms"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    x86_64-manylinux_2_28 (CPython 3.12): 3 packages, all with compatible wheels
+    x86_64-unknown-linux-musl (CPython 3.12): 3 packages, 1 unavailable
+      Unavailable:
+        - abi3-package v1.0.0
+    aarch64-apple-darwin (CPython 3.12): 3 packages, 2 unavailable
+      Unavailable:
+        - abi3-package v1.0.0
+        - maturin v2.0.0
+    ");
+
+    // An existing lockfile can be validated without re-resolving.
+    uv_snapshot!(context.filters(), context
+        .lock()
+        .arg("--frozen")
+        .arg("--platforms")
+        .arg("x86_64-manylinux_2_28@3.13")
+        .arg("--platforms")
+        .arg("linux@3.11")
+        .arg("--preview-features")
+        .arg("lock-platforms"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    x86_64-manylinux_2_28@3.13 (CPython 3.13): 3 packages, all with compatible wheels
+    linux@3.11 (CPython 3.11): incompatible with the project's Python requirement: `>=3.12`
+    ");
+
+    // Platforms outside the project's supported environments aren't covered by the lockfile.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["ok==1.0.0", "abi3-package", "maturin==2.0.0"]
+
+        [tool.uv]
+        environments = ["sys_platform == 'linux'"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context
+        .lock()
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test/links"))
+        .arg("--platforms")
+        .arg("x86_64-manylinux_2_28,aarch64-apple-darwin")
+        .arg("--preview-features")
+        .arg("lock-platforms"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    x86_64-manylinux_2_28 (CPython 3.12): 3 packages, all with compatible wheels
+    aarch64-apple-darwin (CPython 3.12): not covered by the project's supported environments: `sys_platform == 'linux'`
+    ");
+
+    Ok(())
+}
+
+/// Upgrade a package by the smallest possible step with `--resolution security`, retaining all
+/// other locked versions.
+#[test]
+fn lock_resolution_security() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio<4"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    // Loosen the requirement; the locked versions are retained.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--resolution").arg("security").arg("--upgrade"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Changed 0 of 4 locked packages
+    ");
+
+    // Require a patched version of `anyio`; it should move to the lowest compatible version, rather
+    // than the latest.
+    uv_snapshot!(context.filters(), context.lock().arg("--resolution").arg("security").arg("--upgrade-package").arg("anyio>=4.1"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Updated anyio v3.7.1 -> v4.1.0
+    Changed 1 of 4 locked packages (1 updated)
+    ");
+
+    // The resolution mode isn't recorded, so the lockfile remains valid for the default mode.
+    let lock = context.read("uv.lock");
+    assert!(!lock.contains("resolution-mode"));
+
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Merge two lockfiles that upgraded different packages, as a Git merge driver would.
+#[test]
+fn lock_merge() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio", "iniconfig"]
+        "#,
+    )?;
+
+    // Lock the base versions.
+    context
+        .lock()
+        .arg("--upgrade-package")
+        .arg("anyio==3.7.1")
+        .arg("--upgrade-package")
+        .arg("iniconfig==1.1.1")
+        .assert()
+        .success();
+    let base = context.read("uv.lock");
+
+    // On one branch, upgrade `anyio`.
+    context
+        .lock()
+        .arg("--upgrade-package")
+        .arg("anyio==4.0.0")
+        .assert()
+        .success();
+    let ours = context.read("uv.lock");
+
+    // On another branch, upgrade `iniconfig`.
+    fs_err::write(context.temp_dir.join("uv.lock"), &base)?;
+    context
+        .lock()
+        .arg("--upgrade-package")
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+    let theirs = context.read("uv.lock");
+
+    context.temp_dir.child("base.lock").write_str(&base)?;
+    context.temp_dir.child("ours.lock").write_str(&ours)?;
+    context.temp_dir.child("theirs.lock").write_str(&theirs)?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("merge").arg("base.lock").arg("ours.lock").arg("theirs.lock"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Merged lockfile into `ours.lock`
+    ");
+
+    // Both upgrades should be retained.
+    let merged = context.read("ours.lock");
+    assert!(merged.contains("name = \"anyio\"\nversion = \"4.0.0\""));
+    assert!(merged.contains("name = \"iniconfig\"\nversion = \"2.0.0\""));
+
+    // And the merged lockfile should be up-to-date.
+    fs_err::write(context.temp_dir.join("uv.lock"), &merged)?;
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Merge two lockfiles that added different dependencies, before `pyproject.toml` has been
+/// merged, as a Git merge driver would.
+#[test]
+fn lock_merge_dependencies() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+    context.lock().assert().success();
+    let base = context.read("uv.lock");
+
+    // On another branch, add `typing-extensions`.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "typing-extensions"]
+        "#,
+    )?;
+    context.lock().assert().success();
+    let theirs = context.read("uv.lock");
+
+    // On one branch, add `anyio`. The working tree retains this version of `pyproject.toml`.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "anyio"]
+        "#,
+    )?;
+    context.lock().assert().success();
+    let ours = context.read("uv.lock");
+
+    context.temp_dir.child("base.lock").write_str(&base)?;
+    context.temp_dir.child("ours.lock").write_str(&ours)?;
+    context.temp_dir.child("theirs.lock").write_str(&theirs)?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("merge").arg("base.lock").arg("ours.lock").arg("theirs.lock"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    Merged lockfile into `ours.lock`
+    ");
+
+    // Both additions should be retained.
+    let merged = context.read("ours.lock");
+    assert!(merged.contains("name = \"anyio\""));
+    assert!(merged.contains("name = \"typing-extensions\""));
+
+    // And the merged lockfile should be up-to-date with the merged `pyproject.toml`.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "anyio", "typing-extensions"]
+        "#,
+    )?;
+    fs_err::write(context.temp_dir.join("uv.lock"), &merged)?;
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Fall back to conflict markers if no Python interpreter can be found to resolve the merge.
+#[test]
+fn lock_merge_missing_interpreter() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]).with_filtered_python_sources();
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    for (name, requires_python) in [
+        ("base.lock", ">=3.12"),
+        ("ours.lock", ">=3.12.1"),
+        ("theirs.lock", ">=3.12.2"),
+    ] {
+        context.temp_dir.child(name).write_str(&formatdoc! {r#"
+            version = 1
+            revision = 3
+            requires-python = "{requires_python}"
+
+            [[package]]
+            name = "project"
+            version = "0.1.0"
+            source = {{ virtual = "." }}
+        "#})?;
+    }
+
+    uv_snapshot!(context.filters(), context.lock().arg("merge").arg("base.lock").arg("ours.lock").arg("theirs.lock"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Failed to find a Python interpreter for the project: No interpreter found for Python >=3.12 in [PYTHON SOURCES]
+
+    hint: A managed Python download is available for Python >=3.12, but Python downloads are set to 'never'
+    ");
+
+    assert_snapshot!(context.read("ours.lock"), @r#"
+    version = 1
+    revision = 3
+    <<<<<<< ours
+    requires-python = ">=3.12.1"
+    =======
+    requires-python = ">=3.12.2"
+    >>>>>>> theirs
+
+    [[package]]
+    name = "project"
+    version = "0.1.0"
+    source = { virtual = "." }
+    "#);
+
+    Ok(())
+}
+
+/// Fall back to conflict markers if the lockfiles can't be merged.
+#[test]
+fn lock_merge_conflict() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    context.temp_dir.child("base.lock").write_str("a\nb\nc\n")?;
+    context
+        .temp_dir
+        .child("ours.lock")
+        .write_str("a\nours\nc\n")?;
+    context
+        .temp_dir
+        .child("theirs.lock")
+        .write_str("a\ntheirs\nc\n")?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("merge").arg("base.lock").arg("ours.lock").arg("theirs.lock"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Failed to parse the lockfile at `base.lock`: TOML parse error at line 1, column 2
+      |
+    1 | a
+      |  ^
+    key with no value, expected `=`
+    ");
+
+    assert_snapshot!(context.read("ours.lock"), @"
+    a
+    <<<<<<< ours
+    ours
+    =======
+    theirs
+    >>>>>>> theirs
+    c
+    ");
+
+    Ok(())
+}
+
+/// Succeed if the lockfiles can't be parsed, but merge cleanly line-by-line.
+#[test]
+fn lock_merge_clean() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    context
+        .temp_dir
+        .child("base.lock")
+        .write_str("a\nb\nc\nd\ne\n")?;
+    context
+        .temp_dir
+        .child("ours.lock")
+        .write_str("ours\nb\nc\nd\ne\n")?;
+    context
+        .temp_dir
+        .child("theirs.lock")
+        .write_str("a\nb\nc\nd\ntheirs\n")?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("merge").arg("base.lock").arg("ours.lock").arg("theirs.lock"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Failed to parse the lockfile at `base.lock`: TOML parse error at line 1, column 2
+      |
+    1 | a
+      |  ^
+    key with no value, expected `=`
+    ");
+
+    assert_snapshot!(context.read("ours.lock"), @"
+    ours
+    b
+    c
+    d
+    theirs
+    ");
+
+    Ok(())
+}
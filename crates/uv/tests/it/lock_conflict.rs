@@ -1,6 +1,9 @@
 use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
 use assert_fs::prelude::*;
+use indoc::indoc;
 use insta::assert_snapshot;
+use predicates::prelude::*;
 
 use crate::common::{TestContext, uv_snapshot};
 
@@ -38,7 +41,7 @@ fn extra_basic() -> Result<()> {
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @"
+    uv_snapshot!(context.filters(), context.lock(), @r#"
     success: false
     exit_code: 1
     ----- stdout -----
@@ -47,7 +50,18 @@ fn extra_basic() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because project[extra2] depends on sortedcontainers==2.4.0 and project[extra1] depends on sortedcontainers==2.3.0, we can conclude that project[extra1] and project[extra2] are incompatible.
           And because your project requires project[extra1] and project[extra2], we can conclude that your project's requirements are unsatisfiable.
-    ");
+      help: The project can be locked if `project[extra1]` and `project[extra2]` are resolved separately. To do so, declare them as conflicting in `pyproject.toml`:
+
+            [tool.uv]
+            conflicts = [
+                [
+                    { extra = "extra1" },
+                    { extra = "extra2" },
+                ],
+            ]
+
+            Or, use `uv lock --auto-conflicts` to declare them automatically.
+    "#);
 
     // And now with the same extra configuration, we tell uv about
     // the conflicting extras, which forces it to resolve each in
@@ -207,6 +221,169 @@ fn extra_basic() -> Result<()> {
     Ok(())
 }
 
+/// Like `extra_basic`, but declares the conflicting extras with `--auto-conflicts`.
+#[test]
+fn extra_auto_conflicts() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [project.optional-dependencies]
+        extra1 = ["sortedcontainers==2.3.0"]
+        extra2 = ["sortedcontainers==2.4.0"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--auto-conflicts"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Declared conflicts in `pyproject.toml`
+    Resolved 3 packages in [TIME]
+    ");
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    assert_snapshot!(
+        pyproject_toml, @r#"
+    [project]
+    name = "project"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+
+    [project.optional-dependencies]
+    extra1 = ["sortedcontainers==2.3.0"]
+    extra2 = ["sortedcontainers==2.4.0"]
+
+    [tool.uv]
+    conflicts = [
+        [
+            { extra = "extra1" },
+            { extra = "extra2" },
+        ],
+    ]
+    "#
+    );
+
+    // The lockfile is up-to-date with the declared conflicts.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Don't suggest (or declare) conflicts between extras when the resolution would still fail with
+/// the conflicts declared, e.g., because each extra is incompatible with the project's own
+/// dependencies.
+#[test]
+fn extra_auto_conflicts_insufficient() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject = indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [project.optional-dependencies]
+        extra1 = ["sortedcontainers==2.3.0", "iniconfig==1.1.1"]
+        extra2 = ["sortedcontainers==2.4.0", "iniconfig==1.1.1"]
+        "#
+    };
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(pyproject)?;
+
+    context
+        .lock()
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No solution found"))
+        .stderr(predicate::str::contains("tool.uv").not());
+
+    context
+        .lock()
+        .arg("--auto-conflicts")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Declared conflicts").not());
+
+    assert_eq!(context.read("pyproject.toml"), pyproject);
+
+    Ok(())
+}
+
+/// Like `extra_auto_conflicts`, but with three conflicting extras, which requires re-resolving to
+/// find every conflict before declaring them.
+#[test]
+fn extra_auto_conflicts_three_extras() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [project.optional-dependencies]
+        extra1 = ["sortedcontainers==2.2.0"]
+        extra2 = ["sortedcontainers==2.3.0"]
+        project3 = ["sortedcontainers==2.4.0"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--auto-conflicts"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Declared conflicts in `pyproject.toml`
+    Resolved 4 packages in [TIME]
+    ");
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    assert_snapshot!(
+        pyproject_toml, @r#"
+    [project]
+    name = "project"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+
+    [project.optional-dependencies]
+    extra1 = ["sortedcontainers==2.2.0"]
+    extra2 = ["sortedcontainers==2.3.0"]
+    project3 = ["sortedcontainers==2.4.0"]
+
+    [tool.uv]
+    conflicts = [
+        [
+            { extra = "extra1" },
+            { extra = "extra2" },
+            { extra = "project3" },
+        ],
+    ]
+    "#
+    );
+
+    Ok(())
+}
+
 /// Like `lock_conflicting_extra_basic`, but defines three conflicting
 /// extras instead of two.
 #[test]
@@ -230,7 +407,7 @@ fn extra_basic_three_extras() -> Result<()> {
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @"
+    uv_snapshot!(context.filters(), context.lock(), @r#"
     success: false
     exit_code: 1
     ----- stdout -----
@@ -239,7 +416,19 @@ fn extra_basic_three_extras() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because project[extra2] depends on sortedcontainers==2.3.0 and project[extra1] depends on sortedcontainers==2.2.0, we can conclude that project[extra1] and project[extra2] are incompatible.
           And because your project requires project[extra1] and project[extra2], we can conclude that your project's requirements are unsatisfiable.
-    ");
+      help: The project can be locked if `project[extra1]`, `project[extra2]`, and `project[project3]` are resolved separately. To do so, declare them as conflicting in `pyproject.toml`:
+
+            [tool.uv]
+            conflicts = [
+                [
+                    { extra = "extra1" },
+                    { extra = "extra2" },
+                    { extra = "project3" },
+                ],
+            ]
+
+            Or, use `uv lock --auto-conflicts` to declare them automatically.
+    "#);
 
     // And now with the same extra configuration, we tell uv about
     // the conflicting extras, which forces it to resolve each in
@@ -506,7 +695,7 @@ fn extra_multiple_not_conflicting2() -> Result<()> {
     )?;
 
     // Fails, as expected.
-    uv_snapshot!(context.filters(), context.lock(), @"
+    uv_snapshot!(context.filters(), context.lock(), @r#"
     success: false
     exit_code: 1
     ----- stdout -----
@@ -515,7 +704,30 @@ fn extra_multiple_not_conflicting2() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because project[extra2] depends on sortedcontainers==2.4.0 and project[extra1] depends on sortedcontainers==2.3.0, we can conclude that project[extra1] and project[extra2] are incompatible.
           And because your project requires project[extra1] and project[extra2], we can conclude that your project's requirements are unsatisfiable.
-    ");
+      help: The project can be locked if `project[extra1]`, `project[extra2]`, `project[project3]`, and `project[project4]` are resolved separately. To do so, declare them as conflicting in `pyproject.toml`:
+
+            [tool.uv]
+            conflicts = [
+                [
+                    { extra = "extra1" },
+                    { extra = "extra2" },
+                ],
+                [
+                    { extra = "extra1" },
+                    { extra = "project4" },
+                ],
+                [
+                    { extra = "extra2" },
+                    { extra = "project3" },
+                ],
+                [
+                    { extra = "project3" },
+                    { extra = "project4" },
+                ],
+            ]
+
+            Or, use `uv lock --auto-conflicts` to declare them automatically.
+    "#);
 
     // If we define extra1/extra2 as conflicting and project3/project4
     // as conflicting, that still isn't enough! That's because extra1
@@ -546,7 +758,7 @@ fn extra_multiple_not_conflicting2() -> Result<()> {
         project4 = ["sortedcontainers==2.4.0"]
         "#,
     )?;
-    uv_snapshot!(context.filters(), context.lock(), @"
+    uv_snapshot!(context.filters(), context.lock(), @r#"
     success: false
     exit_code: 1
     ----- stdout -----
@@ -555,7 +767,22 @@ fn extra_multiple_not_conflicting2() -> Result<()> {
       × No solution found when resolving dependencies for split (included: project[extra2], project[project3]; excluded: project[extra1], project[project4]):
       ╰─▶ Because project[project3] depends on sortedcontainers==2.3.0 and project[extra2] depends on sortedcontainers==2.4.0, we can conclude that project[extra2] and project[project3] are incompatible.
           And because your project requires project[extra2] and project[project3], we can conclude that your project's requirements are unsatisfiable.
-    ");
+      help: The project can be locked if `project[extra1]`, `project[extra2]`, `project[project3]`, and `project[project4]` are resolved separately. To do so, declare them as conflicting in `pyproject.toml`:
+
+            [tool.uv]
+            conflicts = [
+                [
+                    { extra = "extra1" },
+                    { extra = "project4" },
+                ],
+                [
+                    { extra = "extra2" },
+                    { extra = "project3" },
+                ],
+            ]
+
+            Or, use `uv lock --auto-conflicts` to declare them automatically.
+    "#);
 
     // One could try to declare all pairs of conflicting extras as
     // conflicting, but this doesn't quite work either. For example,
@@ -667,7 +894,7 @@ fn extra_multiple_independent() -> Result<()> {
         project4 = ["anyio==4.2.0"]
         "#,
     )?;
-    uv_snapshot!(context.filters(), context.lock(), @"
+    uv_snapshot!(context.filters(), context.lock(), @r#"
     success: false
     exit_code: 1
     ----- stdout -----
@@ -676,7 +903,22 @@ fn extra_multiple_independent() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because project[extra2] depends on sortedcontainers==2.4.0 and project[extra1] depends on sortedcontainers==2.3.0, we can conclude that project[extra1] and project[extra2] are incompatible.
           And because your project requires project[extra1] and project[extra2], we can conclude that your project's requirements are unsatisfiable.
-    ");
+      help: The project can be locked if `project[extra1]`, `project[extra2]`, `project[project3]`, and `project[project4]` are resolved separately. To do so, declare them as conflicting in `pyproject.toml`:
+
+            [tool.uv]
+            conflicts = [
+                [
+                    { extra = "extra1" },
+                    { extra = "extra2" },
+                ],
+                [
+                    { extra = "project3" },
+                    { extra = "project4" },
+                ],
+            ]
+
+            Or, use `uv lock --auto-conflicts` to declare them automatically.
+    "#);
 
     // OK, responding to the error, we declare our anyio extras
     // as conflicting. But now we should see sortedcontainers as
@@ -703,7 +945,7 @@ fn extra_multiple_independent() -> Result<()> {
         project4 = ["anyio==4.2.0"]
         "#,
     )?;
-    uv_snapshot!(context.filters(), context.lock(), @"
+    uv_snapshot!(context.filters(), context.lock(), @r#"
     success: false
     exit_code: 1
     ----- stdout -----
@@ -712,7 +954,18 @@ fn extra_multiple_independent() -> Result<()> {
       × No solution found when resolving dependencies for split (included: project[project4]; excluded: project[project3]):
       ╰─▶ Because project[extra2] depends on sortedcontainers==2.4.0 and project[extra1] depends on sortedcontainers==2.3.0, we can conclude that project[extra1] and project[extra2] are incompatible.
           And because your project requires project[extra1] and project[extra2], we can conclude that your project's requirements are unsatisfiable.
-    ");
+      help: The project can be locked if `project[extra1]` and `project[extra2]` are resolved separately. To do so, declare them as conflicting in `pyproject.toml`:
+
+            [tool.uv]
+            conflicts = [
+                [
+                    { extra = "extra1" },
+                    { extra = "extra2" },
+                ],
+            ]
+
+            Or, use `uv lock --auto-conflicts` to declare them automatically.
+    "#);
 
     // Once we declare ALL our conflicting extras, resolution succeeds.
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
@@ -987,7 +1240,7 @@ fn extra_config_change_ignore_lockfile() -> Result<()> {
     )?;
     // Re-run with `--locked`, which should now fail because of
     // the conflicting group config removal.
-    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @"
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r#"
     success: false
     exit_code: 1
     ----- stdout -----
@@ -996,7 +1249,18 @@ fn extra_config_change_ignore_lockfile() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because project[extra2] depends on sortedcontainers==2.4.0 and project[extra1] depends on sortedcontainers==2.3.0, we can conclude that project[extra1] and project[extra2] are incompatible.
           And because your project requires project[extra1] and project[extra2], we can conclude that your project's requirements are unsatisfiable.
-    ");
+      help: The project can be locked if `project[extra1]` and `project[extra2]` are resolved separately. To do so, declare them as conflicting in `pyproject.toml`:
+
+            [tool.uv]
+            conflicts = [
+                [
+                    { extra = "extra1" },
+                    { extra = "extra2" },
+                ],
+            ]
+
+            Or, use `uv lock --auto-conflicts` to declare them automatically.
+    "#);
 
     Ok(())
 }
@@ -1551,7 +1815,7 @@ fn extra_nested_across_workspace() -> Result<()> {
     // `dummy[extra1]` conflicts with `dummysub[extra2]` and that
     // `dummy[extra2]` conflicts with `dummysub[extra1]`. So we end
     // up with a resolution failure.
-    uv_snapshot!(context.filters(), context.lock(), @"
+    uv_snapshot!(context.filters(), context.lock(), @r#"
     success: false
     exit_code: 1
     ----- stdout -----
@@ -1564,7 +1828,22 @@ fn extra_nested_across_workspace() -> Result<()> {
           And because we know from (1) that dummy[extra2] depends on proxy1[extra2]==0.1.0, we can conclude that dummy[extra2] and proxy1[extra1]==0.1.0 are incompatible.
           And because only proxy1[extra1]==0.1.0 is available and dummysub[extra1] depends on proxy1[extra1], we can conclude that dummysub[extra1] and dummy[extra2] are incompatible.
           And because your workspace requires dummy[extra2] and dummysub[extra1], we can conclude that your workspace's requirements are unsatisfiable.
-    ");
+      help: The project can be locked if `dummy[extra1]`, `dummy[extra2]`, `dummysub[extra1]`, and `dummysub[extra2]` are resolved separately. To do so, declare them as conflicting in `pyproject.toml`:
+
+            [tool.uv]
+            conflicts = [
+                [
+                    { extra = "extra1" },
+                    { package = "dummysub", extra = "extra2" },
+                ],
+                [
+                    { extra = "extra2" },
+                    { package = "dummysub", extra = "extra1" },
+                ],
+            ]
+
+            Or, use `uv lock --auto-conflicts` to declare them automatically.
+    "#);
 
     // Now let's write out the full set of conflicts, taking
     // advantage of the optional `package` key.
@@ -1918,7 +2197,7 @@ fn group_basic() -> Result<()> {
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @"
+    uv_snapshot!(context.filters(), context.lock(), @r#"
     success: false
     exit_code: 1
     ----- stdout -----
@@ -1927,7 +2206,18 @@ fn group_basic() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because project:group2 depends on sortedcontainers==2.4.0 and project:group1 depends on sortedcontainers==2.3.0, we can conclude that project:group1 and project:group2 are incompatible.
           And because your project requires project:group1 and project:group2, we can conclude that your project's requirements are unsatisfiable.
-    ");
+      help: The project can be locked if `project:group1` and `project:group2` are resolved separately. To do so, declare them as conflicting in `pyproject.toml`:
+
+            [tool.uv]
+            conflicts = [
+                [
+                    { group = "group1" },
+                    { group = "group2" },
+                ],
+            ]
+
+            Or, use `uv lock --auto-conflicts` to declare them automatically.
+    "#);
 
     // And now with the same group configuration, we tell uv about
     // the conflicting groups, which forces it to resolve each in
@@ -2283,7 +2573,7 @@ fn mixed() -> Result<()> {
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @"
+    uv_snapshot!(context.filters(), context.lock(), @r#"
     success: false
     exit_code: 1
     ----- stdout -----
@@ -2292,7 +2582,18 @@ fn mixed() -> Result<()> {
       × No solution found when resolving dependencies:
       ╰─▶ Because project:group1 depends on sortedcontainers==2.3.0 and project[extra1] depends on sortedcontainers==2.4.0, we can conclude that project:group1 and project[extra1] are incompatible.
           And because your project requires project[extra1] and project:group1, we can conclude that your project's requirements are unsatisfiable.
-    ");
+      help: The project can be locked if `project[extra1]` and `project:group1` are resolved separately. To do so, declare them as conflicting in `pyproject.toml`:
+
+            [tool.uv]
+            conflicts = [
+                [
+                    { extra = "extra1" },
+                    { group = "group1" },
+                ],
+            ]
+
+            Or, use `uv lock --auto-conflicts` to declare them automatically.
+    "#);
 
     // And now with the same extra/group configuration, we tell uv
     // about the conflicting groups, which forces it to resolve each in
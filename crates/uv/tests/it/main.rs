@@ -3,8 +3,13 @@
 
 pub(crate) mod common;
 
+mod audit;
+
 mod auth;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod bin;
+
 mod branching_urls;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
@@ -16,12 +21,18 @@ mod build_backend;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_bundle;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_clean;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_prune;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_verify;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_size;
 
@@ -42,6 +53,9 @@ mod help;
 #[cfg(all(feature = "python", feature = "pypi", feature = "git"))]
 mod init;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod lint;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod lock;
 
@@ -138,6 +152,9 @@ mod tool_upgrade;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tree;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod upgrade;
+
 #[cfg(feature = "python")]
 mod venv;
 
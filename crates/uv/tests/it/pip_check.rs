@@ -1,4 +1,5 @@
 use anyhow::Result;
+use assert_cmd::prelude::*;
 use assert_fs::fixture::FileWriteStr;
 use assert_fs::fixture::PathChild;
 
@@ -220,3 +221,178 @@ fn check_python_version() {
     "
     );
 }
+
+#[test]
+fn check_modified_files() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--strict"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    // Modify one file and remove another, as if the environment were tampered with.
+    let site_packages = context.site_packages();
+    fs_err::write(
+        site_packages.join("iniconfig").join("__init__.py"),
+        "raise SystemExit\n",
+    )?;
+    fs_err::remove_file(site_packages.join("iniconfig").join("_version.py"))?;
+
+    // The `RECORD` file is only verified on request.
+    uv_snapshot!(context.filters(), context.pip_check(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 1 package in [TIME]
+    All installed packages are compatible
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_check().arg("--verify-record"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 1 package in [TIME]
+    Found 1 incompatibility
+    The package `iniconfig` has files that don't match its `RECORD` file:
+      - iniconfig/_version.py (missing)
+      - iniconfig/__init__.py (modified)
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_check().arg("--verify-record").arg("--output-format").arg("json"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    {
+      "checked": 1,
+      "diagnostics": [
+        {
+          "kind": "modified-files",
+          "package": "iniconfig",
+          "message": "The package `iniconfig` has files that don't match its `RECORD` file:\n  - iniconfig/_version.py (missing)\n  - iniconfig/__init__.py (modified)"
+        }
+      ]
+    }
+
+    ----- stderr -----
+    Checked 1 package in [TIME]
+    "#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn check_missing_record() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--strict"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    fs_err::remove_file(
+        context
+            .site_packages()
+            .join("iniconfig-2.0.0.dist-info")
+            .join("RECORD"),
+    )?;
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(
+            r"directory at: .*",
+            "directory at: [SITE_PACKAGES]/iniconfig-2.0.0.dist-info.",
+        )])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.pip_check().arg("--verify-record"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 1 package in [TIME]
+    Found 1 incompatibility
+    The package `iniconfig` is broken or incomplete (unable to read `RECORD` file). Consider recreating the virtualenv, or removing the package directory at: [SITE_PACKAGES]/iniconfig-2.0.0.dist-info.
+    "
+    );
+
+    Ok(())
+}
+
+/// Files whose `RECORD` entries use an unsupported hash algorithm are skipped, with a warning per
+/// package in a deterministic order.
+#[test]
+fn check_record_unsupported_algorithm() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("typing-extensions==4.10.0")
+        .arg("--strict")
+        .assert()
+        .success();
+
+    // Rewrite the `RECORD` files to use a hash algorithm that uv doesn't support.
+    let site_packages = context.site_packages();
+    for dist_info in [
+        "iniconfig-2.0.0.dist-info",
+        "typing_extensions-4.10.0.dist-info",
+    ] {
+        let record = site_packages.join(dist_info).join("RECORD");
+        let contents = fs_err::read_to_string(&record)?;
+        fs_err::write(&record, contents.replace(",sha256=", ",md5="))?;
+    }
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"of \d+ files", "of [N] files")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.pip_check().arg("--verify-record"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 2 packages in [TIME]
+    warning: Skipped verification of [N] files in `iniconfig` with unsupported hash algorithm: `md5`
+    warning: Skipped verification of [N] files in `typing-extensions` with unsupported hash algorithm: `md5`
+    All installed packages are compatible
+    "
+    );
+
+    Ok(())
+}
@@ -94,6 +94,11 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -301,6 +306,11 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -509,6 +519,11 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -749,6 +764,11 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -958,6 +978,11 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -1143,6 +1168,11 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -1377,6 +1407,11 @@ fn resolve_index_url() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -1619,6 +1654,11 @@ fn resolve_index_url() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -1919,6 +1959,11 @@ fn resolve_find_links() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -2150,6 +2195,11 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -2340,6 +2390,11 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -2580,6 +2635,11 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -2843,6 +2903,11 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -3023,6 +3088,11 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -3203,6 +3273,11 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -3385,6 +3460,11 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -3586,6 +3666,11 @@ fn resolve_tool() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     ToolInstallSettings {
         package: "requirements.in",
@@ -3782,6 +3867,11 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -3996,6 +4086,11 @@ fn resolve_both() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -4249,6 +4344,11 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -4581,6 +4681,11 @@ fn resolve_config_file() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -4888,6 +4993,11 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -5071,6 +5181,11 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -5273,6 +5388,11 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -5467,6 +5587,11 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -5709,6 +5834,11 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -5957,6 +6087,11 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -6200,6 +6335,11 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -6450,6 +6590,11 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -6693,6 +6838,11 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -6949,6 +7099,11 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipInstallSettings {
         package: [],
@@ -7122,6 +7277,11 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipInstallSettings {
         package: [],
@@ -7293,6 +7453,11 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipInstallSettings {
         package: [],
@@ -7466,6 +7631,11 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipInstallSettings {
         package: [],
@@ -7637,6 +7807,11 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipInstallSettings {
         package: [],
@@ -7809,6 +7984,11 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipInstallSettings {
         package: [],
@@ -7996,6 +8176,11 @@ fn preview_features() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     VersionSettings {
         value: None,
@@ -8115,6 +8300,11 @@ fn preview_features() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     VersionSettings {
         value: None,
@@ -8234,6 +8424,11 @@ fn preview_features() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     VersionSettings {
         value: None,
@@ -8353,6 +8548,11 @@ fn preview_features() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     VersionSettings {
         value: None,
@@ -8472,6 +8672,11 @@ fn preview_features() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     VersionSettings {
         value: None,
@@ -8593,6 +8798,11 @@ fn preview_features() {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     VersionSettings {
         value: None,
@@ -8733,6 +8943,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -8914,6 +9129,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -9118,6 +9338,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -9297,6 +9522,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -9470,6 +9700,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -9644,6 +9879,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -9883,11 +10123,25 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: None,
         dry_run: Disabled,
+        diff: None,
+        output_format: Text,
+        merge: None,
+        trace: None,
+        explain: None,
+        profile: None,
+        metadata_index: None,
+        platforms: [],
+        auto_conflicts: false,
         script: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -10007,11 +10261,25 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: None,
         dry_run: Disabled,
+        diff: None,
+        output_format: Text,
+        merge: None,
+        trace: None,
+        explain: None,
+        profile: None,
+        metadata_index: None,
+        platforms: [],
+        auto_conflicts: false,
         script: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -10154,11 +10422,25 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: None,
         dry_run: Disabled,
+        diff: None,
+        output_format: Text,
+        merge: None,
+        trace: None,
+        explain: None,
+        profile: None,
+        metadata_index: None,
+        platforms: [],
+        auto_conflicts: false,
         script: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -10276,11 +10558,25 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: None,
         dry_run: Disabled,
+        diff: None,
+        output_format: Text,
+        merge: None,
+        trace: None,
+        explain: None,
+        profile: None,
+        metadata_index: None,
+        platforms: [],
+        auto_conflicts: false,
         script: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -10388,11 +10684,25 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: None,
         dry_run: Disabled,
+        diff: None,
+        output_format: Text,
+        merge: None,
+        trace: None,
+        explain: None,
+        profile: None,
+        metadata_index: None,
+        platforms: [],
+        auto_conflicts: false,
         script: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -10501,11 +10811,25 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     LockSettings {
         lock_check: Disabled,
         frozen: None,
         dry_run: Disabled,
+        diff: None,
+        output_format: Text,
+        merge: None,
+        trace: None,
+        explain: None,
+        profile: None,
+        metadata_index: None,
+        platforms: [],
+        auto_conflicts: false,
         script: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
@@ -10678,6 +11002,11 @@ fn build_isolation_override() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
@@ -10854,6 +11183,11 @@ fn build_isolation_override() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        eviction_policy: EvictionPolicy {
+            max_size: None,
+            max_age: None,
+        },
+        remote_build_cache: None,
     }
     PipCompileSettings {
         format: None,
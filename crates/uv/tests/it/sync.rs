@@ -15567,3 +15567,87 @@ fn sync_reinstalls_on_version_change() -> Result<()> {
 
     Ok(())
 }
+
+/// Enforce the license policy against the lockfile before installing any packages.
+#[test]
+fn sync_license_policy() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["copyleft"]
+
+        [tool.uv.sources]
+        copyleft = { path = "copyleft" }
+
+        [tool.uv.license-policy]
+        deny = ["GPL-3.0-only"]
+    "#})?;
+
+    context
+        .temp_dir
+        .child("copyleft")
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "copyleft"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        license = "GPL-3.0-only"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync(), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+      × The locked dependencies violate the license policy:
+      ╰─▶ Because copyleft==0.1.0 is licensed under GPL-3.0-only, which is denied by the license policy, and project depends on copyleft, we can conclude that project's dependencies violate the license policy.
+      help: If the license of a package has been reviewed, add it to `tool.uv.license-policy.exceptions` to exempt it from the policy
+    ");
+
+    // The lockfile isn't written.
+    context
+        .temp_dir
+        .child("uv.lock")
+        .assert(predicate::path::missing());
+
+    // Lock the project without the policy.
+    let policy = context.read("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["copyleft"]
+
+        [tool.uv.sources]
+        copyleft = { path = "copyleft" }
+    "#})?;
+    context.lock().assert().success();
+    pyproject_toml.write_str(&policy)?;
+
+    // The policy is enforced against a frozen lockfile, too.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × The locked dependencies violate the license policy:
+      ╰─▶ Because copyleft==0.1.0 is licensed under GPL-3.0-only, which is denied by the license policy, and project depends on copyleft, we can conclude that project's dependencies violate the license policy.
+      help: If the license of a package has been reviewed, add it to `tool.uv.license-policy.exceptions` to exempt it from the policy
+    ");
+
+    Ok(())
+}
@@ -1799,3 +1799,242 @@ fn workspace_circular_dependencies() -> Result<()> {
 
     Ok(())
 }
+
+/// Write a project with a handwritten lockfile, for rendering the tree with `--frozen`.
+fn write_format_project(context: &TestContext) -> Result<()> {
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio"]
+
+        [dependency-groups]
+        dev = ["anyio", "iniconfig"]
+    "#})?;
+
+    context.temp_dir.child("uv.lock").write_str(indoc! {r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "anyio"
+        version = "4.3.0"
+        source = { registry = "https://pypi.org/simple" }
+        dependencies = [
+            { name = "idna" },
+            { name = "sniffio" },
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/anyio-4.3.0.tar.gz", hash = "sha256:1111111111111111111111111111111111111111111111111111111111111111", size = 1 }
+
+        [[package]]
+        name = "idna"
+        version = "3.6"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/idna-3.6.tar.gz", hash = "sha256:2222222222222222222222222222222222222222222222222222222222222222", size = 1 }
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/iniconfig-2.0.0.tar.gz", hash = "sha256:3333333333333333333333333333333333333333333333333333333333333333", size = 1 }
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "anyio" },
+        ]
+
+        [package.dev-dependencies]
+        dev = [
+            { name = "anyio" },
+            { name = "iniconfig" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "anyio" }]
+
+        [package.metadata.requires-dev]
+        dev = [
+            { name = "anyio" },
+            { name = "iniconfig" },
+        ]
+
+        [[package]]
+        name = "sniffio"
+        version = "1.3.1"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/sniffio-1.3.1.tar.gz", hash = "sha256:4444444444444444444444444444444444444444444444444444444444444444", size = 1 }
+    "#})?;
+
+    Ok(())
+}
+
+#[test]
+fn format_json() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    write_format_project(&context)?;
+
+    uv_snapshot!(context.filters(), context.tree().arg("--frozen").arg("--universal").arg("--format").arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [
+      {
+        "name": "project",
+        "version": "0.1.0",
+        "dependencies": [
+          {
+            "name": "anyio",
+            "version": "4.3.0",
+            "dependencies": [
+              {
+                "name": "idna",
+                "version": "3.6",
+                "dependencies": []
+              },
+              {
+                "name": "sniffio",
+                "version": "1.3.1",
+                "dependencies": []
+              }
+            ]
+          },
+          {
+            "name": "anyio",
+            "version": "4.3.0",
+            "group": "dev",
+            "deduplicated": true,
+            "dependencies": []
+          },
+          {
+            "name": "iniconfig",
+            "version": "2.0.0",
+            "group": "dev",
+            "dependencies": []
+          }
+        ]
+      }
+    ]
+
+    ----- stderr -----
+    "#);
+
+    // The depth limit applies to the JSON output.
+    uv_snapshot!(context.filters(), context.tree().arg("--frozen").arg("--universal").arg("--format").arg("json").arg("--depth").arg("1"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [
+      {
+        "name": "project",
+        "version": "0.1.0",
+        "dependencies": [
+          {
+            "name": "anyio",
+            "version": "4.3.0",
+            "dependencies": []
+          },
+          {
+            "name": "anyio",
+            "version": "4.3.0",
+            "group": "dev",
+            "deduplicated": true,
+            "dependencies": []
+          },
+          {
+            "name": "iniconfig",
+            "version": "2.0.0",
+            "group": "dev",
+            "dependencies": []
+          }
+        ]
+      }
+    ]
+
+    ----- stderr -----
+    "#);
+
+    Ok(())
+}
+
+#[test]
+fn format_dot() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    write_format_project(&context)?;
+
+    uv_snapshot!(context.filters(), context.tree().arg("--frozen").arg("--universal").arg("--format").arg("dot"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    digraph {
+        n0 [label="project v0.1.0"]
+        n1 [label="anyio v4.3.0"]
+        n2 [label="iniconfig v2.0.0"]
+        n3 [label="idna v3.6"]
+        n4 [label="sniffio v1.3.1"]
+        n0 -> n1
+        n0 -> n1 [label="group: dev"]
+        n0 -> n2 [label="group: dev"]
+        n1 -> n3
+        n1 -> n4
+    }
+
+    ----- stderr -----
+    "#);
+
+    Ok(())
+}
+
+#[test]
+fn format_mermaid() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    write_format_project(&context)?;
+
+    uv_snapshot!(context.filters(), context.tree().arg("--frozen").arg("--universal").arg("--format").arg("mermaid"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    flowchart TD
+        n0["project v0.1.0"]
+        n1["anyio v4.3.0"]
+        n2["iniconfig v2.0.0"]
+        n3["idna v3.6"]
+        n4["sniffio v1.3.1"]
+        n0 --> n1
+        n0 -->|"group: dev"| n1
+        n0 -->|"group: dev"| n2
+        n1 --> n3
+        n1 --> n4
+
+    ----- stderr -----
+    "#);
+
+    // Inverting the tree reverses the edges.
+    uv_snapshot!(context.filters(), context.tree().arg("--frozen").arg("--universal").arg("--format").arg("mermaid").arg("--invert"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    flowchart TD
+        n0["idna v3.6"]
+        n1["iniconfig v2.0.0"]
+        n2["sniffio v1.3.1"]
+        n3["anyio v4.3.0"]
+        n4["project v0.1.0"]
+        n0 --> n3
+        n1 -->|"group: dev"| n4
+        n2 --> n3
+        n3 --> n4
+        n3 -->|"group: dev"| n4
+
+    ----- stderr -----
+    "#);
+
+    Ok(())
+}
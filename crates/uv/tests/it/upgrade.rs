@@ -0,0 +1,148 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use insta::assert_snapshot;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Raise the lower bounds of the project's requirements, with and without an upgrade limit.
+#[test]
+fn upgrade() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio>=3.0"]
+
+        [dependency-groups]
+        dev = ["iniconfig>=1"]
+        "#,
+    )?;
+
+    // Lock an older version of `anyio`.
+    context
+        .lock()
+        .arg("--upgrade-package")
+        .arg("anyio==3.7.0")
+        .assert()
+        .success();
+
+    // Within the same major version, `anyio` can only be upgraded to the latest 3.x release.
+    uv_snapshot!(context.filters(), context.upgrade().arg("--limit").arg("minor").arg("--dry-run"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Update anyio v3.7.0 -> v3.7.1
+    Bump `anyio>=3.0` -> `anyio>=3.7.1` in `project.dependencies` (pyproject.toml)
+    Bump `iniconfig>=1` -> `iniconfig>=2.0.0` in `dependency-groups.dev` (pyproject.toml)
+    ");
+
+    // Only upgrade the development dependencies.
+    uv_snapshot!(context.filters(), context.upgrade().arg("--dev"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Bumped `iniconfig>=1` -> `iniconfig>=2.0.0` in `dependency-groups.dev` (pyproject.toml)
+    Resolved 5 packages in [TIME]
+    ");
+
+    // Upgrade the remaining requirements.
+    uv_snapshot!(context.filters(), context.upgrade(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Updated anyio v3.7.0 -> v4.3.0
+    Bumped `anyio>=3.0` -> `anyio>=4.3.0` in `project.dependencies` (pyproject.toml)
+    Resolved 5 packages in [TIME]
+    ");
+
+    assert_snapshot!(context.read("pyproject.toml"), @r#"
+    [project]
+    name = "project"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = ["anyio>=4.3.0"]
+
+    [dependency-groups]
+    dev = ["iniconfig>=2.0.0"]
+    "#);
+
+    // The lockfile is up-to-date with the new bounds.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    ");
+
+    uv_snapshot!(context.filters(), context.upgrade(), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    All requirement bounds are up-to-date
+    ");
+
+    Ok(())
+}
+
+/// The lockfile is always updated along with the requirements, so `--frozen` is rejected.
+#[test]
+fn upgrade_frozen() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio>=3.0", "idna~=3.0", "sniffio==1.3.1"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.upgrade().arg("--frozen"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: unexpected argument '--frozen' found
+
+      tip: to pass '--frozen' as a value, use '-- --frozen'
+
+    Usage: uv upgrade --cache-dir [CACHE_DIR] [PACKAGES]...
+
+    For more information, try '--help'.
+    ");
+
+    uv_snapshot!(context.filters(), context.upgrade().arg("iniconfig"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No requirement on `iniconfig` with a version specifier was found in the selected dependencies
+    ");
+
+    Ok(())
+}